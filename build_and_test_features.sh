@@ -28,4 +28,9 @@ done
 
 RUSTFLAGS='-C target-feature=+fma' cargo check
 
+RUSTFLAGS='-C target-feature=+avx' cargo test
+
 cargo check -p glam-no_std
+
+RUSTFLAGS='-C target-feature=+sse4.1,+fma' cargo test
+RUSTFLAGS='-C target-feature=+sse4.1,+fma' cargo test --features fast-math
@@ -9,6 +9,7 @@ enum Target {
     Sse2,
     Wasm32,
     CoreSimd,
+    Avx,
 }
 
 impl ContextBuilder {
@@ -57,6 +58,54 @@ impl ContextBuilder {
         Self::new_tvecn_swizzle_impl(4, "D")
     }
 
+    pub fn new_i8vec2_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(2, "I8")
+    }
+
+    pub fn new_i8vec3_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(3, "I8")
+    }
+
+    pub fn new_i8vec4_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(4, "I8")
+    }
+
+    pub fn new_u8vec2_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(2, "U8")
+    }
+
+    pub fn new_u8vec3_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(3, "U8")
+    }
+
+    pub fn new_u8vec4_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(4, "U8")
+    }
+
+    pub fn new_i16vec2_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(2, "I16")
+    }
+
+    pub fn new_i16vec3_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(3, "I16")
+    }
+
+    pub fn new_i16vec4_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(4, "I16")
+    }
+
+    pub fn new_u16vec2_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(2, "U16")
+    }
+
+    pub fn new_u16vec3_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(3, "U16")
+    }
+
+    pub fn new_u16vec4_swizzle_impl() -> Self {
+        Self::new_tvecn_swizzle_impl(4, "U16")
+    }
+
     pub fn new_ivec2_swizzle_impl() -> Self {
         Self::new_tvecn_swizzle_impl(2, "I")
     }
@@ -194,6 +243,66 @@ impl ContextBuilder {
         Self::new_vecn(4).with_scalar_t("f64")
     }
 
+    pub fn new_hvec2() -> Self {
+        Self::new_vecn(2).with_scalar_t("f16")
+    }
+
+    pub fn new_hvec3() -> Self {
+        Self::new_vecn(3).with_scalar_t("f16")
+    }
+
+    pub fn new_hvec4() -> Self {
+        Self::new_vecn(4).with_scalar_t("f16")
+    }
+
+    pub fn new_i8vec2() -> Self {
+        Self::new_vecn(2).with_scalar_t("i8")
+    }
+
+    pub fn new_i8vec3() -> Self {
+        Self::new_vecn(3).with_scalar_t("i8")
+    }
+
+    pub fn new_i8vec4() -> Self {
+        Self::new_vecn(4).with_scalar_t("i8")
+    }
+
+    pub fn new_u8vec2() -> Self {
+        Self::new_vecn(2).with_scalar_t("u8")
+    }
+
+    pub fn new_u8vec3() -> Self {
+        Self::new_vecn(3).with_scalar_t("u8")
+    }
+
+    pub fn new_u8vec4() -> Self {
+        Self::new_vecn(4).with_scalar_t("u8")
+    }
+
+    pub fn new_i16vec2() -> Self {
+        Self::new_vecn(2).with_scalar_t("i16")
+    }
+
+    pub fn new_i16vec3() -> Self {
+        Self::new_vecn(3).with_scalar_t("i16")
+    }
+
+    pub fn new_i16vec4() -> Self {
+        Self::new_vecn(4).with_scalar_t("i16")
+    }
+
+    pub fn new_u16vec2() -> Self {
+        Self::new_vecn(2).with_scalar_t("u16")
+    }
+
+    pub fn new_u16vec3() -> Self {
+        Self::new_vecn(3).with_scalar_t("u16")
+    }
+
+    pub fn new_u16vec4() -> Self {
+        Self::new_vecn(4).with_scalar_t("u16")
+    }
+
     pub fn new_ivec2() -> Self {
         Self::new_vecn(2).with_scalar_t("i32")
     }
@@ -218,6 +327,14 @@ impl ContextBuilder {
         Self::new_vecn(4).with_scalar_t("u32")
     }
 
+    pub fn new_ivec4a() -> Self {
+        Self::new_vecn(4).with_scalar_t("i32").with_is_align(true)
+    }
+
+    pub fn new_uvec4a() -> Self {
+        Self::new_vecn(4).with_scalar_t("u32").with_is_align(true)
+    }
+
     pub fn new_i64vec2() -> Self {
         Self::new_vecn(2).with_scalar_t("i64")
     }
@@ -253,6 +370,163 @@ impl ContextBuilder {
         Self::new_quat().with_scalar_t("f64")
     }
 
+    pub fn new_dualquat() -> Self {
+        ContextBuilder::new()
+            .with_template("dualquat.rs.tera")
+            .target_scalar()
+            .with_scalar_t("f32")
+    }
+
+    pub fn new_ddualquat() -> Self {
+        Self::new_dualquat().with_scalar_t("f64")
+    }
+
+    fn new_taabbn(dim: u32, scalar_t: &str) -> Self {
+        ContextBuilder::new()
+            .with_template("aabb.rs.tera")
+            .target_scalar()
+            .with_scalar_t(scalar_t)
+            .with_dimension(dim)
+    }
+
+    pub fn new_aabb2() -> Self {
+        Self::new_taabbn(2, "f32")
+    }
+
+    pub fn new_aabb3() -> Self {
+        Self::new_taabbn(3, "f32")
+    }
+
+    pub fn new_aabb3a() -> Self {
+        Self::new_taabbn(3, "f32").with_is_align(true)
+    }
+
+    pub fn new_daabb3() -> Self {
+        Self::new_taabbn(3, "f64")
+    }
+
+    pub fn new_irect() -> Self {
+        Self::new_taabbn(2, "i32")
+    }
+
+    pub fn new_iaabb3() -> Self {
+        Self::new_taabbn(3, "i32")
+    }
+
+    pub fn new_ray3() -> Self {
+        ContextBuilder::new()
+            .with_template("ray.rs.tera")
+            .target_scalar()
+            .with_scalar_t("f32")
+    }
+
+    pub fn new_dray3() -> Self {
+        Self::new_ray3().with_scalar_t("f64")
+    }
+
+    pub fn new_plane3() -> Self {
+        ContextBuilder::new()
+            .with_template("plane.rs.tera")
+            .target_scalar()
+            .with_scalar_t("f32")
+    }
+
+    pub fn new_dplane3() -> Self {
+        Self::new_plane3().with_scalar_t("f64")
+    }
+
+    pub fn new_sphere() -> Self {
+        ContextBuilder::new()
+            .with_template("sphere.rs.tera")
+            .target_scalar()
+            .with_scalar_t("f32")
+    }
+
+    pub fn new_dsphere() -> Self {
+        Self::new_sphere().with_scalar_t("f64")
+    }
+
+    pub fn new_frustum() -> Self {
+        ContextBuilder::new()
+            .with_template("frustum.rs.tera")
+            .target_scalar()
+            .with_scalar_t("f32")
+    }
+
+    pub fn new_dfrustum() -> Self {
+        Self::new_frustum().with_scalar_t("f64")
+    }
+
+    fn new_angle(self_t: &str, scalar_t: &str) -> Self {
+        ContextBuilder::new()
+            .with_template("angle.rs.tera")
+            .target_scalar()
+            .with_scalar_t(scalar_t)
+            .with_self_t(self_t)
+    }
+
+    pub fn new_radians() -> Self {
+        Self::new_angle("Radians", "f32")
+    }
+
+    pub fn new_degrees() -> Self {
+        Self::new_angle("Degrees", "f32")
+    }
+
+    pub fn new_dradians() -> Self {
+        Self::new_angle("DRadians", "f64")
+    }
+
+    pub fn new_ddegrees() -> Self {
+        Self::new_angle("DDegrees", "f64")
+    }
+
+    pub fn new_rot2() -> Self {
+        ContextBuilder::new()
+            .with_template("rot2.rs.tera")
+            .target_scalar()
+            .with_scalar_t("f32")
+    }
+
+    pub fn new_drot2() -> Self {
+        Self::new_rot2().with_scalar_t("f64")
+    }
+
+    fn new_tisometryn(dim: u32, scalar_t: &str) -> Self {
+        ContextBuilder::new()
+            .with_template("isometry.rs.tera")
+            .target_scalar()
+            .with_scalar_t(scalar_t)
+            .with_dimension(dim)
+    }
+
+    pub fn new_isometry2() -> Self {
+        Self::new_tisometryn(2, "f32")
+    }
+
+    pub fn new_isometry3() -> Self {
+        Self::new_tisometryn(3, "f32")
+    }
+
+    pub fn new_disometry2() -> Self {
+        Self::new_tisometryn(2, "f64")
+    }
+
+    pub fn new_disometry3() -> Self {
+        Self::new_tisometryn(3, "f64")
+    }
+
+    pub fn new_similarity3() -> Self {
+        ContextBuilder::new()
+            .with_template("similarity.rs.tera")
+            .target_scalar()
+            .with_scalar_t("f32")
+    }
+
+    pub fn new_dsimilarity3() -> Self {
+        Self::new_similarity3().with_scalar_t("f64")
+    }
+
     fn new_tmatn(dim: u32, scalar_t: &str) -> Self {
         ContextBuilder::new()
             .with_template("mat.rs.tera")
@@ -289,6 +563,42 @@ impl ContextBuilder {
         Self::new_tmatn(4, "f64")
     }
 
+    fn new_tmatcxr(cols: u32, rows: u32, scalar_t: &str) -> Self {
+        Self::new_tmatn(cols, scalar_t).with_key_val("rows", &rows)
+    }
+
+    pub fn new_mat2x3() -> Self {
+        Self::new_tmatcxr(2, 3, "f32")
+    }
+
+    pub fn new_mat3x2() -> Self {
+        Self::new_tmatcxr(3, 2, "f32")
+    }
+
+    pub fn new_mat3x4() -> Self {
+        Self::new_tmatcxr(3, 4, "f32")
+    }
+
+    pub fn new_mat4x3() -> Self {
+        Self::new_tmatcxr(4, 3, "f32")
+    }
+
+    pub fn new_dmat2x3() -> Self {
+        Self::new_tmatcxr(2, 3, "f64")
+    }
+
+    pub fn new_dmat3x2() -> Self {
+        Self::new_tmatcxr(3, 2, "f64")
+    }
+
+    pub fn new_dmat3x4() -> Self {
+        Self::new_tmatcxr(3, 4, "f64")
+    }
+
+    pub fn new_dmat4x3() -> Self {
+        Self::new_tmatcxr(4, 3, "f64")
+    }
+
     pub fn with_template(mut self, template_path: &str) -> Self {
         self.0.insert("template_path", template_path);
         self
@@ -304,6 +614,7 @@ impl ContextBuilder {
         self.0.insert("is_coresimd", &(target == Target::CoreSimd));
         self.0.insert("is_wasm32", &(target == Target::Wasm32));
         self.0.insert("is_scalar", &(target == Target::Scalar));
+        self.0.insert("is_avx", &(target == Target::Avx));
         self
     }
 
@@ -323,6 +634,10 @@ impl ContextBuilder {
         self.with_target(Target::CoreSimd)
     }
 
+    pub fn target_avx(self) -> Self {
+        self.with_target(Target::Avx)
+    }
+
     fn with_self_t(mut self, self_t: &str) -> Self {
         self.0.insert("self_t", self_t);
         self
@@ -420,6 +735,54 @@ pub fn build_output_pairs() -> HashMap<&'static str, tera::Context> {
             "src/swizzles/dvec4_impl.rs",
             ContextBuilder::new_dvec4_swizzle_impl().build(),
         ),
+        (
+            "src/swizzles/i8vec2_impl.rs",
+            ContextBuilder::new_i8vec2_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/i8vec3_impl.rs",
+            ContextBuilder::new_i8vec3_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/i8vec4_impl.rs",
+            ContextBuilder::new_i8vec4_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/u8vec2_impl.rs",
+            ContextBuilder::new_u8vec2_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/u8vec3_impl.rs",
+            ContextBuilder::new_u8vec3_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/u8vec4_impl.rs",
+            ContextBuilder::new_u8vec4_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/i16vec2_impl.rs",
+            ContextBuilder::new_i16vec2_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/i16vec3_impl.rs",
+            ContextBuilder::new_i16vec3_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/i16vec4_impl.rs",
+            ContextBuilder::new_i16vec4_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/u16vec2_impl.rs",
+            ContextBuilder::new_u16vec2_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/u16vec3_impl.rs",
+            ContextBuilder::new_u16vec3_swizzle_impl().build(),
+        ),
+        (
+            "src/swizzles/u16vec4_impl.rs",
+            ContextBuilder::new_u16vec4_swizzle_impl().build(),
+        ),
         (
             "src/swizzles/ivec2_impl.rs",
             ContextBuilder::new_ivec2_swizzle_impl().build(),
@@ -547,15 +910,68 @@ pub fn build_output_pairs() -> HashMap<&'static str, tera::Context> {
             "src/f32/coresimd/vec4.rs",
             ContextBuilder::new_vec4().target_coresimd().build(),
         ),
-        ("src/f64/dvec2.rs", ContextBuilder::new_dvec2().build()),
+        (
+            "src/f64/scalar/dvec2.rs",
+            ContextBuilder::new_dvec2().build(),
+        ),
+        (
+            "src/f64/avx/dvec2.rs",
+            ContextBuilder::new_dvec2().target_avx().build(),
+        ),
         ("src/f64/dvec3.rs", ContextBuilder::new_dvec3().build()),
-        ("src/f64/dvec4.rs", ContextBuilder::new_dvec4().build()),
+        (
+            "src/f64/scalar/dvec4.rs",
+            ContextBuilder::new_dvec4().build(),
+        ),
+        (
+            "src/f64/avx/dvec4.rs",
+            ContextBuilder::new_dvec4().target_avx().build(),
+        ),
+        ("src/f16/hvec2.rs", ContextBuilder::new_hvec2().build()),
+        ("src/f16/hvec3.rs", ContextBuilder::new_hvec3().build()),
+        ("src/f16/hvec4.rs", ContextBuilder::new_hvec4().build()),
+        ("src/i8/i8vec2.rs", ContextBuilder::new_i8vec2().build()),
+        ("src/i8/i8vec3.rs", ContextBuilder::new_i8vec3().build()),
+        ("src/i8/i8vec4.rs", ContextBuilder::new_i8vec4().build()),
+        ("src/u8/u8vec2.rs", ContextBuilder::new_u8vec2().build()),
+        ("src/u8/u8vec3.rs", ContextBuilder::new_u8vec3().build()),
+        ("src/u8/u8vec4.rs", ContextBuilder::new_u8vec4().build()),
+        ("src/i16/i16vec2.rs", ContextBuilder::new_i16vec2().build()),
+        ("src/i16/i16vec3.rs", ContextBuilder::new_i16vec3().build()),
+        ("src/i16/i16vec4.rs", ContextBuilder::new_i16vec4().build()),
+        ("src/u16/u16vec2.rs", ContextBuilder::new_u16vec2().build()),
+        ("src/u16/u16vec3.rs", ContextBuilder::new_u16vec3().build()),
+        ("src/u16/u16vec4.rs", ContextBuilder::new_u16vec4().build()),
         ("src/i32/ivec2.rs", ContextBuilder::new_ivec2().build()),
         ("src/i32/ivec3.rs", ContextBuilder::new_ivec3().build()),
         ("src/i32/ivec4.rs", ContextBuilder::new_ivec4().build()),
         ("src/u32/uvec2.rs", ContextBuilder::new_uvec2().build()),
         ("src/u32/uvec3.rs", ContextBuilder::new_uvec3().build()),
         ("src/u32/uvec4.rs", ContextBuilder::new_uvec4().build()),
+        (
+            "src/i32/scalar/ivec4a.rs",
+            ContextBuilder::new_ivec4a().build(),
+        ),
+        (
+            "src/i32/sse2/ivec4a.rs",
+            ContextBuilder::new_ivec4a().target_sse2().build(),
+        ),
+        (
+            "src/i32/coresimd/ivec4a.rs",
+            ContextBuilder::new_ivec4a().target_coresimd().build(),
+        ),
+        (
+            "src/u32/scalar/uvec4a.rs",
+            ContextBuilder::new_uvec4a().build(),
+        ),
+        (
+            "src/u32/sse2/uvec4a.rs",
+            ContextBuilder::new_uvec4a().target_sse2().build(),
+        ),
+        (
+            "src/u32/coresimd/uvec4a.rs",
+            ContextBuilder::new_uvec4a().target_coresimd().build(),
+        ),
         ("src/i64/i64vec2.rs", ContextBuilder::new_i64vec2().build()),
         ("src/i64/i64vec3.rs", ContextBuilder::new_i64vec3().build()),
         ("src/i64/i64vec4.rs", ContextBuilder::new_i64vec4().build()),
@@ -575,7 +991,75 @@ pub fn build_output_pairs() -> HashMap<&'static str, tera::Context> {
             "src/f32/coresimd/quat.rs",
             ContextBuilder::new_quat().target_coresimd().build(),
         ),
-        ("src/f64/dquat.rs", ContextBuilder::new_dquat().build()),
+        (
+            "src/f64/scalar/dquat.rs",
+            ContextBuilder::new_dquat().build(),
+        ),
+        (
+            "src/f64/avx/dquat.rs",
+            ContextBuilder::new_dquat().target_avx().build(),
+        ),
+        ("src/f32/aabb2.rs", ContextBuilder::new_aabb2().build()),
+        ("src/f32/aabb3.rs", ContextBuilder::new_aabb3().build()),
+        ("src/f32/aabb3a.rs", ContextBuilder::new_aabb3a().build()),
+        ("src/f64/daabb3.rs", ContextBuilder::new_daabb3().build()),
+        ("src/i32/irect.rs", ContextBuilder::new_irect().build()),
+        ("src/i32/iaabb3.rs", ContextBuilder::new_iaabb3().build()),
+        (
+            "src/f32/dualquat.rs",
+            ContextBuilder::new_dualquat().build(),
+        ),
+        (
+            "src/f64/ddualquat.rs",
+            ContextBuilder::new_ddualquat().build(),
+        ),
+        (
+            "src/f32/isometry2.rs",
+            ContextBuilder::new_isometry2().build(),
+        ),
+        (
+            "src/f32/isometry3.rs",
+            ContextBuilder::new_isometry3().build(),
+        ),
+        (
+            "src/f64/disometry2.rs",
+            ContextBuilder::new_disometry2().build(),
+        ),
+        (
+            "src/f64/disometry3.rs",
+            ContextBuilder::new_disometry3().build(),
+        ),
+        ("src/f32/frustum.rs", ContextBuilder::new_frustum().build()),
+        (
+            "src/f64/dfrustum.rs",
+            ContextBuilder::new_dfrustum().build(),
+        ),
+        ("src/f32/plane3.rs", ContextBuilder::new_plane3().build()),
+        ("src/f64/dplane3.rs", ContextBuilder::new_dplane3().build()),
+        ("src/f32/ray3.rs", ContextBuilder::new_ray3().build()),
+        ("src/f64/dray3.rs", ContextBuilder::new_dray3().build()),
+        ("src/f32/radians.rs", ContextBuilder::new_radians().build()),
+        ("src/f32/degrees.rs", ContextBuilder::new_degrees().build()),
+        (
+            "src/f64/dradians.rs",
+            ContextBuilder::new_dradians().build(),
+        ),
+        (
+            "src/f64/ddegrees.rs",
+            ContextBuilder::new_ddegrees().build(),
+        ),
+        ("src/f32/rot2.rs", ContextBuilder::new_rot2().build()),
+        ("src/f64/drot2.rs", ContextBuilder::new_drot2().build()),
+        ("src/f32/sphere.rs", ContextBuilder::new_sphere().build()),
+        ("src/f64/dsphere.rs", ContextBuilder::new_dsphere().build()),
+        (
+            "src/f32/similarity3.rs",
+            ContextBuilder::new_similarity3().build(),
+        ),
+        (
+            "src/f64/dsimilarity3.rs",
+            ContextBuilder::new_dsimilarity3().build(),
+        ),
         ("src/f32/scalar/mat2.rs", ContextBuilder::new_mat2().build()),
         (
             "src/f32/sse2/mat2.rs",
@@ -622,5 +1106,13 @@ pub fn build_output_pairs() -> HashMap<&'static str, tera::Context> {
         ),
         ("src/f64/dmat3.rs", ContextBuilder::new_dmat3().build()),
         ("src/f64/dmat4.rs", ContextBuilder::new_dmat4().build()),
+        ("src/f32/mat2x3.rs", ContextBuilder::new_mat2x3().build()),
+        ("src/f32/mat3x2.rs", ContextBuilder::new_mat3x2().build()),
+        ("src/f32/mat3x4.rs", ContextBuilder::new_mat3x4().build()),
+        ("src/f32/mat4x3.rs", ContextBuilder::new_mat4x3().build()),
+        ("src/f64/dmat2x3.rs", ContextBuilder::new_dmat2x3().build()),
+        ("src/f64/dmat3x2.rs", ContextBuilder::new_dmat3x2().build()),
+        ("src/f64/dmat3x4.rs", ContextBuilder::new_dmat3x4().build()),
+        ("src/f64/dmat4x3.rs", ContextBuilder::new_dmat4x3().build()),
     ])
 }
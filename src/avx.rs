@@ -0,0 +1,82 @@
+use crate::{BVec2, BVec4};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(C)]
+union UnionCast {
+    f64x4: [f64; 4],
+    f32x8: [f32; 8],
+    u32x8: [u32; 8],
+    m256d: __m256d,
+    m256: __m256,
+}
+
+pub const fn m256d_from_f64x4(f64x4: [f64; 4]) -> __m256d {
+    unsafe { UnionCast { f64x4 }.m256d }
+}
+
+pub const fn m256_from_f32x8(f32x8: [f32; 8]) -> __m256 {
+    unsafe { UnionCast { f32x8 }.m256 }
+}
+
+pub(crate) const fn m256_from_u32x8(u32x8: [u32; 8]) -> __m256 {
+    unsafe { UnionCast { u32x8 }.m256 }
+}
+
+/// Calculates the vector 2 dot product.
+///
+/// The lanes are multiplied in parallel and summed in `x, y` order so the result matches the
+/// scalar implementation exactly.
+#[inline]
+pub(crate) unsafe fn dot2(lhs: __m128d, rhs: __m128d) -> f64 {
+    let x2_y2 = _mm_mul_pd(lhs, rhs);
+    let y2_y2 = _mm_unpackhi_pd(x2_y2, x2_y2);
+    _mm_cvtsd_f64(x2_y2) + _mm_cvtsd_f64(y2_y2)
+}
+
+/// Calculates the vector 4 dot product.
+///
+/// The lanes are multiplied in parallel and summed in `x, y, z, w` order so the result matches
+/// the scalar implementation exactly.
+#[inline]
+pub(crate) unsafe fn dot4(lhs: __m256d, rhs: __m256d) -> f64 {
+    let x2_y2_z2_w2 = _mm256_mul_pd(lhs, rhs);
+    let x2_y2 = _mm256_castpd256_pd128(x2_y2_z2_w2);
+    let z2_w2 = _mm256_extractf128_pd(x2_y2_z2_w2, 1);
+    let y2_y2 = _mm_unpackhi_pd(x2_y2, x2_y2);
+    let w2_w2 = _mm_unpackhi_pd(z2_w2, z2_w2);
+    _mm_cvtsd_f64(x2_y2) + _mm_cvtsd_f64(y2_y2) + _mm_cvtsd_f64(z2_w2) + _mm_cvtsd_f64(w2_w2)
+}
+
+/// Converts the result of `_mm_movemask_pd` into a `BVec2`.
+#[inline(always)]
+pub(crate) fn bvec2_from_bitmask(bitmask: i32) -> BVec2 {
+    BVec2::new(bitmask & 0x1 != 0, bitmask & 0x2 != 0)
+}
+
+/// Converts the result of `_mm256_movemask_pd` into a `BVec4`.
+#[inline(always)]
+pub(crate) fn bvec4_from_bitmask(bitmask: i32) -> BVec4 {
+    BVec4::new(
+        bitmask & 0x1 != 0,
+        bitmask & 0x2 != 0,
+        bitmask & 0x4 != 0,
+        bitmask & 0x8 != 0,
+    )
+}
+
+#[test]
+fn test_dot() {
+    unsafe {
+        let a = m256d_from_f64x4([1.0, 2.0, 3.0, 4.0]);
+        let b = m256d_from_f64x4([5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(70.0, dot4(a, b));
+        assert_eq!(
+            17.0,
+            dot2(_mm256_castpd256_pd128(a), _mm256_castpd256_pd128(b))
+        );
+    }
+}
@@ -0,0 +1,750 @@
+/*!
+Parametric curve segments and splines.
+
+The segment types are generic over the vector type and are implemented for [`Vec2`], [`Vec3`],
+[`Vec3A`] and [`DVec3`]:
+
+* [`QuadraticBezier`] and [`CubicBezier`], defined by their control points.
+* [`Hermite`], defined by its end points and the tangents at the end points.
+* [`CatmullRom`], which passes through its two inner points, with
+  [`Parameterization::Uniform`] or [`Parameterization::Centripetal`] knot parameterization.
+  Centripetal segments do not form cusps or self-intersections within a segment.
+* [`BSpline`], a uniform cubic B-spline segment, which does not pass through its control points
+  but is twice continuously differentiable when chained.
+
+Every segment is parameterized over `[0, 1]` and can be evaluated with `position`, `velocity` and
+`acceleration`, the first and second derivatives with respect to the parameter. Bézier and
+Hermite segments can be split into two segments of the same type, the other cubic segment types
+can be converted to a [`CubicBezier`] first.
+
+[`CubicSpline`] joins a slice of [`CubicBezier`] segments into a single curve, with queries for
+the arc length, the parameter at a given arc length and the closest point on the curve.
+
+```
+use glam::curves::{CatmullRom, CubicBezier, CubicSpline};
+use glam::Vec3;
+
+let points = [
+    Vec3::new(0.0, 0.0, 0.0),
+    Vec3::new(1.0, 0.0, 0.0),
+    Vec3::new(2.0, 1.0, 0.0),
+    Vec3::new(3.0, 1.0, 1.0),
+    Vec3::new(4.0, 0.0, 1.0),
+];
+// A curve through the inner points.
+let segments: Vec<CubicBezier<Vec3>> = points
+    .windows(4)
+    .map(|w| CatmullRom::centripetal(w[0], w[1], w[2], w[3]).to_cubic_bezier())
+    .collect();
+let spline = CubicSpline::new(&segments);
+
+assert!(spline.position(0.0).abs_diff_eq(points[1], 1e-6));
+assert!(spline.position(2.0).abs_diff_eq(points[3], 1e-6));
+
+// Move along the curve at a constant speed.
+let t = spline.param_at_length(0.5 * spline.length());
+let midpoint = spline.position(t);
+```
+
+Arc lengths are computed with Gauss-Legendre quadrature and are approximate. The length of a
+[`CubicSpline`] is recomputed by every query, so cache the result of [`CubicSpline::length()`]
+when it is used often.
+*/
+
+use crate::{DVec3, Vec2, Vec3, Vec3A};
+
+/// Nodes and weights of the 5 point Gauss-Legendre quadrature rule on `[-1, 1]`.
+const GAUSS_LEGENDRE_5: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_889),
+    (-0.538_469_310_105_683, 0.478_628_670_499_366),
+    (0.538_469_310_105_683, 0.478_628_670_499_366),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189),
+    (0.906_179_845_938_664, 0.236_926_885_056_189),
+];
+
+/// The number of intervals each segment is split into for arc length quadrature.
+const ARC_LENGTH_INTERVALS: usize = 8;
+
+/// The number of evenly spaced samples used to find a starting point for closest point
+/// queries.
+const CLOSEST_POINT_SAMPLES: usize = 16;
+
+/// The maximum number of Newton iterations used by numerical queries.
+const MAX_ITERATIONS: usize = 16;
+
+/// A quadratic Bézier curve segment.
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub struct QuadraticBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+}
+
+impl<V> QuadraticBezier<V> {
+    /// Creates a segment from its control points.
+    #[inline(always)]
+    pub const fn new(p0: V, p1: V, p2: V) -> Self {
+        Self { p0, p1, p2 }
+    }
+}
+
+/// A cubic Bézier curve segment.
+///
+/// The segment starts at `p0` in the direction of `p1` and ends at `p3` coming from the
+/// direction of `p2`.
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub struct CubicBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+    pub p3: V,
+}
+
+impl<V> CubicBezier<V> {
+    /// Creates a segment from its control points.
+    #[inline(always)]
+    pub const fn new(p0: V, p1: V, p2: V, p3: V) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+}
+
+/// A cubic Hermite curve segment from `p0` to `p1` with the tangents `v0` and `v1`.
+///
+/// The tangents are the velocities at the end points with respect to the `[0, 1]` parameter.
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub struct Hermite<V> {
+    pub p0: V,
+    pub v0: V,
+    pub p1: V,
+    pub v1: V,
+}
+
+impl<V> Hermite<V> {
+    /// Creates a segment from its end points and tangents.
+    #[inline(always)]
+    pub const fn new(p0: V, v0: V, p1: V, v1: V) -> Self {
+        Self { p0, v0, p1, v1 }
+    }
+}
+
+/// The knot parameterization of a [`CatmullRom`] segment.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub enum Parameterization {
+    /// Evenly spaced knots.
+    Uniform,
+    /// Knots spaced by the square root of the distance between the points.
+    Centripetal,
+}
+
+impl Default for Parameterization {
+    fn default() -> Self {
+        Self::Uniform
+    }
+}
+
+/// A Catmull-Rom curve segment from `p1` to `p2`.
+///
+/// The outer points `p0` and `p3` determine the tangents at `p1` and `p2`, so chaining segments
+/// over a sliding window of points gives a curve through all points except the first and last.
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub struct CatmullRom<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+    pub p3: V,
+    pub parameterization: Parameterization,
+}
+
+impl<V> CatmullRom<V> {
+    /// Creates a segment with [`Parameterization::Uniform`].
+    #[inline(always)]
+    pub const fn new(p0: V, p1: V, p2: V, p3: V) -> Self {
+        Self {
+            p0,
+            p1,
+            p2,
+            p3,
+            parameterization: Parameterization::Uniform,
+        }
+    }
+
+    /// Creates a segment with [`Parameterization::Centripetal`].
+    #[inline(always)]
+    pub const fn centripetal(p0: V, p1: V, p2: V, p3: V) -> Self {
+        Self {
+            p0,
+            p1,
+            p2,
+            p3,
+            parameterization: Parameterization::Centripetal,
+        }
+    }
+}
+
+/// A uniform cubic B-spline curve segment.
+///
+/// The segment starts near `p1` and ends near `p2` but does not pass through any of its control
+/// points. Chaining segments over a sliding window of points gives a curve with continuous
+/// position, velocity and acceleration.
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub struct BSpline<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+    pub p3: V,
+}
+
+impl<V> BSpline<V> {
+    /// Creates a segment from its control points.
+    #[inline(always)]
+    pub const fn new(p0: V, p1: V, p2: V, p3: V) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+}
+
+/// A curve made of a sequence of [`CubicBezier`] segments.
+///
+/// The curve is parameterized over `[0, n]` where `n` is the number of segments, segment `i`
+/// covers the range `[i, i + 1]`. Parameters outside of this range are clamped.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub struct CubicSpline<'a, V> {
+    segments: &'a [CubicBezier<V>],
+}
+
+impl<'a, V> CubicSpline<'a, V> {
+    /// Creates a spline from a slice of segments.
+    ///
+    /// The segments are not required to be connected, but usually the end of each segment is
+    /// the start of the next.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `segments` is empty.
+    #[inline]
+    pub fn new(segments: &'a [CubicBezier<V>]) -> Self {
+        assert!(!segments.is_empty());
+        Self { segments }
+    }
+
+    /// Returns the segments of the spline.
+    #[inline]
+    pub fn segments(&self) -> &'a [CubicBezier<V>] {
+        self.segments
+    }
+}
+
+/// Sums four points scaled by basis function weights.
+trait WeightedSum: Sized {
+    type Scalar;
+
+    fn weighted_sum(points: [Self; 4], weights: [Self::Scalar; 4]) -> Self;
+}
+
+macro_rules! impl_curves {
+    ($t:ident, $vec:ident) => {
+        impl WeightedSum for $vec {
+            type Scalar = $t;
+
+            #[inline]
+            fn weighted_sum(p: [$vec; 4], w: [$t; 4]) -> $vec {
+                p[0].mul_add(
+                    $vec::splat(w[0]),
+                    p[1].mul_add(
+                        $vec::splat(w[1]),
+                        p[2].mul_add($vec::splat(w[2]), p[3] * w[3]),
+                    ),
+                )
+            }
+        }
+
+        impl QuadraticBezier<$vec> {
+            /// Returns the position at the parameter `t`.
+            #[inline]
+            pub fn position(&self, t: $t) -> $vec {
+                let a = self.p0.lerp(self.p1, t);
+                let b = self.p1.lerp(self.p2, t);
+                a.lerp(b, t)
+            }
+
+            /// Returns the first derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn velocity(&self, t: $t) -> $vec {
+                (self.p1 - self.p0).lerp(self.p2 - self.p1, t) * 2.0
+            }
+
+            /// Returns the second derivative with respect to the parameter, which is constant.
+            #[inline]
+            pub fn acceleration(&self, _t: $t) -> $vec {
+                (self.p2 - self.p1 * 2.0 + self.p0) * 2.0
+            }
+
+            /// Splits the segment at the parameter `t` into two segments covering `[0, t]` and
+            /// `[t, 1]`.
+            #[inline]
+            pub fn split(&self, t: $t) -> (Self, Self) {
+                let a = self.p0.lerp(self.p1, t);
+                let b = self.p1.lerp(self.p2, t);
+                let m = a.lerp(b, t);
+                (Self::new(self.p0, a, m), Self::new(m, b, self.p2))
+            }
+
+            /// Returns the same curve as a [`CubicBezier`] segment.
+            #[inline]
+            pub fn to_cubic_bezier(&self) -> CubicBezier<$vec> {
+                CubicBezier::new(
+                    self.p0,
+                    self.p0.lerp(self.p1, 2.0 / 3.0),
+                    self.p2.lerp(self.p1, 2.0 / 3.0),
+                    self.p2,
+                )
+            }
+        }
+
+        impl CubicBezier<$vec> {
+            /// Returns the position at the parameter `t`.
+            #[inline]
+            pub fn position(&self, t: $t) -> $vec {
+                let a = self.p0.lerp(self.p1, t);
+                let b = self.p1.lerp(self.p2, t);
+                let c = self.p2.lerp(self.p3, t);
+                let ab = a.lerp(b, t);
+                let bc = b.lerp(c, t);
+                ab.lerp(bc, t)
+            }
+
+            /// Returns the first derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn velocity(&self, t: $t) -> $vec {
+                QuadraticBezier::new(self.p1 - self.p0, self.p2 - self.p1, self.p3 - self.p2)
+                    .position(t)
+                    * 3.0
+            }
+
+            /// Returns the second derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn acceleration(&self, t: $t) -> $vec {
+                let a = self.p2 - self.p1 * 2.0 + self.p0;
+                let b = self.p3 - self.p2 * 2.0 + self.p1;
+                a.lerp(b, t) * 6.0
+            }
+
+            /// Splits the segment at the parameter `t` into two segments covering `[0, t]` and
+            /// `[t, 1]`.
+            #[inline]
+            pub fn split(&self, t: $t) -> (Self, Self) {
+                let a = self.p0.lerp(self.p1, t);
+                let b = self.p1.lerp(self.p2, t);
+                let c = self.p2.lerp(self.p3, t);
+                let ab = a.lerp(b, t);
+                let bc = b.lerp(c, t);
+                let m = ab.lerp(bc, t);
+                (Self::new(self.p0, a, ab, m), Self::new(m, bc, c, self.p3))
+            }
+
+            /// Returns the same curve as a [`Hermite`] segment.
+            #[inline]
+            pub fn to_hermite(&self) -> Hermite<$vec> {
+                Hermite::new(
+                    self.p0,
+                    (self.p1 - self.p0) * 3.0,
+                    self.p3,
+                    (self.p3 - self.p2) * 3.0,
+                )
+            }
+
+            /// Returns the approximate arc length of the segment.
+            #[inline]
+            pub fn length(&self) -> $t {
+                self.arc_length(1.0)
+            }
+
+            /// Returns the approximate arc length of the segment over the parameter range
+            /// `[0, t]`.
+            fn arc_length(&self, t: $t) -> $t {
+                let h = t / ARC_LENGTH_INTERVALS as $t;
+                let mut length = 0.0;
+                for i in 0..ARC_LENGTH_INTERVALS {
+                    let mid = (i as $t + 0.5) * h;
+                    for &(x, w) in &GAUSS_LEGENDRE_5 {
+                        let speed = self.velocity(mid + 0.5 * h * x as $t).length();
+                        length += w as $t * speed;
+                    }
+                }
+                length * 0.5 * h
+            }
+
+            /// Returns the parameter at which the approximate arc length from the start of the
+            /// segment is `length`.
+            ///
+            /// The result is clamped to `[0, 1]`.
+            pub fn param_at_length(&self, length: $t) -> $t {
+                let total = self.length();
+                if length <= 0.0 {
+                    return 0.0;
+                }
+                if length >= total {
+                    return 1.0;
+                }
+                let tolerance = total * $t::EPSILON * 4.0;
+                let (mut lo, mut hi) = (0.0, 1.0);
+                let mut t = length / total;
+                for _ in 0..MAX_ITERATIONS {
+                    let error = self.arc_length(t) - length;
+                    if crate::$t::math::abs(error) <= tolerance {
+                        break;
+                    }
+                    if error > 0.0 {
+                        hi = t;
+                    } else {
+                        lo = t;
+                    }
+                    // Newton step, falling back to bisection when it leaves the bracket
+                    let next = t - error / self.velocity(t).length();
+                    t = if next > lo && next < hi {
+                        next
+                    } else {
+                        0.5 * (lo + hi)
+                    };
+                }
+                t
+            }
+
+            /// Returns the parameter of the point on the segment closest to `point`.
+            ///
+            /// The search starts from the closest of several evenly spaced samples, so the
+            /// result is the global minimum unless the curve passes very close to `point`
+            /// more than once.
+            pub fn closest_param(&self, point: $vec) -> $t {
+                let mut best_t = 0.0;
+                let mut best_d = self.p0.distance_squared(point);
+                for i in 1..=CLOSEST_POINT_SAMPLES {
+                    let t = i as $t / CLOSEST_POINT_SAMPLES as $t;
+                    let d = self.position(t).distance_squared(point);
+                    if d < best_d {
+                        best_t = t;
+                        best_d = d;
+                    }
+                }
+
+                // Newton iterations on the derivative of the squared distance
+                let mut t = best_t;
+                for _ in 0..MAX_ITERATIONS {
+                    let offset = self.position(t) - point;
+                    let velocity = self.velocity(t);
+                    let slope = offset.dot(velocity);
+                    let curvature = velocity.dot(velocity) + offset.dot(self.acceleration(t));
+                    if curvature <= 0.0 {
+                        break;
+                    }
+                    let next = (t - slope / curvature).clamp(0.0, 1.0);
+                    let done = crate::$t::math::abs(next - t) <= $t::EPSILON;
+                    t = next;
+                    if done {
+                        break;
+                    }
+                }
+                if self.position(t).distance_squared(point) < best_d {
+                    t
+                } else {
+                    best_t
+                }
+            }
+
+            /// Returns the point on the segment closest to `point`.
+            ///
+            /// See [`Self::closest_param()`].
+            #[inline]
+            pub fn closest_point(&self, point: $vec) -> $vec {
+                self.position(self.closest_param(point))
+            }
+        }
+
+        impl Hermite<$vec> {
+            #[inline]
+            fn points(&self) -> [$vec; 4] {
+                [self.p0, self.v0, self.p1, self.v1]
+            }
+
+            /// Returns the position at the parameter `t`.
+            #[inline]
+            pub fn position(&self, t: $t) -> $vec {
+                let t2 = t * t;
+                let t3 = t2 * t;
+                $vec::weighted_sum(
+                    self.points(),
+                    [
+                        2.0 * t3 - 3.0 * t2 + 1.0,
+                        t3 - 2.0 * t2 + t,
+                        3.0 * t2 - 2.0 * t3,
+                        t3 - t2,
+                    ],
+                )
+            }
+
+            /// Returns the first derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn velocity(&self, t: $t) -> $vec {
+                let t2 = t * t;
+                $vec::weighted_sum(
+                    self.points(),
+                    [
+                        6.0 * t2 - 6.0 * t,
+                        3.0 * t2 - 4.0 * t + 1.0,
+                        6.0 * t - 6.0 * t2,
+                        3.0 * t2 - 2.0 * t,
+                    ],
+                )
+            }
+
+            /// Returns the second derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn acceleration(&self, t: $t) -> $vec {
+                $vec::weighted_sum(
+                    self.points(),
+                    [12.0 * t - 6.0, 6.0 * t - 4.0, 6.0 - 12.0 * t, 6.0 * t - 2.0],
+                )
+            }
+
+            /// Splits the segment at the parameter `t` into two segments covering `[0, t]` and
+            /// `[t, 1]`.
+            ///
+            /// The tangents are scaled so each new segment is parameterized over `[0, 1]`.
+            #[inline]
+            pub fn split(&self, t: $t) -> (Self, Self) {
+                let m = self.position(t);
+                let v = self.velocity(t);
+                (
+                    Self::new(self.p0, self.v0 * t, m, v * t),
+                    Self::new(m, v * (1.0 - t), self.p1, self.v1 * (1.0 - t)),
+                )
+            }
+
+            /// Returns the same curve as a [`CubicBezier`] segment.
+            #[inline]
+            pub fn to_cubic_bezier(&self) -> CubicBezier<$vec> {
+                CubicBezier::new(
+                    self.p0,
+                    self.p0 + self.v0 / 3.0,
+                    self.p1 - self.v1 / 3.0,
+                    self.p1,
+                )
+            }
+        }
+
+        impl CatmullRom<$vec> {
+            /// Returns the same curve as a [`Hermite`] segment.
+            pub fn to_hermite(&self) -> Hermite<$vec> {
+                let (p0, p1, p2, p3) = (self.p0, self.p1, self.p2, self.p3);
+                match self.parameterization {
+                    Parameterization::Uniform => {
+                        Hermite::new(p1, (p2 - p0) * 0.5, p2, (p3 - p1) * 0.5)
+                    }
+                    Parameterization::Centripetal => {
+                        use crate::$t::math;
+                        // Knot intervals, coincident points fall back to the middle interval
+                        let mut dt1 = math::sqrt(p1.distance(p2));
+                        if dt1 < 1e-4 {
+                            dt1 = 1.0;
+                        }
+                        let mut dt0 = math::sqrt(p0.distance(p1));
+                        if dt0 < 1e-4 {
+                            dt0 = dt1;
+                        }
+                        let mut dt2 = math::sqrt(p2.distance(p3));
+                        if dt2 < 1e-4 {
+                            dt2 = dt1;
+                        }
+                        let m1 = (p1 - p0) / dt0 - (p2 - p0) / (dt0 + dt1) + (p2 - p1) / dt1;
+                        let m2 = (p2 - p1) / dt1 - (p3 - p1) / (dt1 + dt2) + (p3 - p2) / dt2;
+                        Hermite::new(p1, m1 * dt1, p2, m2 * dt1)
+                    }
+                }
+            }
+
+            /// Returns the same curve as a [`CubicBezier`] segment.
+            #[inline]
+            pub fn to_cubic_bezier(&self) -> CubicBezier<$vec> {
+                self.to_hermite().to_cubic_bezier()
+            }
+
+            /// Returns the position at the parameter `t`.
+            #[inline]
+            pub fn position(&self, t: $t) -> $vec {
+                self.to_hermite().position(t)
+            }
+
+            /// Returns the first derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn velocity(&self, t: $t) -> $vec {
+                self.to_hermite().velocity(t)
+            }
+
+            /// Returns the second derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn acceleration(&self, t: $t) -> $vec {
+                self.to_hermite().acceleration(t)
+            }
+        }
+
+        impl BSpline<$vec> {
+            #[inline]
+            fn points(&self) -> [$vec; 4] {
+                [self.p0, self.p1, self.p2, self.p3]
+            }
+
+            /// Returns the position at the parameter `t`.
+            #[inline]
+            pub fn position(&self, t: $t) -> $vec {
+                let s = 1.0 - t;
+                let t2 = t * t;
+                let t3 = t2 * t;
+                $vec::weighted_sum(
+                    self.points(),
+                    [
+                        s * s * s / 6.0,
+                        (3.0 * t3 - 6.0 * t2 + 4.0) / 6.0,
+                        (-3.0 * t3 + 3.0 * t2 + 3.0 * t + 1.0) / 6.0,
+                        t3 / 6.0,
+                    ],
+                )
+            }
+
+            /// Returns the first derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn velocity(&self, t: $t) -> $vec {
+                let s = 1.0 - t;
+                let t2 = t * t;
+                $vec::weighted_sum(
+                    self.points(),
+                    [
+                        -0.5 * s * s,
+                        1.5 * t2 - 2.0 * t,
+                        -1.5 * t2 + t + 0.5,
+                        0.5 * t2,
+                    ],
+                )
+            }
+
+            /// Returns the second derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn acceleration(&self, t: $t) -> $vec {
+                $vec::weighted_sum(self.points(), [1.0 - t, 3.0 * t - 2.0, 1.0 - 3.0 * t, t])
+            }
+
+            /// Returns the same curve as a [`CubicBezier`] segment.
+            #[inline]
+            pub fn to_cubic_bezier(&self) -> CubicBezier<$vec> {
+                let (p0, p1, p2, p3) = (self.p0, self.p1, self.p2, self.p3);
+                CubicBezier::new(
+                    (p0 + p1 * 4.0 + p2) / 6.0,
+                    p1.lerp(p2, 1.0 / 3.0),
+                    p1.lerp(p2, 2.0 / 3.0),
+                    (p1 + p2 * 4.0 + p3) / 6.0,
+                )
+            }
+        }
+
+        impl CubicSpline<'_, $vec> {
+            /// Returns the segment containing the parameter `t` and the parameter within it.
+            #[inline]
+            fn segment(&self, t: $t) -> (&CubicBezier<$vec>, $t) {
+                let last = self.segments.len() - 1;
+                let t = t.clamp(0.0, self.segments.len() as $t);
+                let i = (t as usize).min(last);
+                (&self.segments[i], t - i as $t)
+            }
+
+            /// Returns the position at the parameter `t`.
+            #[inline]
+            pub fn position(&self, t: $t) -> $vec {
+                let (segment, t) = self.segment(t);
+                segment.position(t)
+            }
+
+            /// Returns the first derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn velocity(&self, t: $t) -> $vec {
+                let (segment, t) = self.segment(t);
+                segment.velocity(t)
+            }
+
+            /// Returns the second derivative with respect to the parameter at `t`.
+            #[inline]
+            pub fn acceleration(&self, t: $t) -> $vec {
+                let (segment, t) = self.segment(t);
+                segment.acceleration(t)
+            }
+
+            /// Returns the approximate arc length of the spline.
+            pub fn length(&self) -> $t {
+                self.segments.iter().map(|segment| segment.length()).sum()
+            }
+
+            /// Returns the parameter at which the approximate arc length from the start of the
+            /// spline is `length`.
+            ///
+            /// Evaluating the spline at evenly spaced lengths gives points at evenly spaced
+            /// distances along the curve. The result is clamped to the parameter range of the
+            /// spline.
+            pub fn param_at_length(&self, mut length: $t) -> $t {
+                if length <= 0.0 {
+                    return 0.0;
+                }
+                for (i, segment) in self.segments.iter().enumerate() {
+                    let segment_length = segment.length();
+                    if length < segment_length {
+                        return i as $t + segment.param_at_length(length);
+                    }
+                    length -= segment_length;
+                }
+                self.segments.len() as $t
+            }
+
+            /// Returns the position at the approximate arc length `length` from the start of the
+            /// spline.
+            ///
+            /// See [`Self::param_at_length()`].
+            #[inline]
+            pub fn position_at_length(&self, length: $t) -> $vec {
+                self.position(self.param_at_length(length))
+            }
+
+            /// Returns the parameter of the point on the spline closest to `point`.
+            ///
+            /// See [`CubicBezier::closest_param()`].
+            pub fn closest_param(&self, point: $vec) -> $t {
+                let mut best_t = 0.0;
+                let mut best_d = $t::INFINITY;
+                for (i, segment) in self.segments.iter().enumerate() {
+                    let t = segment.closest_param(point);
+                    let d = segment.position(t).distance_squared(point);
+                    if d < best_d {
+                        best_t = i as $t + t;
+                        best_d = d;
+                    }
+                }
+                best_t
+            }
+
+            /// Returns the point on the spline closest to `point`.
+            ///
+            /// See [`CubicBezier::closest_param()`].
+            #[inline]
+            pub fn closest_point(&self, point: $vec) -> $vec {
+                self.position(self.closest_param(point))
+            }
+        }
+    };
+}
+
+impl_curves!(f32, Vec2);
+impl_curves!(f32, Vec3);
+impl_curves!(f32, Vec3A);
+impl_curves!(f64, DVec3);
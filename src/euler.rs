@@ -1,15 +1,36 @@
 /*
-Conversion from quaternions to Euler rotation sequences.
+Conversion between quaternions and Euler rotation sequences.
 
-From: http://bediyap.com/programming/convert-quaternion-to-euler-rotations/
+Quaternion to Euler angles uses the method from "Quaternion to Euler angles conversion: A direct,
+general and computationally efficient method" by Evandro Bernardes and Stéphane Viollet, which
+handles all twelve sequences the same way and stays accurate close to gimbal lock.
+
+See: https://doi.org/10.1371/journal.pone.0276302
 */
 
 use crate::{DQuat, Quat};
 
 /// Euler rotation sequences.
 ///
-/// The angles are applied starting from the right.
-/// E.g. XYZ will first apply the z-axis rotation.
+/// The first letters of a variant name are the axes of the three rotations in the order the angles
+/// are passed to functions such as [`Quat::from_euler()`] and returned from [`Quat::to_euler()`].
+///
+/// Intrinsic sequences rotate around the axes of the rotating frame, so the angles are applied
+/// starting from the right. E.g. `XYZ` will first apply the z-axis rotation, so `XYZ` with the
+/// angles `a`, `b` and `c` is `Rx(a) * Ry(b) * Rz(c)`.
+///
+/// Extrinsic sequences, with an `Ex` suffix, rotate around the fixed world axes and apply the
+/// angles starting from the left. E.g. `XYZEx` will first apply the x-axis rotation, so `XYZEx`
+/// with the angles `a`, `b` and `c` is `Rz(c) * Ry(b) * Rx(a)`.
+///
+/// Tait-Bryan sequences such as `XYZ` use three different axes and the second angle is in the
+/// range `[-PI/2, PI/2]`. Proper Euler sequences such as `ZXZ` use the same axis for the first and
+/// last rotation and the second angle is in the range `[0, PI]`. The first and third angles are in
+/// the range `[-PI, PI]`.
+///
+/// At gimbal lock, where the second angle is at the end of its range, the first and third axes
+/// line up and only their sum or difference is defined. Conversions to Euler angles then return a
+/// third angle of zero.
 ///
 /// YXZ can be used for yaw (y-axis), pitch (x-axis), roll (z-axis).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -26,6 +47,45 @@ pub enum EulerRot {
     XYZ,
     /// Intrinsic three-axis rotation XZY
     XZY,
+
+    /// Intrinsic two-axis rotation ZYZ
+    ZYZ,
+    /// Intrinsic two-axis rotation ZXZ
+    ZXZ,
+    /// Intrinsic two-axis rotation YXY
+    YXY,
+    /// Intrinsic two-axis rotation YZY
+    YZY,
+    /// Intrinsic two-axis rotation XYX
+    XYX,
+    /// Intrinsic two-axis rotation XZX
+    XZX,
+
+    /// Extrinsic three-axis rotation ZYX
+    ZYXEx,
+    /// Extrinsic three-axis rotation ZXY
+    ZXYEx,
+    /// Extrinsic three-axis rotation YXZ
+    YXZEx,
+    /// Extrinsic three-axis rotation YZX
+    YZXEx,
+    /// Extrinsic three-axis rotation XYZ
+    XYZEx,
+    /// Extrinsic three-axis rotation XZY
+    XZYEx,
+
+    /// Extrinsic two-axis rotation ZYZ
+    ZYZEx,
+    /// Extrinsic two-axis rotation ZXZ
+    ZXZEx,
+    /// Extrinsic two-axis rotation YXY
+    YXYEx,
+    /// Extrinsic two-axis rotation YZY
+    YZYEx,
+    /// Extrinsic two-axis rotation XYX
+    XYXEx,
+    /// Extrinsic two-axis rotation XZX
+    XZXEx,
 }
 
 impl Default for EulerRot {
@@ -35,20 +95,46 @@ impl Default for EulerRot {
     }
 }
 
+impl EulerRot {
+    /// Returns the axes of the sequence in the order of the angles, where `0` is the x-axis, `1` is
+    /// the y-axis and `2` is the z-axis, and whether the sequence is extrinsic.
+    const fn axes(self) -> (usize, usize, usize, bool) {
+        use EulerRot::*;
+        match self {
+            ZYX => (2, 1, 0, false),
+            ZXY => (2, 0, 1, false),
+            YXZ => (1, 0, 2, false),
+            YZX => (1, 2, 0, false),
+            XYZ => (0, 1, 2, false),
+            XZY => (0, 2, 1, false),
+            ZYZ => (2, 1, 2, false),
+            ZXZ => (2, 0, 2, false),
+            YXY => (1, 0, 1, false),
+            YZY => (1, 2, 1, false),
+            XYX => (0, 1, 0, false),
+            XZX => (0, 2, 0, false),
+            ZYXEx => (2, 1, 0, true),
+            ZXYEx => (2, 0, 1, true),
+            YXZEx => (1, 0, 2, true),
+            YZXEx => (1, 2, 0, true),
+            XYZEx => (0, 1, 2, true),
+            XZYEx => (0, 2, 1, true),
+            ZYZEx => (2, 1, 2, true),
+            ZXZEx => (2, 0, 2, true),
+            YXYEx => (1, 0, 1, true),
+            YZYEx => (1, 2, 1, true),
+            XYXEx => (0, 1, 0, true),
+            XZXEx => (0, 2, 0, true),
+        }
+    }
+}
+
 /// Conversion from quaternion to euler angles.
 pub(crate) trait EulerFromQuaternion<Q: Copy>: Sized + Copy {
     type Output;
-    /// Compute the angle of the first axis (X-x-x)
-    fn first(self, q: Q) -> Self::Output;
-    /// Compute then angle of the second axis (x-X-x)
-    fn second(self, q: Q) -> Self::Output;
-    /// Compute then angle of the third axis (x-x-X)
-    fn third(self, q: Q) -> Self::Output;
 
     /// Compute all angles of a rotation in the notation order
-    fn convert_quat(self, q: Q) -> (Self::Output, Self::Output, Self::Output) {
-        (self.first(q), self.second(q), self.third(q))
-    }
+    fn convert_quat(self, q: Q) -> (Self::Output, Self::Output, Self::Output);
 }
 
 /// Conversion from euler angles to quaternion.
@@ -59,81 +145,80 @@ pub(crate) trait EulerToQuaternion<T>: Copy {
 }
 
 macro_rules! impl_from_quat {
-    ($t:ident, $quat:ident) => {
+    ($t:ident, $quat:ident, $gimbal_lock_epsilon:expr) => {
         impl EulerFromQuaternion<$quat> for EulerRot {
             type Output = $t;
-            fn first(self, q: $quat) -> $t {
+            fn convert_quat(self, q: $quat) -> ($t, $t, $t) {
                 use crate::$t::math;
-                use EulerRot::*;
-                match self {
-                    ZYX => math::atan2(
-                        2.0 * (q.x * q.y + q.w * q.z),
-                        q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z,
-                    ),
-                    ZXY => math::atan2(
-                        -2.0 * (q.x * q.y - q.w * q.z),
-                        q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z,
-                    ),
-                    YXZ => math::atan2(
-                        2.0 * (q.x * q.z + q.w * q.y),
-                        q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z,
-                    ),
-                    YZX => math::atan2(
-                        -2.0 * (q.x * q.z - q.w * q.y),
-                        q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z,
-                    ),
-                    XYZ => math::atan2(
-                        -2.0 * (q.y * q.z - q.w * q.x),
-                        q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z,
-                    ),
-                    XZY => math::atan2(
-                        2.0 * (q.y * q.z + q.w * q.x),
-                        q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z,
-                    ),
-                }
-            }
+                use core::$t::consts::{FRAC_PI_2, PI, TAU};
 
-            fn second(self, q: $quat) -> $t {
-                use crate::$t::math;
-                use EulerRot::*;
-                match self {
-                    ZYX => math::asin_clamped(-2.0 * (q.x * q.z - q.w * q.y)),
-                    ZXY => math::asin_clamped(2.0 * (q.y * q.z + q.w * q.x)),
-                    YXZ => math::asin_clamped(-2.0 * (q.y * q.z - q.w * q.x)),
-                    YZX => math::asin_clamped(2.0 * (q.x * q.y + q.w * q.z)),
-                    XYZ => math::asin_clamped(2.0 * (q.x * q.z + q.w * q.y)),
-                    XZY => math::asin_clamped(-2.0 * (q.x * q.y - q.w * q.z)),
+                // The method works on the extrinsic sequence `i, j, k`. An intrinsic sequence is
+                // the same as the reversed extrinsic sequence with the angles reversed.
+                let (first, second, third, extrinsic) = self.axes();
+                let (i, j, k) = if extrinsic {
+                    (first, second, third)
+                } else {
+                    (third, second, first)
+                };
+                let proper = i == k;
+                let k = if proper { 3 - i - j } else { k };
+                // The sign of the permutation `i, j, k`
+                let sign = if (i + 1) % 3 == j { 1.0 } else { -1.0 };
+
+                let v = [q.x, q.y, q.z];
+                let (a, b, c, d) = if proper {
+                    (q.w, v[i], v[j], v[k] * sign)
+                } else {
+                    (
+                        q.w - v[j],
+                        v[i] + v[k] * sign,
+                        v[j] + q.w,
+                        v[k] * sign - v[i],
+                    )
+                };
+
+                let angle2 =
+                    2.0 * math::atan2(math::sqrt(c * c + d * d), math::sqrt(a * a + b * b));
+                let half_sum = math::atan2(b, a);
+                let half_diff = math::atan2(d, c);
+                let (mut angle1, mut angle3) = if angle2 <= $gimbal_lock_epsilon {
+                    // Only `angle1 + angle3` is defined, the angle returned last is zero.
+                    if extrinsic {
+                        (2.0 * half_sum, 0.0)
+                    } else {
+                        (0.0, 2.0 * half_sum)
+                    }
+                } else if angle2 >= PI - $gimbal_lock_epsilon {
+                    // Only `angle3 - angle1` is defined, the angle returned last is zero.
+                    if extrinsic {
+                        (-2.0 * half_diff, 0.0)
+                    } else {
+                        (0.0, 2.0 * half_diff)
+                    }
+                } else {
+                    (half_sum - half_diff, half_sum + half_diff)
+                };
+
+                let angle2 = if proper {
+                    angle2
+                } else {
+                    angle3 *= sign;
+                    angle2 - FRAC_PI_2
+                };
+
+                // Wrap to `[-PI, PI]`
+                for angle in [&mut angle1, &mut angle3] {
+                    if *angle > PI {
+                        *angle -= TAU;
+                    } else if *angle < -PI {
+                        *angle += TAU;
+                    }
                 }
-            }
 
-            fn third(self, q: $quat) -> $t {
-                use crate::$t::math;
-                use EulerRot::*;
-                match self {
-                    ZYX => math::atan2(
-                        2.0 * (q.y * q.z + q.w * q.x),
-                        q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z,
-                    ),
-                    ZXY => math::atan2(
-                        -2.0 * (q.x * q.z - q.w * q.y),
-                        q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z,
-                    ),
-                    YXZ => math::atan2(
-                        2.0 * (q.x * q.y + q.w * q.z),
-                        q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z,
-                    ),
-                    YZX => math::atan2(
-                        -2.0 * (q.y * q.z - q.w * q.x),
-                        q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z,
-                    ),
-                    XYZ => math::atan2(
-                        -2.0 * (q.x * q.y - q.w * q.z),
-                        q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z,
-                    ),
-                    XZY => math::atan2(
-                        2.0 * (q.x * q.z + q.w * q.y),
-                        q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z,
-                    ),
+                if extrinsic {
+                    (angle1, angle2, angle3)
+                } else {
+                    (angle3, angle2, angle1)
                 }
             }
         }
@@ -147,26 +232,19 @@ macro_rules! impl_to_quat {
             type Output = $quat;
             #[inline(always)]
             fn new_quat(self, u: $t, v: $t, w: $t) -> $quat {
-                use EulerRot::*;
-                #[inline(always)]
-                fn rot_x(a: $t) -> $quat {
-                    $quat::from_rotation_x(a)
-                }
-                #[inline(always)]
-                fn rot_y(a: $t) -> $quat {
-                    $quat::from_rotation_y(a)
-                }
                 #[inline(always)]
-                fn rot_z(a: $t) -> $quat {
-                    $quat::from_rotation_z(a)
+                fn rot(axis: usize, a: $t) -> $quat {
+                    match axis {
+                        0 => $quat::from_rotation_x(a),
+                        1 => $quat::from_rotation_y(a),
+                        _ => $quat::from_rotation_z(a),
+                    }
                 }
-                match self {
-                    ZYX => rot_z(u) * rot_y(v) * rot_x(w),
-                    ZXY => rot_z(u) * rot_x(v) * rot_y(w),
-                    YXZ => rot_y(u) * rot_x(v) * rot_z(w),
-                    YZX => rot_y(u) * rot_z(v) * rot_x(w),
-                    XYZ => rot_x(u) * rot_y(v) * rot_z(w),
-                    XZY => rot_x(u) * rot_z(v) * rot_y(w),
+                let (first, second, third, extrinsic) = self.axes();
+                if extrinsic {
+                    rot(third, w) * rot(second, v) * rot(first, u)
+                } else {
+                    rot(first, u) * rot(second, v) * rot(third, w)
                 }
                 .normalize()
             }
@@ -175,7 +253,9 @@ macro_rules! impl_to_quat {
     };
 }
 
-impl_from_quat!(f32, Quat);
-impl_from_quat!(f64, DQuat);
+// Only treat rounding noise in the second angle as gimbal lock, the general case is accurate until
+// then.
+impl_from_quat!(f32, Quat, 1e-5);
+impl_from_quat!(f64, DQuat, 1e-12);
 impl_to_quat!(f32, Quat);
 impl_to_quat!(f64, DQuat);
@@ -0,0 +1,126 @@
+// Software conversions between IEEE 754 binary16 bit patterns and `f32`/`f64`.
+//
+// Narrowing conversions round to nearest, ties to even. Values that overflow the half precision
+// range become infinity, values that underflow become signed zero or a subnormal and NaN inputs
+// always produce a quiet NaN with the sign and upper payload bits preserved.
+
+#[inline]
+pub(crate) fn f32_to_f16(value: f32) -> u16 {
+    let x = value.to_bits();
+    let sign = ((x >> 16) & 0x8000) as u16;
+    let exp = x & 0x7f80_0000;
+    let man = x & 0x007f_ffff;
+
+    // NaN or infinity
+    if exp == 0x7f80_0000 {
+        let nan_bit = if man == 0 { 0 } else { 0x0200 };
+        return sign | 0x7c00 | nan_bit | (man >> 13) as u16;
+    }
+
+    let half_exp = (exp >> 23) as i32 - 127 + 15;
+
+    // Overflow to infinity
+    if half_exp >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Subnormal or zero
+    if half_exp <= 0 {
+        let shift = (14 - half_exp) as u32;
+        if shift > 24 {
+            return sign;
+        }
+        let man = man | 0x0080_0000;
+        let half_man = man >> shift;
+        let round_bit = 1 << (shift - 1);
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            return sign | (half_man + 1) as u16;
+        }
+        return sign | half_man as u16;
+    }
+
+    // Normal, a carry out of the mantissa correctly rounds up into the exponent
+    let half = sign | ((half_exp as u16) << 10) | (man >> 13) as u16;
+    let round_bit = 0x0000_1000;
+    if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+        half + 1
+    } else {
+        half
+    }
+}
+
+#[inline]
+pub(crate) fn f64_to_f16(value: f64) -> u16 {
+    let x = value.to_bits();
+    let sign = ((x >> 48) & 0x8000) as u16;
+    let exp = x & 0x7ff0_0000_0000_0000;
+    let man = x & 0x000f_ffff_ffff_ffff;
+
+    // NaN or infinity
+    if exp == 0x7ff0_0000_0000_0000 {
+        let nan_bit = if man == 0 { 0 } else { 0x0200 };
+        return sign | 0x7c00 | nan_bit | (man >> 42) as u16;
+    }
+
+    let half_exp = (exp >> 52) as i64 - 1023 + 15;
+
+    // Overflow to infinity
+    if half_exp >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Subnormal or zero
+    if half_exp <= 0 {
+        let shift = (43 - half_exp) as u64;
+        if shift > 53 {
+            return sign;
+        }
+        let man = man | 0x0010_0000_0000_0000;
+        let half_man = man >> shift;
+        let round_bit = 1 << (shift - 1);
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            return sign | (half_man + 1) as u16;
+        }
+        return sign | half_man as u16;
+    }
+
+    // Normal, a carry out of the mantissa correctly rounds up into the exponent
+    let half = sign | ((half_exp as u16) << 10) | (man >> 42) as u16;
+    let round_bit = 0x0000_0200_0000_0000;
+    if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+        half + 1
+    } else {
+        half
+    }
+}
+
+#[inline]
+pub(crate) fn f16_to_f32(half: u16) -> f32 {
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exp = (half & 0x7c00) as u32;
+    let man = (half & 0x03ff) as u32;
+
+    // NaN or infinity
+    if exp == 0x7c00 {
+        return f32::from_bits(sign | 0x7f80_0000 | (man << 13));
+    }
+
+    // Zero or subnormal, which is always a normal `f32`
+    if exp == 0 {
+        if man == 0 {
+            return f32::from_bits(sign);
+        }
+        let shift = man.leading_zeros() - 21;
+        let man = (man << shift) & 0x03ff;
+        let exp = 113 - shift;
+        return f32::from_bits(sign | (exp << 23) | (man << 13));
+    }
+
+    f32::from_bits(sign | (((exp >> 10) + 112) << 23) | (man << 13))
+}
+
+#[inline]
+pub(crate) fn f16_to_f64(half: u16) -> f64 {
+    // Every half precision value is exactly representable as an `f32`.
+    f16_to_f32(half) as f64
+}
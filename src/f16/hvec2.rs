@@ -0,0 +1,279 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f16::convert::*, DVec2, Vec2};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A 2-dimensional vector of IEEE 754 half precision (binary16) values.
+///
+/// This is a storage type, components are stored as raw `u16` bit patterns. Use
+/// [`Self::as_vec2()`] to convert to [`Vec2`] for arithmetic and
+/// [`Self::from_vec2()`] to narrow the result back to half precision.
+///
+/// Equality and hashing compare the bit patterns of the components, so `0.0` and `-0.0` are
+/// not equal while two NaN values with the same bits are.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+#[cfg_attr(target_arch = "spirv", repr(simd))]
+pub struct HVec2 {
+    pub x: u16,
+    pub y: u16,
+}
+
+impl HVec2 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat_bits(0x0000);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat_bits(0x3c00);
+
+    /// All negative ones.
+    pub const NEG_ONE: Self = Self::splat_bits(0xbc00);
+
+    /// All NAN.
+    pub const NAN: Self = Self::splat_bits(0x7e00);
+
+    /// All positive infinity.
+    pub const INFINITY: Self = Self::splat_bits(0x7c00);
+
+    /// All negative infinity.
+    pub const NEG_INFINITY: Self = Self::splat_bits(0xfc00);
+
+    /// A unit-length vector pointing along the positive X axis.
+    pub const X: Self = Self::from_bits(0x3c00, 0x0000);
+
+    /// A unit-length vector pointing along the positive Y axis.
+    pub const Y: Self = Self::from_bits(0x0000, 0x3c00);
+
+    /// A unit-length vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::from_bits(0xbc00, 0x0000);
+
+    /// A unit-length vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::from_bits(0x0000, 0xbc00);
+
+    /// Creates a new vector, rounding each component to the nearest half precision value.
+    #[inline]
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x: f32_to_f16(x),
+            y: f32_to_f16(y),
+        }
+    }
+
+    /// Creates a vector with all elements set to `v` rounded to half precision.
+    #[inline]
+    pub fn splat(v: f32) -> Self {
+        Self::splat_bits(f32_to_f16(v))
+    }
+
+    /// Creates a new vector from the raw half precision bit patterns of each component.
+    #[inline(always)]
+    pub const fn from_bits(x: u16, y: u16) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a vector with all elements set to the raw half precision bit pattern `bits`.
+    #[inline(always)]
+    pub const fn splat_bits(bits: u16) -> Self {
+        Self { x: bits, y: bits }
+    }
+
+    /// Creates a new vector from an array of `f32` values, rounding each to half precision.
+    #[inline]
+    pub fn from_array(a: [f32; 2]) -> Self {
+        Self::new(a[0], a[1])
+    }
+
+    /// `[x, y]` widened to `f32`.
+    #[inline]
+    pub fn to_array(&self) -> [f32; 2] {
+        [f16_to_f32(self.x), f16_to_f32(self.y)]
+    }
+
+    /// Creates a new vector from an array of raw half precision bit patterns.
+    #[inline]
+    pub const fn from_bits_array(a: [u16; 2]) -> Self {
+        Self::from_bits(a[0], a[1])
+    }
+
+    /// `[x, y]` as raw half precision bit patterns.
+    #[inline]
+    pub const fn to_bits_array(&self) -> [u16; 2] {
+        [self.x, self.y]
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.  If any element is either
+    /// `NaN` or positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        (self.x & 0x7c00) != 0x7c00 && (self.y & 0x7c00) != 0x7c00
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        (self.x & 0x7fff) > 0x7c00 || (self.y & 0x7fff) > 0x7c00
+    }
+
+    /// Creates a new vector from a [`Vec2`], rounding each component to the nearest
+    /// half precision value.
+    ///
+    /// Rounding is IEEE 754 round-to-nearest-even. Values too large to be represented become
+    /// infinity, infinities are preserved and NaN values remain NaN.
+    #[inline]
+    pub fn from_vec2(v: Vec2) -> Self {
+        Self {
+            x: f32_to_f16(v.x),
+            y: f32_to_f16(v.y),
+        }
+    }
+
+    /// Converts `self` to a [`Vec2`]. This conversion is lossless.
+    #[inline]
+    pub fn as_vec2(&self) -> Vec2 {
+        Vec2::new(f16_to_f32(self.x), f16_to_f32(self.y))
+    }
+
+    /// Converts each element of `src` using [`Self::from_vec2()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn from_vec2_slice(src: &[Vec2], dst: &mut [Self]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = Self::from_vec2(*s);
+        }
+    }
+
+    /// Converts each element of `src` using [`Self::as_vec2()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn as_vec2_slice(src: &[Self], dst: &mut [Vec2]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.as_vec2();
+        }
+    }
+
+    /// Creates a new vector from a [`DVec2`], rounding each component to the nearest
+    /// half precision value.
+    ///
+    /// Rounding is IEEE 754 round-to-nearest-even. Values too large to be represented become
+    /// infinity, infinities are preserved and NaN values remain NaN.
+    #[inline]
+    pub fn from_dvec2(v: DVec2) -> Self {
+        Self {
+            x: f64_to_f16(v.x),
+            y: f64_to_f16(v.y),
+        }
+    }
+
+    /// Converts `self` to a [`DVec2`]. This conversion is lossless.
+    #[inline]
+    pub fn as_dvec2(&self) -> DVec2 {
+        DVec2::new(f16_to_f64(self.x), f16_to_f64(self.y))
+    }
+
+    /// Converts each element of `src` using [`Self::from_dvec2()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn from_dvec2_slice(src: &[DVec2], dst: &mut [Self]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = Self::from_dvec2(*s);
+        }
+    }
+
+    /// Converts each element of `src` using [`Self::as_dvec2()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn as_dvec2_slice(src: &[Self], dst: &mut [DVec2]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.as_dvec2();
+        }
+    }
+}
+
+impl Default for HVec2 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for HVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_vec2(), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for HVec2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(HVec2))
+            .field(&f16_to_f32(self.x))
+            .field(&f16_to_f32(self.y))
+            .finish()
+    }
+}
+
+impl From<[f32; 2]> for HVec2 {
+    #[inline]
+    fn from(a: [f32; 2]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<HVec2> for [f32; 2] {
+    #[inline]
+    fn from(v: HVec2) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<Vec2> for HVec2 {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        Self::from_vec2(v)
+    }
+}
+
+impl From<HVec2> for Vec2 {
+    #[inline]
+    fn from(v: HVec2) -> Self {
+        v.as_vec2()
+    }
+}
+
+impl From<DVec2> for HVec2 {
+    #[inline]
+    fn from(v: DVec2) -> Self {
+        Self::from_dvec2(v)
+    }
+}
+
+impl From<HVec2> for DVec2 {
+    #[inline]
+    fn from(v: HVec2) -> Self {
+        v.as_dvec2()
+    }
+}
@@ -0,0 +1,342 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f16::convert::*, DVec3, Vec3, Vec3A};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A 3-dimensional vector of IEEE 754 half precision (binary16) values.
+///
+/// This is a storage type, components are stored as raw `u16` bit patterns. Use
+/// [`Self::as_vec3()`] to convert to [`Vec3`] for arithmetic and
+/// [`Self::from_vec3()`] to narrow the result back to half precision.
+///
+/// Equality and hashing compare the bit patterns of the components, so `0.0` and `-0.0` are
+/// not equal while two NaN values with the same bits are.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+#[cfg_attr(target_arch = "spirv", repr(simd))]
+pub struct HVec3 {
+    pub x: u16,
+    pub y: u16,
+    pub z: u16,
+}
+
+impl HVec3 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat_bits(0x0000);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat_bits(0x3c00);
+
+    /// All negative ones.
+    pub const NEG_ONE: Self = Self::splat_bits(0xbc00);
+
+    /// All NAN.
+    pub const NAN: Self = Self::splat_bits(0x7e00);
+
+    /// All positive infinity.
+    pub const INFINITY: Self = Self::splat_bits(0x7c00);
+
+    /// All negative infinity.
+    pub const NEG_INFINITY: Self = Self::splat_bits(0xfc00);
+
+    /// A unit-length vector pointing along the positive X axis.
+    pub const X: Self = Self::from_bits(0x3c00, 0x0000, 0x0000);
+
+    /// A unit-length vector pointing along the positive Y axis.
+    pub const Y: Self = Self::from_bits(0x0000, 0x3c00, 0x0000);
+
+    /// A unit-length vector pointing along the positive Z axis.
+    pub const Z: Self = Self::from_bits(0x0000, 0x0000, 0x3c00);
+
+    /// A unit-length vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::from_bits(0xbc00, 0x0000, 0x0000);
+
+    /// A unit-length vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::from_bits(0x0000, 0xbc00, 0x0000);
+
+    /// A unit-length vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self::from_bits(0x0000, 0x0000, 0xbc00);
+
+    /// Creates a new vector, rounding each component to the nearest half precision value.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            x: f32_to_f16(x),
+            y: f32_to_f16(y),
+            z: f32_to_f16(z),
+        }
+    }
+
+    /// Creates a vector with all elements set to `v` rounded to half precision.
+    #[inline]
+    pub fn splat(v: f32) -> Self {
+        Self::splat_bits(f32_to_f16(v))
+    }
+
+    /// Creates a new vector from the raw half precision bit patterns of each component.
+    #[inline(always)]
+    pub const fn from_bits(x: u16, y: u16, z: u16) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a vector with all elements set to the raw half precision bit pattern `bits`.
+    #[inline(always)]
+    pub const fn splat_bits(bits: u16) -> Self {
+        Self {
+            x: bits,
+            y: bits,
+            z: bits,
+        }
+    }
+
+    /// Creates a new vector from an array of `f32` values, rounding each to half precision.
+    #[inline]
+    pub fn from_array(a: [f32; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+
+    /// `[x, y, z]` widened to `f32`.
+    #[inline]
+    pub fn to_array(&self) -> [f32; 3] {
+        [f16_to_f32(self.x), f16_to_f32(self.y), f16_to_f32(self.z)]
+    }
+
+    /// Creates a new vector from an array of raw half precision bit patterns.
+    #[inline]
+    pub const fn from_bits_array(a: [u16; 3]) -> Self {
+        Self::from_bits(a[0], a[1], a[2])
+    }
+
+    /// `[x, y, z]` as raw half precision bit patterns.
+    #[inline]
+    pub const fn to_bits_array(&self) -> [u16; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.  If any element is either
+    /// `NaN` or positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        (self.x & 0x7c00) != 0x7c00 && (self.y & 0x7c00) != 0x7c00 && (self.z & 0x7c00) != 0x7c00
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        (self.x & 0x7fff) > 0x7c00 || (self.y & 0x7fff) > 0x7c00 || (self.z & 0x7fff) > 0x7c00
+    }
+
+    /// Creates a new vector from a [`Vec3`], rounding each component to the nearest
+    /// half precision value.
+    ///
+    /// Rounding is IEEE 754 round-to-nearest-even. Values too large to be represented become
+    /// infinity, infinities are preserved and NaN values remain NaN.
+    #[inline]
+    pub fn from_vec3(v: Vec3) -> Self {
+        Self {
+            x: f32_to_f16(v.x),
+            y: f32_to_f16(v.y),
+            z: f32_to_f16(v.z),
+        }
+    }
+
+    /// Converts `self` to a [`Vec3`]. This conversion is lossless.
+    #[inline]
+    pub fn as_vec3(&self) -> Vec3 {
+        Vec3::new(f16_to_f32(self.x), f16_to_f32(self.y), f16_to_f32(self.z))
+    }
+
+    /// Converts each element of `src` using [`Self::from_vec3()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn from_vec3_slice(src: &[Vec3], dst: &mut [Self]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = Self::from_vec3(*s);
+        }
+    }
+
+    /// Converts each element of `src` using [`Self::as_vec3()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn as_vec3_slice(src: &[Self], dst: &mut [Vec3]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.as_vec3();
+        }
+    }
+
+    /// Creates a new vector from a [`Vec3A`], rounding each component to the nearest
+    /// half precision value.
+    ///
+    /// Rounding is IEEE 754 round-to-nearest-even. Values too large to be represented become
+    /// infinity, infinities are preserved and NaN values remain NaN.
+    #[inline]
+    pub fn from_vec3a(v: Vec3A) -> Self {
+        Self {
+            x: f32_to_f16(v.x),
+            y: f32_to_f16(v.y),
+            z: f32_to_f16(v.z),
+        }
+    }
+
+    /// Converts `self` to a [`Vec3A`]. This conversion is lossless.
+    #[inline]
+    pub fn as_vec3a(&self) -> Vec3A {
+        Vec3A::new(f16_to_f32(self.x), f16_to_f32(self.y), f16_to_f32(self.z))
+    }
+
+    /// Converts each element of `src` using [`Self::from_vec3a()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn from_vec3a_slice(src: &[Vec3A], dst: &mut [Self]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = Self::from_vec3a(*s);
+        }
+    }
+
+    /// Converts each element of `src` using [`Self::as_vec3a()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn as_vec3a_slice(src: &[Self], dst: &mut [Vec3A]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.as_vec3a();
+        }
+    }
+
+    /// Creates a new vector from a [`DVec3`], rounding each component to the nearest
+    /// half precision value.
+    ///
+    /// Rounding is IEEE 754 round-to-nearest-even. Values too large to be represented become
+    /// infinity, infinities are preserved and NaN values remain NaN.
+    #[inline]
+    pub fn from_dvec3(v: DVec3) -> Self {
+        Self {
+            x: f64_to_f16(v.x),
+            y: f64_to_f16(v.y),
+            z: f64_to_f16(v.z),
+        }
+    }
+
+    /// Converts `self` to a [`DVec3`]. This conversion is lossless.
+    #[inline]
+    pub fn as_dvec3(&self) -> DVec3 {
+        DVec3::new(f16_to_f64(self.x), f16_to_f64(self.y), f16_to_f64(self.z))
+    }
+
+    /// Converts each element of `src` using [`Self::from_dvec3()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn from_dvec3_slice(src: &[DVec3], dst: &mut [Self]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = Self::from_dvec3(*s);
+        }
+    }
+
+    /// Converts each element of `src` using [`Self::as_dvec3()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn as_dvec3_slice(src: &[Self], dst: &mut [DVec3]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.as_dvec3();
+        }
+    }
+}
+
+impl Default for HVec3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for HVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_vec3(), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for HVec3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(HVec3))
+            .field(&f16_to_f32(self.x))
+            .field(&f16_to_f32(self.y))
+            .field(&f16_to_f32(self.z))
+            .finish()
+    }
+}
+
+impl From<[f32; 3]> for HVec3 {
+    #[inline]
+    fn from(a: [f32; 3]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<HVec3> for [f32; 3] {
+    #[inline]
+    fn from(v: HVec3) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<Vec3> for HVec3 {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        Self::from_vec3(v)
+    }
+}
+
+impl From<HVec3> for Vec3 {
+    #[inline]
+    fn from(v: HVec3) -> Self {
+        v.as_vec3()
+    }
+}
+
+impl From<DVec3> for HVec3 {
+    #[inline]
+    fn from(v: DVec3) -> Self {
+        Self::from_dvec3(v)
+    }
+}
+
+impl From<HVec3> for DVec3 {
+    #[inline]
+    fn from(v: HVec3) -> Self {
+        v.as_dvec3()
+    }
+}
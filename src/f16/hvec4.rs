@@ -0,0 +1,327 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f16::convert::*, DVec4, Vec4};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A 4-dimensional vector of IEEE 754 half precision (binary16) values.
+///
+/// This is a storage type, components are stored as raw `u16` bit patterns. Use
+/// [`Self::as_vec4()`] to convert to [`Vec4`] for arithmetic and
+/// [`Self::from_vec4()`] to narrow the result back to half precision.
+///
+/// Equality and hashing compare the bit patterns of the components, so `0.0` and `-0.0` are
+/// not equal while two NaN values with the same bits are.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+#[cfg_attr(target_arch = "spirv", repr(simd))]
+pub struct HVec4 {
+    pub x: u16,
+    pub y: u16,
+    pub z: u16,
+    pub w: u16,
+}
+
+impl HVec4 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat_bits(0x0000);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat_bits(0x3c00);
+
+    /// All negative ones.
+    pub const NEG_ONE: Self = Self::splat_bits(0xbc00);
+
+    /// All NAN.
+    pub const NAN: Self = Self::splat_bits(0x7e00);
+
+    /// All positive infinity.
+    pub const INFINITY: Self = Self::splat_bits(0x7c00);
+
+    /// All negative infinity.
+    pub const NEG_INFINITY: Self = Self::splat_bits(0xfc00);
+
+    /// A unit-length vector pointing along the positive X axis.
+    pub const X: Self = Self::from_bits(0x3c00, 0x0000, 0x0000, 0x0000);
+
+    /// A unit-length vector pointing along the positive Y axis.
+    pub const Y: Self = Self::from_bits(0x0000, 0x3c00, 0x0000, 0x0000);
+
+    /// A unit-length vector pointing along the positive Z axis.
+    pub const Z: Self = Self::from_bits(0x0000, 0x0000, 0x3c00, 0x0000);
+
+    /// A unit-length vector pointing along the positive W axis.
+    pub const W: Self = Self::from_bits(0x0000, 0x0000, 0x0000, 0x3c00);
+
+    /// A unit-length vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::from_bits(0xbc00, 0x0000, 0x0000, 0x0000);
+
+    /// A unit-length vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::from_bits(0x0000, 0xbc00, 0x0000, 0x0000);
+
+    /// A unit-length vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self::from_bits(0x0000, 0x0000, 0xbc00, 0x0000);
+
+    /// A unit-length vector pointing along the negative W axis.
+    pub const NEG_W: Self = Self::from_bits(0x0000, 0x0000, 0x0000, 0xbc00);
+
+    /// Creates a new vector, rounding each component to the nearest half precision value.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self {
+            x: f32_to_f16(x),
+            y: f32_to_f16(y),
+            z: f32_to_f16(z),
+            w: f32_to_f16(w),
+        }
+    }
+
+    /// Creates a vector with all elements set to `v` rounded to half precision.
+    #[inline]
+    pub fn splat(v: f32) -> Self {
+        Self::splat_bits(f32_to_f16(v))
+    }
+
+    /// Creates a new vector from the raw half precision bit patterns of each component.
+    #[inline(always)]
+    pub const fn from_bits(x: u16, y: u16, z: u16, w: u16) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a vector with all elements set to the raw half precision bit pattern `bits`.
+    #[inline(always)]
+    pub const fn splat_bits(bits: u16) -> Self {
+        Self {
+            x: bits,
+            y: bits,
+            z: bits,
+            w: bits,
+        }
+    }
+
+    /// Creates a new vector from an array of `f32` values, rounding each to half precision.
+    #[inline]
+    pub fn from_array(a: [f32; 4]) -> Self {
+        Self::new(a[0], a[1], a[2], a[3])
+    }
+
+    /// `[x, y, z, w]` widened to `f32`.
+    #[inline]
+    pub fn to_array(&self) -> [f32; 4] {
+        [
+            f16_to_f32(self.x),
+            f16_to_f32(self.y),
+            f16_to_f32(self.z),
+            f16_to_f32(self.w),
+        ]
+    }
+
+    /// Creates a new vector from an array of raw half precision bit patterns.
+    #[inline]
+    pub const fn from_bits_array(a: [u16; 4]) -> Self {
+        Self::from_bits(a[0], a[1], a[2], a[3])
+    }
+
+    /// `[x, y, z, w]` as raw half precision bit patterns.
+    #[inline]
+    pub const fn to_bits_array(&self) -> [u16; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.  If any element is either
+    /// `NaN` or positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        (self.x & 0x7c00) != 0x7c00
+            && (self.y & 0x7c00) != 0x7c00
+            && (self.z & 0x7c00) != 0x7c00
+            && (self.w & 0x7c00) != 0x7c00
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        (self.x & 0x7fff) > 0x7c00
+            || (self.y & 0x7fff) > 0x7c00
+            || (self.z & 0x7fff) > 0x7c00
+            || (self.w & 0x7fff) > 0x7c00
+    }
+
+    /// Creates a new vector from a [`Vec4`], rounding each component to the nearest
+    /// half precision value.
+    ///
+    /// Rounding is IEEE 754 round-to-nearest-even. Values too large to be represented become
+    /// infinity, infinities are preserved and NaN values remain NaN.
+    #[inline]
+    pub fn from_vec4(v: Vec4) -> Self {
+        Self {
+            x: f32_to_f16(v.x),
+            y: f32_to_f16(v.y),
+            z: f32_to_f16(v.z),
+            w: f32_to_f16(v.w),
+        }
+    }
+
+    /// Converts `self` to a [`Vec4`]. This conversion is lossless.
+    #[inline]
+    pub fn as_vec4(&self) -> Vec4 {
+        Vec4::new(
+            f16_to_f32(self.x),
+            f16_to_f32(self.y),
+            f16_to_f32(self.z),
+            f16_to_f32(self.w),
+        )
+    }
+
+    /// Converts each element of `src` using [`Self::from_vec4()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn from_vec4_slice(src: &[Vec4], dst: &mut [Self]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = Self::from_vec4(*s);
+        }
+    }
+
+    /// Converts each element of `src` using [`Self::as_vec4()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn as_vec4_slice(src: &[Self], dst: &mut [Vec4]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.as_vec4();
+        }
+    }
+
+    /// Creates a new vector from a [`DVec4`], rounding each component to the nearest
+    /// half precision value.
+    ///
+    /// Rounding is IEEE 754 round-to-nearest-even. Values too large to be represented become
+    /// infinity, infinities are preserved and NaN values remain NaN.
+    #[inline]
+    pub fn from_dvec4(v: DVec4) -> Self {
+        Self {
+            x: f64_to_f16(v.x),
+            y: f64_to_f16(v.y),
+            z: f64_to_f16(v.z),
+            w: f64_to_f16(v.w),
+        }
+    }
+
+    /// Converts `self` to a [`DVec4`]. This conversion is lossless.
+    #[inline]
+    pub fn as_dvec4(&self) -> DVec4 {
+        DVec4::new(
+            f16_to_f64(self.x),
+            f16_to_f64(self.y),
+            f16_to_f64(self.z),
+            f16_to_f64(self.w),
+        )
+    }
+
+    /// Converts each element of `src` using [`Self::from_dvec4()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn from_dvec4_slice(src: &[DVec4], dst: &mut [Self]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = Self::from_dvec4(*s);
+        }
+    }
+
+    /// Converts each element of `src` using [`Self::as_dvec4()`], writing the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    pub fn as_dvec4_slice(src: &[Self], dst: &mut [DVec4]) {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.as_dvec4();
+        }
+    }
+}
+
+impl Default for HVec4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for HVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_vec4(), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for HVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(HVec4))
+            .field(&f16_to_f32(self.x))
+            .field(&f16_to_f32(self.y))
+            .field(&f16_to_f32(self.z))
+            .field(&f16_to_f32(self.w))
+            .finish()
+    }
+}
+
+impl From<[f32; 4]> for HVec4 {
+    #[inline]
+    fn from(a: [f32; 4]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<HVec4> for [f32; 4] {
+    #[inline]
+    fn from(v: HVec4) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<Vec4> for HVec4 {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        Self::from_vec4(v)
+    }
+}
+
+impl From<HVec4> for Vec4 {
+    #[inline]
+    fn from(v: HVec4) -> Self {
+        v.as_vec4()
+    }
+}
+
+impl From<DVec4> for HVec4 {
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        Self::from_dvec4(v)
+    }
+}
+
+impl From<HVec4> for DVec4 {
+    #[inline]
+    fn from(v: HVec4) -> Self {
+        v.as_dvec4()
+    }
+}
@@ -1,7 +1,25 @@
+mod aabb2;
+mod aabb3;
+mod aabb3a;
 mod affine2;
 mod affine3a;
+mod degrees;
+mod dualquat;
+mod frustum;
+mod isometry2;
+mod isometry3;
+mod mat2x3;
 mod mat3;
+mod mat3x2;
+mod mat3x4;
+mod mat4x3;
 pub(crate) mod math;
+mod plane3;
+mod radians;
+mod ray3;
+mod rot2;
+mod similarity3;
+mod sphere;
 mod vec2;
 mod vec3;
 
@@ -55,13 +73,31 @@ use wasm32::*;
 #[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
 use coresimd::*;
 
+pub use aabb2::Aabb2;
+pub use aabb3::Aabb3;
+pub use aabb3a::Aabb3A;
 pub use affine2::Affine2;
 pub use affine3a::Affine3A;
+pub use degrees::Degrees;
+pub use dualquat::DualQuat;
+pub use frustum::Frustum;
+pub use isometry2::Isometry2;
+pub use isometry3::Isometry3;
 pub use mat2::{mat2, Mat2};
+pub use mat2x3::{mat2x3, Mat2x3};
 pub use mat3::{mat3, Mat3};
 pub use mat3a::{mat3a, Mat3A};
+pub use mat3x2::{mat3x2, Mat3x2};
+pub use mat3x4::{mat3x4, Mat3x4};
 pub use mat4::{mat4, Mat4};
+pub use mat4x3::{mat4x3, Mat4x3};
+pub use plane3::Plane3;
 pub use quat::{quat, Quat};
+pub use radians::Radians;
+pub use ray3::Ray3;
+pub use rot2::Rot2;
+pub use similarity3::Similarity3;
+pub use sphere::Sphere;
 pub use vec2::{vec2, Vec2};
 pub use vec3::{vec3, Vec3};
 pub use vec3a::{vec3a, Vec3A};
@@ -97,6 +133,14 @@ mod test {
         const_assert_eq!(16, core::mem::size_of::<super::Mat2>());
     }
 
+    mod const_test_mat2x3 {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Mat2x3>()
+        );
+        const_assert_eq!(24, core::mem::size_of::<super::Mat2x3>());
+    }
+
     mod const_test_mat3 {
         const_assert_eq!(
             core::mem::align_of::<f32>(),
@@ -105,6 +149,22 @@ mod test {
         const_assert_eq!(36, core::mem::size_of::<super::Mat3>());
     }
 
+    mod const_test_mat3x2 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec2>(),
+            core::mem::align_of::<super::Mat3x2>()
+        );
+        const_assert_eq!(24, core::mem::size_of::<super::Mat3x2>());
+    }
+
+    mod const_test_mat3x4 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec4>(),
+            core::mem::align_of::<super::Mat3x4>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::Mat3x4>());
+    }
+
     mod const_test_mat3a {
         const_assert_eq!(16, core::mem::align_of::<super::Mat3A>());
         const_assert_eq!(48, core::mem::size_of::<super::Mat3A>());
@@ -118,6 +178,121 @@ mod test {
         const_assert_eq!(64, core::mem::size_of::<super::Mat4>());
     }
 
+    mod const_test_mat4x3 {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Mat4x3>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::Mat4x3>());
+    }
+
+    mod const_test_aabb2 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec2>(),
+            core::mem::align_of::<super::Aabb2>()
+        );
+        const_assert_eq!(16, core::mem::size_of::<super::Aabb2>());
+    }
+
+    mod const_test_aabb3 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec3>(),
+            core::mem::align_of::<super::Aabb3>()
+        );
+        const_assert_eq!(24, core::mem::size_of::<super::Aabb3>());
+    }
+
+    mod const_test_aabb3a {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec3A>(),
+            core::mem::align_of::<super::Aabb3A>()
+        );
+        const_assert_eq!(32, core::mem::size_of::<super::Aabb3A>());
+    }
+
+    mod const_test_dualquat {
+        const_assert_eq!(
+            core::mem::align_of::<super::Quat>(),
+            core::mem::align_of::<super::DualQuat>()
+        );
+        const_assert_eq!(32, core::mem::size_of::<super::DualQuat>());
+    }
+
+    mod const_test_frustum {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec4>(),
+            core::mem::align_of::<super::Frustum>()
+        );
+        const_assert_eq!(96, core::mem::size_of::<super::Frustum>());
+    }
+
+    mod const_test_isometry2 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Vec2>(),
+            core::mem::align_of::<super::Isometry2>()
+        );
+        #[cfg(not(feature = "cuda"))]
+        const_assert_eq!(12, core::mem::size_of::<super::Isometry2>());
+        #[cfg(feature = "cuda")]
+        const_assert_eq!(16, core::mem::size_of::<super::Isometry2>());
+    }
+
+    mod const_test_isometry3 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Quat>(),
+            core::mem::align_of::<super::Isometry3>()
+        );
+        #[cfg(not(feature = "scalar-math"))]
+        const_assert_eq!(32, core::mem::size_of::<super::Isometry3>());
+        #[cfg(feature = "scalar-math")]
+        const_assert_eq!(28, core::mem::size_of::<super::Isometry3>());
+    }
+
+    mod const_test_ray3 {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Ray3>()
+        );
+        const_assert_eq!(24, core::mem::size_of::<super::Ray3>());
+    }
+
+    mod const_test_angle {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Radians>()
+        );
+        const_assert_eq!(4, core::mem::size_of::<super::Radians>());
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Degrees>()
+        );
+        const_assert_eq!(4, core::mem::size_of::<super::Degrees>());
+    }
+
+    mod const_test_rot2 {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Rot2>()
+        );
+        const_assert_eq!(8, core::mem::size_of::<super::Rot2>());
+    }
+
+    mod const_test_similarity3 {
+        const_assert_eq!(
+            core::mem::align_of::<super::Quat>(),
+            core::mem::align_of::<super::Similarity3>()
+        );
+        const_assert_eq!(32, core::mem::size_of::<super::Similarity3>());
+    }
+
+    mod const_test_plane3 {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Plane3>()
+        );
+        const_assert_eq!(16, core::mem::size_of::<super::Plane3>());
+    }
+
     mod const_test_quat {
         #[cfg(feature = "scalar-math")]
         const_assert_eq!(
@@ -129,6 +304,14 @@ mod test {
         const_assert_eq!(16, core::mem::size_of::<super::Quat>());
     }
 
+    mod const_test_sphere {
+        const_assert_eq!(
+            core::mem::align_of::<f32>(),
+            core::mem::align_of::<super::Sphere>()
+        );
+        const_assert_eq!(16, core::mem::size_of::<super::Sphere>());
+    }
+
     mod const_test_vec2 {
         #[cfg(not(feature = "cuda"))]
         const_assert_eq!(
@@ -0,0 +1,242 @@
+// Generated from aabb.rs.tera template. Edit the template, not the generated file.
+
+use crate::{Affine3A, Mat4, Vec3A};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A 3D axis-aligned bounding box, defined by its minimum and maximum corners.
+///
+/// The bounds are inclusive, a point lying on the boundary is contained by the box.
+/// A box where `min` is greater than `max` on any axis is empty.
+///
+/// This type uses [`Vec3A`] for storage and is 16 byte aligned.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Aabb3A {
+    pub min: Vec3A,
+    pub max: Vec3A,
+}
+
+impl Aabb3A {
+    /// An empty box, with `min` set to the largest and `max` set to the smallest
+    /// representable value.
+    ///
+    /// The union of this with any other box or point is that box or point,
+    /// which makes it a good initial value when accumulating bounds.
+    pub const EMPTY: Self = Self {
+        min: Vec3A::splat(f32::INFINITY),
+        max: Vec3A::splat(f32::NEG_INFINITY),
+    };
+
+    /// Creates a new box from its `min` and `max` corners.
+    #[inline(always)]
+    pub const fn new(min: Vec3A, max: Vec3A) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a new box from its `center` and `half_extents`.
+    #[inline]
+    pub fn from_center_half_extents(center: Vec3A, half_extents: Vec3A) -> Self {
+        Self {
+            min: center - half_extents,
+            max: center + half_extents,
+        }
+    }
+
+    /// Creates the smallest box containing all of the given `points`.
+    ///
+    /// Returns [`Self::EMPTY`] if `points` is empty.
+    #[inline]
+    pub fn from_points(points: &[Vec3A]) -> Self {
+        points
+            .iter()
+            .fold(Self::EMPTY, |aabb, point| aabb.union_point(*point))
+    }
+
+    /// Returns `true` if `min` is greater than `max` on any axis.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.cmpgt(self.max).any()
+    }
+
+    /// Returns the center of `self`.
+    #[inline]
+    pub fn center(&self) -> Vec3A {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the half extents of `self`, which is half of its size.
+    #[inline]
+    pub fn half_extents(&self) -> Vec3A {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns the size of `self`, which is the difference between `max` and `min`.
+    #[inline]
+    pub fn size(&self) -> Vec3A {
+        self.max - self.min
+    }
+
+    /// Returns the smallest box containing both `self` and `rhs`.
+    #[inline]
+    pub fn union(&self, rhs: &Self) -> Self {
+        Self {
+            min: self.min.min(rhs.min),
+            max: self.max.max(rhs.max),
+        }
+    }
+
+    /// Returns the smallest box containing both `self` and `point`.
+    #[inline]
+    pub fn union_point(&self, point: Vec3A) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    /// Returns the box where `self` and `rhs` overlap.
+    ///
+    /// If `self` and `rhs` do not intersect the result will be empty, see [`Self::is_empty()`].
+    #[inline]
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        Self {
+            min: self.min.max(rhs.min),
+            max: self.max.min(rhs.max),
+        }
+    }
+
+    /// Returns `true` if `point` is inside or on the boundary of `self`.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3A) -> bool {
+        (point.cmpge(self.min) & point.cmple(self.max)).all()
+    }
+
+    /// Returns `true` if `rhs` is entirely inside `self`.
+    #[inline]
+    pub fn contains(&self, rhs: &Self) -> bool {
+        (rhs.min.cmpge(self.min) & rhs.max.cmple(self.max)).all()
+    }
+
+    /// Returns `true` if `self` and `rhs` overlap or touch.
+    #[inline]
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        (self.min.cmple(rhs.max) & self.max.cmpge(rhs.min)).all()
+    }
+
+    /// Returns the point inside or on the boundary of `self` which is closest to `point`.
+    ///
+    /// If `point` is inside `self` then `point` is returned.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3A) -> Vec3A {
+        point.max(self.min).min(self.max)
+    }
+
+    /// Returns `self` with `amount` subtracted from `min` and added to `max` on every axis.
+    ///
+    /// A negative `amount` shrinks the box.
+    #[inline]
+    pub fn grow(&self, amount: f32) -> Self {
+        Self {
+            min: self.min - Vec3A::splat(amount),
+            max: self.max + Vec3A::splat(amount),
+        }
+    }
+
+    /// Returns the smallest axis-aligned box containing `self` after it has been
+    /// transformed by `transform`.
+    ///
+    /// This uses Arvo's method, which is much cheaper than transforming each of the corners of
+    /// `self`.
+    ///
+    /// The result is undefined if `self` is empty.
+    #[inline]
+    pub fn transformed_by(&self, transform: &Affine3A) -> Self {
+        // Each axis of the result is the sum of the contributions of each axis of `self`, for
+        // which the smaller and larger of the scaled matrix column go to `min` and `max`.
+        let (min, max) = (self.min, self.max);
+        let mut new_min = transform.translation;
+        let mut new_max = transform.translation;
+
+        let a = transform.matrix3.x_axis * min.x;
+        let b = transform.matrix3.x_axis * max.x;
+        new_min += a.min(b);
+        new_max += a.max(b);
+
+        let a = transform.matrix3.y_axis * min.y;
+        let b = transform.matrix3.y_axis * max.y;
+        new_min += a.min(b);
+        new_max += a.max(b);
+
+        let a = transform.matrix3.z_axis * min.z;
+        let b = transform.matrix3.z_axis * max.z;
+        new_min += a.min(b);
+        new_max += a.max(b);
+
+        Self {
+            min: new_min,
+            max: new_max,
+        }
+    }
+
+    /// Returns the smallest axis-aligned box containing `self` after it has been
+    /// transformed by the affine transform `mat`.
+    ///
+    /// See [`Self::transformed_by()`].
+    #[inline]
+    pub fn transformed_by_mat4(&self, mat: &Mat4) -> Self {
+        self.transformed_by(&Affine3A::from_mat4(*mat))
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.min.is_finite() && self.max.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.min.is_nan() || self.max.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two bounding boxes contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.min.abs_diff_eq(rhs.min, max_abs_diff) && self.max.abs_diff_eq(rhs.max, max_abs_diff)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for Aabb3A {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(Aabb3A))
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for Aabb3A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
+
+impl From<crate::Aabb3> for Aabb3A {
+    #[inline]
+    fn from(aabb: crate::Aabb3) -> Self {
+        Self::new(aabb.min.into(), aabb.max.into())
+    }
+}
@@ -1,6 +1,6 @@
 // Generated from affine.rs.tera template. Edit the template, not the generated file.
 
-use crate::{Mat2, Mat3, Mat3A, Vec2, Vec3A};
+use crate::{Mat2, Mat3, Mat3A, Radians, Rot2, Vec2, Vec3A};
 use core::ops::{Deref, DerefMut, Mul};
 
 /// A 2D affine transform, which can represent translation, rotation, scaling and shear.
@@ -122,7 +122,7 @@ impl Affine2 {
 
     /// Creates an affine transform from the given rotation `angle`.
     #[inline]
-    pub fn from_angle(angle: f32) -> Self {
+    pub fn from_angle(angle: impl Into<Radians>) -> Self {
         Self {
             matrix2: Mat2::from_angle(angle),
             translation: Vec2::ZERO,
@@ -166,7 +166,11 @@ impl Affine2 {
     /// Equivalent to `Affine2::from_translation(translation) *
     /// Affine2::from_angle(angle) * Affine2::from_scale(scale)`
     #[inline]
-    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
+    pub fn from_scale_angle_translation(
+        scale: Vec2,
+        angle: impl Into<Radians>,
+        translation: Vec2,
+    ) -> Self {
         let rotation = Mat2::from_angle(angle);
         Self {
             matrix2: Mat2::from_cols(rotation.x_axis * scale.x, rotation.y_axis * scale.y),
@@ -179,13 +183,25 @@ impl Affine2 {
     ///
     /// Equivalent to `Affine2::from_translation(translation) * Affine2::from_angle(angle)`
     #[inline]
-    pub fn from_angle_translation(angle: f32, translation: Vec2) -> Self {
+    pub fn from_angle_translation(angle: impl Into<Radians>, translation: Vec2) -> Self {
         Self {
             matrix2: Mat2::from_angle(angle),
             translation,
         }
     }
 
+    /// Creates an affine transform from the given 2D `rotation` and `translation`.
+    ///
+    /// Equivalent to `Affine2::from_angle_translation(rotation.angle(), translation)` but
+    /// does not require any trigonometry.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Rot2, translation: Vec2) -> Self {
+        Self {
+            matrix2: Mat2::from(rotation),
+            translation,
+        }
+    }
+
     /// The given `Mat3` must be an affine transform,
     #[inline]
     pub fn from_mat3(m: Mat3) -> Self {
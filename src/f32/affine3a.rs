@@ -1,6 +1,6 @@
 // Generated from affine.rs.tera template. Edit the template, not the generated file.
 
-use crate::{Mat3, Mat3A, Mat4, Quat, Vec3, Vec3A};
+use crate::{EulerRot, Mat3, Mat3A, Mat4, Quat, Radians, Vec3, Vec3A};
 use core::ops::{Deref, DerefMut, Mul};
 
 /// A 3D affine transform, which can represent translation, rotation, scaling and shear.
@@ -135,17 +135,46 @@ impl Affine3A {
     /// Creates an affine transform containing a 3D rotation around a normalized
     /// rotation `axis` of `angle` (in radians).
     #[inline]
-    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Radians>) -> Self {
         Self {
             matrix3: Mat3A::from_axis_angle(axis, angle),
             translation: Vec3A::ZERO,
         }
     }
 
+    /// Creates an affine transform containing a 3D rotation from the given euler rotation
+    /// sequence and angles (in radians).
+    #[inline]
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<Radians>,
+        b: impl Into<Radians>,
+        c: impl Into<Radians>,
+    ) -> Self {
+        Self {
+            matrix3: Mat3A::from_euler(order, a, b, c),
+            translation: Vec3A::ZERO,
+        }
+    }
+
+    /// Extracts the angles (in radians) of the given euler rotation sequence from the rotation
+    /// part of `self`, ignoring the translation.
+    ///
+    /// See [`EulerRot`] for the range of the returned angles and the behavior at gimbal lock.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any column of `self.matrix3` is not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    pub fn to_euler(&self, order: EulerRot) -> (f32, f32, f32) {
+        self.matrix3.to_euler(order)
+    }
+
     /// Creates an affine transform containing a 3D rotation around the x axis of
     /// `angle` (in radians).
     #[inline]
-    pub fn from_rotation_x(angle: f32) -> Self {
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
         Self {
             matrix3: Mat3A::from_rotation_x(angle),
             translation: Vec3A::ZERO,
@@ -155,7 +184,7 @@ impl Affine3A {
     /// Creates an affine transform containing a 3D rotation around the y axis of
     /// `angle` (in radians).
     #[inline]
-    pub fn from_rotation_y(angle: f32) -> Self {
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
         Self {
             matrix3: Mat3A::from_rotation_y(angle),
             translation: Vec3A::ZERO,
@@ -165,7 +194,7 @@ impl Affine3A {
     /// Creates an affine transform containing a 3D rotation around the z axis of
     /// `angle` (in radians).
     #[inline]
-    pub fn from_rotation_z(angle: f32) -> Self {
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
         Self {
             matrix3: Mat3A::from_rotation_z(angle),
             translation: Vec3A::ZERO,
@@ -366,6 +395,70 @@ impl Affine3A {
             .into()
     }
 
+    /// Transforms each 3D point in `points` in place using [`Self::transform_point3()`].
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    #[inline]
+    pub fn transform_points3_slice(&self, points: &mut [Vec3]) {
+        let mat = Mat4::from(*self);
+        crate::wide::map_vec3_slice(
+            points,
+            |v| v.transform_point3(&mat),
+            |v| self.transform_point3(v),
+        );
+    }
+
+    /// Transforms each 3D point in `src` using [`Self::transform_point3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    #[inline]
+    pub fn transform_points3_slice_to(&self, src: &[Vec3], dst: &mut [Vec3]) {
+        let mat = Mat4::from(*self);
+        crate::wide::map_vec3_slice_to(
+            src,
+            dst,
+            |v| v.transform_point3(&mat),
+            |v| self.transform_point3(v),
+        );
+    }
+
+    /// Transforms each 3D vector in `vectors` in place using [`Self::transform_vector3()`].
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    #[inline]
+    pub fn transform_vectors3_slice(&self, vectors: &mut [Vec3]) {
+        let mat = Mat4::from(*self);
+        crate::wide::map_vec3_slice(
+            vectors,
+            |v| v.transform_vector3(&mat),
+            |v| self.transform_vector3(v),
+        );
+    }
+
+    /// Transforms each 3D vector in `src` using [`Self::transform_vector3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    #[inline]
+    pub fn transform_vectors3_slice_to(&self, src: &[Vec3], dst: &mut [Vec3]) {
+        let mat = Mat4::from(*self);
+        crate::wide::map_vec3_slice_to(
+            src,
+            dst,
+            |v| v.transform_vector3(&mat),
+            |v| self.transform_vector3(v),
+        );
+    }
+
     /// Transforms the given [`Vec3A`], applying shear, scale, rotation and translation.
     #[inline]
     pub fn transform_point3a(&self, rhs: Vec3A) -> Vec3A {
@@ -4,7 +4,7 @@ use crate::{
     coresimd::*,
     euler::{EulerFromQuaternion, EulerRot, EulerToQuaternion},
     f32::math,
-    DQuat, Mat3, Mat3A, Mat4, Vec2, Vec3, Vec3A, Vec4,
+    DQuat, Mat3, Mat3A, Mat4, Radians, Vec2, Vec3, Vec3A, Vec4,
 };
 
 use core::simd::*;
@@ -119,9 +119,9 @@ impl Quat {
     ///
     /// Will panic if `axis` is not normalized when `glam_assert` is enabled.
     #[inline]
-    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Radians>) -> Self {
         glam_assert!(axis.is_normalized());
-        let (s, c) = math::sin_cos(angle * 0.5);
+        let (s, c) = math::sin_cos(angle.into().0 * 0.5);
         let v = axis * s;
         Self::from_xyzw(v.x, v.y, v.z, c)
     }
@@ -141,29 +141,34 @@ impl Quat {
 
     /// Creates a quaternion from the `angle` (in radians) around the x axis.
     #[inline]
-    pub fn from_rotation_x(angle: f32) -> Self {
-        let (s, c) = math::sin_cos(angle * 0.5);
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
+        let (s, c) = math::sin_cos(angle.into().0 * 0.5);
         Self::from_xyzw(s, 0.0, 0.0, c)
     }
 
     /// Creates a quaternion from the `angle` (in radians) around the y axis.
     #[inline]
-    pub fn from_rotation_y(angle: f32) -> Self {
-        let (s, c) = math::sin_cos(angle * 0.5);
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
+        let (s, c) = math::sin_cos(angle.into().0 * 0.5);
         Self::from_xyzw(0.0, s, 0.0, c)
     }
 
     /// Creates a quaternion from the `angle` (in radians) around the z axis.
     #[inline]
-    pub fn from_rotation_z(angle: f32) -> Self {
-        let (s, c) = math::sin_cos(angle * 0.5);
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
+        let (s, c) = math::sin_cos(angle.into().0 * 0.5);
         Self::from_xyzw(0.0, 0.0, s, c)
     }
 
     #[inline]
     /// Creates a quaternion from the given Euler rotation sequence and the angles (in radians).
-    pub fn from_euler(euler: EulerRot, a: f32, b: f32, c: f32) -> Self {
-        euler.new_quat(a, b, c)
+    pub fn from_euler(
+        euler: EulerRot,
+        a: impl Into<Radians>,
+        b: impl Into<Radians>,
+        c: impl Into<Radians>,
+    ) -> Self {
+        euler.new_quat(a.into().0, b.into().0, c.into().0)
     }
 
     /// From the columns of a 3x3 rotation matrix.
@@ -238,6 +243,24 @@ impl Quat {
         Self::from_rotation_axes(mat.x_axis.into(), mat.y_axis.into(), mat.z_axis.into())
     }
 
+    /// Creates a quaternion from the rotation closest to the given 3x3 matrix.
+    ///
+    /// Unlike [`Self::from_mat3()`] the matrix does not need to be a pure rotation. Numerical
+    /// drift, scale and shear are removed using [`Mat3::polar_decomposition()`].
+    #[inline]
+    pub fn from_mat3_nearest(mat: &Mat3) -> Self {
+        Self::from_mat3(&mat.polar_decomposition().0)
+    }
+
+    /// Creates a quaternion from the rotation closest to the given 3x3 SIMD aligned matrix.
+    ///
+    /// Unlike [`Self::from_mat3a()`] the matrix does not need to be a pure rotation. Numerical
+    /// drift, scale and shear are removed using [`Mat3A::polar_decomposition()`].
+    #[inline]
+    pub fn from_mat3a_nearest(mat: &Mat3A) -> Self {
+        Self::from_mat3a(&mat.polar_decomposition().0)
+    }
+
     /// Creates a quaternion from a 3x3 rotation matrix inside a homogeneous 4x4 matrix.
     #[inline]
     pub fn from_mat4(mat: &Mat4) -> Self {
@@ -362,7 +385,97 @@ impl Quat {
         axis * angle
     }
 
+    /// Decomposes `self` into a swing and a twist around `axis` such that `self` is equal to
+    /// `swing * twist`.
+    ///
+    /// The twist is the part of the rotation around `axis` and is applied first. The swing is the
+    /// remaining rotation around an axis perpendicular to `axis`. Both have a non-negative `w` so
+    /// their angles are in the range `[-PI, PI]`.
+    ///
+    /// If `self` is a half turn around an axis perpendicular to `axis` the twist is not defined
+    /// and [`Self::IDENTITY`] is returned for it.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `axis` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn to_swing_twist(self, axis: Vec3) -> (Self, Self) {
+        glam_assert!(self.is_normalized());
+        glam_assert!(axis.is_normalized());
+
+        let q = if self.w < 0.0 { -self } else { self };
+        let projected = axis * q.xyz().dot(axis);
+        let twist = Self::from_xyzw(projected.x, projected.y, projected.z, q.w);
+        let length_squared = twist.length_squared();
+        if length_squared > f32::EPSILON * f32::EPSILON {
+            let twist = twist * (1.0 / math::sqrt(length_squared));
+            (q.mul_quat(twist.conjugate()), twist)
+        } else {
+            (q, Self::IDENTITY)
+        }
+    }
+
+    /// Creates a quaternion from a swing and a twist such that the result is equal to
+    /// `swing * twist`.
+    ///
+    /// This is the inverse of [`Self::to_swing_twist()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `swing` or `twist` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_swing_twist(swing: Self, twist: Self) -> Self {
+        glam_assert!(swing.is_normalized() && twist.is_normalized());
+        swing.mul_quat(twist)
+    }
+
+    /// Limits the swing of `self` around `axis` to a cone with a half angle of `max_angle`
+    /// (in radians), keeping the twist.
+    ///
+    /// See [`Self::to_swing_twist()`] for how the rotation is decomposed. If the swing is inside
+    /// the cone `self` is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `axis` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn clamp_swing(self, axis: Vec3, max_angle: f32) -> Self {
+        let (swing, twist) = self.to_swing_twist(axis);
+        let (swing_axis, swing_angle) = swing.to_axis_angle();
+        if swing_angle <= max_angle {
+            return self;
+        }
+        Self::from_axis_angle(swing_axis, max_angle.max(0.0)).mul_quat(twist)
+    }
+
+    /// Limits the twist of `self` around `axis` to the range `[min_angle, max_angle]` (in
+    /// radians), keeping the swing.
+    ///
+    /// The twist angle is measured counterclockwise around `axis` in the range `[-PI, PI]`. See
+    /// [`Self::to_swing_twist()`] for how the rotation is decomposed. If the twist is inside the
+    /// range `self` is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `axis` are not normalized or if `min_angle` is greater than
+    /// `max_angle` when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn clamp_twist(self, axis: Vec3, min_angle: f32, max_angle: f32) -> Self {
+        glam_assert!(min_angle <= max_angle);
+        let (swing, twist) = self.to_swing_twist(axis);
+        let twist_angle = 2.0 * math::atan2(twist.xyz().dot(axis), twist.w);
+        if twist_angle >= min_angle && twist_angle <= max_angle {
+            return self;
+        }
+        let clamped = twist_angle.max(min_angle).min(max_angle);
+        swing.mul_quat(Self::from_axis_angle(axis, clamped))
+    }
+
     /// Returns the rotation angles for the given euler rotation sequence.
+    ///
+    /// See [`EulerRot`] for the range of the returned angles and the behavior at gimbal lock.
     #[inline]
     pub fn to_euler(self, euler: EulerRot) -> (f32, f32, f32) {
         euler.convert_quat(self)
@@ -491,6 +604,54 @@ impl Quat {
         positive_w_angle < threshold_angle
     }
 
+    /// Returns the exponential of `self`.
+    ///
+    /// This is the inverse of [`Self::ln()`]. If `self` has a `w` of zero and a vector part of
+    /// `axis * angle / 2` the result is the rotation of `angle` radians around `axis`.
+    #[must_use]
+    #[inline]
+    pub fn exp(self) -> Self {
+        let v = self.xyz();
+        let theta = v.length();
+        let (sin, cos) = math::sin_cos(theta);
+        // `sin(theta) / theta` tends to one as `theta` tends to zero
+        let sinc = if theta > 0.0 { sin / theta } else { 1.0 };
+        let scale = math::exp(self.w);
+        let v = v * (sinc * scale);
+        Self::from_xyzw(v.x, v.y, v.z, cos * scale)
+    }
+
+    /// Returns the natural logarithm of `self`.
+    ///
+    /// If `self` is a rotation of `angle` radians around `axis` the result has a `w` of zero and
+    /// a vector part of `axis * angle / 2`.
+    ///
+    /// The logarithm of `-IDENTITY` is not unique, in that case a rotation around the x axis is
+    /// used.
+    #[must_use]
+    #[inline]
+    pub fn ln(self) -> Self {
+        let v = self.xyz();
+        let v_length = v.length();
+        let half_angle = math::atan2(v_length, self.w);
+        let v = if v_length > 0.0 {
+            v * (half_angle / v_length)
+        } else {
+            Vec3::new(half_angle, 0.0, 0.0)
+        };
+        Self::from_xyzw(v.x, v.y, v.z, math::ln(self.length()))
+    }
+
+    /// Returns `self` raised to the power `n`.
+    ///
+    /// If `self` is normalized this scales the angle of its rotation by `n`. A rotation with a
+    /// negative `w` is scaled along the longer path, negate `self` first to use the shorter one.
+    #[must_use]
+    #[inline]
+    pub fn powf(self, n: f32) -> Self {
+        (self.ln() * n).exp()
+    }
+
     /// Returns the angle (in radians) for the minimal rotation
     /// for transforming this quaternion into another.
     ///
@@ -505,6 +666,29 @@ impl Quat {
         math::acos_approx(math::abs(self.dot(rhs))) * 2.0
     }
 
+    /// Rotates towards `rhs` up to `max_angle` (in radians).
+    ///
+    /// When `max_angle` is `0.0`, the result will be equal to `self`. When `max_angle` is equal to
+    /// `self.angle_between(rhs)`, the result will be equal to `rhs`. If `max_angle` is negative,
+    /// rotates towards the exact opposite of `rhs`. Will not go past the target.
+    ///
+    /// Both quaternions must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `rhs` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn rotate_towards(self, rhs: Self, max_angle: f32) -> Self {
+        glam_assert!(self.is_normalized() && rhs.is_normalized());
+        let angle = self.angle_between(rhs);
+        if angle <= 1e-4 {
+            return rhs;
+        }
+        let s = (max_angle / angle).clamp(-1.0, 1.0);
+        self.slerp(rhs, s)
+    }
+
     /// Returns true if the absolute difference of all elements between `self` and `rhs`
     /// is less than or equal to `max_abs_diff`.
     ///
@@ -545,6 +729,20 @@ impl Quat {
         Quat(interpolated).normalize()
     }
 
+    /// Performs a normalized linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    ///
+    /// This is the same as [`Self::lerp()`], which also takes the shortest path and normalizes the
+    /// result. It is cheaper than [`Self::slerp()`] but the angular velocity is not constant.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn nlerp(self, end: Self, s: f32) -> Self {
+        self.lerp(end, s)
+    }
+
     /// Performs a spherical linear interpolation between `self` and `end`
     /// based on the value `s`.
     ///
@@ -594,6 +792,99 @@ impl Quat {
         }
     }
 
+    /// Performs a spherical linear interpolation between `self` and `end` based on the value `s`,
+    /// which may be outside of the `[0, 1]` range.
+    ///
+    /// Values of `s` outside of `[0, 1]` continue along the same arc with the same angular
+    /// velocity. Unlike [`Self::slerp()`], which falls back to [`Self::lerp()`] for nearly equal
+    /// rotations, the result stays on the arc for any `s`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn slerp_unclamped(self, mut end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        // take the shorter path, see `slerp`
+        if self.dot(end) < 0.0 {
+            end = -end;
+        }
+        self.slerp_arc(end, s)
+    }
+
+    /// Interpolates along the arc from `self` to `end` without correcting for the longer path.
+    #[inline]
+    fn slerp_arc(self, end: Self, s: f32) -> Self {
+        self.mul_quat(self.conjugate().mul_quat(end).powf(s))
+    }
+
+    /// Computes the control point of `self` for [`Self::squad()`] from the previous and next
+    /// rotations in a sequence.
+    ///
+    /// The result is used as the `start_tangent` when interpolating from `self` and as the
+    /// `end_tangent` when interpolating to `self`. For the first and last rotations of a sequence
+    /// `self` can be passed in place of the missing neighbor.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self`, `prev` or `next` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn squad_tangent(self, prev: Self, next: Self) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(prev.is_normalized() && next.is_normalized());
+
+        let prev = if self.dot(prev) < 0.0 { -prev } else { prev };
+        let next = if self.dot(next) < 0.0 { -next } else { next };
+        let inv = self.conjugate();
+        let ln_prev = inv.mul_quat(prev).ln();
+        let ln_next = inv.mul_quat(next).ln();
+        self.mul_quat(ln_prev.add(ln_next).mul(-0.25).exp())
+    }
+
+    /// Performs a spherical cubic interpolation between `self` and `end` based on the value `s`,
+    /// using the control points from [`Self::squad_tangent()`].
+    ///
+    /// Interpolating a sequence of rotations with `squad` gives a smooth path with a continuous
+    /// angular velocity at each rotation, unlike a chain of [`Self::slerp()`] calls.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result will
+    /// be equal to `end`.
+    ///
+    /// ```
+    /// # use glam::Quat;
+    /// let keys = [
+    ///     Quat::IDENTITY,
+    ///     Quat::from_rotation_y(1.0),
+    ///     Quat::from_rotation_x(1.0),
+    ///     Quat::from_rotation_z(0.5),
+    /// ];
+    /// let start_tangent = keys[1].squad_tangent(keys[0], keys[2]);
+    /// let end_tangent = keys[2].squad_tangent(keys[1], keys[3]);
+    /// let q = keys[1].squad(start_tangent, end_tangent, keys[2], 0.5);
+    /// assert!(q.is_normalized());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any of the quaternions are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn squad(self, start_tangent: Self, end_tangent: Self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized() && end.is_normalized());
+        glam_assert!(start_tangent.is_normalized() && end_tangent.is_normalized());
+
+        // move `end` to the same hemisphere as `self` so the shorter path is taken
+        let (end, end_tangent) = if self.dot(end) < 0.0 {
+            (-end, -end_tangent)
+        } else {
+            (end, end_tangent)
+        };
+        let outer = self.slerp_arc(end, s);
+        let inner = start_tangent.slerp_arc(end_tangent, s);
+        outer.slerp_arc(inner, 2.0 * s * (1.0 - s))
+    }
+
     /// Multiplies a quaternion and a 3D vector, returning the rotated vector.
     ///
     /// # Panics
@@ -606,6 +897,37 @@ impl Quat {
         self.mul_vec3a(rhs.into()).into()
     }
 
+    /// Rotates each 3D vector in `vectors` in place using [`Self::mul_vec3()`].
+    ///
+    /// Multiple elements are rotated at a time using the [`wide`](crate::wide) types.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn mul_vec3_slice(self, vectors: &mut [Vec3]) {
+        glam_assert!(self.is_normalized());
+
+        crate::wide::map_vec3_slice(vectors, |v| self * v, |v| self.mul_vec3(v));
+    }
+
+    /// Rotates each 3D vector in `src` using [`Self::mul_vec3()`] and writes the results to
+    /// `dst`.
+    ///
+    /// Multiple elements are rotated at a time using the [`wide`](crate::wide) types.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn mul_vec3_slice_to(self, src: &[Vec3], dst: &mut [Vec3]) {
+        glam_assert!(self.is_normalized());
+
+        crate::wide::map_vec3_slice_to(src, dst, |v| self * v, |v| self.mul_vec3(v));
+    }
+
     /// Multiplies two quaternions. If they each represent a rotation, the result will
     /// represent the combined rotation.
     ///
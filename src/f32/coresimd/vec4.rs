@@ -340,6 +340,53 @@ impl Vec4 {
         (self - rhs).length_squared()
     }
 
+    /// Returns the element-wise quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// Each element is `self / rhs` truncated to an integer and then adjusted so that the
+    /// remainder returned by [`Self::rem_euclid()`] is never negative.
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::div_euclid(self.x, rhs.x),
+            math::div_euclid(self.y, rhs.y),
+            math::div_euclid(self.z, rhs.z),
+            math::div_euclid(self.w, rhs.w),
+        )
+    }
+
+    /// Returns the element-wise quotient of Euclidean division of `self` by the scalar `rhs`.
+    ///
+    /// See [`Self::div_euclid()`] for how the quotient is rounded.
+    #[inline]
+    pub fn div_euclid_scalar(self, rhs: f32) -> Self {
+        self.div_euclid(Self::splat(rhs))
+    }
+
+    /// Returns the element-wise remainder of Euclidean division of `self` by `rhs`.
+    ///
+    /// Each element of the result is in the range `[0, rhs.abs())`, which makes this suitable
+    /// for wrapping coordinates into a tile or chunk. Due to rounding an element may be equal
+    /// to `rhs.abs()` when `self` is negative and much smaller in magnitude than `rhs`.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::rem_euclid(self.x, rhs.x),
+            math::rem_euclid(self.y, rhs.y),
+            math::rem_euclid(self.z, rhs.z),
+            math::rem_euclid(self.w, rhs.w),
+        )
+    }
+
+    /// Returns the element-wise remainder of Euclidean division of `self` by the scalar `rhs`.
+    ///
+    /// Each element of the result is in the range `[0, rhs.abs())`, although due to rounding an
+    /// element may be equal to `rhs.abs()` when `self` is negative and much smaller in magnitude
+    /// than `rhs`.
+    #[inline]
+    pub fn rem_euclid_scalar(self, rhs: f32) -> Self {
+        self.rem_euclid(Self::splat(rhs))
+    }
+
     /// Returns `self` normalized to length 1.0.
     ///
     /// For valid results, `self` must _not_ be of length zero, nor very close to zero.
@@ -487,6 +534,9 @@ impl Vec4 {
     /// Returns a vector containing the fractional part of the vector, e.g. `self -
     /// self.floor()`.
     ///
+    /// This matches the GLSL `fract` function, so unlike `f32::fract` the result is
+    /// never negative for negative elements.
+    ///
     /// Note that this is fast but not precise for large numbers.
     #[inline]
     pub fn fract(self) -> Self {
@@ -603,6 +653,30 @@ impl Vec4 {
         crate::DVec4::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
     }
 
+    /// Casts all elements of `self` to `i8`.
+    #[inline]
+    pub fn as_i8vec4(&self) -> crate::I8Vec4 {
+        crate::I8Vec4::new(self.x as i8, self.y as i8, self.z as i8, self.w as i8)
+    }
+
+    /// Casts all elements of `self` to `u8`.
+    #[inline]
+    pub fn as_u8vec4(&self) -> crate::U8Vec4 {
+        crate::U8Vec4::new(self.x as u8, self.y as u8, self.z as u8, self.w as u8)
+    }
+
+    /// Casts all elements of `self` to `i16`.
+    #[inline]
+    pub fn as_i16vec4(&self) -> crate::I16Vec4 {
+        crate::I16Vec4::new(self.x as i16, self.y as i16, self.z as i16, self.w as i16)
+    }
+
+    /// Casts all elements of `self` to `u16`.
+    #[inline]
+    pub fn as_u16vec4(&self) -> crate::U16Vec4 {
+        crate::U16Vec4::new(self.x as u16, self.y as u16, self.z as u16, self.w as u16)
+    }
+
     /// Casts all elements of `self` to `i32`.
     #[inline]
     pub fn as_ivec4(&self) -> crate::IVec4 {
@@ -0,0 +1,261 @@
+// Generated from angle.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f32::math, DDegrees, Radians};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An angle in degrees.
+///
+/// Functions that take an angle accept `impl Into<Radians>`, so a `Degrees` can be
+/// passed anywhere an angle in radians is expected and is converted on the way in.
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Degrees(pub f32);
+
+impl Degrees {
+    /// The zero angle.
+    pub const ZERO: Self = Self(0.0);
+
+    /// A quarter turn, `90` degrees.
+    pub const QUARTER_TURN: Self = Self(90.0);
+
+    /// A half turn, `180` degrees.
+    pub const HALF_TURN: Self = Self(180.0);
+
+    /// A full turn, `360` degrees.
+    pub const FULL_TURN: Self = Self(360.0);
+
+    /// Creates a new angle from a value in degrees.
+    #[inline(always)]
+    pub const fn new(value: f32) -> Self {
+        Self(value)
+    }
+
+    /// Converts `self` to radians.
+    #[inline]
+    pub fn to_radians(self) -> Radians {
+        Radians(self.0.to_radians())
+    }
+
+    /// Returns the sine of the angle.
+    #[inline]
+    pub fn sin(self) -> f32 {
+        math::sin(self.0.to_radians())
+    }
+
+    /// Returns the cosine of the angle.
+    #[inline]
+    pub fn cos(self) -> f32 {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and cosine of the angle.
+    #[inline]
+    pub fn sin_cos(self) -> (f32, f32) {
+        math::sin_cos(self.0.to_radians())
+    }
+
+    /// Returns the tangent of the angle.
+    #[inline]
+    pub fn tan(self) -> f32 {
+        math::tan(self.0.to_radians())
+    }
+
+    /// Returns the equivalent angle in the range `(-180, 180]` degrees.
+    #[doc(alias = "normalize")]
+    #[inline]
+    pub fn wrap(self) -> Self {
+        let wrapped =
+            Self::HALF_TURN.0 - math::rem_euclid(Self::HALF_TURN.0 - self.0, Self::FULL_TURN.0);
+        // `rem_euclid` can round up to a full turn for tiny negative inputs
+        if wrapped <= -Self::HALF_TURN.0 {
+            Self::HALF_TURN
+        } else {
+            Self(wrapped)
+        }
+    }
+
+    /// Returns the shortest signed angle from `self` to `rhs`, in the range
+    /// `(-180, 180]` degrees.
+    ///
+    /// Adding the result to `self` gives an angle equivalent to `rhs`.
+    #[inline]
+    pub fn angle_to(self, rhs: Self) -> Self {
+        (rhs - self).wrap()
+    }
+
+    /// Performs a linear interpolation between `self` and `rhs` based on the value `s`, taking
+    /// the shortest path around the circle.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result
+    /// will be equivalent to `rhs`, but is not wrapped, see [`Self::wrap()`].
+    #[doc(alias = "mix")]
+    #[inline]
+    pub fn lerp(self, rhs: Self, s: f32) -> Self {
+        self + self.angle_to(rhs) * s
+    }
+
+    /// Returns the absolute value of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(math::abs(self.0))
+    }
+
+    /// Returns `true` if the angle is neither infinite nor `NaN`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Returns `true` if the angle is `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns true if the absolute difference between `self` and `rhs` is less than or equal
+    /// to `max_abs_diff`.
+    ///
+    /// The angles are compared as plain numbers, so angles which differ by a full turn are not
+    /// considered equal. Use [`Self::angle_to()`] to compare the equivalent angles instead.
+    #[inline]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        math::abs(self.0 - rhs.0) <= max_abs_diff
+    }
+
+    /// Casts the angle of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(self) -> DDegrees {
+        DDegrees(self.0 as f64)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for Degrees {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(Degrees)).field(&self.0).finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for Degrees {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str("°")
+    }
+}
+
+impl From<Radians> for Degrees {
+    #[inline]
+    fn from(angle: Radians) -> Self {
+        angle.to_degrees()
+    }
+}
+
+impl Neg for Degrees {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Add for Degrees {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Degrees {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Degrees {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Degrees {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<f32> for Degrees {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
+impl MulAssign<f32> for Degrees {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        self.0 *= rhs;
+    }
+}
+
+impl Mul<Degrees> for f32 {
+    type Output = Degrees;
+    #[inline]
+    fn mul(self, rhs: Degrees) -> Degrees {
+        Degrees(self * rhs.0)
+    }
+}
+
+impl Div<f32> for Degrees {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        Self(self.0 / rhs)
+    }
+}
+
+impl DivAssign<f32> for Degrees {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        self.0 /= rhs;
+    }
+}
+
+/// Returns the ratio of two angles.
+impl Div for Degrees {
+    type Output = f32;
+    #[inline]
+    fn div(self, rhs: Self) -> f32 {
+        self.0 / rhs.0
+    }
+}
+
+impl Sum for Degrees {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Degrees {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
@@ -0,0 +1,484 @@
+// Generated from dualquat.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f32::math, Affine3A, DDualQuat, Mat4, Quat, Vec3, Vec3A};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Add, Mul, MulAssign, Neg, Sub};
+
+/// Computes the Hamilton product of two quaternions.
+///
+/// Unlike `Quat::mul_quat` this does not require the inputs to be normalized, which is
+/// needed for the dual part.
+#[inline(always)]
+fn quat_mul(a: Quat, b: Quat) -> Quat {
+    Quat::from_xyzw(
+        a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+    )
+}
+
+/// A dual quaternion representing a rigid transform, that is a rotation followed by a
+/// translation.
+///
+/// Dual quaternions are commonly used for skinning as blending them does not suffer from the
+/// volume loss artifacts of blending matrices.
+///
+/// This type consists of a real part, which is the rotation, and a dual part, which encodes
+/// the translation. Rigid transforms are represented by unit dual quaternions, use
+/// [`Self::normalize()`] to renormalize after blending or accumulating many transforms.
+///
+/// Scale and shear can not be represented by a dual quaternion.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct DualQuat {
+    pub real: Quat,
+    pub dual: Quat,
+}
+
+impl DualQuat {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self {
+        real: Quat::IDENTITY,
+        dual: Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+    };
+
+    /// All NANs.
+    pub const NAN: Self = Self {
+        real: Quat::NAN,
+        dual: Quat::NAN,
+    };
+
+    /// Creates a dual quaternion from its real and dual parts.
+    ///
+    /// This function does not check if the input is normalized, it is up to the user to
+    /// provide normalized input or to normalize the resulting dual quaternion.
+    #[inline(always)]
+    pub const fn from_real_dual(real: Quat, dual: Quat) -> Self {
+        Self { real, dual }
+    }
+
+    /// Creates a dual quaternion from a rotation followed by a translation.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let t = Quat::from_xyzw(translation.x, translation.y, translation.z, 0.0);
+        Self {
+            real: rotation,
+            dual: quat_mul(t, rotation) * 0.5,
+        }
+    }
+
+    /// Creates a dual quaternion from the given `rotation` quaternion.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_quat(rotation: Quat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            real: rotation,
+            dual: Self::IDENTITY.dual,
+        }
+    }
+
+    /// Creates a dual quaternion from the given `translation`.
+    #[inline]
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            real: Quat::IDENTITY,
+            dual: Quat::from_xyzw(
+                translation.x * 0.5,
+                translation.y * 0.5,
+                translation.z * 0.5,
+                0.0,
+            ),
+        }
+    }
+
+    /// Creates a dual quaternion from the rotation and translation of an affine transform.
+    ///
+    /// Any scale in `a` is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of `a.matrix3` is zero or if the resulting rotation is
+    /// not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_affine3(a: &Affine3A) -> Self {
+        let (_, rotation, translation) = a.to_scale_rotation_translation();
+        Self::from_rotation_translation(rotation, translation)
+    }
+
+    /// Creates a dual quaternion from the rotation and translation of a 4x4 matrix.
+    ///
+    /// Any scale in `mat` is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of `mat` is zero or if the resulting rotation is not
+    /// normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_mat4(mat: &Mat4) -> Self {
+        let (_, rotation, translation) = mat.to_scale_rotation_translation();
+        Self::from_rotation_translation(rotation, translation)
+    }
+
+    /// Returns the rotation of `self`.
+    #[inline]
+    pub fn rotation(&self) -> Quat {
+        self.real
+    }
+
+    /// Returns the translation of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn translation(&self) -> Vec3 {
+        glam_assert!(self.is_normalized());
+        quat_mul(self.dual, self.real.conjugate()).xyz() * 2.0
+    }
+
+    /// Returns the rotation and translation of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn to_rotation_translation(&self) -> (Quat, Vec3) {
+        (self.real, self.translation())
+    }
+
+    /// Returns the quaternion conjugate of both parts of `self`.
+    ///
+    /// For a unit dual quaternion this is the inverse transform.
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Returns the inverse of a normalized dual quaternion.
+    ///
+    /// Typically dual quaternion inverse returns the conjugate of a normalized dual
+    /// quaternion. Because `self` is assumed to already be unit length this method *does not*
+    /// normalize before returning the conjugate.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        glam_assert!(self.is_normalized());
+        self.conjugate()
+    }
+
+    /// Computes the dot product of the real parts of `self` and `rhs`.
+    ///
+    /// A negative value means `self` and `rhs` rotate in opposite directions around the
+    /// 4D hypersphere and one of them should be negated before blending.
+    #[inline]
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.real.dot(rhs.real)
+    }
+
+    /// Returns `self` normalized so that it represents a rigid transform.
+    ///
+    /// This divides both parts by the length of the real part and then removes any component
+    /// of the dual part that is not orthogonal to the real part.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the real part of `self` is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let length_recip = self.real.length_recip();
+        glam_assert!(length_recip.is_finite());
+        let real = self.real * length_recip;
+        let dual = self.dual * length_recip;
+        Self {
+            real,
+            dual: dual - real * real.dot(dual),
+        }
+    }
+
+    /// Returns whether `self` is a unit dual quaternion, meaning the real part has a length
+    /// of `1.0` and is orthogonal to the dual part.
+    ///
+    /// Uses a precision threshold of `1e-4`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        self.real.is_normalized() && math::abs(self.real.dot(self.dual)) <= 1e-4
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.real.is_finite() && self.dual.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.real.is_nan() || self.dual.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two dual quaternions contain similar elements. It works
+    /// best when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.real.abs_diff_eq(rhs.real, max_abs_diff)
+            && self.dual.abs_diff_eq(rhs.dual, max_abs_diff)
+    }
+
+    /// Transforms the given 3D point, applying rotation and translation.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_point3(&self, rhs: Vec3) -> Vec3 {
+        self.real.mul_vec3(rhs) + self.translation()
+    }
+
+    /// Transforms the given 3D vector, applying rotation (but NOT translation).
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vector3(&self, rhs: Vec3) -> Vec3 {
+        self.real.mul_vec3(rhs)
+    }
+
+    /// Transforms the given [`Vec3A`] point, applying rotation and translation.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_point3a(&self, rhs: Vec3A) -> Vec3A {
+        self.real.mul_vec3a(rhs) + Vec3A::from(self.translation())
+    }
+
+    /// Transforms the given [`Vec3A`] vector, applying rotation (but NOT translation).
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vector3a(&self, rhs: Vec3A) -> Vec3A {
+        self.real.mul_vec3a(rhs)
+    }
+
+    /// Multiplies two dual quaternions. If they each represent a rigid transform, the result
+    /// will represent the combined transform.
+    ///
+    /// Note that due to dual quaternion multiplication rules, the order of operations is
+    /// important: `self * rhs` applies `rhs` first and then `self`.
+    #[inline]
+    pub fn mul_dual_quat(self, rhs: Self) -> Self {
+        Self {
+            real: quat_mul(self.real, rhs.real),
+            dual: quat_mul(self.real, rhs.dual) + quat_mul(self.dual, rhs.real),
+        }
+    }
+
+    /// Performs dual quaternion linear blending (DLB) between `self` and `end` based on the
+    /// value `s`.
+    ///
+    /// The blend takes the shortest path and the result is normalized. When `s` is `0.0`,
+    /// the result will be equal to `self`. When `s` is `1.0`, the result will be equal to
+    /// `end`.
+    ///
+    /// To blend more than two transforms, for example when skinning, accumulate weighted dual
+    /// quaternions with `+` and `*`, negating any with a negative [`Self::dot()`] against the
+    /// first one, and [`Self::normalize()`] the sum.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[doc(alias = "dlb")]
+    pub fn lerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        let end = if self.dot(end) < 0.0 { -end } else { end };
+        (self * (1.0 - s) + end * s).normalize()
+    }
+
+    /// Performs screw linear interpolation (ScLERP) between `self` and `end` based on the
+    /// value `s`.
+    ///
+    /// This interpolates with constant angular and linear velocity along the screw motion
+    /// between the two transforms, taking the shortest path. When `s` is `0.0`, the result
+    /// will be equal to `self`. When `s` is `1.0`, the result will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn sclerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        let end = if self.dot(end) < 0.0 { -end } else { end };
+
+        // The relative transform from `self` to `end` as a screw motion.
+        let diff = self.conjugate().mul_dual_quat(end);
+        let real = diff.real;
+        let dual = diff.dual;
+        let sin_half_angle = real.xyz().length();
+        if sin_half_angle < 1e-5 {
+            // The screw axis is ill defined for small rotations, where linear blending is
+            // accurate.
+            return self.lerp(end, s);
+        }
+
+        let axis = real.xyz() / sin_half_angle;
+        let pitch = -2.0 * dual.w / sin_half_angle;
+        let moment = (dual.xyz() - axis * (0.5 * pitch * real.w)) / sin_half_angle;
+
+        let half_angle = math::atan2(sin_half_angle, real.w) * s;
+        let pitch = pitch * s;
+        let (sin, cos) = math::sin_cos(half_angle);
+        let real = axis * sin;
+        let dual = moment * sin + axis * (0.5 * pitch * cos);
+        self.mul_dual_quat(Self {
+            real: Quat::from_xyzw(real.x, real.y, real.z, cos),
+            dual: Quat::from_xyzw(dual.x, dual.y, dual.z, -0.5 * pitch * sin),
+        })
+    }
+
+    #[inline]
+    pub fn as_f64(self) -> DDualQuat {
+        DDualQuat::from_real_dual(self.real.as_f64(), self.dual.as_f64())
+    }
+}
+
+impl Default for DualQuat {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DualQuat {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DualQuat))
+            .field("real", &self.real)
+            .field("dual", &self.dual)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DualQuat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.real, self.dual)
+    }
+}
+
+impl Add<DualQuat> for DualQuat {
+    type Output = Self;
+    /// Adds two dual quaternions.
+    ///
+    /// The sum is not guaranteed to be normalized.
+    ///
+    /// Note that addition is not the same as combining the transforms represented by the
+    /// two dual quaternions! That corresponds to multiplication.
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            real: self.real + rhs.real,
+            dual: self.dual + rhs.dual,
+        }
+    }
+}
+
+impl Sub<DualQuat> for DualQuat {
+    type Output = Self;
+    /// Subtracts the `rhs` dual quaternion from `self`.
+    ///
+    /// The difference is not guaranteed to be normalized.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            real: self.real - rhs.real,
+            dual: self.dual - rhs.dual,
+        }
+    }
+}
+
+impl Mul<f32> for DualQuat {
+    type Output = Self;
+    /// Multiplies a dual quaternion by a scalar value.
+    ///
+    /// The product is not guaranteed to be normalized.
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            real: self.real * rhs,
+            dual: self.dual * rhs,
+        }
+    }
+}
+
+impl Mul<DualQuat> for DualQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_dual_quat(rhs)
+    }
+}
+
+impl MulAssign<DualQuat> for DualQuat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_dual_quat(rhs);
+    }
+}
+
+impl Neg for DualQuat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self * -1.0
+    }
+}
+
+impl From<DualQuat> for Mat4 {
+    #[inline]
+    fn from(dq: DualQuat) -> Mat4 {
+        let (rotation, translation) = dq.to_rotation_translation();
+        Mat4::from_rotation_translation(rotation, translation)
+    }
+}
+
+impl From<DualQuat> for Affine3A {
+    #[inline]
+    fn from(dq: DualQuat) -> Affine3A {
+        let (rotation, translation) = dq.to_rotation_translation();
+        Affine3A::from_rotation_translation(rotation, translation)
+    }
+}
@@ -1,6 +1,8 @@
 // Generated from mat.rs.tera template. Edit the template, not the generated file.
 
-use crate::{f32::math, swizzles::*, DMat3, EulerRot, Mat2, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A};
+use crate::{
+    f32::math, swizzles::*, DMat3, EulerRot, Mat2, Mat3A, Mat4, Quat, Radians, Vec2, Vec3, Vec3A,
+};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -182,10 +184,10 @@ impl Mat3 {
     ///
     /// Will panic if `axis` is not normalized when `glam_assert` is enabled.
     #[inline]
-    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Radians>) -> Self {
         glam_assert!(axis.is_normalized());
 
-        let (sin, cos) = math::sin_cos(angle);
+        let (sin, cos) = math::sin_cos(angle.into().0);
         let (xsin, ysin, zsin) = axis.mul(sin).into();
         let (x, y, z) = axis.into();
         let (x2, y2, z2) = axis.mul(axis).into();
@@ -203,15 +205,37 @@ impl Mat3 {
     #[inline]
     /// Creates a 3D rotation matrix from the given euler rotation sequence and the angles (in
     /// radians).
-    pub fn from_euler(order: EulerRot, a: f32, b: f32, c: f32) -> Self {
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<Radians>,
+        b: impl Into<Radians>,
+        c: impl Into<Radians>,
+    ) -> Self {
         let quat = Quat::from_euler(order, a, b, c);
         Self::from_quat(quat)
     }
 
+    /// Extracts the angles (in radians) of the given euler rotation sequence from `self`.
+    ///
+    /// See [`EulerRot`] for the range of the returned angles and the behavior at gimbal lock.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any column of `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn to_euler(&self, order: EulerRot) -> (f32, f32, f32) {
+        glam_assert!(
+            self.x_axis.is_normalized()
+                && self.y_axis.is_normalized()
+                && self.z_axis.is_normalized()
+        );
+        Quat::from_mat3(self).to_euler(order)
+    }
+
     /// Creates a 3D rotation matrix from `angle` (in radians) around the x axis.
     #[inline]
-    pub fn from_rotation_x(angle: f32) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             Vec3::X,
             Vec3::new(0.0, cosa, sina),
@@ -221,8 +245,8 @@ impl Mat3 {
 
     /// Creates a 3D rotation matrix from `angle` (in radians) around the y axis.
     #[inline]
-    pub fn from_rotation_y(angle: f32) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             Vec3::new(cosa, 0.0, -sina),
             Vec3::Y,
@@ -232,8 +256,8 @@ impl Mat3 {
 
     /// Creates a 3D rotation matrix from `angle` (in radians) around the z axis.
     #[inline]
-    pub fn from_rotation_z(angle: f32) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             Vec3::new(cosa, sina, 0.0),
             Vec3::new(-sina, cosa, 0.0),
@@ -260,8 +284,8 @@ impl Mat3 {
     /// The resulting matrix can be used to transform 2D points and vectors. See
     /// [`Self::transform_point2()`] and [`Self::transform_vector2()`].
     #[inline]
-    pub fn from_angle(angle: f32) -> Self {
-        let (sin, cos) = math::sin_cos(angle);
+    pub fn from_angle(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = math::sin_cos(angle.into().0);
         Self::from_cols(Vec3::new(cos, sin, 0.0), Vec3::new(-sin, cos, 0.0), Vec3::Z)
     }
 
@@ -271,8 +295,12 @@ impl Mat3 {
     /// The resulting matrix can be used to transform 2D points and vectors. See
     /// [`Self::transform_point2()`] and [`Self::transform_vector2()`].
     #[inline]
-    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
-        let (sin, cos) = math::sin_cos(angle);
+    pub fn from_scale_angle_translation(
+        scale: Vec2,
+        angle: impl Into<Radians>,
+        translation: Vec2,
+    ) -> Self {
+        let (sin, cos) = math::sin_cos(angle.into().0);
         Self::from_cols(
             Vec3::new(cos * scale.x, sin * scale.x, 0.0),
             Vec3::new(-sin * scale.y, cos * scale.y, 0.0),
@@ -434,6 +462,213 @@ impl Mat3 {
         Self::from_cols(tmp0.mul(inv_det), tmp1.mul(inv_det), tmp2.mul(inv_det)).transpose()
     }
 
+    /// Computes the eigenvalues and eigenvectors of `self`, which is assumed to be symmetric.
+    ///
+    /// Returns the eigenvalues in descending order and a matrix with the matching normalized
+    /// eigenvectors as columns. The eigenvector matrix is orthonormal with a determinant of `1`.
+    ///
+    /// The cyclic Jacobi method is used, which also converges when eigenvalues are repeated. In
+    /// that case the returned eigenvectors are an arbitrary orthonormal basis of the shared
+    /// eigenspace.
+    ///
+    /// Only the symmetric part of `self`, `(self + self.transpose()) / 2`, is used.
+    #[must_use]
+    pub fn symmetric_eigen(&self) -> (Vec3, Self) {
+        let mut a = (*self + self.transpose()) * 0.5;
+        let mut v = Self::IDENTITY;
+        for _ in 0..math::JACOBI_MAX_SWEEPS {
+            let off = a.y_axis.x * a.y_axis.x + a.z_axis.x * a.z_axis.x + a.z_axis.y * a.z_axis.y;
+            let diag = a.x_axis.x * a.x_axis.x + a.y_axis.y * a.y_axis.y + a.z_axis.z * a.z_axis.z;
+
+            if off <= f32::EPSILON * f32::EPSILON * diag {
+                break;
+            }
+
+            for p in 0..2 {
+                for q in p + 1..3 {
+                    let apq = a.col(q)[p];
+                    if apq == 0.0 {
+                        continue;
+                    }
+
+                    // Rotate in the `pq` plane so that the `pq` element becomes zero.
+                    let (c, s) = math::jacobi_rotation(a.col(p)[p], a.col(q)[q], apq);
+                    let mut j = Self::IDENTITY;
+                    j.col_mut(p)[p] = c;
+                    j.col_mut(p)[q] = -s;
+                    j.col_mut(q)[p] = s;
+                    j.col_mut(q)[q] = c;
+                    a = j.transpose() * a * j;
+                    a.col_mut(p)[q] = 0.0;
+                    a.col_mut(q)[p] = 0.0;
+                    v *= j;
+                }
+            }
+        }
+
+        let mut values = [a.x_axis.x, a.y_axis.y, a.z_axis.z];
+        let mut vectors = [v.x_axis, v.y_axis, v.z_axis];
+
+        for i in 0..2 {
+            for j in i + 1..3 {
+                if values[j] > values[i] {
+                    values.swap(i, j);
+                    vectors.swap(i, j);
+                }
+            }
+        }
+
+        if vectors[0].cross(vectors[1]).dot(vectors[2]) < 0.0 {
+            vectors[2] = -vectors[2];
+        }
+        let vectors = Self::from_cols(vectors[0], vectors[1], vectors[2]);
+
+        (Vec3::from_array(values), vectors)
+    }
+
+    /// Computes the singular value decomposition of `self`.
+    ///
+    /// Returns `(u, sigma, v)` where `u` and `v` are orthonormal matrices and `sigma` contains the
+    /// singular values in descending order, such that `self` is equal to
+    /// `u * Self::from_diagonal(sigma) * v.transpose()`.
+    ///
+    /// The singular values are never negative. `v` always has a determinant of `1` and so does
+    /// `u` unless the determinant of `self` is negative. If `self` is singular the columns of `u`
+    /// matching zero singular values are chosen to complete an orthonormal basis.
+    ///
+    /// The one-sided Jacobi method is used, which is accurate for small singular values.
+    #[must_use]
+    pub fn svd(&self) -> (Self, Vec3, Self) {
+        let mut u = [self.x_axis, self.y_axis, self.z_axis];
+        let mut v = [Vec3::X, Vec3::Y, Vec3::Z];
+
+        for _ in 0..math::JACOBI_MAX_SWEEPS {
+            let mut converged = true;
+            for p in 0..2 {
+                for q in p + 1..3 {
+                    // Rotate columns `p` and `q` of `u` until they are orthogonal.
+                    let alpha = u[p].length_squared();
+                    let beta = u[q].length_squared();
+                    let gamma = u[p].dot(u[q]);
+                    if math::abs(gamma) <= f32::EPSILON * math::sqrt(alpha * beta) {
+                        continue;
+                    }
+                    converged = false;
+
+                    let (c, s) = math::jacobi_rotation(alpha, beta, gamma);
+                    let (up, uq) = (u[p], u[q]);
+                    u[p] = up * c - uq * s;
+                    u[q] = up * s + uq * c;
+                    let (vp, vq) = (v[p], v[q]);
+                    v[p] = vp * c - vq * s;
+                    v[q] = vp * s + vq * c;
+                }
+            }
+            if converged {
+                break;
+            }
+        }
+
+        let mut sigma = u.map(|c| c.length());
+        for i in 0..2 {
+            for j in i + 1..3 {
+                if sigma[j] > sigma[i] {
+                    sigma.swap(i, j);
+                    u.swap(i, j);
+                    v.swap(i, j);
+                }
+            }
+        }
+
+        if v[0].cross(v[1]).dot(v[2]) < 0.0 {
+            u[2] = -u[2];
+            v[2] = -v[2];
+        }
+
+        let tolerance = sigma[0] * f32::EPSILON;
+        u[0] = if sigma[0] > 0.0 {
+            u[0] / sigma[0]
+        } else {
+            Vec3::X
+        };
+
+        u[1] = if sigma[1] > tolerance {
+            u[1] / sigma[1]
+        } else {
+            u[0].any_orthonormal_vector()
+        };
+        u[2] = if sigma[2] > tolerance {
+            u[2] / sigma[2]
+        } else {
+            u[0].cross(u[1])
+        };
+        (
+            Self::from_cols(u[0], u[1], u[2]),
+            Vec3::from_array(sigma),
+            Self::from_cols(v[0], v[1], v[2]),
+        )
+    }
+
+    /// Returns a rotation matrix made from the columns of `self` using the Gram-Schmidt process.
+    ///
+    /// The column at index `primary` keeps its direction and is normalized. The next column,
+    /// wrapping around from the z axis to the x axis, is made orthogonal to it and normalized, and
+    /// the remaining column is replaced by their cross product so the result is always a rotation.
+    /// If the next column is parallel to the primary one an arbitrary orthogonal axis is used.
+    ///
+    /// This is cheaper than [`Self::polar_decomposition()`] and is suitable for removing small
+    /// amounts of numerical drift, but the result is not the closest rotation to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `primary` is greater than 2.
+    ///
+    /// Will panic if the `primary` column is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn orthonormalize(&self, primary: usize) -> Self {
+        let (i, j, k) = match primary {
+            0 => (0, 1, 2),
+            1 => (1, 2, 0),
+            2 => (2, 0, 1),
+            _ => panic!("index out of bounds"),
+        };
+        let a = self.col(i).normalize();
+        let b = self.col(j);
+        let ortho = b - a * a.dot(b);
+        // Fall back to an arbitrary axis if `b` is too close to parallel for a stable direction.
+        let b = if ortho.length_squared() > f32::EPSILON * b.length_squared() {
+            ortho.normalize()
+        } else {
+            a.any_orthonormal_vector()
+        };
+        let mut res = Self::ZERO;
+        *res.col_mut(i) = a;
+        *res.col_mut(j) = b;
+        *res.col_mut(k) = a.cross(b);
+        res
+    }
+
+    /// Decomposes `self` into a rotation and a symmetric stretch matrix such that `self` is equal
+    /// to `rotation * stretch`.
+    ///
+    /// The rotation is the closest rotation matrix to `self`, which makes this useful for
+    /// recovering a valid rotation from a matrix that contains numerical drift, scale or shear.
+    ///
+    /// If the determinant of `self` is negative the reflection is kept in `stretch`, which then
+    /// has one negative eigenvalue. If `self` is singular the rotation is still valid but not
+    /// unique.
+    #[must_use]
+    pub fn polar_decomposition(&self) -> (Self, Self) {
+        let (mut u, mut sigma, v) = self.svd();
+        if u.determinant() < 0.0 {
+            u.z_axis = -u.z_axis;
+            sigma.z = -sigma.z;
+        }
+        let vt = v.transpose();
+        (u * vt, v * Self::from_diagonal(sigma) * vt)
+    }
+
     /// Transforms the given 2D vector as a point.
     ///
     /// This is the equivalent of multiplying `rhs` as a 3D vector where `z` is `1`.
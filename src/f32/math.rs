@@ -50,11 +50,6 @@ mod libm_math {
         super::acos_approx_f32(f)
     }
 
-    #[inline(always)]
-    pub(crate) fn asin_clamped(f: f32) -> f32 {
-        libm::asinf(f.clamp(-1.0, 1.0))
-    }
-
     #[inline(always)]
     pub(crate) fn atan2(f: f32, other: f32) -> f32 {
         libm::atan2f(f, other)
@@ -115,6 +110,11 @@ mod libm_math {
         libm::expf(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn ln(f: f32) -> f32 {
+        libm::logf(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f32, n: f32) -> f32 {
         libm::powf(f, n)
@@ -124,6 +124,26 @@ mod libm_math {
     pub(crate) fn mul_add(a: f32, b: f32, c: f32) -> f32 {
         libm::fmaf(a, b, c)
     }
+
+    #[inline(always)]
+    pub(crate) fn div_euclid(a: f32, b: f32) -> f32 {
+        // Based on https://doc.rust-lang.org/src/std/f32.rs.html
+        let q = libm::truncf(a / b);
+        if a % b < 0.0 {
+            return if b > 0.0 { q - 1.0 } else { q + 1.0 };
+        }
+        q
+    }
+
+    #[inline(always)]
+    pub(crate) fn rem_euclid(a: f32, b: f32) -> f32 {
+        let r = a % b;
+        if r < 0.0 {
+            r + abs(b)
+        } else {
+            r
+        }
+    }
 }
 
 #[cfg(not(feature = "libm"))]
@@ -138,11 +158,6 @@ mod std_math {
         super::acos_approx_f32(f)
     }
 
-    #[inline(always)]
-    pub(crate) fn asin_clamped(f: f32) -> f32 {
-        f32::asin(f32::clamp(f, -1.0, 1.0))
-    }
-
     #[inline(always)]
     pub(crate) fn atan2(f: f32, other: f32) -> f32 {
         f32::atan2(f, other)
@@ -199,6 +214,11 @@ mod std_math {
         f32::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn ln(f: f32) -> f32 {
+        f32::ln(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f32, n: f32) -> f32 {
         f32::powf(f, n)
@@ -208,6 +228,16 @@ mod std_math {
     pub(crate) fn mul_add(a: f32, b: f32, c: f32) -> f32 {
         f32::mul_add(a, b, c)
     }
+
+    #[inline(always)]
+    pub(crate) fn div_euclid(a: f32, b: f32) -> f32 {
+        f32::div_euclid(a, b)
+    }
+
+    #[inline(always)]
+    pub(crate) fn rem_euclid(a: f32, b: f32) -> f32 {
+        f32::rem_euclid(a, b)
+    }
 }
 
 #[cfg(feature = "libm")]
@@ -215,3 +245,18 @@ pub(crate) use libm_math::*;
 
 #[cfg(not(feature = "libm"))]
 pub(crate) use std_math::*;
+
+/// The maximum number of sweeps over all off-diagonal pairs done by the Jacobi eigenvalue and
+/// singular value methods. 3x3 matrices usually converge in less than 6.
+pub(crate) const JACOBI_MAX_SWEEPS: usize = 16;
+
+/// Returns the cosine and sine of the Jacobi rotation that zeroes the off-diagonal `apq` of the
+/// symmetric 2x2 matrix `[[app, apq], [apq, aqq]]`.
+#[inline]
+pub(crate) fn jacobi_rotation(app: f32, aqq: f32, apq: f32) -> (f32, f32) {
+    // Use the smaller root of `t^2 + 2 * t * theta - 1 = 0` for stability.
+    let theta = (aqq - app) / (2.0 * apq);
+    let t = signum(theta) / (abs(theta) + sqrt(theta * theta + 1.0));
+    let c = 1.0 / sqrt(t * t + 1.0);
+    (c, t * c)
+}
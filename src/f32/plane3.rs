@@ -0,0 +1,208 @@
+// Generated from plane.rs.tera template. Edit the template, not the generated file.
+
+use crate::{Affine3A, Mat4, Vec3, Vec4};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A 3D plane, defined by a `normal` and a distance `d` such that the plane contains all
+/// points `p` where `normal.dot(p) + d == 0`.
+///
+/// When `normal` is normalized, `-d` is the distance of the plane from the origin along
+/// `normal` and [`Self::signed_distance()`] returns true distances. Most methods assume that
+/// the plane is normalized, use [`Self::normalize()`] if it is not.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Plane3 {
+    pub normal: Vec3,
+    pub d: f32,
+}
+
+impl Plane3 {
+    /// Creates a new plane from a `normal` and a distance `d`.
+    ///
+    /// This function does not check if the input is normalized.
+    #[inline(always)]
+    pub const fn new(normal: Vec3, d: f32) -> Self {
+        Self { normal, d }
+    }
+
+    /// Creates a plane with the given `normal` which contains `point`.
+    ///
+    /// This function does not check if `normal` is normalized.
+    #[inline]
+    pub fn from_normal_point(normal: Vec3, point: Vec3) -> Self {
+        Self {
+            normal,
+            d: -normal.dot(point),
+        }
+    }
+
+    /// Creates a normalized plane containing the points `a`, `b` and `c`.
+    ///
+    /// The normal faces towards the side from which the points appear in counter-clockwise
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the points are collinear when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        let normal = (b - a).cross(c - a).normalize();
+        Self::from_normal_point(normal, a)
+    }
+
+    /// Creates a plane from a 4D vector containing the `normal` in `xyz` and `d` in `w`.
+    #[inline]
+    pub fn from_vec4(v: Vec4) -> Self {
+        Self {
+            normal: v.truncate(),
+            d: v.w,
+        }
+    }
+
+    /// Returns the plane as a 4D vector containing the `normal` in `xyz` and `d` in `w`.
+    #[inline]
+    pub fn to_vec4(&self) -> Vec4 {
+        self.normal.extend(self.d)
+    }
+
+    /// Returns `self` scaled so that `normal` is of length `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let length_recip = self.normal.length_recip();
+        glam_assert!(length_recip.is_finite());
+        Self {
+            normal: self.normal * length_recip,
+            d: self.d * length_recip,
+        }
+    }
+
+    /// Returns whether `normal` is of length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        self.normal.is_normalized()
+    }
+
+    /// Returns the signed distance of `point` from the plane, which is positive on the side
+    /// that `normal` faces.
+    ///
+    /// The distance is scaled by the length of `normal` if the plane is not normalized.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) + self.d
+    }
+
+    /// Returns the closest point to `point` on the plane.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn project_point(&self, point: Vec3) -> Vec3 {
+        glam_assert!(self.is_normalized());
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Returns `vector` with the component along `normal` removed, so that it is parallel to
+    /// the plane.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn project_vector(&self, vector: Vec3) -> Vec3 {
+        glam_assert!(self.is_normalized());
+        vector - self.normal * self.normal.dot(vector)
+    }
+
+    /// Returns the plane facing the opposite direction.
+    #[inline]
+    pub fn flip(&self) -> Self {
+        Self {
+            normal: -self.normal,
+            d: -self.d,
+        }
+    }
+
+    /// Returns `self` transformed by `transform`.
+    ///
+    /// The normal is transformed by the inverse transpose of the linear part of `transform`
+    /// and the result is normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of `transform.matrix3` is zero when `glam_assert` is
+    /// enabled.
+    #[inline]
+    pub fn transformed_by(&self, transform: &Affine3A) -> Self {
+        let normal = transform
+            .matrix3
+            .inverse()
+            .transpose()
+            .mul_vec3(self.normal);
+        let translation = Vec3::from(transform.translation);
+        Self {
+            normal,
+            d: self.d - normal.dot(translation),
+        }
+        .normalize()
+    }
+
+    /// Returns `self` transformed by the affine transform `mat`.
+    ///
+    /// See [`Self::transformed_by()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of `mat` is zero when `glam_assert` is enabled.
+    #[inline]
+    pub fn transformed_by_mat4(&self, mat: &Mat4) -> Self {
+        Self::from_vec4(mat.inverse().transpose() * self.to_vec4()).normalize()
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.normal.is_finite() && self.d.is_finite()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two planes contain similar elements. It works best when
+    /// comparing with a known value. The `max_abs_diff` that should be used used depends on the
+    /// values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.to_vec4().abs_diff_eq(rhs.to_vec4(), max_abs_diff)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for Plane3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(Plane3))
+            .field("normal", &self.normal)
+            .field("d", &self.d)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for Plane3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.normal, self.d)
+    }
+}
@@ -0,0 +1,271 @@
+// Generated from angle.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f32::math, DRadians, Degrees};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An angle in radians.
+///
+/// Functions that take an angle, such as
+/// [`Quat::from_rotation_x()`](crate::Quat::from_rotation_x), accept
+/// `impl Into<Radians>`. This allows passing a `Radians`, a [`Degrees`] or a
+/// plain `f32`, which is interpreted as radians.
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Radians(pub f32);
+
+impl Radians {
+    /// The zero angle.
+    pub const ZERO: Self = Self(0.0);
+
+    /// A quarter turn, `PI/2` radians.
+    pub const QUARTER_TURN: Self = Self(core::f32::consts::FRAC_PI_2);
+
+    /// A half turn, `PI` radians.
+    pub const HALF_TURN: Self = Self(core::f32::consts::PI);
+
+    /// A full turn, `2*PI` radians.
+    pub const FULL_TURN: Self = Self(core::f32::consts::TAU);
+
+    /// Creates a new angle from a value in radians.
+    #[inline(always)]
+    pub const fn new(value: f32) -> Self {
+        Self(value)
+    }
+
+    /// Converts `self` to degrees.
+    #[inline]
+    pub fn to_degrees(self) -> Degrees {
+        Degrees(self.0.to_degrees())
+    }
+
+    /// Returns the sine of the angle.
+    #[inline]
+    pub fn sin(self) -> f32 {
+        math::sin(self.0)
+    }
+
+    /// Returns the cosine of the angle.
+    #[inline]
+    pub fn cos(self) -> f32 {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and cosine of the angle.
+    #[inline]
+    pub fn sin_cos(self) -> (f32, f32) {
+        math::sin_cos(self.0)
+    }
+
+    /// Returns the tangent of the angle.
+    #[inline]
+    pub fn tan(self) -> f32 {
+        math::tan(self.0)
+    }
+
+    /// Returns the equivalent angle in the range `(-PI, PI]` radians.
+    #[doc(alias = "normalize")]
+    #[inline]
+    pub fn wrap(self) -> Self {
+        let wrapped =
+            Self::HALF_TURN.0 - math::rem_euclid(Self::HALF_TURN.0 - self.0, Self::FULL_TURN.0);
+        // `rem_euclid` can round up to a full turn for tiny negative inputs
+        if wrapped <= -Self::HALF_TURN.0 {
+            Self::HALF_TURN
+        } else {
+            Self(wrapped)
+        }
+    }
+
+    /// Returns the shortest signed angle from `self` to `rhs`, in the range
+    /// `(-PI, PI]` radians.
+    ///
+    /// Adding the result to `self` gives an angle equivalent to `rhs`.
+    #[inline]
+    pub fn angle_to(self, rhs: Self) -> Self {
+        (rhs - self).wrap()
+    }
+
+    /// Performs a linear interpolation between `self` and `rhs` based on the value `s`, taking
+    /// the shortest path around the circle.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result
+    /// will be equivalent to `rhs`, but is not wrapped, see [`Self::wrap()`].
+    #[doc(alias = "mix")]
+    #[inline]
+    pub fn lerp(self, rhs: Self, s: f32) -> Self {
+        self + self.angle_to(rhs) * s
+    }
+
+    /// Returns the absolute value of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(math::abs(self.0))
+    }
+
+    /// Returns `true` if the angle is neither infinite nor `NaN`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Returns `true` if the angle is `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns true if the absolute difference between `self` and `rhs` is less than or equal
+    /// to `max_abs_diff`.
+    ///
+    /// The angles are compared as plain numbers, so angles which differ by a full turn are not
+    /// considered equal. Use [`Self::angle_to()`] to compare the equivalent angles instead.
+    #[inline]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        math::abs(self.0 - rhs.0) <= max_abs_diff
+    }
+
+    /// Casts the angle of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(self) -> DRadians {
+        DRadians(self.0 as f64)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for Radians {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(Radians)).field(&self.0).finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for Radians {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" rad")
+    }
+}
+
+impl From<Degrees> for Radians {
+    #[inline]
+    fn from(angle: Degrees) -> Self {
+        angle.to_radians()
+    }
+}
+
+/// A plain `f32` angle is interpreted as radians.
+impl From<f32> for Radians {
+    #[inline(always)]
+    fn from(angle: f32) -> Self {
+        Self(angle)
+    }
+}
+
+impl Neg for Radians {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Add for Radians {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Radians {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Radians {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Radians {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<f32> for Radians {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
+impl MulAssign<f32> for Radians {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        self.0 *= rhs;
+    }
+}
+
+impl Mul<Radians> for f32 {
+    type Output = Radians;
+    #[inline]
+    fn mul(self, rhs: Radians) -> Radians {
+        Radians(self * rhs.0)
+    }
+}
+
+impl Div<f32> for Radians {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        Self(self.0 / rhs)
+    }
+}
+
+impl DivAssign<f32> for Radians {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        self.0 /= rhs;
+    }
+}
+
+/// Returns the ratio of two angles.
+impl Div for Radians {
+    type Output = f32;
+    #[inline]
+    fn div(self, rhs: Self) -> f32 {
+        self.0 / rhs.0
+    }
+}
+
+impl Sum for Radians {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Radians {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
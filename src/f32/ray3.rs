@@ -0,0 +1,258 @@
+// Generated from ray.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f32::math, Aabb3, Aabb3A, Affine3A, Mat4, Plane3, Sphere, Vec3, Vec3A};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A 3D ray with an origin and a direction.
+///
+/// The intersection methods return the distance along the ray to the hit point in multiples of
+/// `direction`, so if `direction` is normalized the result is the distance from `origin`. Only
+/// hits in front of the origin, at a distance of zero or more, are reported.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Ray3 {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray3 {
+    /// Creates a new ray from an `origin` and a `direction`.
+    ///
+    /// The direction does not need to be normalized.
+    #[inline(always)]
+    pub const fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Creates a new ray starting at `origin` with a normalized direction pointing towards
+    /// `target`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `origin` and `target` are equal when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_points(origin: Vec3, target: Vec3) -> Self {
+        Self {
+            origin,
+            direction: (target - origin).normalize(),
+        }
+    }
+
+    /// Returns the point at the given distance `t` along the ray.
+    #[inline]
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.direction.mul_add(Vec3::splat(t), self.origin)
+    }
+
+    /// Returns the distance along the ray to where it intersects `plane`.
+    ///
+    /// Returns `None` if the ray is parallel to the plane or the plane is behind the origin.
+    #[inline]
+    pub fn intersect_plane(&self, plane: &Plane3) -> Option<f32> {
+        // The parallel test is relative to the lengths of the normal and direction, so that
+        // short directions and unnormalized planes are not rejected.
+        let denom = plane.normal.dot(self.direction);
+        let scale_squared = plane.normal.length_squared() * self.direction.length_squared();
+        if denom * denom <= f32::EPSILON * f32::EPSILON * scale_squared {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t >= 0.0 {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the distance along the ray to where it first intersects the surface of `sphere`.
+    ///
+    /// If the origin is inside the sphere this is where the ray exits the sphere. Returns `None`
+    /// if the ray misses the sphere or the sphere is behind the origin.
+    #[inline]
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f32> {
+        let oc = self.origin - sphere.center;
+        let a = self.direction.length_squared();
+        let b = oc.dot(self.direction);
+        let c = oc.length_squared() - sphere.radius * sphere.radius;
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrt_discriminant = math::sqrt(discriminant);
+        let near = (-b - sqrt_discriminant) / a;
+        if near >= 0.0 {
+            return Some(near);
+        }
+        let far = (-b + sqrt_discriminant) / a;
+        if far >= 0.0 {
+            Some(far)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the distance along the ray to where it enters `aabb`, using the slab test.
+    ///
+    /// If the origin is inside the box `0.0` is returned. Returns `None` if the ray misses the
+    /// box or the box is behind the origin.
+    #[inline]
+    pub fn intersect_aabb(&self, aabb: &Aabb3) -> Option<f32> {
+        // A ray parallel to a slab either lies within it for its whole length or misses the box.
+        // Those axes are handled separately as the slab distances would be `0.0 * inf = NaN` when
+        // the origin is on a face of the slab.
+        let parallel = self.direction.cmpeq(Vec3::ZERO);
+        if (parallel & (self.origin.cmplt(aabb.min) | self.origin.cmpgt(aabb.max))).any() {
+            return None;
+        }
+        let inv_direction = self.direction.recip();
+        let t1 = Vec3::select(
+            parallel,
+            Vec3::splat(f32::NEG_INFINITY),
+            (aabb.min - self.origin) * inv_direction,
+        );
+        let t2 = Vec3::select(
+            parallel,
+            Vec3::splat(f32::INFINITY),
+            (aabb.max - self.origin) * inv_direction,
+        );
+        let t_enter = t1.min(t2).max_element().max(0.0);
+        let t_exit = t1.max(t2).min_element();
+        if t_enter <= t_exit {
+            Some(t_enter)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the distance along the ray to where it enters `aabb`, using the slab test.
+    ///
+    /// This is the same as [`Self::intersect_aabb()`] but uses [`Vec3A`] for the calculation.
+    #[inline]
+    pub fn intersect_aabb3a(&self, aabb: &Aabb3A) -> Option<f32> {
+        let origin = Vec3A::from(self.origin);
+        let direction = Vec3A::from(self.direction);
+        let parallel = direction.cmpeq(Vec3A::ZERO);
+        if (parallel & (origin.cmplt(aabb.min) | origin.cmpgt(aabb.max))).any() {
+            return None;
+        }
+        let inv_direction = direction.recip();
+        let t1 = Vec3A::select(
+            parallel,
+            Vec3A::splat(f32::NEG_INFINITY),
+            (aabb.min - origin) * inv_direction,
+        );
+        let t2 = Vec3A::select(
+            parallel,
+            Vec3A::splat(f32::INFINITY),
+            (aabb.max - origin) * inv_direction,
+        );
+        let t_enter = t1.min(t2).max_element().max(0.0);
+        let t_exit = t1.max(t2).min_element();
+        if t_enter <= t_exit {
+            Some(t_enter)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the distance along the ray to where it intersects the triangle `a`, `b`, `c`,
+    /// using the Möller–Trumbore algorithm.
+    ///
+    /// Triangles are hit from either side. Returns `None` if the ray misses the triangle, is
+    /// parallel to it or the triangle is behind the origin.
+    #[inline]
+    pub fn intersect_triangle(&self, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = self.direction.cross(edge2);
+        let det = edge1.dot(p);
+        // The parallel test is relative to the size of the triangle and the length of the
+        // direction, so that small triangles and short directions are not rejected.
+        let scale_squared =
+            edge1.length_squared() * edge2.length_squared() * self.direction.length_squared();
+        if det * det <= f32::EPSILON * f32::EPSILON * scale_squared {
+            return None;
+        }
+        let inv_det = det.recip();
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = edge2.dot(q) * inv_det;
+        if t >= 0.0 {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` transformed by `transform`.
+    ///
+    /// The direction is not normalized, so distances along the transformed ray match
+    /// distances along `self`.
+    #[inline]
+    pub fn transformed_by(&self, transform: &Affine3A) -> Self {
+        Self {
+            origin: transform.transform_point3(self.origin),
+            direction: transform.transform_vector3(self.direction),
+        }
+    }
+
+    /// Returns `self` transformed by the affine transform `mat`.
+    ///
+    /// See [`Self::transformed_by()`].
+    #[inline]
+    pub fn transformed_by_mat4(&self, mat: &Mat4) -> Self {
+        Self {
+            origin: mat.transform_point3(self.origin),
+            direction: mat.transform_vector3(self.direction),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.origin.is_finite() && self.direction.is_finite()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two rays contain similar elements. It works best when
+    /// comparing with a known value. The `max_abs_diff` that should be used used depends on the
+    /// values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.origin.abs_diff_eq(rhs.origin, max_abs_diff)
+            && self.direction.abs_diff_eq(rhs.direction, max_abs_diff)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for Ray3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(Ray3))
+            .field("origin", &self.origin)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for Ray3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.origin, self.direction)
+    }
+}
@@ -0,0 +1,312 @@
+// Generated from rot2.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f32::math, Affine2, DRot2, Mat2, Mat3, Radians, Vec2};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Mul, MulAssign};
+
+/// A 2D rotation, stored as a unit complex number.
+///
+/// This is the 2D equivalent of a quaternion. Unlike storing an angle, applying this
+/// rotation to a vector does not require any trigonometry.
+///
+/// Rotations are represented by unit length complex numbers, use [`Self::normalize()`] to
+/// renormalize after accumulating many rotations.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Rot2 {
+    /// The cosine of the rotation angle, the real part.
+    pub cos: f32,
+    /// The sine of the rotation angle, the imaginary part.
+    pub sin: f32,
+}
+
+impl Rot2 {
+    /// The identity rotation.
+    pub const IDENTITY: Self = Self { cos: 1.0, sin: 0.0 };
+
+    /// All NANs.
+    pub const NAN: Self = Self {
+        cos: f32::NAN,
+        sin: f32::NAN,
+    };
+
+    /// Creates a rotation from the given `sin` and `cos` of the rotation angle.
+    ///
+    /// This function does not check if the input is normalized, it is up to the user to
+    /// provide normalized input or to normalize the resulting rotation.
+    #[inline(always)]
+    pub const fn from_sin_cos(sin: f32, cos: f32) -> Self {
+        Self { cos, sin }
+    }
+
+    /// Creates a rotation from an `angle` (in radians).
+    #[inline]
+    pub fn from_angle(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = math::sin_cos(angle.into().0);
+        Self { cos, sin }
+    }
+
+    /// Gets the minimal rotation for transforming `from` to `to`.
+    ///
+    /// The inputs must be unit vectors.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `from` or `to` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_rotation_arc(from: Vec2, to: Vec2) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+        Self {
+            cos: from.dot(to),
+            sin: from.perp_dot(to),
+        }
+    }
+
+    /// Creates a rotation from the `[cos, sin]` components of the given vector, as returned by
+    /// [`Vec2::from_angle()`].
+    ///
+    /// This function does not check if the input is normalized.
+    #[inline(always)]
+    pub const fn from_vec2(v: Vec2) -> Self {
+        Self { cos: v.x, sin: v.y }
+    }
+
+    /// Returns the rotation as a vector containing `[cos, sin]`, which can be used with
+    /// [`Vec2::rotate()`].
+    #[inline(always)]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.cos, self.sin)
+    }
+
+    /// Returns the angle of the rotation in radians, in the range `[-PI, PI]`.
+    #[inline]
+    pub fn angle(self) -> f32 {
+        math::atan2(self.sin, self.cos)
+    }
+
+    /// Returns the angle (in radians) of the rotation from `self` to `rhs`, in the range
+    /// `[-PI, PI]`.
+    #[inline]
+    pub fn angle_between(self, rhs: Self) -> f32 {
+        self.inverse().mul_rot2(rhs).angle()
+    }
+
+    /// Returns the inverse of a normalized rotation, which is its complex conjugate.
+    ///
+    /// Because `self` is assumed to already be unit length this method *does not* normalize
+    /// before returning the conjugate.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn inverse(self) -> Self {
+        glam_assert!(self.is_normalized());
+        Self {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Computes the length of `self`.
+    #[doc(alias = "magnitude")]
+    #[inline]
+    pub fn length(self) -> f32 {
+        math::sqrt(self.length_squared())
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is generally faster than `length()` as it avoids a square root operation.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.cos * self.cos + self.sin * self.sin
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let length_recip = self.length().recip();
+        glam_assert!(length_recip.is_finite());
+        Self {
+            cos: self.cos * length_recip,
+            sin: self.sin * length_recip,
+        }
+    }
+
+    /// Returns whether `self` of length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        math::abs(self.length_squared() - 1.0) <= 1e-4
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.cos.is_finite() && self.sin.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.cos.is_nan() || self.sin.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two rotations contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends
+    /// on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.to_vec2().abs_diff_eq(rhs.to_vec2(), max_abs_diff)
+    }
+
+    /// Performs a normalized linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    ///
+    /// This is cheaper than [`Self::slerp()`] but the angular velocity is not constant. When
+    /// `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result will be
+    /// equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[doc(alias = "mix")]
+    #[inline]
+    pub fn nlerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        Self::from_vec2(self.to_vec2().lerp(end.to_vec2(), s)).normalize()
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    ///
+    /// The interpolation takes the shortest path and has a constant angular velocity. When
+    /// `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result will be
+    /// equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn slerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        self.mul_rot2(Self::from_angle(self.angle_between(end) * s))
+    }
+
+    /// Multiplies a rotation and a 2D vector, returning the rotated vector.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn mul_vec2(self, rhs: Vec2) -> Vec2 {
+        glam_assert!(self.is_normalized());
+        self.to_vec2().rotate(rhs)
+    }
+
+    /// Multiplies two rotations. If they are both normalized the result will also be
+    /// normalized.
+    ///
+    /// The resulting rotation is the sum of the angles of `self` and `rhs`.
+    #[inline]
+    pub fn mul_rot2(self, rhs: Self) -> Self {
+        Self {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+
+    #[inline]
+    pub fn as_f64(self) -> DRot2 {
+        DRot2::from_sin_cos(self.sin as f64, self.cos as f64)
+    }
+}
+
+impl Default for Rot2 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for Rot2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(Rot2))
+            .field("cos", &self.cos)
+            .field("sin", &self.sin)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for Rot2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.cos, self.sin)
+    }
+}
+
+impl Mul<Rot2> for Rot2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_rot2(rhs)
+    }
+}
+
+impl MulAssign<Rot2> for Rot2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_rot2(rhs);
+    }
+}
+
+impl Mul<Vec2> for Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        self.mul_vec2(rhs)
+    }
+}
+
+impl From<Rot2> for Mat2 {
+    #[inline]
+    fn from(r: Rot2) -> Mat2 {
+        Mat2::from_cols_array(&[r.cos, r.sin, -r.sin, r.cos])
+    }
+}
+
+impl From<Rot2> for Mat3 {
+    #[inline]
+    fn from(r: Rot2) -> Mat3 {
+        Mat3::from_mat2(Mat2::from(r))
+    }
+}
+
+impl From<Rot2> for Affine2 {
+    #[inline]
+    fn from(r: Rot2) -> Affine2 {
+        Affine2::from_rotation_translation(r, Vec2::ZERO)
+    }
+}
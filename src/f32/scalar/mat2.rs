@@ -1,6 +1,6 @@
 // Generated from mat.rs.tera template. Edit the template, not the generated file.
 
-use crate::{f32::math, swizzles::*, DMat2, Mat3, Mat3A, Vec2};
+use crate::{f32::math, swizzles::*, DMat2, Mat3, Mat3A, Radians, Vec2};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -90,15 +90,15 @@ impl Mat2 {
     /// Creates a 2x2 matrix containing the combining non-uniform `scale` and rotation of
     /// `angle` (in radians).
     #[inline]
-    pub fn from_scale_angle(scale: Vec2, angle: f32) -> Self {
-        let (sin, cos) = math::sin_cos(angle);
+    pub fn from_scale_angle(scale: Vec2, angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = math::sin_cos(angle.into().0);
         Self::new(cos * scale.x, sin * scale.x, -sin * scale.y, cos * scale.y)
     }
 
     /// Creates a 2x2 matrix containing a rotation of `angle` (in radians).
     #[inline]
-    pub fn from_angle(angle: f32) -> Self {
-        let (sin, cos) = math::sin_cos(angle);
+    pub fn from_angle(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = math::sin_cos(angle.into().0);
         Self::new(cos, sin, -sin, cos)
     }
 
@@ -231,6 +231,148 @@ impl Mat2 {
         )
     }
 
+    /// Computes the eigenvalues and eigenvectors of `self`, which is assumed to be symmetric.
+    ///
+    /// Returns the eigenvalues in descending order and a matrix with the matching normalized
+    /// eigenvectors as columns. The eigenvector matrix is orthonormal with a determinant of `1`.
+    ///
+    /// The cyclic Jacobi method is used, which also converges when eigenvalues are repeated. In
+    /// that case the returned eigenvectors are an arbitrary orthonormal basis of the shared
+    /// eigenspace.
+    ///
+    /// Only the symmetric part of `self`, `(self + self.transpose()) / 2`, is used.
+    #[must_use]
+    pub fn symmetric_eigen(&self) -> (Vec2, Self) {
+        let mut a = (*self + self.transpose()) * 0.5;
+        let mut v = Self::IDENTITY;
+        for _ in 0..math::JACOBI_MAX_SWEEPS {
+            let off = a.y_axis.x * a.y_axis.x;
+            let diag = a.x_axis.x * a.x_axis.x + a.y_axis.y * a.y_axis.y;
+
+            if off <= f32::EPSILON * f32::EPSILON * diag {
+                break;
+            }
+
+            for p in 0..1 {
+                for q in p + 1..2 {
+                    let apq = a.col(q)[p];
+                    if apq == 0.0 {
+                        continue;
+                    }
+
+                    // Rotate in the `pq` plane so that the `pq` element becomes zero.
+                    let (c, s) = math::jacobi_rotation(a.col(p)[p], a.col(q)[q], apq);
+                    let mut j = Self::IDENTITY;
+                    j.col_mut(p)[p] = c;
+                    j.col_mut(p)[q] = -s;
+                    j.col_mut(q)[p] = s;
+                    j.col_mut(q)[q] = c;
+                    a = j.transpose() * a * j;
+                    a.col_mut(p)[q] = 0.0;
+                    a.col_mut(q)[p] = 0.0;
+                    v *= j;
+                }
+            }
+        }
+
+        let mut values = [a.x_axis.x, a.y_axis.y];
+        let mut vectors = [v.x_axis, v.y_axis];
+
+        for i in 0..1 {
+            for j in i + 1..2 {
+                if values[j] > values[i] {
+                    values.swap(i, j);
+                    vectors.swap(i, j);
+                }
+            }
+        }
+
+        if vectors[0].perp_dot(vectors[1]) < 0.0 {
+            vectors[1] = -vectors[1];
+        }
+        let vectors = Self::from_cols(vectors[0], vectors[1]);
+
+        (Vec2::from_array(values), vectors)
+    }
+
+    /// Computes the singular value decomposition of `self`.
+    ///
+    /// Returns `(u, sigma, v)` where `u` and `v` are orthonormal matrices and `sigma` contains the
+    /// singular values in descending order, such that `self` is equal to
+    /// `u * Self::from_diagonal(sigma) * v.transpose()`.
+    ///
+    /// The singular values are never negative. `v` always has a determinant of `1` and so does
+    /// `u` unless the determinant of `self` is negative. If `self` is singular the columns of `u`
+    /// matching zero singular values are chosen to complete an orthonormal basis.
+    ///
+    /// The one-sided Jacobi method is used, which is accurate for small singular values.
+    #[must_use]
+    pub fn svd(&self) -> (Self, Vec2, Self) {
+        let mut u = [self.x_axis, self.y_axis];
+        let mut v = [Vec2::X, Vec2::Y];
+
+        for _ in 0..math::JACOBI_MAX_SWEEPS {
+            let mut converged = true;
+            for p in 0..1 {
+                for q in p + 1..2 {
+                    // Rotate columns `p` and `q` of `u` until they are orthogonal.
+                    let alpha = u[p].length_squared();
+                    let beta = u[q].length_squared();
+                    let gamma = u[p].dot(u[q]);
+                    if math::abs(gamma) <= f32::EPSILON * math::sqrt(alpha * beta) {
+                        continue;
+                    }
+                    converged = false;
+
+                    let (c, s) = math::jacobi_rotation(alpha, beta, gamma);
+                    let (up, uq) = (u[p], u[q]);
+                    u[p] = up * c - uq * s;
+                    u[q] = up * s + uq * c;
+                    let (vp, vq) = (v[p], v[q]);
+                    v[p] = vp * c - vq * s;
+                    v[q] = vp * s + vq * c;
+                }
+            }
+            if converged {
+                break;
+            }
+        }
+
+        let mut sigma = u.map(|c| c.length());
+        for i in 0..1 {
+            for j in i + 1..2 {
+                if sigma[j] > sigma[i] {
+                    sigma.swap(i, j);
+                    u.swap(i, j);
+                    v.swap(i, j);
+                }
+            }
+        }
+
+        if v[0].perp_dot(v[1]) < 0.0 {
+            u[1] = -u[1];
+            v[1] = -v[1];
+        }
+
+        let tolerance = sigma[0] * f32::EPSILON;
+        u[0] = if sigma[0] > 0.0 {
+            u[0] / sigma[0]
+        } else {
+            Vec2::X
+        };
+
+        u[1] = if sigma[1] > tolerance {
+            u[1] / sigma[1]
+        } else {
+            u[0].perp()
+        };
+        (
+            Self::from_cols(u[0], u[1]),
+            Vec2::from_array(sigma),
+            Self::from_cols(v[0], v[1]),
+        )
+    }
+
     /// Transforms a 2D vector.
     #[inline]
     pub fn mul_vec2(&self, rhs: Vec2) -> Vec2 {
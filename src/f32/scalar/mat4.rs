@@ -1,6 +1,8 @@
 // Generated from mat.rs.tera template. Edit the template, not the generated file.
 
-use crate::{f32::math, swizzles::*, DMat4, EulerRot, Mat3, Mat3A, Quat, Vec3, Vec3A, Vec4};
+use crate::{
+    f32::math, swizzles::*, DMat4, EulerRot, Mat3, Mat3A, Quat, Radians, Vec3, Vec3A, Vec4,
+};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -335,10 +337,10 @@ impl Mat4 {
     ///
     /// Will panic if `axis` is not normalized when `glam_assert` is enabled.
     #[inline]
-    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Radians>) -> Self {
         glam_assert!(axis.is_normalized());
 
-        let (sin, cos) = math::sin_cos(angle);
+        let (sin, cos) = math::sin_cos(angle.into().0);
         let axis_sin = axis.mul(sin);
         let axis_sq = axis.mul(axis);
         let omc = 1.0 - cos;
@@ -374,19 +376,43 @@ impl Mat4 {
     ///
     /// The resulting matrix can be used to transform 3D points and vectors. See
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
-    pub fn from_euler(order: EulerRot, a: f32, b: f32, c: f32) -> Self {
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<Radians>,
+        b: impl Into<Radians>,
+        c: impl Into<Radians>,
+    ) -> Self {
         let quat = Quat::from_euler(order, a, b, c);
         Self::from_quat(quat)
     }
 
+    /// Extracts the angles (in radians) of the given euler rotation sequence from the rotation
+    /// part of `self`.
+    ///
+    /// See [`EulerRot`] for the range of the returned angles and the behavior at gimbal lock.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any of the first three columns of `self` is not normalized when
+    /// `glam_assert` is enabled.
+    #[inline]
+    pub fn to_euler(&self, order: EulerRot) -> (f32, f32, f32) {
+        glam_assert!(
+            self.x_axis.xyz().is_normalized()
+                && self.y_axis.xyz().is_normalized()
+                && self.z_axis.xyz().is_normalized()
+        );
+        Quat::from_mat4(self).to_euler(order)
+    }
+
     /// Creates an affine transformation matrix containing a 3D rotation around the x axis of
     /// `angle` (in radians).
     ///
     /// The resulting matrix can be used to transform 3D points and vectors. See
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
     #[inline]
-    pub fn from_rotation_x(angle: f32) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             Vec4::X,
             Vec4::new(0.0, cosa, sina, 0.0),
@@ -401,8 +427,8 @@ impl Mat4 {
     /// The resulting matrix can be used to transform 3D points and vectors. See
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
     #[inline]
-    pub fn from_rotation_y(angle: f32) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             Vec4::new(cosa, 0.0, -sina, 0.0),
             Vec4::Y,
@@ -417,8 +443,8 @@ impl Mat4 {
     /// The resulting matrix can be used to transform 3D points and vectors. See
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
     #[inline]
-    pub fn from_rotation_z(angle: f32) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             Vec4::new(cosa, sina, 0.0, 0.0),
             Vec4::new(-sina, cosa, 0.0, 0.0),
@@ -721,13 +747,13 @@ impl Mat4 {
     /// See <https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluPerspective.xml>
     #[inline]
     pub fn perspective_rh_gl(
-        fov_y_radians: f32,
+        fov_y_radians: impl Into<Radians>,
         aspect_ratio: f32,
         z_near: f32,
         z_far: f32,
     ) -> Self {
         let inv_length = 1.0 / (z_near - z_far);
-        let f = 1.0 / math::tan(0.5 * fov_y_radians);
+        let f = 1.0 / math::tan(0.5 * fov_y_radians.into().0);
         let a = f / aspect_ratio;
         let b = (z_near + z_far) * inv_length;
         let c = (2.0 * z_near * z_far) * inv_length;
@@ -746,9 +772,14 @@ impl Mat4 {
     /// Will panic if `z_near` or `z_far` are less than or equal to zero when `glam_assert` is
     /// enabled.
     #[inline]
-    pub fn perspective_lh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
+    pub fn perspective_lh(
+        fov_y_radians: impl Into<Radians>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
         glam_assert!(z_near > 0.0 && z_far > 0.0);
-        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians);
+        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians.into().0);
         let h = cos_fov / sin_fov;
         let w = h / aspect_ratio;
         let r = z_far / (z_far - z_near);
@@ -767,9 +798,14 @@ impl Mat4 {
     /// Will panic if `z_near` or `z_far` are less than or equal to zero when `glam_assert` is
     /// enabled.
     #[inline]
-    pub fn perspective_rh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
+    pub fn perspective_rh(
+        fov_y_radians: impl Into<Radians>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
         glam_assert!(z_near > 0.0 && z_far > 0.0);
-        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians);
+        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians.into().0);
         let h = cos_fov / sin_fov;
         let w = h / aspect_ratio;
         let r = z_far / (z_near - z_far);
@@ -787,9 +823,13 @@ impl Mat4 {
     ///
     /// Will panic if `z_near` is less than or equal to zero when `glam_assert` is enabled.
     #[inline]
-    pub fn perspective_infinite_lh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32) -> Self {
+    pub fn perspective_infinite_lh(
+        fov_y_radians: impl Into<Radians>,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
         glam_assert!(z_near > 0.0);
-        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians);
+        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians.into().0);
         let h = cos_fov / sin_fov;
         let w = h / aspect_ratio;
         Self::from_cols(
@@ -807,12 +847,12 @@ impl Mat4 {
     /// Will panic if `z_near` is less than or equal to zero when `glam_assert` is enabled.
     #[inline]
     pub fn perspective_infinite_reverse_lh(
-        fov_y_radians: f32,
+        fov_y_radians: impl Into<Radians>,
         aspect_ratio: f32,
         z_near: f32,
     ) -> Self {
         glam_assert!(z_near > 0.0);
-        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians);
+        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians.into().0);
         let h = cos_fov / sin_fov;
         let w = h / aspect_ratio;
         Self::from_cols(
@@ -826,9 +866,13 @@ impl Mat4 {
     /// Creates an infinite right-handed perspective projection matrix with
     /// `[0,1]` depth range.
     #[inline]
-    pub fn perspective_infinite_rh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32) -> Self {
+    pub fn perspective_infinite_rh(
+        fov_y_radians: impl Into<Radians>,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
         glam_assert!(z_near > 0.0);
-        let f = 1.0 / math::tan(0.5 * fov_y_radians);
+        let f = 1.0 / math::tan(0.5 * fov_y_radians.into().0);
         Self::from_cols(
             Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
             Vec4::new(0.0, f, 0.0, 0.0),
@@ -841,12 +885,12 @@ impl Mat4 {
     /// with `[0,1]` depth range.
     #[inline]
     pub fn perspective_infinite_reverse_rh(
-        fov_y_radians: f32,
+        fov_y_radians: impl Into<Radians>,
         aspect_ratio: f32,
         z_near: f32,
     ) -> Self {
         glam_assert!(z_near > 0.0);
-        let f = 1.0 / math::tan(0.5 * fov_y_radians);
+        let f = 1.0 / math::tan(0.5 * fov_y_radians.into().0);
         Self::from_cols(
             Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
             Vec4::new(0.0, f, 0.0, 0.0),
@@ -992,6 +1036,128 @@ impl Mat4 {
         res.xyz()
     }
 
+    /// Transforms each 3D point in `points` in place using [`Self::project_point3()`].
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    ///
+    /// This method assumes that `self` contains a projective transform.
+    #[inline]
+    pub fn project_points3_slice(&self, points: &mut [Vec3]) {
+        crate::wide::map_vec3_slice(
+            points,
+            |v| v.project_point3(self),
+            |v| self.project_point3(v),
+        );
+    }
+
+    /// Transforms each 3D point in `src` using [`Self::project_point3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    ///
+    /// This method assumes that `self` contains a projective transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    #[inline]
+    pub fn project_points3_slice_to(&self, src: &[Vec3], dst: &mut [Vec3]) {
+        crate::wide::map_vec3_slice_to(
+            src,
+            dst,
+            |v| v.project_point3(self),
+            |v| self.project_point3(v),
+        );
+    }
+
+    /// Transforms each 3D point in `points` in place using [`Self::transform_point3()`].
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_points3_slice(&self, points: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+
+        crate::wide::map_vec3_slice(
+            points,
+            |v| v.transform_point3(self),
+            |v| self.transform_point3(v),
+        );
+    }
+
+    /// Transforms each 3D point in `src` using [`Self::transform_point3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_points3_slice_to(&self, src: &[Vec3], dst: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+
+        crate::wide::map_vec3_slice_to(
+            src,
+            dst,
+            |v| v.transform_point3(self),
+            |v| self.transform_point3(v),
+        );
+    }
+
+    /// Transforms each 3D vector in `vectors` in place using [`Self::transform_vector3()`].
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vectors3_slice(&self, vectors: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+
+        crate::wide::map_vec3_slice(
+            vectors,
+            |v| v.transform_vector3(self),
+            |v| self.transform_vector3(v),
+        );
+    }
+
+    /// Transforms each 3D vector in `src` using [`Self::transform_vector3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// Multiple elements are transformed at a time using the [`wide`](crate::wide) types.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vectors3_slice_to(&self, src: &[Vec3], dst: &mut [Vec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
+
+        crate::wide::map_vec3_slice_to(
+            src,
+            dst,
+            |v| v.transform_vector3(self),
+            |v| self.transform_vector3(v),
+        );
+    }
+
     /// Transforms the given [`Vec3A`] as 3D point.
     ///
     /// This is the equivalent of multiplying the [`Vec3A`] as a 4D vector where `w` is `1.0`.
@@ -0,0 +1,291 @@
+// Generated from similarity.rs.tera template. Edit the template, not the generated file.
+
+use crate::{Affine3A, DSimilarity3, Isometry3, Mat4, Quat, Vec3, Vec3A};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Mul, MulAssign};
+
+/// A 3D similarity transform, which consists of a uniform scale, followed by a rotation and
+/// then a translation.
+///
+/// Unlike an affine transform, a similarity transform can not accumulate non-uniform scale or
+/// shear when composed and it can be inverted cheaply and exactly.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Similarity3 {
+    pub rotation: Quat,
+    pub translation: Vec3,
+    pub scale: f32,
+}
+
+impl Similarity3 {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self {
+        rotation: Quat::IDENTITY,
+        translation: Vec3::ZERO,
+        scale: 1.0,
+    };
+
+    /// All NANs.
+    pub const NAN: Self = Self {
+        rotation: Quat::NAN,
+        translation: Vec3::NAN,
+        scale: f32::NAN,
+    };
+
+    /// Creates a similarity transform from a uniform `scale`, followed by a `rotation` and then
+    /// a `translation`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_scale_rotation_translation(scale: f32, rotation: Quat, translation: Vec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            rotation,
+            translation,
+            scale,
+        }
+    }
+
+    /// Creates a similarity transform from the given uniform `scale`.
+    #[inline(always)]
+    pub const fn from_scale(scale: f32) -> Self {
+        Self {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a similarity transform from the given `rotation` quaternion.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_quat(rotation: Quat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            rotation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a similarity transform from the given `translation`.
+    #[inline(always)]
+    pub const fn from_translation(translation: Vec3) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a similarity transform from an isometry, with a scale of `1.0`.
+    #[inline(always)]
+    pub const fn from_isometry(iso: Isometry3) -> Self {
+        Self {
+            rotation: iso.rotation,
+            translation: iso.translation,
+            scale: 1.0,
+        }
+    }
+
+    /// Returns the rotation and translation of `self` as an isometry, discarding the scale.
+    #[inline(always)]
+    pub const fn to_isometry(&self) -> Isometry3 {
+        Isometry3 {
+            rotation: self.rotation,
+            translation: self.translation,
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// This is exact and much cheaper than inverting the equivalent affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the scale of `self` is zero when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        glam_assert!(self.scale != 0.0);
+        let scale = self.scale.recip();
+        let rotation = self.rotation.conjugate();
+        Self {
+            rotation,
+            translation: -rotation.mul_vec3(self.translation) * scale,
+            scale,
+        }
+    }
+
+    /// Multiplies two similarity transforms, returning the combined transform.
+    ///
+    /// Note that the order of operations is important: `self * rhs` applies `rhs` first and
+    /// then `self`.
+    #[inline]
+    pub fn mul_similarity(&self, rhs: &Self) -> Self {
+        Self {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_point3(rhs.translation),
+            scale: self.scale * rhs.scale,
+        }
+    }
+
+    /// Transforms the given 3D point, applying scale, rotation and translation.
+    #[inline]
+    pub fn transform_point3(&self, rhs: Vec3) -> Vec3 {
+        self.transform_vector3(rhs) + self.translation
+    }
+
+    /// Transforms the given 3D vector, applying scale and rotation (but NOT translation).
+    #[inline]
+    pub fn transform_vector3(&self, rhs: Vec3) -> Vec3 {
+        self.rotation.mul_vec3(rhs * self.scale)
+    }
+
+    /// Transforms the given [`Vec3A`] point, applying scale, rotation and translation.
+    #[inline]
+    pub fn transform_point3a(&self, rhs: Vec3A) -> Vec3A {
+        self.transform_vector3a(rhs) + Vec3A::from(self.translation)
+    }
+
+    /// Transforms the given [`Vec3A`] vector, applying scale and rotation (but NOT
+    /// translation).
+    #[inline]
+    pub fn transform_vector3a(&self, rhs: Vec3A) -> Vec3A {
+        self.rotation.mul_vec3a(rhs * self.scale)
+    }
+
+    /// Interpolates between `self` and `end` based on the value `s`.
+    ///
+    /// The rotation is spherically interpolated while the scale and translation are linearly
+    /// interpolated.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the rotation of `self` or `end` is not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    pub fn lerp(&self, end: &Self, s: f32) -> Self {
+        Self {
+            rotation: self.rotation.slerp(end.rotation, s),
+            translation: self.translation.lerp(end.translation, s),
+            scale: self.scale + (end.scale - self.scale) * s,
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.rotation.is_finite() && self.translation.is_finite() && self.scale.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.rotation.is_nan() || self.translation.is_nan() || self.scale.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two similarity transforms contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+            && self.scale <= rhs.scale + max_abs_diff
+            && self.scale >= rhs.scale - max_abs_diff
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(&self) -> DSimilarity3 {
+        DSimilarity3 {
+            rotation: self.rotation.as_f64(),
+            translation: self.translation.as_dvec3(),
+            scale: self.scale as f64,
+        }
+    }
+}
+
+impl Default for Similarity3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for Similarity3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(Similarity3))
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .field("scale", &self.scale)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for Similarity3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}]",
+            self.rotation, self.translation, self.scale
+        )
+    }
+}
+
+impl Mul<Similarity3> for Similarity3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_similarity(&rhs)
+    }
+}
+
+impl MulAssign<Similarity3> for Similarity3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_similarity(&rhs);
+    }
+}
+
+impl From<Isometry3> for Similarity3 {
+    #[inline]
+    fn from(iso: Isometry3) -> Self {
+        Self::from_isometry(iso)
+    }
+}
+
+impl From<Similarity3> for Affine3A {
+    #[inline]
+    fn from(sim: Similarity3) -> Affine3A {
+        Affine3A::from_scale_rotation_translation(
+            Vec3::splat(sim.scale),
+            sim.rotation,
+            sim.translation,
+        )
+    }
+}
+
+impl From<Similarity3> for Mat4 {
+    #[inline]
+    fn from(sim: Similarity3) -> Mat4 {
+        Mat4::from_scale_rotation_translation(Vec3::splat(sim.scale), sim.rotation, sim.translation)
+    }
+}
@@ -90,12 +90,7 @@ impl Vec3A {
     /// uses the element from `if_false`.
     #[inline]
     pub fn select(mask: BVec3A, if_true: Self, if_false: Self) -> Self {
-        Self(unsafe {
-            _mm_or_ps(
-                _mm_andnot_ps(mask.0, if_false.0),
-                _mm_and_ps(if_true.0, mask.0),
-            )
-        })
+        Self(unsafe { m128_select(mask.0, if_true.0, if_false.0) })
     }
 
     /// Creates a new vector from an array.
@@ -403,6 +398,51 @@ impl Vec3A {
         (self - rhs).length_squared()
     }
 
+    /// Returns the element-wise quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// Each element is `self / rhs` truncated to an integer and then adjusted so that the
+    /// remainder returned by [`Self::rem_euclid()`] is never negative.
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::div_euclid(self.x, rhs.x),
+            math::div_euclid(self.y, rhs.y),
+            math::div_euclid(self.z, rhs.z),
+        )
+    }
+
+    /// Returns the element-wise quotient of Euclidean division of `self` by the scalar `rhs`.
+    ///
+    /// See [`Self::div_euclid()`] for how the quotient is rounded.
+    #[inline]
+    pub fn div_euclid_scalar(self, rhs: f32) -> Self {
+        self.div_euclid(Self::splat(rhs))
+    }
+
+    /// Returns the element-wise remainder of Euclidean division of `self` by `rhs`.
+    ///
+    /// Each element of the result is in the range `[0, rhs.abs())`, which makes this suitable
+    /// for wrapping coordinates into a tile or chunk. Due to rounding an element may be equal
+    /// to `rhs.abs()` when `self` is negative and much smaller in magnitude than `rhs`.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::rem_euclid(self.x, rhs.x),
+            math::rem_euclid(self.y, rhs.y),
+            math::rem_euclid(self.z, rhs.z),
+        )
+    }
+
+    /// Returns the element-wise remainder of Euclidean division of `self` by the scalar `rhs`.
+    ///
+    /// Each element of the result is in the range `[0, rhs.abs())`, although due to rounding an
+    /// element may be equal to `rhs.abs()` when `self` is negative and much smaller in magnitude
+    /// than `rhs`.
+    #[inline]
+    pub fn rem_euclid_scalar(self, rhs: f32) -> Self {
+        self.rem_euclid(Self::splat(rhs))
+    }
+
     /// Returns `self` normalized to length 1.0.
     ///
     /// For valid results, `self` must _not_ be of length zero, nor very close to zero.
@@ -552,6 +592,9 @@ impl Vec3A {
     /// Returns a vector containing the fractional part of the vector, e.g. `self -
     /// self.floor()`.
     ///
+    /// This matches the GLSL `fract` function, so unlike `f32::fract` the result is
+    /// never negative for negative elements.
+    ///
     /// Note that this is fast but not precise for large numbers.
     #[inline]
     pub fn fract(self) -> Self {
@@ -733,6 +776,30 @@ impl Vec3A {
         crate::DVec3::new(self.x as f64, self.y as f64, self.z as f64)
     }
 
+    /// Casts all elements of `self` to `i8`.
+    #[inline]
+    pub fn as_i8vec3(&self) -> crate::I8Vec3 {
+        crate::I8Vec3::new(self.x as i8, self.y as i8, self.z as i8)
+    }
+
+    /// Casts all elements of `self` to `u8`.
+    #[inline]
+    pub fn as_u8vec3(&self) -> crate::U8Vec3 {
+        crate::U8Vec3::new(self.x as u8, self.y as u8, self.z as u8)
+    }
+
+    /// Casts all elements of `self` to `i16`.
+    #[inline]
+    pub fn as_i16vec3(&self) -> crate::I16Vec3 {
+        crate::I16Vec3::new(self.x as i16, self.y as i16, self.z as i16)
+    }
+
+    /// Casts all elements of `self` to `u16`.
+    #[inline]
+    pub fn as_u16vec3(&self) -> crate::U16Vec3 {
+        crate::U16Vec3::new(self.x as u16, self.y as u16, self.z as u16)
+    }
+
     /// Casts all elements of `self` to `i32`.
     #[inline]
     pub fn as_ivec3(&self) -> crate::IVec3 {
@@ -1,20 +1,75 @@
+mod daabb3;
 mod daffine2;
 mod daffine3;
+mod ddegrees;
+mod ddualquat;
+mod dfrustum;
+mod disometry2;
+mod disometry3;
 mod dmat2;
+mod dmat2x3;
 mod dmat3;
+mod dmat3x2;
+mod dmat3x4;
 mod dmat4;
-mod dquat;
-mod dvec2;
+mod dmat4x3;
+mod dplane3;
+mod dradians;
+mod dray3;
+mod drot2;
+mod dsimilarity3;
+mod dsphere;
 mod dvec3;
-mod dvec4;
 pub(crate) mod math;
 
+#[cfg(any(
+    not(target_feature = "avx"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+mod scalar;
+
+#[cfg(all(
+    target_feature = "avx",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod avx;
+
+#[cfg(any(
+    not(target_feature = "avx"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+use scalar::*;
+
+#[cfg(all(
+    target_feature = "avx",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use avx::*;
+
+pub use daabb3::DAabb3;
 pub use daffine2::DAffine2;
 pub use daffine3::DAffine3;
+pub use ddegrees::DDegrees;
+pub use ddualquat::DDualQuat;
+pub use dfrustum::DFrustum;
+pub use disometry2::DIsometry2;
+pub use disometry3::DIsometry3;
 pub use dmat2::{dmat2, DMat2};
+pub use dmat2x3::{dmat2x3, DMat2x3};
 pub use dmat3::{dmat3, DMat3};
+pub use dmat3x2::{dmat3x2, DMat3x2};
+pub use dmat3x4::{dmat3x4, DMat3x4};
 pub use dmat4::{dmat4, DMat4};
+pub use dmat4x3::{dmat4x3, DMat4x3};
+pub use dplane3::DPlane3;
 pub use dquat::{dquat, DQuat};
+pub use dradians::DRadians;
+pub use dray3::DRay3;
+pub use drot2::DRot2;
+pub use dsimilarity3::DSimilarity3;
+pub use dsphere::DSphere;
 pub use dvec2::{dvec2, DVec2};
 pub use dvec3::{dvec3, DVec3};
 pub use dvec4::{dvec4, DVec4};
@@ -22,6 +77,14 @@ pub use dvec4::{dvec4, DVec4};
 #[cfg(not(target_arch = "spirv"))]
 mod test {
     pub use super::*;
+    mod const_test_daabb3 {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DAabb3>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::DAabb3>());
+    }
+
     mod const_test_daffine2 {
         const_assert_eq!(
             core::mem::align_of::<super::DVec2>(),
@@ -30,6 +93,99 @@ mod test {
         const_assert_eq!(48, core::mem::size_of::<super::DAffine2>());
     }
 
+    mod const_test_ddualquat {
+        const_assert_eq!(
+            core::mem::align_of::<super::DQuat>(),
+            core::mem::align_of::<super::DDualQuat>()
+        );
+        const_assert_eq!(64, core::mem::size_of::<super::DDualQuat>());
+    }
+
+    mod const_test_disometry2 {
+        const_assert_eq!(
+            core::mem::align_of::<super::DVec2>(),
+            core::mem::align_of::<super::DIsometry2>()
+        );
+        #[cfg(not(any(
+            feature = "cuda",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        )))]
+        const_assert_eq!(24, core::mem::size_of::<super::DIsometry2>());
+        #[cfg(any(
+            feature = "cuda",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        ))]
+        const_assert_eq!(32, core::mem::size_of::<super::DIsometry2>());
+    }
+
+    mod const_test_disometry3 {
+        const_assert_eq!(
+            core::mem::align_of::<super::DQuat>(),
+            core::mem::align_of::<super::DIsometry3>()
+        );
+        #[cfg(not(all(
+            target_feature = "avx",
+            not(any(feature = "core-simd", feature = "scalar-math"))
+        )))]
+        const_assert_eq!(56, core::mem::size_of::<super::DIsometry3>());
+        #[cfg(all(
+            target_feature = "avx",
+            not(any(feature = "core-simd", feature = "scalar-math"))
+        ))]
+        const_assert_eq!(64, core::mem::size_of::<super::DIsometry3>());
+    }
+
+    mod const_test_dray3 {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DRay3>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::DRay3>());
+    }
+
+    mod const_test_dangle {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DRadians>()
+        );
+        const_assert_eq!(8, core::mem::size_of::<super::DRadians>());
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DDegrees>()
+        );
+        const_assert_eq!(8, core::mem::size_of::<super::DDegrees>());
+    }
+
+    mod const_test_drot2 {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DRot2>()
+        );
+        const_assert_eq!(16, core::mem::size_of::<super::DRot2>());
+    }
+
+    mod const_test_dsimilarity3 {
+        const_assert_eq!(
+            core::mem::align_of::<super::DQuat>(),
+            core::mem::align_of::<super::DSimilarity3>()
+        );
+        const_assert_eq!(64, core::mem::size_of::<super::DSimilarity3>());
+    }
+
+    mod const_test_dfrustum {
+        const_assert_eq!(
+            core::mem::align_of::<super::DVec4>(),
+            core::mem::align_of::<super::DFrustum>()
+        );
+        const_assert_eq!(192, core::mem::size_of::<super::DFrustum>());
+    }
+
     mod const_test_dmat2 {
         const_assert_eq!(
             core::mem::align_of::<super::DVec2>(),
@@ -38,6 +194,14 @@ mod test {
         const_assert_eq!(32, core::mem::size_of::<super::DMat2>());
     }
 
+    mod const_test_dmat2x3 {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DMat2x3>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::DMat2x3>());
+    }
+
     mod const_test_dmat3 {
         const_assert_eq!(
             core::mem::align_of::<f64>(),
@@ -46,6 +210,22 @@ mod test {
         const_assert_eq!(72, core::mem::size_of::<super::DMat3>());
     }
 
+    mod const_test_dmat3x2 {
+        const_assert_eq!(
+            core::mem::align_of::<super::DVec2>(),
+            core::mem::align_of::<super::DMat3x2>()
+        );
+        const_assert_eq!(48, core::mem::size_of::<super::DMat3x2>());
+    }
+
+    mod const_test_dmat3x4 {
+        const_assert_eq!(
+            core::mem::align_of::<super::DVec4>(),
+            core::mem::align_of::<super::DMat3x4>()
+        );
+        const_assert_eq!(96, core::mem::size_of::<super::DMat3x4>());
+    }
+
     mod const_test_dmat4 {
         const_assert_eq!(
             core::mem::align_of::<super::DVec4>(),
@@ -54,24 +234,74 @@ mod test {
         const_assert_eq!(128, core::mem::size_of::<super::DMat4>());
     }
 
+    mod const_test_dmat4x3 {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DMat4x3>()
+        );
+        const_assert_eq!(96, core::mem::size_of::<super::DMat4x3>());
+    }
+
+    mod const_test_dplane3 {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DPlane3>()
+        );
+        const_assert_eq!(32, core::mem::size_of::<super::DPlane3>());
+    }
+
     mod const_test_dquat {
-        #[cfg(not(target_arch = "spirv"))]
+        #[cfg(not(any(
+            target_arch = "spirv",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        )))]
         const_assert_eq!(
             core::mem::align_of::<f64>(),
             core::mem::align_of::<super::DQuat>()
         );
-        #[cfg(target_arch = "spirv")]
+        #[cfg(any(
+            target_arch = "spirv",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        ))]
         const_assert_eq!(32, core::mem::align_of::<super::DQuat>());
         const_assert_eq!(32, core::mem::size_of::<super::DQuat>());
     }
 
+    mod const_test_dsphere {
+        const_assert_eq!(
+            core::mem::align_of::<f64>(),
+            core::mem::align_of::<super::DSphere>()
+        );
+        const_assert_eq!(32, core::mem::size_of::<super::DSphere>());
+    }
+
     mod const_test_dvec2 {
-        #[cfg(not(any(feature = "cuda", target_arch = "spirv")))]
+        #[cfg(not(any(
+            feature = "cuda",
+            target_arch = "spirv",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        )))]
         const_assert_eq!(
             core::mem::align_of::<f64>(),
             core::mem::align_of::<super::DVec2>()
         );
-        #[cfg(any(feature = "cuda", target_arch = "spirv"))]
+        #[cfg(any(
+            feature = "cuda",
+            target_arch = "spirv",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        ))]
         const_assert_eq!(16, core::mem::align_of::<super::DVec2>());
         const_assert_eq!(16, core::mem::size_of::<super::DVec2>());
     }
@@ -88,13 +318,31 @@ mod test {
     }
 
     mod const_test_dvec4 {
-        #[cfg(not(any(feature = "cuda", target_arch = "spirv")))]
+        #[cfg(not(any(
+            feature = "cuda",
+            target_arch = "spirv",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        )))]
         const_assert_eq!(
             core::mem::align_of::<f64>(),
             core::mem::align_of::<super::DVec4>()
         );
-        #[cfg(any(feature = "cuda", target_arch = "spirv"))]
+        #[cfg(all(
+            any(feature = "cuda", target_arch = "spirv"),
+            not(all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            ))
+        ))]
         const_assert_eq!(16, core::mem::align_of::<super::DVec4>());
+        #[cfg(all(
+            target_feature = "avx",
+            not(any(feature = "core-simd", feature = "scalar-math"))
+        ))]
+        const_assert_eq!(32, core::mem::align_of::<super::DVec4>());
         const_assert_eq!(32, core::mem::size_of::<super::DVec4>());
     }
 }
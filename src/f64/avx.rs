@@ -0,0 +1,3 @@
+pub mod dquat;
+pub mod dvec2;
+pub mod dvec4;
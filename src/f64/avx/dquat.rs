@@ -0,0 +1,1178 @@
+// Generated from quat.rs.tera template. Edit the template, not the generated file.
+
+use crate::{
+    avx::*,
+    euler::{EulerFromQuaternion, EulerRot, EulerToQuaternion},
+    f64::math,
+    DMat3, DMat4, DRadians, DVec2, DVec3, DVec4, Quat,
+};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, Deref, DerefMut, Div, Mul, MulAssign, Neg, Sub};
+
+#[repr(C)]
+union UnionCast {
+    a: [f64; 4],
+    v: DQuat,
+}
+
+/// Creates a quaternion from `x`, `y`, `z` and `w` values.
+///
+/// This should generally not be called manually unless you know what you are doing. Use
+/// one of the other constructors instead such as `identity` or `from_axis_angle`.
+#[inline]
+pub const fn dquat(x: f64, y: f64, z: f64, w: f64) -> DQuat {
+    DQuat::from_xyzw(x, y, z, w)
+}
+
+/// A quaternion representing an orientation.
+///
+/// This quaternion is intended to be of unit length but may denormalize due to
+/// floating point "error creep" which can occur when successive quaternion
+/// operations are applied.
+///
+/// SIMD vector types are used for storage on supported platforms.
+///
+/// This type is 32 byte aligned.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DQuat(pub(crate) __m256d);
+
+impl DQuat {
+    /// All zeros.
+    const ZERO: Self = Self::from_array([0.0; 4]);
+
+    /// The identity quaternion. Corresponds to no rotation.
+    pub const IDENTITY: Self = Self::from_xyzw(0.0, 0.0, 0.0, 1.0);
+
+    /// All NANs.
+    pub const NAN: Self = Self::from_array([f64::NAN; 4]);
+
+    /// Creates a new rotation quaternion.
+    ///
+    /// This should generally not be called manually unless you know what you are doing.
+    /// Use one of the other constructors instead such as `identity` or `from_axis_angle`.
+    ///
+    /// `from_xyzw` is mostly used by unit tests and `serde` deserialization.
+    ///
+    /// # Preconditions
+    ///
+    /// This function does not check if the input is normalized, it is up to the user to
+    /// provide normalized input or to normalized the resulting quaternion.
+    #[inline(always)]
+    pub const fn from_xyzw(x: f64, y: f64, z: f64, w: f64) -> Self {
+        unsafe { UnionCast { a: [x, y, z, w] }.v }
+    }
+
+    /// Creates a rotation quaternion from an array.
+    ///
+    /// # Preconditions
+    ///
+    /// This function does not check if the input is normalized, it is up to the user to
+    /// provide normalized input or to normalized the resulting quaternion.
+    #[inline]
+    pub const fn from_array(a: [f64; 4]) -> Self {
+        Self::from_xyzw(a[0], a[1], a[2], a[3])
+    }
+
+    /// Creates a new rotation quaternion from a 4D vector.
+    ///
+    /// # Preconditions
+    ///
+    /// This function does not check if the input is normalized, it is up to the user to
+    /// provide normalized input or to normalized the resulting quaternion.
+    #[inline]
+    pub fn from_vec4(v: DVec4) -> Self {
+        Self(v.0)
+    }
+
+    /// Creates a rotation quaternion from a slice.
+    ///
+    /// # Preconditions
+    ///
+    /// This function does not check if the input is normalized, it is up to the user to
+    /// provide normalized input or to normalized the resulting quaternion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` length is less than 4.
+    #[inline]
+    pub fn from_slice(slice: &[f64]) -> Self {
+        assert!(slice.len() >= 4);
+        Self(unsafe { _mm256_loadu_pd(slice.as_ptr()) })
+    }
+
+    /// Writes the quaternion to an unaligned slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` length is less than 4.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [f64]) {
+        assert!(slice.len() >= 4);
+        unsafe { _mm256_storeu_pd(slice.as_mut_ptr(), self.0) }
+    }
+
+    /// Create a quaternion for a normalized rotation `axis` and `angle` (in radians).
+    /// The axis must be normalized (unit-length).
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `axis` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_axis_angle(axis: DVec3, angle: impl Into<DRadians>) -> Self {
+        glam_assert!(axis.is_normalized());
+        let (s, c) = math::sin_cos(angle.into().0 * 0.5);
+        let v = axis * s;
+        Self::from_xyzw(v.x, v.y, v.z, c)
+    }
+
+    /// Create a quaternion that rotates `v.length()` radians around `v.normalize()`.
+    ///
+    /// `from_scaled_axis(Vec3::ZERO)` results in the identity quaternion.
+    #[inline]
+    pub fn from_scaled_axis(v: DVec3) -> Self {
+        let length = v.length();
+        if length == 0.0 {
+            Self::IDENTITY
+        } else {
+            Self::from_axis_angle(v / length, length)
+        }
+    }
+
+    /// Creates a quaternion from the `angle` (in radians) around the x axis.
+    #[inline]
+    pub fn from_rotation_x(angle: impl Into<DRadians>) -> Self {
+        let (s, c) = math::sin_cos(angle.into().0 * 0.5);
+        Self::from_xyzw(s, 0.0, 0.0, c)
+    }
+
+    /// Creates a quaternion from the `angle` (in radians) around the y axis.
+    #[inline]
+    pub fn from_rotation_y(angle: impl Into<DRadians>) -> Self {
+        let (s, c) = math::sin_cos(angle.into().0 * 0.5);
+        Self::from_xyzw(0.0, s, 0.0, c)
+    }
+
+    /// Creates a quaternion from the `angle` (in radians) around the z axis.
+    #[inline]
+    pub fn from_rotation_z(angle: impl Into<DRadians>) -> Self {
+        let (s, c) = math::sin_cos(angle.into().0 * 0.5);
+        Self::from_xyzw(0.0, 0.0, s, c)
+    }
+
+    #[inline]
+    /// Creates a quaternion from the given Euler rotation sequence and the angles (in radians).
+    pub fn from_euler(
+        euler: EulerRot,
+        a: impl Into<DRadians>,
+        b: impl Into<DRadians>,
+        c: impl Into<DRadians>,
+    ) -> Self {
+        euler.new_quat(a.into().0, b.into().0, c.into().0)
+    }
+
+    /// From the columns of a 3x3 rotation matrix.
+    #[inline]
+    pub(crate) fn from_rotation_axes(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3) -> Self {
+        // Based on https://github.com/microsoft/DirectXMath `XM$quaternionRotationMatrix`
+        let (m00, m01, m02) = x_axis.into();
+        let (m10, m11, m12) = y_axis.into();
+        let (m20, m21, m22) = z_axis.into();
+        if m22 <= 0.0 {
+            // x^2 + y^2 >= z^2 + w^2
+            let dif10 = m11 - m00;
+            let omm22 = 1.0 - m22;
+            if dif10 <= 0.0 {
+                // x^2 >= y^2
+                let four_xsq = omm22 - dif10;
+                let inv4x = 0.5 / math::sqrt(four_xsq);
+                Self::from_xyzw(
+                    four_xsq * inv4x,
+                    (m01 + m10) * inv4x,
+                    (m02 + m20) * inv4x,
+                    (m12 - m21) * inv4x,
+                )
+            } else {
+                // y^2 >= x^2
+                let four_ysq = omm22 + dif10;
+                let inv4y = 0.5 / math::sqrt(four_ysq);
+                Self::from_xyzw(
+                    (m01 + m10) * inv4y,
+                    four_ysq * inv4y,
+                    (m12 + m21) * inv4y,
+                    (m20 - m02) * inv4y,
+                )
+            }
+        } else {
+            // z^2 + w^2 >= x^2 + y^2
+            let sum10 = m11 + m00;
+            let opm22 = 1.0 + m22;
+            if sum10 <= 0.0 {
+                // z^2 >= w^2
+                let four_zsq = opm22 - sum10;
+                let inv4z = 0.5 / math::sqrt(four_zsq);
+                Self::from_xyzw(
+                    (m02 + m20) * inv4z,
+                    (m12 + m21) * inv4z,
+                    four_zsq * inv4z,
+                    (m01 - m10) * inv4z,
+                )
+            } else {
+                // w^2 >= z^2
+                let four_wsq = opm22 + sum10;
+                let inv4w = 0.5 / math::sqrt(four_wsq);
+                Self::from_xyzw(
+                    (m12 - m21) * inv4w,
+                    (m20 - m02) * inv4w,
+                    (m01 - m10) * inv4w,
+                    four_wsq * inv4w,
+                )
+            }
+        }
+    }
+
+    /// Creates a quaternion from a 3x3 rotation matrix.
+    #[inline]
+    pub fn from_mat3(mat: &DMat3) -> Self {
+        Self::from_rotation_axes(mat.x_axis, mat.y_axis, mat.z_axis)
+    }
+
+    /// Creates a quaternion from the rotation closest to the given 3x3 matrix.
+    ///
+    /// Unlike [`Self::from_mat3()`] the matrix does not need to be a pure rotation. Numerical
+    /// drift, scale and shear are removed using [`DMat3::polar_decomposition()`].
+    #[inline]
+    pub fn from_mat3_nearest(mat: &DMat3) -> Self {
+        Self::from_mat3(&mat.polar_decomposition().0)
+    }
+
+    /// Creates a quaternion from a 3x3 rotation matrix inside a homogeneous 4x4 matrix.
+    #[inline]
+    pub fn from_mat4(mat: &DMat4) -> Self {
+        Self::from_rotation_axes(
+            mat.x_axis.truncate(),
+            mat.y_axis.truncate(),
+            mat.z_axis.truncate(),
+        )
+    }
+
+    /// Gets the minimal rotation for transforming `from` to `to`.  The rotation is in the
+    /// plane spanned by the two vectors.  Will rotate at most 180 degrees.
+    ///
+    /// The input vectors must be normalized (unit-length).
+    ///
+    /// `from_rotation_arc(from, to) * from ≈ to`.
+    ///
+    /// For near-singular cases (from≈to and from≈-to) the current implementation
+    /// is only accurate to about 0.001 (for `f32`).
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `from` or `to` are not normalized when `glam_assert` is enabled.
+    pub fn from_rotation_arc(from: DVec3, to: DVec3) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+
+        const ONE_MINUS_EPS: f64 = 1.0 - 2.0 * core::f64::EPSILON;
+        let dot = from.dot(to);
+        if dot > ONE_MINUS_EPS {
+            // 0° singulary: from ≈ to
+            Self::IDENTITY
+        } else if dot < -ONE_MINUS_EPS {
+            // 180° singulary: from ≈ -to
+            use core::f64::consts::PI; // half a turn = 𝛕/2 = 180°
+            Self::from_axis_angle(from.any_orthonormal_vector(), PI)
+        } else {
+            let c = from.cross(to);
+            Self::from_xyzw(c.x, c.y, c.z, 1.0 + dot).normalize()
+        }
+    }
+
+    /// Gets the minimal rotation for transforming `from` to either `to` or `-to`.  This means
+    /// that the resulting quaternion will rotate `from` so that it is colinear with `to`.
+    ///
+    /// The rotation is in the plane spanned by the two vectors.  Will rotate at most 90
+    /// degrees.
+    ///
+    /// The input vectors must be normalized (unit-length).
+    ///
+    /// `to.dot(from_rotation_arc_colinear(from, to) * from).abs() ≈ 1`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `from` or `to` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_rotation_arc_colinear(from: DVec3, to: DVec3) -> Self {
+        if from.dot(to) < 0.0 {
+            Self::from_rotation_arc(from, -to)
+        } else {
+            Self::from_rotation_arc(from, to)
+        }
+    }
+
+    /// Gets the minimal rotation for transforming `from` to `to`.  The resulting rotation is
+    /// around the z axis. Will rotate at most 180 degrees.
+    ///
+    /// The input vectors must be normalized (unit-length).
+    ///
+    /// `from_rotation_arc_2d(from, to) * from ≈ to`.
+    ///
+    /// For near-singular cases (from≈to and from≈-to) the current implementation
+    /// is only accurate to about 0.001 (for `f32`).
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `from` or `to` are not normalized when `glam_assert` is enabled.
+    pub fn from_rotation_arc_2d(from: DVec2, to: DVec2) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+
+        const ONE_MINUS_EPSILON: f64 = 1.0 - 2.0 * core::f64::EPSILON;
+        let dot = from.dot(to);
+        if dot > ONE_MINUS_EPSILON {
+            // 0° singulary: from ≈ to
+            Self::IDENTITY
+        } else if dot < -ONE_MINUS_EPSILON {
+            // 180° singulary: from ≈ -to
+            const COS_FRAC_PI_2: f64 = 0.0;
+            const SIN_FRAC_PI_2: f64 = 1.0;
+            // rotation around z by PI radians
+            Self::from_xyzw(0.0, 0.0, SIN_FRAC_PI_2, COS_FRAC_PI_2)
+        } else {
+            // vector3 cross where z=0
+            let z = from.x * to.y - to.x * from.y;
+            let w = 1.0 + dot;
+            // calculate length with x=0 and y=0 to normalize
+            let len_rcp = 1.0 / math::sqrt(z * z + w * w);
+            Self::from_xyzw(0.0, 0.0, z * len_rcp, w * len_rcp)
+        }
+    }
+
+    /// Returns the rotation axis (normalized) and angle (in radians) of `self`.
+    #[inline]
+    pub fn to_axis_angle(self) -> (DVec3, f64) {
+        const EPSILON: f64 = 1.0e-8;
+        let v = DVec3::new(self.x, self.y, self.z);
+        let length = v.length();
+        if length >= EPSILON {
+            let angle = 2.0 * math::atan2(length, self.w);
+            let axis = v / length;
+            (axis, angle)
+        } else {
+            (DVec3::X, 0.0)
+        }
+    }
+
+    /// Returns the rotation axis scaled by the rotation in radians.
+    #[inline]
+    pub fn to_scaled_axis(self) -> DVec3 {
+        let (axis, angle) = self.to_axis_angle();
+        axis * angle
+    }
+
+    /// Decomposes `self` into a swing and a twist around `axis` such that `self` is equal to
+    /// `swing * twist`.
+    ///
+    /// The twist is the part of the rotation around `axis` and is applied first. The swing is the
+    /// remaining rotation around an axis perpendicular to `axis`. Both have a non-negative `w` so
+    /// their angles are in the range `[-PI, PI]`.
+    ///
+    /// If `self` is a half turn around an axis perpendicular to `axis` the twist is not defined
+    /// and [`Self::IDENTITY`] is returned for it.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `axis` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn to_swing_twist(self, axis: DVec3) -> (Self, Self) {
+        glam_assert!(self.is_normalized());
+        glam_assert!(axis.is_normalized());
+
+        let q = if self.w < 0.0 { -self } else { self };
+        let projected = axis * q.xyz().dot(axis);
+        let twist = Self::from_xyzw(projected.x, projected.y, projected.z, q.w);
+        let length_squared = twist.length_squared();
+        if length_squared > f64::EPSILON * f64::EPSILON {
+            let twist = twist * (1.0 / math::sqrt(length_squared));
+            (q.mul_quat(twist.conjugate()), twist)
+        } else {
+            (q, Self::IDENTITY)
+        }
+    }
+
+    /// Creates a quaternion from a swing and a twist such that the result is equal to
+    /// `swing * twist`.
+    ///
+    /// This is the inverse of [`Self::to_swing_twist()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `swing` or `twist` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_swing_twist(swing: Self, twist: Self) -> Self {
+        glam_assert!(swing.is_normalized() && twist.is_normalized());
+        swing.mul_quat(twist)
+    }
+
+    /// Limits the swing of `self` around `axis` to a cone with a half angle of `max_angle`
+    /// (in radians), keeping the twist.
+    ///
+    /// See [`Self::to_swing_twist()`] for how the rotation is decomposed. If the swing is inside
+    /// the cone `self` is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `axis` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn clamp_swing(self, axis: DVec3, max_angle: f64) -> Self {
+        let (swing, twist) = self.to_swing_twist(axis);
+        let (swing_axis, swing_angle) = swing.to_axis_angle();
+        if swing_angle <= max_angle {
+            return self;
+        }
+        Self::from_axis_angle(swing_axis, max_angle.max(0.0)).mul_quat(twist)
+    }
+
+    /// Limits the twist of `self` around `axis` to the range `[min_angle, max_angle]` (in
+    /// radians), keeping the swing.
+    ///
+    /// The twist angle is measured counterclockwise around `axis` in the range `[-PI, PI]`. See
+    /// [`Self::to_swing_twist()`] for how the rotation is decomposed. If the twist is inside the
+    /// range `self` is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `axis` are not normalized or if `min_angle` is greater than
+    /// `max_angle` when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn clamp_twist(self, axis: DVec3, min_angle: f64, max_angle: f64) -> Self {
+        glam_assert!(min_angle <= max_angle);
+        let (swing, twist) = self.to_swing_twist(axis);
+        let twist_angle = 2.0 * math::atan2(twist.xyz().dot(axis), twist.w);
+        if twist_angle >= min_angle && twist_angle <= max_angle {
+            return self;
+        }
+        let clamped = twist_angle.max(min_angle).min(max_angle);
+        swing.mul_quat(Self::from_axis_angle(axis, clamped))
+    }
+
+    /// Returns the rotation angles for the given euler rotation sequence.
+    ///
+    /// See [`EulerRot`] for the range of the returned angles and the behavior at gimbal lock.
+    #[inline]
+    pub fn to_euler(self, euler: EulerRot) -> (f64, f64, f64) {
+        euler.convert_quat(self)
+    }
+
+    /// `[x, y, z, w]`
+    #[inline]
+    pub fn to_array(&self) -> [f64; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Returns the vector part of the quaternion.
+    #[inline]
+    pub fn xyz(self) -> DVec3 {
+        DVec3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the quaternion conjugate of `self`. For a unit quaternion the
+    /// conjugate is also the inverse.
+    #[must_use]
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        const SIGN: __m256d = m256d_from_f64x4([-0.0, -0.0, -0.0, 0.0]);
+        Self(unsafe { _mm256_xor_pd(self.0, SIGN) })
+    }
+
+    /// Returns the inverse of a normalized quaternion.
+    ///
+    /// Typically quaternion inverse returns the conjugate of a normalized quaternion.
+    /// Because `self` is assumed to already be unit length this method *does not* normalize
+    /// before returning the conjugate.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn inverse(self) -> Self {
+        glam_assert!(self.is_normalized());
+        self.conjugate()
+    }
+
+    /// Computes the dot product of `self` and `rhs`. The dot product is
+    /// equal to the cosine of the angle between two quaternion rotations.
+    #[inline]
+    pub fn dot(self, rhs: Self) -> f64 {
+        DVec4::from(self).dot(DVec4::from(rhs))
+    }
+
+    /// Computes the length of `self`.
+    #[doc(alias = "magnitude")]
+    #[inline]
+    pub fn length(self) -> f64 {
+        DVec4::from(self).length()
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is generally faster than `length()` as it avoids a square
+    /// root operation.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    pub fn length_squared(self) -> f64 {
+        DVec4::from(self).length_squared()
+    }
+
+    /// Computes `1.0 / length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f64 {
+        DVec4::from(self).length_recip()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    ///
+    /// Panics
+    ///
+    /// Will panic if `self` is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn normalize(self) -> Self {
+        Self::from_vec4(DVec4::from(self).normalize())
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        DVec4::from(self).is_finite()
+    }
+
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        DVec4::from(self).is_nan()
+    }
+
+    /// Returns whether `self` of length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        DVec4::from(self).is_normalized()
+    }
+
+    #[inline]
+    pub fn is_near_identity(self) -> bool {
+        // Based on https://github.com/nfrechette/rtm `rtm::quat_near_identity`
+        let threshold_angle = 0.002_847_144_6;
+        // Because of floating point precision, we cannot represent very small rotations.
+        // The closest f32 to 1.0 that is not 1.0 itself yields:
+        // 0.99999994.acos() * 2.0  = 0.000690533954 rad
+        //
+        // An error threshold of 1.e-6 is used by default.
+        // (1.0 - 1.e-6).acos() * 2.0 = 0.00284714461 rad
+        // (1.0 - 1.e-7).acos() * 2.0 = 0.00097656250 rad
+        //
+        // We don't really care about the angle value itself, only if it's close to 0.
+        // This will happen whenever quat.w is close to 1.0.
+        // If the quat.w is close to -1.0, the angle will be near 2*PI which is close to
+        // a negative 0 rotation. By forcing quat.w to be positive, we'll end up with
+        // the shortest path.
+        let positive_w_angle = math::acos_approx(math::abs(self.w)) * 2.0;
+        positive_w_angle < threshold_angle
+    }
+
+    /// Returns the exponential of `self`.
+    ///
+    /// This is the inverse of [`Self::ln()`]. If `self` has a `w` of zero and a vector part of
+    /// `axis * angle / 2` the result is the rotation of `angle` radians around `axis`.
+    #[must_use]
+    #[inline]
+    pub fn exp(self) -> Self {
+        let v = self.xyz();
+        let theta = v.length();
+        let (sin, cos) = math::sin_cos(theta);
+        // `sin(theta) / theta` tends to one as `theta` tends to zero
+        let sinc = if theta > 0.0 { sin / theta } else { 1.0 };
+        let scale = math::exp(self.w);
+        let v = v * (sinc * scale);
+        Self::from_xyzw(v.x, v.y, v.z, cos * scale)
+    }
+
+    /// Returns the natural logarithm of `self`.
+    ///
+    /// If `self` is a rotation of `angle` radians around `axis` the result has a `w` of zero and
+    /// a vector part of `axis * angle / 2`.
+    ///
+    /// The logarithm of `-IDENTITY` is not unique, in that case a rotation around the x axis is
+    /// used.
+    #[must_use]
+    #[inline]
+    pub fn ln(self) -> Self {
+        let v = self.xyz();
+        let v_length = v.length();
+        let half_angle = math::atan2(v_length, self.w);
+        let v = if v_length > 0.0 {
+            v * (half_angle / v_length)
+        } else {
+            DVec3::new(half_angle, 0.0, 0.0)
+        };
+        Self::from_xyzw(v.x, v.y, v.z, math::ln(self.length()))
+    }
+
+    /// Returns `self` raised to the power `n`.
+    ///
+    /// If `self` is normalized this scales the angle of its rotation by `n`. A rotation with a
+    /// negative `w` is scaled along the longer path, negate `self` first to use the shorter one.
+    #[must_use]
+    #[inline]
+    pub fn powf(self, n: f64) -> Self {
+        (self.ln() * n).exp()
+    }
+
+    /// Returns the angle (in radians) for the minimal rotation
+    /// for transforming this quaternion into another.
+    ///
+    /// Both quaternions must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `rhs` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn angle_between(self, rhs: Self) -> f64 {
+        glam_assert!(self.is_normalized() && rhs.is_normalized());
+        math::acos_approx(math::abs(self.dot(rhs))) * 2.0
+    }
+
+    /// Rotates towards `rhs` up to `max_angle` (in radians).
+    ///
+    /// When `max_angle` is `0.0`, the result will be equal to `self`. When `max_angle` is equal to
+    /// `self.angle_between(rhs)`, the result will be equal to `rhs`. If `max_angle` is negative,
+    /// rotates towards the exact opposite of `rhs`. Will not go past the target.
+    ///
+    /// Both quaternions must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `rhs` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[must_use]
+    pub fn rotate_towards(self, rhs: Self, max_angle: f64) -> Self {
+        glam_assert!(self.is_normalized() && rhs.is_normalized());
+        let angle = self.angle_between(rhs);
+        if angle <= 1e-4 {
+            return rhs;
+        }
+        let s = (max_angle / angle).clamp(-1.0, 1.0);
+        self.slerp(rhs, s)
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two quaternions contain similar elements. It works
+    /// best when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        DVec4::from(self).abs_diff_eq(DVec4::from(rhs), max_abs_diff)
+    }
+
+    /// Performs a linear interpolation between `self` and `rhs` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    #[doc(alias = "mix")]
+    pub fn lerp(self, end: Self, s: f64) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        let start = self;
+        let dot = start.dot(end);
+        let bias = if dot >= 0.0 { 1.0 } else { -1.0 };
+        let interpolated = start.add(end.mul(bias).sub(start).mul(s));
+        interpolated.normalize()
+    }
+
+    /// Performs a normalized linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    ///
+    /// This is the same as [`Self::lerp()`], which also takes the shortest path and normalizes the
+    /// result. It is cheaper than [`Self::slerp()`] but the angular velocity is not constant.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn nlerp(self, end: Self, s: f64) -> Self {
+        self.lerp(end, s)
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end`
+    /// based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn slerp(self, mut end: Self, s: f64) -> Self {
+        // http://number-none.com/product/Understanding%20Slerp,%20Then%20Not%20Using%20It/
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        const DOT_THRESHOLD: f64 = 0.9995;
+
+        // Note that a rotation can be represented by two quaternions: `q` and
+        // `-q`. The slerp path between `q` and `end` will be different from the
+        // path between `-q` and `end`. One path will take the long way around and
+        // one will take the short way. In order to correct for this, the `dot`
+        // product between `self` and `end` should be positive. If the `dot`
+        // product is negative, slerp between `self` and `-end`.
+        let mut dot = self.dot(end);
+        if dot < 0.0 {
+            end = -end;
+            dot = -dot;
+        }
+
+        if dot > DOT_THRESHOLD {
+            // assumes lerp returns a normalized quaternion
+            self.lerp(end, s)
+        } else {
+            let theta = math::acos_approx(dot);
+
+            let scale1 = math::sin(theta * (1.0 - s));
+            let scale2 = math::sin(theta * s);
+            let theta_sin = math::sin(theta);
+
+            self.mul(scale1).add(end.mul(scale2)).mul(1.0 / theta_sin)
+        }
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end` based on the value `s`,
+    /// which may be outside of the `[0, 1]` range.
+    ///
+    /// Values of `s` outside of `[0, 1]` continue along the same arc with the same angular
+    /// velocity. Unlike [`Self::slerp()`], which falls back to [`Self::lerp()`] for nearly equal
+    /// rotations, the result stays on the arc for any `s`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn slerp_unclamped(self, mut end: Self, s: f64) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        // take the shorter path, see `slerp`
+        if self.dot(end) < 0.0 {
+            end = -end;
+        }
+        self.slerp_arc(end, s)
+    }
+
+    /// Interpolates along the arc from `self` to `end` without correcting for the longer path.
+    #[inline]
+    fn slerp_arc(self, end: Self, s: f64) -> Self {
+        self.mul_quat(self.conjugate().mul_quat(end).powf(s))
+    }
+
+    /// Computes the control point of `self` for [`Self::squad()`] from the previous and next
+    /// rotations in a sequence.
+    ///
+    /// The result is used as the `start_tangent` when interpolating from `self` and as the
+    /// `end_tangent` when interpolating to `self`. For the first and last rotations of a sequence
+    /// `self` can be passed in place of the missing neighbor.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self`, `prev` or `next` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn squad_tangent(self, prev: Self, next: Self) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(prev.is_normalized() && next.is_normalized());
+
+        let prev = if self.dot(prev) < 0.0 { -prev } else { prev };
+        let next = if self.dot(next) < 0.0 { -next } else { next };
+        let inv = self.conjugate();
+        let ln_prev = inv.mul_quat(prev).ln();
+        let ln_next = inv.mul_quat(next).ln();
+        self.mul_quat(ln_prev.add(ln_next).mul(-0.25).exp())
+    }
+
+    /// Performs a spherical cubic interpolation between `self` and `end` based on the value `s`,
+    /// using the control points from [`Self::squad_tangent()`].
+    ///
+    /// Interpolating a sequence of rotations with `squad` gives a smooth path with a continuous
+    /// angular velocity at each rotation, unlike a chain of [`Self::slerp()`] calls.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result will
+    /// be equal to `end`.
+    ///
+    /// ```
+    /// # use glam::DQuat;
+    /// let keys = [
+    ///     DQuat::IDENTITY,
+    ///     DQuat::from_rotation_y(1.0),
+    ///     DQuat::from_rotation_x(1.0),
+    ///     DQuat::from_rotation_z(0.5),
+    /// ];
+    /// let start_tangent = keys[1].squad_tangent(keys[0], keys[2]);
+    /// let end_tangent = keys[2].squad_tangent(keys[1], keys[3]);
+    /// let q = keys[1].squad(start_tangent, end_tangent, keys[2], 0.5);
+    /// assert!(q.is_normalized());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any of the quaternions are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn squad(self, start_tangent: Self, end_tangent: Self, end: Self, s: f64) -> Self {
+        glam_assert!(self.is_normalized() && end.is_normalized());
+        glam_assert!(start_tangent.is_normalized() && end_tangent.is_normalized());
+
+        // move `end` to the same hemisphere as `self` so the shorter path is taken
+        let (end, end_tangent) = if self.dot(end) < 0.0 {
+            (-end, -end_tangent)
+        } else {
+            (end, end_tangent)
+        };
+        let outer = self.slerp_arc(end, s);
+        let inner = start_tangent.slerp_arc(end_tangent, s);
+        outer.slerp_arc(inner, 2.0 * s * (1.0 - s))
+    }
+
+    /// Multiplies a quaternion and a 3D vector, returning the rotated vector.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn mul_vec3(self, rhs: DVec3) -> DVec3 {
+        glam_assert!(self.is_normalized());
+
+        let w = self.w;
+        let b = DVec3::new(self.x, self.y, self.z);
+        let b2 = b.dot(b);
+        rhs.mul(w * w - b2)
+            .add(b.mul(rhs.dot(b) * 2.0))
+            .add(b.cross(rhs).mul(w * 2.0))
+    }
+
+    /// Rotates each 3D vector in `vectors` in place using [`Self::mul_vec3()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn mul_vec3_slice(self, vectors: &mut [DVec3]) {
+        glam_assert!(self.is_normalized());
+
+        for v in vectors {
+            *v = self.mul_vec3(*v);
+        }
+    }
+
+    /// Rotates each 3D vector in `src` using [`Self::mul_vec3()`] and writes the results to
+    /// `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn mul_vec3_slice_to(self, src: &[DVec3], dst: &mut [DVec3]) {
+        glam_assert!(self.is_normalized());
+
+        assert_eq!(src.len(), dst.len());
+        for (s, d) in src.iter().zip(dst) {
+            *d = self.mul_vec3(*s);
+        }
+    }
+
+    /// Multiplies two quaternions. If they each represent a rotation, the result will
+    /// represent the combined rotation.
+    ///
+    /// Note that due to floating point rounding the result may not be perfectly normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `rhs` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn mul_quat(self, rhs: Self) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(rhs.is_normalized());
+
+        let (x0, y0, z0, w0) = self.into();
+        let (x1, y1, z1, w1) = rhs.into();
+        Self::from_xyzw(
+            w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+            w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+            w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+            w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+        )
+    }
+
+    /// Creates a quaternion from a 3x3 rotation matrix inside a 3D affine transform.
+    #[inline]
+    pub fn from_affine3(a: &crate::DAffine3) -> Self {
+        #[allow(clippy::useless_conversion)]
+        Self::from_rotation_axes(
+            a.matrix3.x_axis.into(),
+            a.matrix3.y_axis.into(),
+            a.matrix3.z_axis.into(),
+        )
+    }
+
+    #[inline]
+    pub fn as_f32(self) -> Quat {
+        Quat::from_xyzw(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DQuat {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(DQuat))
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DQuat {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl Add<DQuat> for DQuat {
+    type Output = Self;
+    /// Adds two quaternions.
+    ///
+    /// The sum is not guaranteed to be normalized.
+    ///
+    /// Note that addition is not the same as combining the rotations represented by the
+    /// two quaternions! That corresponds to multiplication.
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_vec4(DVec4::from(self) + DVec4::from(rhs))
+    }
+}
+
+impl Sub<DQuat> for DQuat {
+    type Output = Self;
+    /// Subtracts the `rhs` quaternion from `self`.
+    ///
+    /// The difference is not guaranteed to be normalized.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_vec4(DVec4::from(self) - DVec4::from(rhs))
+    }
+}
+
+impl Mul<f64> for DQuat {
+    type Output = Self;
+    /// Multiplies a quaternion by a scalar value.
+    ///
+    /// The product is not guaranteed to be normalized.
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_vec4(DVec4::from(self) * rhs)
+    }
+}
+
+impl Div<f64> for DQuat {
+    type Output = Self;
+    /// Divides a quaternion by a scalar value.
+    /// The quotient is not guaranteed to be normalized.
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self::from_vec4(DVec4::from(self) / rhs)
+    }
+}
+
+impl Mul<DQuat> for DQuat {
+    type Output = Self;
+    /// Multiplies two quaternions. If they each represent a rotation, the result will
+    /// represent the combined rotation.
+    ///
+    /// Note that due to floating point rounding the result may not be perfectly
+    /// normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `rhs` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_quat(rhs)
+    }
+}
+
+impl MulAssign<DQuat> for DQuat {
+    /// Multiplies two quaternions. If they each represent a rotation, the result will
+    /// represent the combined rotation.
+    ///
+    /// Note that due to floating point rounding the result may not be perfectly
+    /// normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `rhs` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_quat(rhs);
+    }
+}
+
+impl Mul<DVec3> for DQuat {
+    type Output = DVec3;
+    /// Multiplies a quaternion and a 3D vector, returning the rotated vector.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    fn mul(self, rhs: DVec3) -> Self::Output {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Neg for DQuat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self * -1.0
+    }
+}
+
+impl Default for DQuat {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl PartialEq for DQuat {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        DVec4::from(*self).eq(&DVec4::from(*rhs))
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsRef<[f64; 4]> for DQuat {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 4] {
+        unsafe { &*(self as *const Self as *const [f64; 4]) }
+    }
+}
+
+impl Sum<Self> for DQuat {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for DQuat {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for DQuat {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::IDENTITY, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for DQuat {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl From<DQuat> for DVec4 {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        Self(q.0)
+    }
+}
+
+impl From<DQuat> for (f64, f64, f64, f64) {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        DVec4::from(q).into()
+    }
+}
+
+impl From<DQuat> for [f64; 4] {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        DVec4::from(q).into()
+    }
+}
+
+impl From<DQuat> for __m256d {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        q.0
+    }
+}
+
+impl Deref for DQuat {
+    type Target = crate::deref::Vec4<f64>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self).cast() }
+    }
+}
+
+impl DerefMut for DQuat {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+}
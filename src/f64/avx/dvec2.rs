@@ -0,0 +1,1108 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{avx::*, f64::math, BVec2, DRadians, DVec3};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::{f32, ops::*};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(C)]
+union UnionCast {
+    a: [f64; 2],
+    v: DVec2,
+}
+
+/// Creates a 2-dimensional vector.
+#[inline(always)]
+pub const fn dvec2(x: f64, y: f64) -> DVec2 {
+    DVec2::new(x, y)
+}
+
+/// A 2-dimensional vector.
+///
+/// SIMD vector types are used for storage on supported platforms.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DVec2(pub(crate) __m128d);
+
+impl DVec2 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat(0.0);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat(1.0);
+
+    /// All negative ones.
+    pub const NEG_ONE: Self = Self::splat(-1.0);
+
+    /// All NAN.
+    pub const NAN: Self = Self::splat(f64::NAN);
+
+    /// A unit-length vector pointing along the positive X axis.
+    pub const X: Self = Self::new(1.0, 0.0);
+
+    /// A unit-length vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(0.0, 1.0);
+
+    /// A unit-length vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::new(-1.0, 0.0);
+
+    /// A unit-length vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::new(0.0, -1.0);
+
+    /// The unit axes.
+    pub const AXES: [Self; 2] = [Self::X, Self::Y];
+
+    /// Creates a new vector.
+    #[inline(always)]
+    pub const fn new(x: f64, y: f64) -> Self {
+        unsafe { UnionCast { a: [x, y] }.v }
+    }
+
+    /// Creates a vector with all elements set to `v`.
+    #[inline]
+    pub const fn splat(v: f64) -> Self {
+        unsafe { UnionCast { a: [v; 2] }.v }
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec2, if_true: Self, if_false: Self) -> Self {
+        Self::new(
+            if mask.x { if_true.x } else { if_false.x },
+            if mask.y { if_true.y } else { if_false.y },
+        )
+    }
+
+    /// Creates a new vector from an array.
+    #[inline]
+    pub const fn from_array(a: [f64; 2]) -> Self {
+        Self::new(a[0], a[1])
+    }
+
+    /// `[x, y]`
+    #[inline]
+    pub const fn to_array(&self) -> [f64; 2] {
+        unsafe { *(self as *const DVec2 as *const [f64; 2]) }
+    }
+
+    /// Creates a vector from the first 2 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 2 elements long.
+    #[inline]
+    pub const fn from_slice(slice: &[f64]) -> Self {
+        Self::new(slice[0], slice[1])
+    }
+
+    /// Writes the elements of `self` to the first 2 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 2 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [f64]) {
+        slice[0] = self.x;
+        slice[1] = self.y;
+    }
+
+    /// Creates a 3D vector from `self` and the given `z` value.
+    #[inline]
+    pub const fn extend(self, z: f64) -> DVec3 {
+        // self.x, self.y can't be done in a const-context
+        let [x, y] = self.to_array();
+        DVec3::new(x, y, z)
+    }
+
+    /// Computes the dot product of `self` and `rhs`.
+    #[inline]
+    pub fn dot(self, rhs: Self) -> f64 {
+        unsafe { dot2(self.0, rhs.0) }
+    }
+
+    /// Returns a vector where every component is the dot product of `self` and `rhs`.
+    #[inline]
+    pub fn dot_into_vec(self, rhs: Self) -> Self {
+        Self::splat(self.dot(rhs))
+    }
+
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.min(rhs.x), self.y.min(rhs.y), ..]`.
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_min_pd(self.0, rhs.0) })
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.max(rhs.x), self.y.max(rhs.y), ..]`.
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_max_pd(self.0, rhs.0) })
+    }
+
+    /// Component-wise clamping of values, similar to [`f64::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`.
+    ///
+    /// In other words this computes `min(x, y, ..)`.
+    #[inline]
+    pub fn min_element(self) -> f64 {
+        self.x.min(self.y)
+    }
+
+    /// Returns the horizontal maximum of `self`.
+    ///
+    /// In other words this computes `max(x, y, ..)`.
+    #[inline]
+    pub fn max_element(self) -> f64 {
+        self.x.max(self.y)
+    }
+
+    /// Returns a vector mask containing the result of a `==` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words, this computes `[self.x == rhs.x, self.y == rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpeq(self, rhs: Self) -> BVec2 {
+        unsafe { bvec2_from_bitmask(_mm_movemask_pd(_mm_cmp_pd(self.0, rhs.0, _CMP_EQ_OQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `!=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x != rhs.x, self.y != rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpne(self, rhs: Self) -> BVec2 {
+        unsafe { bvec2_from_bitmask(_mm_movemask_pd(_mm_cmp_pd(self.0, rhs.0, _CMP_NEQ_UQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `>=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x >= rhs.x, self.y >= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpge(self, rhs: Self) -> BVec2 {
+        unsafe { bvec2_from_bitmask(_mm_movemask_pd(_mm_cmp_pd(self.0, rhs.0, _CMP_GE_OQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `>` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x > rhs.x, self.y > rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpgt(self, rhs: Self) -> BVec2 {
+        unsafe { bvec2_from_bitmask(_mm_movemask_pd(_mm_cmp_pd(self.0, rhs.0, _CMP_GT_OQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `<=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x <= rhs.x, self.y <= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmple(self, rhs: Self) -> BVec2 {
+        unsafe { bvec2_from_bitmask(_mm_movemask_pd(_mm_cmp_pd(self.0, rhs.0, _CMP_LE_OQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `<` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x < rhs.x, self.y < rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmplt(self, rhs: Self) -> BVec2 {
+        unsafe { bvec2_from_bitmask(_mm_movemask_pd(_mm_cmp_pd(self.0, rhs.0, _CMP_LT_OQ))) }
+    }
+
+    /// Returns a vector containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(unsafe { _mm_andnot_pd(_mm_set1_pd(-0.0), self.0) })
+    }
+
+    /// Returns a vector with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - `NAN` if the number is `NAN`
+    #[inline]
+    pub fn signum(self) -> Self {
+        unsafe {
+            let result = Self(_mm_or_pd(_mm_and_pd(self.0, Self::NEG_ONE.0), Self::ONE.0));
+            let mask = self.is_nan_mask();
+            Self::select(mask, self, result)
+        }
+    }
+
+    /// Returns a vector with signs of `rhs` and the magnitudes of `self`.
+    #[inline]
+    pub fn copysign(self, rhs: Self) -> Self {
+        unsafe {
+            let mask = Self::splat(-0.0);
+            Self(_mm_or_pd(
+                _mm_and_pd(rhs.0, mask.0),
+                _mm_andnot_pd(mask.0, self.0),
+            ))
+        }
+    }
+
+    /// Returns a bitmask with the lowest 2 bits set to the sign bits from the elements of `self`.
+    ///
+    /// A negative element results in a `1` bit and a positive element in a `0` bit.  Element `x` goes
+    /// into the first lowest bit, element `y` into the second, etc.
+    #[inline]
+    pub fn is_negative_bitmask(self) -> u32 {
+        unsafe { _mm_movemask_pd(self.0) as u32 }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.  If any element is either
+    /// `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.is_nan_mask().any()
+    }
+
+    /// Performs `is_nan` on each element of self, returning a vector mask of the results.
+    ///
+    /// In other words, this computes `[x.is_nan(), y.is_nan(), z.is_nan(), w.is_nan()]`.
+    #[inline]
+    pub fn is_nan_mask(self) -> BVec2 {
+        unsafe { bvec2_from_bitmask(_mm_movemask_pd(_mm_cmp_pd(self.0, self.0, _CMP_UNORD_Q))) }
+    }
+
+    /// Computes the length of `self`.
+    #[doc(alias = "magnitude")]
+    #[inline]
+    pub fn length(self) -> f64 {
+        math::sqrt(self.dot(self))
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is faster than `length()` as it avoids a square root operation.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    pub fn length_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f64 {
+        self.length().recip()
+    }
+
+    /// Computes the Euclidean distance between two points in space.
+    #[inline]
+    pub fn distance(self, rhs: Self) -> f64 {
+        (self - rhs).length()
+    }
+
+    /// Compute the squared euclidean distance between two points in space.
+    #[inline]
+    pub fn distance_squared(self, rhs: Self) -> f64 {
+        (self - rhs).length_squared()
+    }
+
+    /// Returns the element-wise quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// Each element is `self / rhs` truncated to an integer and then adjusted so that the
+    /// remainder returned by [`Self::rem_euclid()`] is never negative.
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::div_euclid(self.x, rhs.x),
+            math::div_euclid(self.y, rhs.y),
+        )
+    }
+
+    /// Returns the element-wise quotient of Euclidean division of `self` by the scalar `rhs`.
+    ///
+    /// See [`Self::div_euclid()`] for how the quotient is rounded.
+    #[inline]
+    pub fn div_euclid_scalar(self, rhs: f64) -> Self {
+        self.div_euclid(Self::splat(rhs))
+    }
+
+    /// Returns the element-wise remainder of Euclidean division of `self` by `rhs`.
+    ///
+    /// Each element of the result is in the range `[0, rhs.abs())`, which makes this suitable
+    /// for wrapping coordinates into a tile or chunk. Due to rounding an element may be equal
+    /// to `rhs.abs()` when `self` is negative and much smaller in magnitude than `rhs`.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::rem_euclid(self.x, rhs.x),
+            math::rem_euclid(self.y, rhs.y),
+        )
+    }
+
+    /// Returns the element-wise remainder of Euclidean division of `self` by the scalar `rhs`.
+    ///
+    /// Each element of the result is in the range `[0, rhs.abs())`, although due to rounding an
+    /// element may be equal to `rhs.abs()` when `self` is negative and much smaller in magnitude
+    /// than `rhs`.
+    #[inline]
+    pub fn rem_euclid_scalar(self, rhs: f64) -> Self {
+        self.rem_euclid(Self::splat(rhs))
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero, nor very close to zero.
+    ///
+    /// See also [`Self::try_normalize()`] and [`Self::normalize_or_zero()`].
+    ///
+    /// Panics
+    ///
+    /// Will panic if `self` is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn normalize(self) -> Self {
+        #[allow(clippy::let_and_return)]
+        let normalized = self.mul(self.length_recip());
+        glam_assert!(normalized.is_finite());
+        normalized
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be `None`.
+    ///
+    /// See also [`Self::normalize_or_zero()`].
+    #[must_use]
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(self * rcp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be zero.
+    ///
+    /// See also [`Self::try_normalize()`].
+    #[must_use]
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            self * rcp
+        } else {
+            Self::ZERO
+        }
+    }
+
+    /// Returns whether `self` is length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        // TODO: do something with epsilon
+        math::abs(self.length_squared() - 1.0) <= 1e-4
+    }
+
+    /// Returns the vector projection of `self` onto `rhs`.
+    ///
+    /// `rhs` must be of non-zero length.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rhs` is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn project_onto(self, rhs: Self) -> Self {
+        let other_len_sq_rcp = rhs.dot(rhs).recip();
+        glam_assert!(other_len_sq_rcp.is_finite());
+        rhs * self.dot(rhs) * other_len_sq_rcp
+    }
+
+    /// Returns the vector rejection of `self` from `rhs`.
+    ///
+    /// The vector rejection is the vector perpendicular to the projection of `self` onto
+    /// `rhs`, in rhs words the result of `self - self.project_onto(rhs)`.
+    ///
+    /// `rhs` must be of non-zero length.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rhs` has a length of zero when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn reject_from(self, rhs: Self) -> Self {
+        self - self.project_onto(rhs)
+    }
+
+    /// Returns the vector projection of `self` onto `rhs`.
+    ///
+    /// `rhs` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rhs` is not normalized when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn project_onto_normalized(self, rhs: Self) -> Self {
+        glam_assert!(rhs.is_normalized());
+        rhs * self.dot(rhs)
+    }
+
+    /// Returns the vector rejection of `self` from `rhs`.
+    ///
+    /// The vector rejection is the vector perpendicular to the projection of `self` onto
+    /// `rhs`, in rhs words the result of `self - self.project_onto(rhs)`.
+    ///
+    /// `rhs` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rhs` is not normalized when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn reject_from_normalized(self, rhs: Self) -> Self {
+        self - self.project_onto_normalized(rhs)
+    }
+
+    /// Returns a vector containing the nearest integer to a number for each element of `self`.
+    /// Round half-way cases away from 0.0.
+    #[inline]
+    pub fn round(self) -> Self {
+        Self::new(math::round(self.x), math::round(self.y))
+    }
+
+    /// Returns a vector containing the largest integer less than or equal to a number for each
+    /// element of `self`.
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self(unsafe { _mm_floor_pd(self.0) })
+    }
+
+    /// Returns a vector containing the smallest integer greater than or equal to a number for
+    /// each element of `self`.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self(unsafe { _mm_ceil_pd(self.0) })
+    }
+
+    /// Returns a vector containing the fractional part of the vector, e.g. `self -
+    /// self.floor()`.
+    ///
+    /// This matches the GLSL `fract` function, so unlike `f64::fract` the result is
+    /// never negative for negative elements.
+    ///
+    /// Note that this is fast but not precise for large numbers.
+    #[inline]
+    pub fn fract(self) -> Self {
+        self - self.floor()
+    }
+
+    /// Returns a vector containing `e^self` (the exponential function) for each element of
+    /// `self`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        Self::new(math::exp(self.x), math::exp(self.y))
+    }
+
+    /// Returns a vector containing each element of `self` raised to the power of `n`.
+    #[inline]
+    pub fn powf(self, n: f64) -> Self {
+        Self::new(math::powf(self.x, n), math::powf(self.y, n))
+    }
+
+    /// Returns a vector containing the reciprocal `1.0/n` of each element of `self`.
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self(unsafe { _mm_div_pd(Self::ONE.0, self.0) })
+    }
+
+    /// Performs a linear interpolation between `self` and `rhs` based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `rhs`. When `s` is outside of range `[0, 1]`, the result is linearly
+    /// extrapolated.
+    #[doc(alias = "mix")]
+    #[inline]
+    pub fn lerp(self, rhs: Self, s: f64) -> Self {
+        self + ((rhs - self) * s)
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs` is
+    /// less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two vectors contain similar elements. It works best when
+    /// comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.sub(rhs).abs().cmple(Self::splat(max_abs_diff)).all()
+    }
+
+    /// Returns a vector with a length no less than `min` and no more than `max`
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp_length(self, min: f64, max: f64) -> Self {
+        glam_assert!(min <= max);
+        let length_sq = self.length_squared();
+        if length_sq < min * min {
+            min * (self / math::sqrt(length_sq))
+        } else if length_sq > max * max {
+            max * (self / math::sqrt(length_sq))
+        } else {
+            self
+        }
+    }
+
+    /// Returns a vector with a length no more than `max`
+    pub fn clamp_length_max(self, max: f64) -> Self {
+        let length_sq = self.length_squared();
+        if length_sq > max * max {
+            max * (self / math::sqrt(length_sq))
+        } else {
+            self
+        }
+    }
+
+    /// Returns a vector with a length no less than `min`
+    pub fn clamp_length_min(self, min: f64) -> Self {
+        let length_sq = self.length_squared();
+        if length_sq < min * min {
+            min * (self / math::sqrt(length_sq))
+        } else {
+            self
+        }
+    }
+
+    /// Fused multiply-add. Computes `(self * a) + b` element-wise with only one rounding
+    /// error, yielding a more accurate result than an unfused multiply-add.
+    ///
+    /// Using `mul_add` *may* be more performant than an unfused multiply-add if the target
+    /// architecture has a dedicated fma CPU instruction. However, this is not always true,
+    /// and will be heavily dependant on designing algorithms with specific target hardware in
+    /// mind.
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::new(
+            math::mul_add(self.x, a.x, b.x),
+            math::mul_add(self.y, a.y, b.y),
+        )
+    }
+
+    /// Creates a 2D vector containing `[angle.cos(), angle.sin()]`. This can be used in
+    /// conjunction with the [`rotate()`][Self::rotate()] method, e.g.
+    /// `DVec2::from_angle(PI).rotate(DVec2::Y)` will create the vector `[-1, 0]`
+    /// and rotate [`DVec2::Y`] around it returning `-DVec2::Y`.
+    #[inline]
+    pub fn from_angle(angle: impl Into<DRadians>) -> Self {
+        let (sin, cos) = math::sin_cos(angle.into().0);
+        Self::new(cos, sin)
+    }
+
+    /// Returns the angle (in radians) between `self` and `rhs`.
+    ///
+    /// The input vectors do not need to be unit length however they must be non-zero.
+    #[inline]
+    pub fn angle_between(self, rhs: Self) -> f64 {
+        let angle = math::acos_approx(
+            self.dot(rhs) / math::sqrt(self.length_squared() * rhs.length_squared()),
+        );
+
+        angle * math::signum(self.perp_dot(rhs))
+    }
+
+    /// Returns a vector that is equal to `self` rotated by 90 degrees.
+    #[inline]
+    pub fn perp(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// The perpendicular dot product of `self` and `rhs`.
+    /// Also known as the wedge product, 2D cross product, and determinant.
+    #[doc(alias = "wedge")]
+    #[doc(alias = "cross")]
+    #[doc(alias = "determinant")]
+    #[inline]
+    pub fn perp_dot(self, rhs: Self) -> f64 {
+        (self.x * rhs.y) - (self.y * rhs.x)
+    }
+
+    /// Returns `rhs` rotated by the angle of `self`. If `self` is normalized,
+    /// then this just rotation. This is what you usually want. Otherwise,
+    /// it will be like a rotation with a multiplication by `self`'s length.
+    #[must_use]
+    #[inline]
+    pub fn rotate(self, rhs: Self) -> Self {
+        Self::new(
+            self.x * rhs.x - self.y * rhs.y,
+            self.y * rhs.x + self.x * rhs.y,
+        )
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_vec2(&self) -> crate::Vec2 {
+        crate::Vec2::new(self.x as f32, self.y as f32)
+    }
+
+    /// Casts all elements of `self` to `i8`.
+    #[inline]
+    pub fn as_i8vec2(&self) -> crate::I8Vec2 {
+        crate::I8Vec2::new(self.x as i8, self.y as i8)
+    }
+
+    /// Casts all elements of `self` to `u8`.
+    #[inline]
+    pub fn as_u8vec2(&self) -> crate::U8Vec2 {
+        crate::U8Vec2::new(self.x as u8, self.y as u8)
+    }
+
+    /// Casts all elements of `self` to `i16`.
+    #[inline]
+    pub fn as_i16vec2(&self) -> crate::I16Vec2 {
+        crate::I16Vec2::new(self.x as i16, self.y as i16)
+    }
+
+    /// Casts all elements of `self` to `u16`.
+    #[inline]
+    pub fn as_u16vec2(&self) -> crate::U16Vec2 {
+        crate::U16Vec2::new(self.x as u16, self.y as u16)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    #[inline]
+    pub fn as_ivec2(&self) -> crate::IVec2 {
+        crate::IVec2::new(self.x as i32, self.y as i32)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    #[inline]
+    pub fn as_uvec2(&self) -> crate::UVec2 {
+        crate::UVec2::new(self.x as u32, self.y as u32)
+    }
+
+    /// Casts all elements of `self` to `i64`.
+    #[inline]
+    pub fn as_i64vec2(&self) -> crate::I64Vec2 {
+        crate::I64Vec2::new(self.x as i64, self.y as i64)
+    }
+
+    /// Casts all elements of `self` to `u64`.
+    #[inline]
+    pub fn as_u64vec2(&self) -> crate::U64Vec2 {
+        crate::U64Vec2::new(self.x as u64, self.y as u64)
+    }
+}
+
+impl Default for DVec2 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl PartialEq for DVec2 {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmpeq(*rhs).all()
+    }
+}
+
+impl Div<DVec2> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_div_pd(self.0, rhs.0) })
+    }
+}
+
+impl DivAssign<DVec2> for DVec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 = unsafe { _mm_div_pd(self.0, rhs.0) };
+    }
+}
+
+impl Div<f64> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self(unsafe { _mm_div_pd(self.0, _mm_set1_pd(rhs)) })
+    }
+}
+
+impl DivAssign<f64> for DVec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        self.0 = unsafe { _mm_div_pd(self.0, _mm_set1_pd(rhs)) };
+    }
+}
+
+impl Div<DVec2> for f64 {
+    type Output = DVec2;
+    #[inline]
+    fn div(self, rhs: DVec2) -> DVec2 {
+        DVec2(unsafe { _mm_div_pd(_mm_set1_pd(self), rhs.0) })
+    }
+}
+
+impl Mul<DVec2> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_mul_pd(self.0, rhs.0) })
+    }
+}
+
+impl MulAssign<DVec2> for DVec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 = unsafe { _mm_mul_pd(self.0, rhs.0) };
+    }
+}
+
+impl Mul<f64> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self(unsafe { _mm_mul_pd(self.0, _mm_set1_pd(rhs)) })
+    }
+}
+
+impl MulAssign<f64> for DVec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        self.0 = unsafe { _mm_mul_pd(self.0, _mm_set1_pd(rhs)) };
+    }
+}
+
+impl Mul<DVec2> for f64 {
+    type Output = DVec2;
+    #[inline]
+    fn mul(self, rhs: DVec2) -> DVec2 {
+        DVec2(unsafe { _mm_mul_pd(_mm_set1_pd(self), rhs.0) })
+    }
+}
+
+impl Add<DVec2> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_add_pd(self.0, rhs.0) })
+    }
+}
+
+impl AddAssign<DVec2> for DVec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = unsafe { _mm_add_pd(self.0, rhs.0) };
+    }
+}
+
+impl Add<f64> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: f64) -> Self {
+        Self(unsafe { _mm_add_pd(self.0, _mm_set1_pd(rhs)) })
+    }
+}
+
+impl AddAssign<f64> for DVec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: f64) {
+        self.0 = unsafe { _mm_add_pd(self.0, _mm_set1_pd(rhs)) };
+    }
+}
+
+impl Add<DVec2> for f64 {
+    type Output = DVec2;
+    #[inline]
+    fn add(self, rhs: DVec2) -> DVec2 {
+        DVec2(unsafe { _mm_add_pd(_mm_set1_pd(self), rhs.0) })
+    }
+}
+
+impl Sub<DVec2> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_sub_pd(self.0, rhs.0) })
+    }
+}
+
+impl SubAssign<DVec2> for DVec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: DVec2) {
+        self.0 = unsafe { _mm_sub_pd(self.0, rhs.0) };
+    }
+}
+
+impl Sub<f64> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: f64) -> Self {
+        Self(unsafe { _mm_sub_pd(self.0, _mm_set1_pd(rhs)) })
+    }
+}
+
+impl SubAssign<f64> for DVec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f64) {
+        self.0 = unsafe { _mm_sub_pd(self.0, _mm_set1_pd(rhs)) };
+    }
+}
+
+impl Sub<DVec2> for f64 {
+    type Output = DVec2;
+    #[inline]
+    fn sub(self, rhs: DVec2) -> DVec2 {
+        DVec2(unsafe { _mm_sub_pd(_mm_set1_pd(self), rhs.0) })
+    }
+}
+
+impl Rem<DVec2> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self::new(self.x.rem(rhs.x), self.y.rem(rhs.y))
+    }
+}
+
+impl RemAssign<DVec2> for DVec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.rem(rhs);
+    }
+}
+
+impl Rem<f64> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: f64) -> Self {
+        self.rem(Self::splat(rhs))
+    }
+}
+
+impl RemAssign<f64> for DVec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: f64) {
+        *self = self.rem(Self::splat(rhs));
+    }
+}
+
+impl Rem<DVec2> for f64 {
+    type Output = DVec2;
+    #[inline]
+    fn rem(self, rhs: DVec2) -> DVec2 {
+        DVec2::splat(self).rem(rhs)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsRef<[f64; 2]> for DVec2 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 2] {
+        unsafe { &*(self as *const DVec2 as *const [f64; 2]) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsMut<[f64; 2]> for DVec2 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 2] {
+        unsafe { &mut *(self as *mut DVec2 as *mut [f64; 2]) }
+    }
+}
+
+impl Sum for DVec2 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for DVec2 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for DVec2 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ONE, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for DVec2 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ONE, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl Neg for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe { _mm_xor_pd(_mm_set1_pd(-0.0), self.0) })
+    }
+}
+
+impl Index<usize> for DVec2 {
+    type Output = f64;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for DVec2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x, self.y)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DVec2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(DVec2))
+            .field(&self.x)
+            .field(&self.y)
+            .finish()
+    }
+}
+
+impl From<DVec2> for __m128d {
+    #[inline]
+    fn from(t: DVec2) -> Self {
+        t.0
+    }
+}
+
+impl From<__m128d> for DVec2 {
+    #[inline]
+    fn from(t: __m128d) -> Self {
+        Self(t)
+    }
+}
+
+impl From<[f64; 2]> for DVec2 {
+    #[inline]
+    fn from(a: [f64; 2]) -> Self {
+        Self::new(a[0], a[1])
+    }
+}
+
+impl From<DVec2> for [f64; 2] {
+    #[inline]
+    fn from(v: DVec2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl From<(f64, f64)> for DVec2 {
+    #[inline]
+    fn from(t: (f64, f64)) -> Self {
+        Self::new(t.0, t.1)
+    }
+}
+
+impl From<DVec2> for (f64, f64) {
+    #[inline]
+    fn from(v: DVec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl Deref for DVec2 {
+    type Target = crate::deref::XY<f64>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self).cast() }
+    }
+}
+
+impl DerefMut for DVec2 {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+}
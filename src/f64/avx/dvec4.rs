@@ -0,0 +1,1150 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{avx::*, f64::math, BVec4, DVec2, DVec3};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::{f32, ops::*};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(C)]
+union UnionCast {
+    a: [f64; 4],
+    v: DVec4,
+}
+
+/// Creates a 4-dimensional vector.
+#[inline(always)]
+pub const fn dvec4(x: f64, y: f64, z: f64, w: f64) -> DVec4 {
+    DVec4::new(x, y, z, w)
+}
+
+/// A 4-dimensional vector.
+///
+/// SIMD vector types are used for storage on supported platforms.
+///
+/// This type is 32 byte aligned.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DVec4(pub(crate) __m256d);
+
+impl DVec4 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat(0.0);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat(1.0);
+
+    /// All negative ones.
+    pub const NEG_ONE: Self = Self::splat(-1.0);
+
+    /// All NAN.
+    pub const NAN: Self = Self::splat(f64::NAN);
+
+    /// A unit-length vector pointing along the positive X axis.
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+
+    /// A unit-length vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+
+    /// A unit-length vector pointing along the positive Z axis.
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+
+    /// A unit-length vector pointing along the positive W axis.
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    /// A unit-length vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::new(-1.0, 0.0, 0.0, 0.0);
+
+    /// A unit-length vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::new(0.0, -1.0, 0.0, 0.0);
+
+    /// A unit-length vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self::new(0.0, 0.0, -1.0, 0.0);
+
+    /// A unit-length vector pointing along the negative W axis.
+    pub const NEG_W: Self = Self::new(0.0, 0.0, 0.0, -1.0);
+
+    /// The unit axes.
+    pub const AXES: [Self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
+
+    /// Creates a new vector.
+    #[inline(always)]
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        unsafe { UnionCast { a: [x, y, z, w] }.v }
+    }
+
+    /// Creates a vector with all elements set to `v`.
+    #[inline]
+    pub const fn splat(v: f64) -> Self {
+        unsafe { UnionCast { a: [v; 4] }.v }
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec4, if_true: Self, if_false: Self) -> Self {
+        Self::new(
+            if mask.x { if_true.x } else { if_false.x },
+            if mask.y { if_true.y } else { if_false.y },
+            if mask.z { if_true.z } else { if_false.z },
+            if mask.w { if_true.w } else { if_false.w },
+        )
+    }
+
+    /// Creates a new vector from an array.
+    #[inline]
+    pub const fn from_array(a: [f64; 4]) -> Self {
+        Self::new(a[0], a[1], a[2], a[3])
+    }
+
+    /// `[x, y, z, w]`
+    #[inline]
+    pub const fn to_array(&self) -> [f64; 4] {
+        unsafe { *(self as *const DVec4 as *const [f64; 4]) }
+    }
+
+    /// Creates a vector from the first 4 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub const fn from_slice(slice: &[f64]) -> Self {
+        Self::new(slice[0], slice[1], slice[2], slice[3])
+    }
+
+    /// Writes the elements of `self` to the first 4 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [f64]) {
+        slice[0] = self.x;
+        slice[1] = self.y;
+        slice[2] = self.z;
+        slice[3] = self.w;
+    }
+
+    /// Creates a 2D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
+    ///
+    /// Truncation to [`DVec3`] may also be performed by using [`self.xyz()`][crate::swizzles::Vec4Swizzles::xyz()].
+    #[inline]
+    pub fn truncate(self) -> DVec3 {
+        use crate::swizzles::Vec4Swizzles;
+        self.xyz()
+    }
+
+    /// Computes the dot product of `self` and `rhs`.
+    #[inline]
+    pub fn dot(self, rhs: Self) -> f64 {
+        unsafe { dot4(self.0, rhs.0) }
+    }
+
+    /// Returns a vector where every component is the dot product of `self` and `rhs`.
+    #[inline]
+    pub fn dot_into_vec(self, rhs: Self) -> Self {
+        Self::splat(self.dot(rhs))
+    }
+
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.min(rhs.x), self.y.min(rhs.y), ..]`.
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_min_pd(self.0, rhs.0) })
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.max(rhs.x), self.y.max(rhs.y), ..]`.
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_max_pd(self.0, rhs.0) })
+    }
+
+    /// Component-wise clamping of values, similar to [`f64::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`.
+    ///
+    /// In other words this computes `min(x, y, ..)`.
+    #[inline]
+    pub fn min_element(self) -> f64 {
+        self.x.min(self.y.min(self.z.min(self.w)))
+    }
+
+    /// Returns the horizontal maximum of `self`.
+    ///
+    /// In other words this computes `max(x, y, ..)`.
+    #[inline]
+    pub fn max_element(self) -> f64 {
+        self.x.max(self.y.max(self.z.max(self.w)))
+    }
+
+    /// Returns a vector mask containing the result of a `==` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words, this computes `[self.x == rhs.x, self.y == rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpeq(self, rhs: Self) -> BVec4 {
+        unsafe { bvec4_from_bitmask(_mm256_movemask_pd(_mm256_cmp_pd(self.0, rhs.0, _CMP_EQ_OQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `!=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x != rhs.x, self.y != rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpne(self, rhs: Self) -> BVec4 {
+        unsafe {
+            bvec4_from_bitmask(_mm256_movemask_pd(_mm256_cmp_pd(
+                self.0,
+                rhs.0,
+                _CMP_NEQ_UQ,
+            )))
+        }
+    }
+
+    /// Returns a vector mask containing the result of a `>=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x >= rhs.x, self.y >= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpge(self, rhs: Self) -> BVec4 {
+        unsafe { bvec4_from_bitmask(_mm256_movemask_pd(_mm256_cmp_pd(self.0, rhs.0, _CMP_GE_OQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `>` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x > rhs.x, self.y > rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpgt(self, rhs: Self) -> BVec4 {
+        unsafe { bvec4_from_bitmask(_mm256_movemask_pd(_mm256_cmp_pd(self.0, rhs.0, _CMP_GT_OQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `<=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x <= rhs.x, self.y <= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmple(self, rhs: Self) -> BVec4 {
+        unsafe { bvec4_from_bitmask(_mm256_movemask_pd(_mm256_cmp_pd(self.0, rhs.0, _CMP_LE_OQ))) }
+    }
+
+    /// Returns a vector mask containing the result of a `<` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x < rhs.x, self.y < rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmplt(self, rhs: Self) -> BVec4 {
+        unsafe { bvec4_from_bitmask(_mm256_movemask_pd(_mm256_cmp_pd(self.0, rhs.0, _CMP_LT_OQ))) }
+    }
+
+    /// Returns a vector containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(unsafe { _mm256_andnot_pd(_mm256_set1_pd(-0.0), self.0) })
+    }
+
+    /// Returns a vector with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - `NAN` if the number is `NAN`
+    #[inline]
+    pub fn signum(self) -> Self {
+        unsafe {
+            let result = Self(_mm256_or_pd(
+                _mm256_and_pd(self.0, Self::NEG_ONE.0),
+                Self::ONE.0,
+            ));
+            let mask = self.is_nan_mask();
+            Self::select(mask, self, result)
+        }
+    }
+
+    /// Returns a vector with signs of `rhs` and the magnitudes of `self`.
+    #[inline]
+    pub fn copysign(self, rhs: Self) -> Self {
+        unsafe {
+            let mask = Self::splat(-0.0);
+            Self(_mm256_or_pd(
+                _mm256_and_pd(rhs.0, mask.0),
+                _mm256_andnot_pd(mask.0, self.0),
+            ))
+        }
+    }
+
+    /// Returns a bitmask with the lowest 4 bits set to the sign bits from the elements of `self`.
+    ///
+    /// A negative element results in a `1` bit and a positive element in a `0` bit.  Element `x` goes
+    /// into the first lowest bit, element `y` into the second, etc.
+    #[inline]
+    pub fn is_negative_bitmask(self) -> u32 {
+        unsafe { _mm256_movemask_pd(self.0) as u32 }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.  If any element is either
+    /// `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.is_nan_mask().any()
+    }
+
+    /// Performs `is_nan` on each element of self, returning a vector mask of the results.
+    ///
+    /// In other words, this computes `[x.is_nan(), y.is_nan(), z.is_nan(), w.is_nan()]`.
+    #[inline]
+    pub fn is_nan_mask(self) -> BVec4 {
+        unsafe {
+            bvec4_from_bitmask(_mm256_movemask_pd(_mm256_cmp_pd(
+                self.0,
+                self.0,
+                _CMP_UNORD_Q,
+            )))
+        }
+    }
+
+    /// Computes the length of `self`.
+    #[doc(alias = "magnitude")]
+    #[inline]
+    pub fn length(self) -> f64 {
+        math::sqrt(self.dot(self))
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is faster than `length()` as it avoids a square root operation.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    pub fn length_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f64 {
+        self.length().recip()
+    }
+
+    /// Computes the Euclidean distance between two points in space.
+    #[inline]
+    pub fn distance(self, rhs: Self) -> f64 {
+        (self - rhs).length()
+    }
+
+    /// Compute the squared euclidean distance between two points in space.
+    #[inline]
+    pub fn distance_squared(self, rhs: Self) -> f64 {
+        (self - rhs).length_squared()
+    }
+
+    /// Returns the element-wise quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// Each element is `self / rhs` truncated to an integer and then adjusted so that the
+    /// remainder returned by [`Self::rem_euclid()`] is never negative.
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::div_euclid(self.x, rhs.x),
+            math::div_euclid(self.y, rhs.y),
+            math::div_euclid(self.z, rhs.z),
+            math::div_euclid(self.w, rhs.w),
+        )
+    }
+
+    /// Returns the element-wise quotient of Euclidean division of `self` by the scalar `rhs`.
+    ///
+    /// See [`Self::div_euclid()`] for how the quotient is rounded.
+    #[inline]
+    pub fn div_euclid_scalar(self, rhs: f64) -> Self {
+        self.div_euclid(Self::splat(rhs))
+    }
+
+    /// Returns the element-wise remainder of Euclidean division of `self` by `rhs`.
+    ///
+    /// Each element of the result is in the range `[0, rhs.abs())`, which makes this suitable
+    /// for wrapping coordinates into a tile or chunk. Due to rounding an element may be equal
+    /// to `rhs.abs()` when `self` is negative and much smaller in magnitude than `rhs`.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::rem_euclid(self.x, rhs.x),
+            math::rem_euclid(self.y, rhs.y),
+            math::rem_euclid(self.z, rhs.z),
+            math::rem_euclid(self.w, rhs.w),
+        )
+    }
+
+    /// Returns the element-wise remainder of Euclidean division of `self` by the scalar `rhs`.
+    ///
+    /// Each element of the result is in the range `[0, rhs.abs())`, although due to rounding an
+    /// element may be equal to `rhs.abs()` when `self` is negative and much smaller in magnitude
+    /// than `rhs`.
+    #[inline]
+    pub fn rem_euclid_scalar(self, rhs: f64) -> Self {
+        self.rem_euclid(Self::splat(rhs))
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero, nor very close to zero.
+    ///
+    /// See also [`Self::try_normalize()`] and [`Self::normalize_or_zero()`].
+    ///
+    /// Panics
+    ///
+    /// Will panic if `self` is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn normalize(self) -> Self {
+        #[allow(clippy::let_and_return)]
+        let normalized = self.mul(self.length_recip());
+        glam_assert!(normalized.is_finite());
+        normalized
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be `None`.
+    ///
+    /// See also [`Self::normalize_or_zero()`].
+    #[must_use]
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(self * rcp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be zero.
+    ///
+    /// See also [`Self::try_normalize()`].
+    #[must_use]
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            self * rcp
+        } else {
+            Self::ZERO
+        }
+    }
+
+    /// Returns whether `self` is length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        // TODO: do something with epsilon
+        math::abs(self.length_squared() - 1.0) <= 1e-4
+    }
+
+    /// Returns the vector projection of `self` onto `rhs`.
+    ///
+    /// `rhs` must be of non-zero length.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rhs` is zero length when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn project_onto(self, rhs: Self) -> Self {
+        let other_len_sq_rcp = rhs.dot(rhs).recip();
+        glam_assert!(other_len_sq_rcp.is_finite());
+        rhs * self.dot(rhs) * other_len_sq_rcp
+    }
+
+    /// Returns the vector rejection of `self` from `rhs`.
+    ///
+    /// The vector rejection is the vector perpendicular to the projection of `self` onto
+    /// `rhs`, in rhs words the result of `self - self.project_onto(rhs)`.
+    ///
+    /// `rhs` must be of non-zero length.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rhs` has a length of zero when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn reject_from(self, rhs: Self) -> Self {
+        self - self.project_onto(rhs)
+    }
+
+    /// Returns the vector projection of `self` onto `rhs`.
+    ///
+    /// `rhs` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rhs` is not normalized when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn project_onto_normalized(self, rhs: Self) -> Self {
+        glam_assert!(rhs.is_normalized());
+        rhs * self.dot(rhs)
+    }
+
+    /// Returns the vector rejection of `self` from `rhs`.
+    ///
+    /// The vector rejection is the vector perpendicular to the projection of `self` onto
+    /// `rhs`, in rhs words the result of `self - self.project_onto(rhs)`.
+    ///
+    /// `rhs` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rhs` is not normalized when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn reject_from_normalized(self, rhs: Self) -> Self {
+        self - self.project_onto_normalized(rhs)
+    }
+
+    /// Returns a vector containing the nearest integer to a number for each element of `self`.
+    /// Round half-way cases away from 0.0.
+    #[inline]
+    pub fn round(self) -> Self {
+        Self::new(
+            math::round(self.x),
+            math::round(self.y),
+            math::round(self.z),
+            math::round(self.w),
+        )
+    }
+
+    /// Returns a vector containing the largest integer less than or equal to a number for each
+    /// element of `self`.
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self(unsafe { _mm256_floor_pd(self.0) })
+    }
+
+    /// Returns a vector containing the smallest integer greater than or equal to a number for
+    /// each element of `self`.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self(unsafe { _mm256_ceil_pd(self.0) })
+    }
+
+    /// Returns a vector containing the fractional part of the vector, e.g. `self -
+    /// self.floor()`.
+    ///
+    /// This matches the GLSL `fract` function, so unlike `f64::fract` the result is
+    /// never negative for negative elements.
+    ///
+    /// Note that this is fast but not precise for large numbers.
+    #[inline]
+    pub fn fract(self) -> Self {
+        self - self.floor()
+    }
+
+    /// Returns a vector containing `e^self` (the exponential function) for each element of
+    /// `self`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        Self::new(
+            math::exp(self.x),
+            math::exp(self.y),
+            math::exp(self.z),
+            math::exp(self.w),
+        )
+    }
+
+    /// Returns a vector containing each element of `self` raised to the power of `n`.
+    #[inline]
+    pub fn powf(self, n: f64) -> Self {
+        Self::new(
+            math::powf(self.x, n),
+            math::powf(self.y, n),
+            math::powf(self.z, n),
+            math::powf(self.w, n),
+        )
+    }
+
+    /// Returns a vector containing the reciprocal `1.0/n` of each element of `self`.
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self(unsafe { _mm256_div_pd(Self::ONE.0, self.0) })
+    }
+
+    /// Performs a linear interpolation between `self` and `rhs` based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `rhs`. When `s` is outside of range `[0, 1]`, the result is linearly
+    /// extrapolated.
+    #[doc(alias = "mix")]
+    #[inline]
+    pub fn lerp(self, rhs: Self, s: f64) -> Self {
+        self + ((rhs - self) * s)
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs` is
+    /// less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two vectors contain similar elements. It works best when
+    /// comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.sub(rhs).abs().cmple(Self::splat(max_abs_diff)).all()
+    }
+
+    /// Returns a vector with a length no less than `min` and no more than `max`
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp_length(self, min: f64, max: f64) -> Self {
+        glam_assert!(min <= max);
+        let length_sq = self.length_squared();
+        if length_sq < min * min {
+            min * (self / math::sqrt(length_sq))
+        } else if length_sq > max * max {
+            max * (self / math::sqrt(length_sq))
+        } else {
+            self
+        }
+    }
+
+    /// Returns a vector with a length no more than `max`
+    pub fn clamp_length_max(self, max: f64) -> Self {
+        let length_sq = self.length_squared();
+        if length_sq > max * max {
+            max * (self / math::sqrt(length_sq))
+        } else {
+            self
+        }
+    }
+
+    /// Returns a vector with a length no less than `min`
+    pub fn clamp_length_min(self, min: f64) -> Self {
+        let length_sq = self.length_squared();
+        if length_sq < min * min {
+            min * (self / math::sqrt(length_sq))
+        } else {
+            self
+        }
+    }
+
+    /// Fused multiply-add. Computes `(self * a) + b` element-wise with only one rounding
+    /// error, yielding a more accurate result than an unfused multiply-add.
+    ///
+    /// Using `mul_add` *may* be more performant than an unfused multiply-add if the target
+    /// architecture has a dedicated fma CPU instruction. However, this is not always true,
+    /// and will be heavily dependant on designing algorithms with specific target hardware in
+    /// mind.
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::new(
+            math::mul_add(self.x, a.x, b.x),
+            math::mul_add(self.y, a.y, b.y),
+            math::mul_add(self.z, a.z, b.z),
+            math::mul_add(self.w, a.w, b.w),
+        )
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_vec4(&self) -> crate::Vec4 {
+        crate::Vec4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+
+    /// Casts all elements of `self` to `i8`.
+    #[inline]
+    pub fn as_i8vec4(&self) -> crate::I8Vec4 {
+        crate::I8Vec4::new(self.x as i8, self.y as i8, self.z as i8, self.w as i8)
+    }
+
+    /// Casts all elements of `self` to `u8`.
+    #[inline]
+    pub fn as_u8vec4(&self) -> crate::U8Vec4 {
+        crate::U8Vec4::new(self.x as u8, self.y as u8, self.z as u8, self.w as u8)
+    }
+
+    /// Casts all elements of `self` to `i16`.
+    #[inline]
+    pub fn as_i16vec4(&self) -> crate::I16Vec4 {
+        crate::I16Vec4::new(self.x as i16, self.y as i16, self.z as i16, self.w as i16)
+    }
+
+    /// Casts all elements of `self` to `u16`.
+    #[inline]
+    pub fn as_u16vec4(&self) -> crate::U16Vec4 {
+        crate::U16Vec4::new(self.x as u16, self.y as u16, self.z as u16, self.w as u16)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    #[inline]
+    pub fn as_ivec4(&self) -> crate::IVec4 {
+        crate::IVec4::new(self.x as i32, self.y as i32, self.z as i32, self.w as i32)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    #[inline]
+    pub fn as_uvec4(&self) -> crate::UVec4 {
+        crate::UVec4::new(self.x as u32, self.y as u32, self.z as u32, self.w as u32)
+    }
+
+    /// Casts all elements of `self` to `i64`.
+    #[inline]
+    pub fn as_i64vec4(&self) -> crate::I64Vec4 {
+        crate::I64Vec4::new(self.x as i64, self.y as i64, self.z as i64, self.w as i64)
+    }
+
+    /// Casts all elements of `self` to `u64`.
+    #[inline]
+    pub fn as_u64vec4(&self) -> crate::U64Vec4 {
+        crate::U64Vec4::new(self.x as u64, self.y as u64, self.z as u64, self.w as u64)
+    }
+}
+
+impl Default for DVec4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl PartialEq for DVec4 {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmpeq(*rhs).all()
+    }
+}
+
+impl Div<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_div_pd(self.0, rhs.0) })
+    }
+}
+
+impl DivAssign<DVec4> for DVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 = unsafe { _mm256_div_pd(self.0, rhs.0) };
+    }
+}
+
+impl Div<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self(unsafe { _mm256_div_pd(self.0, _mm256_set1_pd(rhs)) })
+    }
+}
+
+impl DivAssign<f64> for DVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        self.0 = unsafe { _mm256_div_pd(self.0, _mm256_set1_pd(rhs)) };
+    }
+}
+
+impl Div<DVec4> for f64 {
+    type Output = DVec4;
+    #[inline]
+    fn div(self, rhs: DVec4) -> DVec4 {
+        DVec4(unsafe { _mm256_div_pd(_mm256_set1_pd(self), rhs.0) })
+    }
+}
+
+impl Mul<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_mul_pd(self.0, rhs.0) })
+    }
+}
+
+impl MulAssign<DVec4> for DVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 = unsafe { _mm256_mul_pd(self.0, rhs.0) };
+    }
+}
+
+impl Mul<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self(unsafe { _mm256_mul_pd(self.0, _mm256_set1_pd(rhs)) })
+    }
+}
+
+impl MulAssign<f64> for DVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        self.0 = unsafe { _mm256_mul_pd(self.0, _mm256_set1_pd(rhs)) };
+    }
+}
+
+impl Mul<DVec4> for f64 {
+    type Output = DVec4;
+    #[inline]
+    fn mul(self, rhs: DVec4) -> DVec4 {
+        DVec4(unsafe { _mm256_mul_pd(_mm256_set1_pd(self), rhs.0) })
+    }
+}
+
+impl Add<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_add_pd(self.0, rhs.0) })
+    }
+}
+
+impl AddAssign<DVec4> for DVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = unsafe { _mm256_add_pd(self.0, rhs.0) };
+    }
+}
+
+impl Add<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: f64) -> Self {
+        Self(unsafe { _mm256_add_pd(self.0, _mm256_set1_pd(rhs)) })
+    }
+}
+
+impl AddAssign<f64> for DVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: f64) {
+        self.0 = unsafe { _mm256_add_pd(self.0, _mm256_set1_pd(rhs)) };
+    }
+}
+
+impl Add<DVec4> for f64 {
+    type Output = DVec4;
+    #[inline]
+    fn add(self, rhs: DVec4) -> DVec4 {
+        DVec4(unsafe { _mm256_add_pd(_mm256_set1_pd(self), rhs.0) })
+    }
+}
+
+impl Sub<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_sub_pd(self.0, rhs.0) })
+    }
+}
+
+impl SubAssign<DVec4> for DVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: DVec4) {
+        self.0 = unsafe { _mm256_sub_pd(self.0, rhs.0) };
+    }
+}
+
+impl Sub<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: f64) -> Self {
+        Self(unsafe { _mm256_sub_pd(self.0, _mm256_set1_pd(rhs)) })
+    }
+}
+
+impl SubAssign<f64> for DVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f64) {
+        self.0 = unsafe { _mm256_sub_pd(self.0, _mm256_set1_pd(rhs)) };
+    }
+}
+
+impl Sub<DVec4> for f64 {
+    type Output = DVec4;
+    #[inline]
+    fn sub(self, rhs: DVec4) -> DVec4 {
+        DVec4(unsafe { _mm256_sub_pd(_mm256_set1_pd(self), rhs.0) })
+    }
+}
+
+impl Rem<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.rem(rhs.x),
+            self.y.rem(rhs.y),
+            self.z.rem(rhs.z),
+            self.w.rem(rhs.w),
+        )
+    }
+}
+
+impl RemAssign<DVec4> for DVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.rem(rhs);
+    }
+}
+
+impl Rem<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: f64) -> Self {
+        self.rem(Self::splat(rhs))
+    }
+}
+
+impl RemAssign<f64> for DVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: f64) {
+        *self = self.rem(Self::splat(rhs));
+    }
+}
+
+impl Rem<DVec4> for f64 {
+    type Output = DVec4;
+    #[inline]
+    fn rem(self, rhs: DVec4) -> DVec4 {
+        DVec4::splat(self).rem(rhs)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsRef<[f64; 4]> for DVec4 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 4] {
+        unsafe { &*(self as *const DVec4 as *const [f64; 4]) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsMut<[f64; 4]> for DVec4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 4] {
+        unsafe { &mut *(self as *mut DVec4 as *mut [f64; 4]) }
+    }
+}
+
+impl Sum for DVec4 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for DVec4 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for DVec4 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ONE, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for DVec4 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ONE, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl Neg for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe { _mm256_xor_pd(_mm256_set1_pd(-0.0), self.0) })
+    }
+}
+
+impl Index<usize> for DVec4 {
+    type Output = f64;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for DVec4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(DVec4))
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+
+impl From<DVec4> for __m256d {
+    #[inline]
+    fn from(t: DVec4) -> Self {
+        t.0
+    }
+}
+
+impl From<__m256d> for DVec4 {
+    #[inline]
+    fn from(t: __m256d) -> Self {
+        Self(t)
+    }
+}
+
+impl From<[f64; 4]> for DVec4 {
+    #[inline]
+    fn from(a: [f64; 4]) -> Self {
+        Self::new(a[0], a[1], a[2], a[3])
+    }
+}
+
+impl From<DVec4> for [f64; 4] {
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for DVec4 {
+    #[inline]
+    fn from(t: (f64, f64, f64, f64)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<DVec4> for (f64, f64, f64, f64) {
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<(DVec3, f64)> for DVec4 {
+    #[inline]
+    fn from((v, w): (DVec3, f64)) -> Self {
+        Self::new(v.x, v.y, v.z, w)
+    }
+}
+
+impl From<(f64, DVec3)> for DVec4 {
+    #[inline]
+    fn from((x, v): (f64, DVec3)) -> Self {
+        Self::new(x, v.x, v.y, v.z)
+    }
+}
+
+impl From<(DVec2, f64, f64)> for DVec4 {
+    #[inline]
+    fn from((v, z, w): (DVec2, f64, f64)) -> Self {
+        Self::new(v.x, v.y, z, w)
+    }
+}
+
+impl From<(DVec2, DVec2)> for DVec4 {
+    #[inline]
+    fn from((v, u): (DVec2, DVec2)) -> Self {
+        Self::new(v.x, v.y, u.x, u.y)
+    }
+}
+
+impl Deref for DVec4 {
+    type Target = crate::deref::Vec4<f64>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self).cast() }
+    }
+}
+
+impl DerefMut for DVec4 {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+}
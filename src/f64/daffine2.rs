@@ -1,6 +1,6 @@
 // Generated from affine.rs.tera template. Edit the template, not the generated file.
 
-use crate::{DMat2, DMat3, DVec2};
+use crate::{DMat2, DMat3, DRadians, DRot2, DVec2};
 use core::ops::{Deref, DerefMut, Mul};
 
 /// A 2D affine transform, which can represent translation, rotation, scaling and shear.
@@ -122,7 +122,7 @@ impl DAffine2 {
 
     /// Creates an affine transform from the given rotation `angle`.
     #[inline]
-    pub fn from_angle(angle: f64) -> Self {
+    pub fn from_angle(angle: impl Into<DRadians>) -> Self {
         Self {
             matrix2: DMat2::from_angle(angle),
             translation: DVec2::ZERO,
@@ -166,7 +166,11 @@ impl DAffine2 {
     /// Equivalent to `DAffine2::from_translation(translation) *
     /// DAffine2::from_angle(angle) * DAffine2::from_scale(scale)`
     #[inline]
-    pub fn from_scale_angle_translation(scale: DVec2, angle: f64, translation: DVec2) -> Self {
+    pub fn from_scale_angle_translation(
+        scale: DVec2,
+        angle: impl Into<DRadians>,
+        translation: DVec2,
+    ) -> Self {
         let rotation = DMat2::from_angle(angle);
         Self {
             matrix2: DMat2::from_cols(rotation.x_axis * scale.x, rotation.y_axis * scale.y),
@@ -179,13 +183,25 @@ impl DAffine2 {
     ///
     /// Equivalent to `DAffine2::from_translation(translation) * DAffine2::from_angle(angle)`
     #[inline]
-    pub fn from_angle_translation(angle: f64, translation: DVec2) -> Self {
+    pub fn from_angle_translation(angle: impl Into<DRadians>, translation: DVec2) -> Self {
         Self {
             matrix2: DMat2::from_angle(angle),
             translation,
         }
     }
 
+    /// Creates an affine transform from the given 2D `rotation` and `translation`.
+    ///
+    /// Equivalent to `DAffine2::from_angle_translation(rotation.angle(), translation)` but
+    /// does not require any trigonometry.
+    #[inline]
+    pub fn from_rotation_translation(rotation: DRot2, translation: DVec2) -> Self {
+        Self {
+            matrix2: DMat2::from(rotation),
+            translation,
+        }
+    }
+
     /// The given `DMat3` must be an affine transform,
     #[inline]
     pub fn from_mat3(m: DMat3) -> Self {
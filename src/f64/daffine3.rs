@@ -1,6 +1,6 @@
 // Generated from affine.rs.tera template. Edit the template, not the generated file.
 
-use crate::{DMat3, DMat4, DQuat, DVec3};
+use crate::{DMat3, DMat4, DQuat, DRadians, DVec3, EulerRot};
 use core::ops::{Deref, DerefMut, Mul};
 
 /// A 3D affine transform, which can represent translation, rotation, scaling and shear.
@@ -133,17 +133,46 @@ impl DAffine3 {
     /// Creates an affine transform containing a 3D rotation around a normalized
     /// rotation `axis` of `angle` (in radians).
     #[inline]
-    pub fn from_axis_angle(axis: DVec3, angle: f64) -> Self {
+    pub fn from_axis_angle(axis: DVec3, angle: impl Into<DRadians>) -> Self {
         Self {
             matrix3: DMat3::from_axis_angle(axis, angle),
             translation: DVec3::ZERO,
         }
     }
 
+    /// Creates an affine transform containing a 3D rotation from the given euler rotation
+    /// sequence and angles (in radians).
+    #[inline]
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<DRadians>,
+        b: impl Into<DRadians>,
+        c: impl Into<DRadians>,
+    ) -> Self {
+        Self {
+            matrix3: DMat3::from_euler(order, a, b, c),
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Extracts the angles (in radians) of the given euler rotation sequence from the rotation
+    /// part of `self`, ignoring the translation.
+    ///
+    /// See [`EulerRot`] for the range of the returned angles and the behavior at gimbal lock.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any column of `self.matrix3` is not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    pub fn to_euler(&self, order: EulerRot) -> (f64, f64, f64) {
+        self.matrix3.to_euler(order)
+    }
+
     /// Creates an affine transform containing a 3D rotation around the x axis of
     /// `angle` (in radians).
     #[inline]
-    pub fn from_rotation_x(angle: f64) -> Self {
+    pub fn from_rotation_x(angle: impl Into<DRadians>) -> Self {
         Self {
             matrix3: DMat3::from_rotation_x(angle),
             translation: DVec3::ZERO,
@@ -153,7 +182,7 @@ impl DAffine3 {
     /// Creates an affine transform containing a 3D rotation around the y axis of
     /// `angle` (in radians).
     #[inline]
-    pub fn from_rotation_y(angle: f64) -> Self {
+    pub fn from_rotation_y(angle: impl Into<DRadians>) -> Self {
         Self {
             matrix3: DMat3::from_rotation_y(angle),
             translation: DVec3::ZERO,
@@ -163,7 +192,7 @@ impl DAffine3 {
     /// Creates an affine transform containing a 3D rotation around the z axis of
     /// `angle` (in radians).
     #[inline]
-    pub fn from_rotation_z(angle: f64) -> Self {
+    pub fn from_rotation_z(angle: impl Into<DRadians>) -> Self {
         Self {
             matrix3: DMat3::from_rotation_z(angle),
             translation: DVec3::ZERO,
@@ -368,6 +397,50 @@ impl DAffine3 {
             .into()
     }
 
+    /// Transforms each 3D point in `points` in place using [`Self::transform_point3()`].
+    #[inline]
+    pub fn transform_points3_slice(&self, points: &mut [DVec3]) {
+        for v in points {
+            *v = self.transform_point3(*v);
+        }
+    }
+
+    /// Transforms each 3D point in `src` using [`Self::transform_point3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    #[inline]
+    pub fn transform_points3_slice_to(&self, src: &[DVec3], dst: &mut [DVec3]) {
+        assert_eq!(src.len(), dst.len());
+        for (s, d) in src.iter().zip(dst) {
+            *d = self.transform_point3(*s);
+        }
+    }
+
+    /// Transforms each 3D vector in `vectors` in place using [`Self::transform_vector3()`].
+    #[inline]
+    pub fn transform_vectors3_slice(&self, vectors: &mut [DVec3]) {
+        for v in vectors {
+            *v = self.transform_vector3(*v);
+        }
+    }
+
+    /// Transforms each 3D vector in `src` using [`Self::transform_vector3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    #[inline]
+    pub fn transform_vectors3_slice_to(&self, src: &[DVec3], dst: &mut [DVec3]) {
+        assert_eq!(src.len(), dst.len());
+        for (s, d) in src.iter().zip(dst) {
+            *d = self.transform_vector3(*s);
+        }
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     ///
     /// If any element is either `NaN`, positive or negative infinity, this will return
@@ -0,0 +1,282 @@
+// Generated from frustum.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f64::math, DAabb3, DMat4, DPlane3, DSphere, DVec3, DVec4};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A view frustum, defined by six planes with normals facing into the frustum.
+///
+/// The planes are stored in the order left, right, bottom, top, near and far.
+///
+/// A frustum is usually extracted from a projection or view-projection matrix using the
+/// constructor matching the depth range of the projection:
+///
+/// * [`Self::from_mat4()`] for the `_lh` and `_rh` projections, which map depth to `[0, 1]`
+/// * [`Self::from_mat4_gl()`] for the `_gl` projections, which map depth to `[-1, 1]`
+/// * [`Self::from_mat4_reverse_z()`] for the `_reverse` projections, which map depth to `[1, 0]`
+///
+/// Infinite projections have no far plane. Its normal will be zero and it will contain every
+/// point.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct DFrustum {
+    planes: [DVec4; 6],
+}
+
+impl DFrustum {
+    /// Creates a frustum from six planes with normals facing into the frustum, in the order
+    /// left, right, bottom, top, near and far.
+    ///
+    /// The planes will be normalized.
+    #[inline]
+    pub fn from_planes(planes: [DPlane3; 6]) -> Self {
+        Self {
+            planes: [
+                normalize_plane(planes[0].to_vec4()),
+                normalize_plane(planes[1].to_vec4()),
+                normalize_plane(planes[2].to_vec4()),
+                normalize_plane(planes[3].to_vec4()),
+                normalize_plane(planes[4].to_vec4()),
+                normalize_plane(planes[5].to_vec4()),
+            ],
+        }
+    }
+
+    #[inline]
+    fn from_rows(m: &DMat4, near: DVec4, far: DVec4) -> Self {
+        let row0 = m.row(0);
+        let row1 = m.row(1);
+        let row3 = m.row(3);
+        Self {
+            planes: [
+                normalize_plane(row3 + row0),
+                normalize_plane(row3 - row0),
+                normalize_plane(row3 + row1),
+                normalize_plane(row3 - row1),
+                normalize_plane(near),
+                normalize_plane(far),
+            ],
+        }
+    }
+
+    /// Extracts the frustum from a projection or view-projection matrix which maps depth to
+    /// `[0, 1]`, such as [`DMat4::perspective_rh()`] or [`DMat4::orthographic_lh()`].
+    ///
+    /// The planes are extracted with the Gribb-Hartmann method, if `m` is a view-projection
+    /// matrix the frustum will be in world space, if it is a projection matrix the frustum will
+    /// be in view space.
+    #[inline]
+    pub fn from_mat4(m: &DMat4) -> Self {
+        let row2 = m.row(2);
+        Self::from_rows(m, row2, m.row(3) - row2)
+    }
+
+    /// Extracts the frustum from a projection or view-projection matrix which maps depth to
+    /// `[-1, 1]`, such as [`DMat4::perspective_rh_gl()`] or
+    /// [`DMat4::orthographic_rh_gl()`].
+    ///
+    /// See [`Self::from_mat4()`].
+    #[inline]
+    pub fn from_mat4_gl(m: &DMat4) -> Self {
+        let row2 = m.row(2);
+        let row3 = m.row(3);
+        Self::from_rows(m, row3 + row2, row3 - row2)
+    }
+
+    /// Extracts the frustum from a projection or view-projection matrix which maps depth to
+    /// `[1, 0]`, such as [`DMat4::perspective_infinite_reverse_rh()`].
+    ///
+    /// See [`Self::from_mat4()`].
+    #[inline]
+    pub fn from_mat4_reverse_z(m: &DMat4) -> Self {
+        let row2 = m.row(2);
+        Self::from_rows(m, m.row(3) - row2, row2)
+    }
+
+    /// Returns the six planes of the frustum in the order left, right, bottom, top, near and far.
+    #[inline]
+    pub fn planes(&self) -> [DPlane3; 6] {
+        [
+            DPlane3::from_vec4(self.planes[0]),
+            DPlane3::from_vec4(self.planes[1]),
+            DPlane3::from_vec4(self.planes[2]),
+            DPlane3::from_vec4(self.planes[3]),
+            DPlane3::from_vec4(self.planes[4]),
+            DPlane3::from_vec4(self.planes[5]),
+        ]
+    }
+
+    /// Returns the left plane.
+    #[inline]
+    pub fn left(&self) -> DPlane3 {
+        DPlane3::from_vec4(self.planes[0])
+    }
+
+    /// Returns the right plane.
+    #[inline]
+    pub fn right(&self) -> DPlane3 {
+        DPlane3::from_vec4(self.planes[1])
+    }
+
+    /// Returns the bottom plane.
+    #[inline]
+    pub fn bottom(&self) -> DPlane3 {
+        DPlane3::from_vec4(self.planes[2])
+    }
+
+    /// Returns the top plane.
+    #[inline]
+    pub fn top(&self) -> DPlane3 {
+        DPlane3::from_vec4(self.planes[3])
+    }
+
+    /// Returns the near plane.
+    #[inline]
+    pub fn near(&self) -> DPlane3 {
+        DPlane3::from_vec4(self.planes[4])
+    }
+
+    /// Returns the far plane.
+    ///
+    /// The normal will be zero if the frustum was extracted from an infinite projection.
+    #[inline]
+    pub fn far(&self) -> DPlane3 {
+        DPlane3::from_vec4(self.planes[5])
+    }
+
+    /// Returns the eight corners of the frustum.
+    ///
+    /// The near corners come first, followed by the far corners, each in the order bottom left,
+    /// bottom right, top right and top left.
+    ///
+    /// The far corners will not be finite if the frustum was extracted from an infinite
+    /// projection.
+    #[inline]
+    pub fn corners(&self) -> [DVec3; 8] {
+        let [left, right, bottom, top, near, far] = self.planes;
+        [
+            intersect_planes(near, bottom, left),
+            intersect_planes(near, bottom, right),
+            intersect_planes(near, top, right),
+            intersect_planes(near, top, left),
+            intersect_planes(far, bottom, left),
+            intersect_planes(far, bottom, right),
+            intersect_planes(far, top, right),
+            intersect_planes(far, top, left),
+        ]
+    }
+
+    /// Returns `true` if `point` is inside or on the boundary of the frustum.
+    #[inline]
+    pub fn contains_point(&self, point: DVec3) -> bool {
+        let point = point.extend(1.0);
+        self.planes.iter().all(|plane| plane.dot(point) >= 0.0)
+    }
+
+    /// Returns `true` if `sphere` is entirely inside the frustum.
+    #[inline]
+    pub fn contains_sphere(&self, sphere: &DSphere) -> bool {
+        let center = sphere.center.extend(1.0);
+        self.planes
+            .iter()
+            .all(|plane| plane.dot(center) >= sphere.radius)
+    }
+
+    /// Returns `true` if `sphere` is at least partially inside the frustum.
+    ///
+    /// This test is conservative, spheres outside of the frustum near its edges may also return
+    /// `true`.
+    #[inline]
+    pub fn intersects_sphere(&self, sphere: &DSphere) -> bool {
+        let center = sphere.center.extend(1.0);
+        self.planes
+            .iter()
+            .all(|plane| plane.dot(center) >= -sphere.radius)
+    }
+
+    /// Returns `true` if `aabb` is entirely inside the frustum.
+    #[inline]
+    pub fn contains_aabb(&self, aabb: &DAabb3) -> bool {
+        let center = aabb.center().extend(1.0);
+        let half_extents = aabb.half_extents().extend(0.0);
+        self.planes
+            .iter()
+            .all(|plane| plane.dot(center) - plane.abs().dot(half_extents) >= 0.0)
+    }
+
+    /// Returns `true` if `aabb` is at least partially inside the frustum.
+    ///
+    /// This test is conservative, boxes outside of the frustum near its edges may also return
+    /// `true`. Empty boxes always return `false`.
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &DAabb3) -> bool {
+        let center = aabb.center().extend(1.0);
+        let half_extents = aabb.half_extents().extend(0.0);
+        !aabb.is_empty()
+            && self
+                .planes
+                .iter()
+                .all(|plane| plane.dot(center) + plane.abs().dot(half_extents) >= 0.0)
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.planes.iter().all(|plane| plane.is_finite())
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two frustums contain similar elements. It works best when
+    /// comparing with a known value. The `max_abs_diff` that should be used used depends on the
+    /// values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.planes
+            .iter()
+            .zip(rhs.planes.iter())
+            .all(|(a, b)| a.abs_diff_eq(*b, max_abs_diff))
+    }
+}
+
+/// Normalizes the plane `plane`, leaving planes with a zero normal unchanged.
+#[inline]
+fn normalize_plane(plane: DVec4) -> DVec4 {
+    let length_squared = plane.truncate().length_squared();
+    if length_squared > 0.0 {
+        plane * math::sqrt(length_squared).recip()
+    } else {
+        plane
+    }
+}
+
+/// Returns the point where the planes `a`, `b` and `c` meet.
+#[inline]
+fn intersect_planes(a: DVec4, b: DVec4, c: DVec4) -> DVec3 {
+    let (na, nb, nc) = (a.truncate(), b.truncate(), c.truncate());
+    let bc = nb.cross(nc);
+    let ca = nc.cross(na);
+    let ab = na.cross(nb);
+    (bc * a.w + ca * b.w + ab * c.w) / -na.dot(bc)
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DFrustum {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [left, right, bottom, top, near, far] = self.planes();
+        fmt.debug_struct(stringify!(DFrustum))
+            .field("left", &left)
+            .field("right", &right)
+            .field("bottom", &bottom)
+            .field("top", &top)
+            .field("near", &near)
+            .field("far", &far)
+            .finish()
+    }
+}
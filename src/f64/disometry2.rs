@@ -0,0 +1,201 @@
+// Generated from isometry.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f64::math, DAffine2, DMat3, DVec2, Isometry2};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Mul, MulAssign};
+
+/// A 2D isometry, which is a rigid transform consisting of a rotation by an angle followed by a
+/// translation.
+///
+/// Unlike an affine transform, an isometry can not accumulate scale or shear when composed and
+/// it can be inverted cheaply and exactly.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct DIsometry2 {
+    /// The rotation angle in radians.
+    pub angle: f64,
+    pub translation: DVec2,
+}
+
+impl DIsometry2 {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self {
+        angle: 0.0,
+        translation: DVec2::ZERO,
+    };
+
+    /// All NANs.
+    pub const NAN: Self = Self {
+        angle: f64::NAN,
+        translation: DVec2::NAN,
+    };
+
+    /// Creates an isometry from the given rotation `angle` (in radians) followed by a
+    /// `translation`.
+    #[inline(always)]
+    pub const fn from_angle_translation(angle: f64, translation: DVec2) -> Self {
+        Self { angle, translation }
+    }
+
+    /// Creates an isometry from the given rotation `angle` (in radians).
+    #[inline(always)]
+    pub const fn from_angle(angle: f64) -> Self {
+        Self {
+            angle,
+            translation: DVec2::ZERO,
+        }
+    }
+
+    /// Creates an isometry from the given `translation`.
+    #[inline(always)]
+    pub const fn from_translation(translation: DVec2) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// This is exact and much cheaper than inverting the equivalent affine transform.
+    #[must_use]
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let angle = -self.angle;
+        Self {
+            angle,
+            translation: -DVec2::from_angle(angle).rotate(self.translation),
+        }
+    }
+
+    /// Multiplies two isometries, returning the combined transform.
+    ///
+    /// Note that the order of operations is important: `self * rhs` applies `rhs` first and
+    /// then `self`.
+    #[inline]
+    pub fn mul_isometry(&self, rhs: &Self) -> Self {
+        Self {
+            angle: self.angle + rhs.angle,
+            translation: self.transform_point2(rhs.translation),
+        }
+    }
+
+    /// Transforms the given 2D point, applying rotation and translation.
+    #[inline]
+    pub fn transform_point2(&self, rhs: DVec2) -> DVec2 {
+        self.transform_vector2(rhs) + self.translation
+    }
+
+    /// Transforms the given 2D vector, applying rotation (but NOT translation).
+    #[inline]
+    pub fn transform_vector2(&self, rhs: DVec2) -> DVec2 {
+        DVec2::from_angle(self.angle).rotate(rhs)
+    }
+
+    /// Interpolates between `self` and `end` based on the value `s`.
+    ///
+    /// The angle and translation are interpolated linearly. Note that angles are not wrapped,
+    /// so interpolating from `-PI` to `PI` rotates through zero.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    #[inline]
+    pub fn lerp(&self, end: &Self, s: f64) -> Self {
+        Self {
+            angle: self.angle + (end.angle - self.angle) * s,
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.angle.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.angle.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two isometries contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends
+    /// on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        math::abs(self.angle - rhs.angle) <= max_abs_diff
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(&self) -> Isometry2 {
+        Isometry2 {
+            angle: self.angle as f32,
+            translation: self.translation.as_vec2(),
+        }
+    }
+}
+
+impl Default for DIsometry2 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DIsometry2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DIsometry2))
+            .field("angle", &self.angle)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DIsometry2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.angle, self.translation)
+    }
+}
+
+impl Mul<DIsometry2> for DIsometry2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_isometry(&rhs)
+    }
+}
+
+impl MulAssign<DIsometry2> for DIsometry2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_isometry(&rhs);
+    }
+}
+
+impl From<DIsometry2> for DAffine2 {
+    #[inline]
+    fn from(iso: DIsometry2) -> DAffine2 {
+        DAffine2::from_angle_translation(iso.angle, iso.translation)
+    }
+}
+
+impl From<DIsometry2> for DMat3 {
+    #[inline]
+    fn from(iso: DIsometry2) -> DMat3 {
+        DMat3::from_scale_angle_translation(DVec2::ONE, iso.angle, iso.translation)
+    }
+}
@@ -0,0 +1,244 @@
+// Generated from isometry.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DAffine3, DMat4, DQuat, DVec3, Isometry3};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Mul, MulAssign};
+
+/// A 3D isometry, which is a rigid transform consisting of a rotation followed by a
+/// translation.
+///
+/// Unlike an affine transform, an isometry can not accumulate scale or shear when composed and
+/// it can be inverted cheaply and exactly.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct DIsometry3 {
+    pub rotation: DQuat,
+    pub translation: DVec3,
+}
+
+impl DIsometry3 {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self {
+        rotation: DQuat::IDENTITY,
+        translation: DVec3::ZERO,
+    };
+
+    /// All NANs.
+    pub const NAN: Self = Self {
+        rotation: DQuat::NAN,
+        translation: DVec3::NAN,
+    };
+
+    /// Creates an isometry from a `rotation` followed by a `translation`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_rotation_translation(rotation: DQuat, translation: DVec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    /// Creates an isometry from the given `rotation` quaternion.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_quat(rotation: DQuat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            rotation,
+            translation: DVec3::ZERO,
+        }
+    }
+
+    /// Creates an isometry from the given `translation`.
+    #[inline(always)]
+    pub const fn from_translation(translation: DVec3) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates an isometry from the rotation and translation of an affine transform.
+    ///
+    /// Any scale in `a` is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of `a.matrix3` is zero or if the resulting rotation is
+    /// not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_affine3(a: &DAffine3) -> Self {
+        let (_, rotation, translation) = a.to_scale_rotation_translation();
+        Self::from_rotation_translation(rotation, translation)
+    }
+
+    /// Creates an isometry from the rotation and translation of a 4x4 matrix.
+    ///
+    /// Any scale in `mat` is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the determinant of `mat` is zero or if the resulting rotation is not
+    /// normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn from_mat4(mat: &DMat4) -> Self {
+        let (_, rotation, translation) = mat.to_scale_rotation_translation();
+        Self::from_rotation_translation(rotation, translation)
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// This is exact and much cheaper than inverting the equivalent affine transform.
+    #[must_use]
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        Self {
+            rotation,
+            translation: -rotation.mul_vec3(self.translation),
+        }
+    }
+
+    /// Multiplies two isometries, returning the combined transform.
+    ///
+    /// Note that the order of operations is important: `self * rhs` applies `rhs` first and
+    /// then `self`.
+    #[inline]
+    pub fn mul_isometry(&self, rhs: &Self) -> Self {
+        Self {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_point3(rhs.translation),
+        }
+    }
+
+    /// Transforms the given 3D point, applying rotation and translation.
+    #[inline]
+    pub fn transform_point3(&self, rhs: DVec3) -> DVec3 {
+        self.transform_vector3(rhs) + self.translation
+    }
+
+    /// Transforms the given 3D vector, applying rotation (but NOT translation).
+    #[inline]
+    pub fn transform_vector3(&self, rhs: DVec3) -> DVec3 {
+        self.rotation.mul_vec3(rhs)
+    }
+
+    /// Interpolates between `self` and `end` based on the value `s`.
+    ///
+    /// The rotation is spherically interpolated and the translation linearly interpolated.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the rotation of `self` or `end` is not normalized when `glam_assert` is
+    /// enabled.
+    #[inline]
+    pub fn lerp(&self, end: &Self, s: f64) -> Self {
+        Self {
+            rotation: self.rotation.slerp(end.rotation, s),
+            translation: self.translation.lerp(end.translation, s),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.rotation.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.rotation.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two isometries contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends
+    /// on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(&self) -> Isometry3 {
+        Isometry3 {
+            rotation: self.rotation.as_f32(),
+            translation: self.translation.as_vec3(),
+        }
+    }
+}
+
+impl Default for DIsometry3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DIsometry3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DIsometry3))
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DIsometry3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.rotation, self.translation)
+    }
+}
+
+impl Mul<DIsometry3> for DIsometry3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_isometry(&rhs)
+    }
+}
+
+impl MulAssign<DIsometry3> for DIsometry3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_isometry(&rhs);
+    }
+}
+
+impl From<DIsometry3> for DAffine3 {
+    #[inline]
+    fn from(iso: DIsometry3) -> DAffine3 {
+        DAffine3::from_rotation_translation(iso.rotation, iso.translation)
+    }
+}
+
+impl From<DIsometry3> for DMat4 {
+    #[inline]
+    fn from(iso: DIsometry3) -> DMat4 {
+        DMat4::from_rotation_translation(iso.rotation, iso.translation)
+    }
+}
@@ -0,0 +1,389 @@
+// Generated from mat.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DMat2, DMat3, DMat3x2, DVec2, DVec3, Mat2x3};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Creates a 2x3 matrix from two column vectors.
+#[inline(always)]
+pub const fn dmat2x3(x_axis: DVec3, y_axis: DVec3) -> DMat2x3 {
+    DMat2x3::from_cols(x_axis, y_axis)
+}
+
+/// A 2x3 column major matrix with 2 columns and 3 rows.
+///
+/// The `MatCxR` naming follows WGSL and GLSL, so each of the 2 columns is a
+/// [`DVec3`]. The transpose of this type is [`DMat3x2`].
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DMat2x3 {
+    pub x_axis: DVec3,
+    pub y_axis: DVec3,
+}
+
+impl DMat2x3 {
+    /// A 2x3 matrix with all elements set to `0.0`.
+    pub const ZERO: Self = Self::from_cols(DVec3::ZERO, DVec3::ZERO);
+
+    /// A 2x3 matrix where the diagonal elements are `1`, and all other elements are `0`.
+    pub const IDENTITY: Self = Self::from_cols(DVec3::X, DVec3::Y);
+
+    /// All NAN:s.
+    pub const NAN: Self = Self::from_cols(DVec3::NAN, DVec3::NAN);
+
+    /// Creates a 2x3 matrix from two column vectors.
+    #[inline(always)]
+    pub const fn from_cols(x_axis: DVec3, y_axis: DVec3) -> Self {
+        Self { x_axis, y_axis }
+    }
+
+    /// Creates a 2x3 matrix from a `[f64; 6]` array stored in column major order.
+    #[inline]
+    pub const fn from_cols_array(m: &[f64; 6]) -> Self {
+        Self::from_cols_slice(m)
+    }
+
+    /// Creates a `[f64; 6]` array storing data in column major order.
+    #[inline]
+    pub const fn to_cols_array(&self) -> [f64; 6] {
+        let [x_axis_x, x_axis_y, x_axis_z] = self.x_axis.to_array();
+        let [y_axis_x, y_axis_y, y_axis_z] = self.y_axis.to_array();
+        [x_axis_x, x_axis_y, x_axis_z, y_axis_x, y_axis_y, y_axis_z]
+    }
+
+    /// Creates a 2x3 matrix from a `[[f64; 3]; 2]` 2D array
+    /// stored in column major order.
+    #[inline]
+    pub const fn from_cols_array_2d(m: &[[f64; 3]; 2]) -> Self {
+        Self::from_cols(DVec3::from_array(m[0]), DVec3::from_array(m[1]))
+    }
+
+    /// Creates a `[[f64; 3]; 2]` 2D array storing data in column
+    /// major order.
+    #[inline]
+    pub const fn to_cols_array_2d(&self) -> [[f64; 3]; 2] {
+        [self.x_axis.to_array(), self.y_axis.to_array()]
+    }
+
+    /// Creates a 2x3 matrix from the first 6 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 6 elements long.
+    #[inline]
+    pub const fn from_cols_slice(slice: &[f64]) -> Self {
+        Self::from_cols(
+            DVec3::new(slice[0], slice[1], slice[2]),
+            DVec3::new(slice[3], slice[4], slice[5]),
+        )
+    }
+
+    /// Writes the columns of `self` to the first 6 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 6 elements long.
+    #[inline]
+    pub fn write_cols_to_slice(self, slice: &mut [f64]) {
+        slice[0] = self.x_axis.x;
+        slice[1] = self.x_axis.y;
+        slice[2] = self.x_axis.z;
+        slice[3] = self.y_axis.x;
+        slice[4] = self.y_axis.y;
+        slice[5] = self.y_axis.z;
+    }
+
+    /// Returns the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    pub fn col(&self, index: usize) -> DVec3 {
+        match index {
+            0 => self.x_axis,
+            1 => self.y_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    pub fn col_mut(&mut self, index: usize) -> &mut DVec3 {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the matrix row for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn row(&self, index: usize) -> DVec2 {
+        match index {
+            0 => DVec2::new(self.x_axis.x, self.y_axis.x),
+            1 => DVec2::new(self.x_axis.y, self.y_axis.y),
+            2 => DVec2::new(self.x_axis.z, self.y_axis.z),
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x_axis.is_nan() || self.y_axis.is_nan()
+    }
+
+    /// Returns the transpose of `self`, which is a 3x2 matrix.
+    #[must_use]
+    #[inline]
+    pub fn transpose(&self) -> DMat3x2 {
+        DMat3x2::from_cols(
+            DVec2::new(self.x_axis.x, self.y_axis.x),
+            DVec2::new(self.x_axis.y, self.y_axis.y),
+            DVec2::new(self.x_axis.z, self.y_axis.z),
+        )
+    }
+
+    /// Transforms a 2D vector, returning a 3D vector.
+    #[inline]
+    pub fn mul_vec2(&self, rhs: DVec2) -> DVec3 {
+        self.x_axis.mul(rhs.x).add(self.y_axis.mul(rhs.y))
+    }
+
+    /// Multiplies `self` by a 2x2 matrix, returning a 2x3 matrix.
+    #[inline]
+    pub fn mul_mat2(&self, rhs: &DMat2) -> Self {
+        Self::from_cols(self.mul_vec2(rhs.x_axis), self.mul_vec2(rhs.y_axis))
+    }
+
+    /// Multiplies `self` by a 3x2 matrix, returning a 3x3 matrix.
+    #[inline]
+    pub fn mul_mat3x2(&self, rhs: &DMat3x2) -> DMat3 {
+        DMat3::from_cols(
+            self.mul_vec2(rhs.x_axis),
+            self.mul_vec2(rhs.y_axis),
+            self.mul_vec2(rhs.z_axis),
+        )
+    }
+
+    /// Adds two 2x3 matrices.
+    #[inline]
+    pub fn add_mat2x3(&self, rhs: &Self) -> Self {
+        Self::from_cols(self.x_axis.add(rhs.x_axis), self.y_axis.add(rhs.y_axis))
+    }
+
+    /// Subtracts two 2x3 matrices.
+    #[inline]
+    pub fn sub_mat2x3(&self, rhs: &Self) -> Self {
+        Self::from_cols(self.x_axis.sub(rhs.x_axis), self.y_axis.sub(rhs.y_axis))
+    }
+
+    /// Multiplies a 2x3 matrix by a scalar.
+    #[inline]
+    pub fn mul_scalar(&self, rhs: f64) -> Self {
+        Self::from_cols(self.x_axis.mul(rhs), self.y_axis.mul(rhs))
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two matrices contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
+    }
+
+    #[inline]
+    pub fn as_mat2x3(&self) -> Mat2x3 {
+        Mat2x3::from_cols(self.x_axis.as_vec3(), self.y_axis.as_vec3())
+    }
+}
+
+impl Default for DMat2x3 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Add<DMat2x3> for DMat2x3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_mat2x3(&rhs)
+    }
+}
+
+impl AddAssign<DMat2x3> for DMat2x3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add_mat2x3(&rhs);
+    }
+}
+
+impl Sub<DMat2x3> for DMat2x3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub_mat2x3(&rhs)
+    }
+}
+
+impl SubAssign<DMat2x3> for DMat2x3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub_mat2x3(&rhs);
+    }
+}
+
+impl Neg for DMat2x3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_cols(self.x_axis.neg(), self.y_axis.neg())
+    }
+}
+
+impl Mul<DMat2> for DMat2x3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: DMat2) -> Self::Output {
+        self.mul_mat2(&rhs)
+    }
+}
+
+impl MulAssign<DMat2> for DMat2x3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DMat2) {
+        *self = self.mul_mat2(&rhs);
+    }
+}
+
+impl Mul<DMat3x2> for DMat2x3 {
+    type Output = DMat3;
+    #[inline]
+    fn mul(self, rhs: DMat3x2) -> Self::Output {
+        self.mul_mat3x2(&rhs)
+    }
+}
+
+impl Mul<DMat2x3> for DMat3 {
+    type Output = DMat2x3;
+    #[inline]
+    fn mul(self, rhs: DMat2x3) -> Self::Output {
+        DMat2x3::from_cols(self.mul_vec3(rhs.x_axis), self.mul_vec3(rhs.y_axis))
+    }
+}
+
+impl Mul<DVec2> for DMat2x3 {
+    type Output = DVec3;
+    #[inline]
+    fn mul(self, rhs: DVec2) -> Self::Output {
+        self.mul_vec2(rhs)
+    }
+}
+
+impl Mul<DMat2x3> for f64 {
+    type Output = DMat2x3;
+    #[inline]
+    fn mul(self, rhs: DMat2x3) -> Self::Output {
+        rhs.mul_scalar(self)
+    }
+}
+
+impl Mul<f64> for DMat2x3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.mul_scalar(rhs)
+    }
+}
+
+impl MulAssign<f64> for DMat2x3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = self.mul_scalar(rhs);
+    }
+}
+
+impl Sum<Self> for DMat2x3 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for DMat2x3 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl PartialEq for DMat2x3 {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.x_axis.eq(&rhs.x_axis) && self.y_axis.eq(&rhs.y_axis)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsRef<[f64; 6]> for DMat2x3 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 6] {
+        unsafe { &*(self as *const Self as *const [f64; 6]) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsMut<[f64; 6]> for DMat2x3 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 6] {
+        unsafe { &mut *(self as *mut Self as *mut [f64; 6]) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DMat2x3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DMat2x3))
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DMat2x3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x_axis, self.y_axis)
+    }
+}
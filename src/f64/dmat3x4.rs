@@ -0,0 +1,447 @@
+// Generated from mat.rs.tera template. Edit the template, not the generated file.
+
+use crate::{DMat3, DMat4, DMat4x3, DVec3, DVec4, Mat3x4};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Creates a 3x4 matrix from three column vectors.
+#[inline(always)]
+pub const fn dmat3x4(x_axis: DVec4, y_axis: DVec4, z_axis: DVec4) -> DMat3x4 {
+    DMat3x4::from_cols(x_axis, y_axis, z_axis)
+}
+
+/// A 3x4 column major matrix with 3 columns and 4 rows.
+///
+/// The `MatCxR` naming follows WGSL and GLSL, so each of the 3 columns is a
+/// [`DVec4`]. The transpose of this type is [`DMat4x3`].
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DMat3x4 {
+    pub x_axis: DVec4,
+    pub y_axis: DVec4,
+    pub z_axis: DVec4,
+}
+
+impl DMat3x4 {
+    /// A 3x4 matrix with all elements set to `0.0`.
+    pub const ZERO: Self = Self::from_cols(DVec4::ZERO, DVec4::ZERO, DVec4::ZERO);
+
+    /// A 3x4 matrix where the diagonal elements are `1`, and all other elements are `0`.
+    pub const IDENTITY: Self = Self::from_cols(DVec4::X, DVec4::Y, DVec4::Z);
+
+    /// All NAN:s.
+    pub const NAN: Self = Self::from_cols(DVec4::NAN, DVec4::NAN, DVec4::NAN);
+
+    /// Creates a 3x4 matrix from three column vectors.
+    #[inline(always)]
+    pub const fn from_cols(x_axis: DVec4, y_axis: DVec4, z_axis: DVec4) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 3x4 matrix from a `[f64; 12]` array stored in column major order.
+    #[inline]
+    pub const fn from_cols_array(m: &[f64; 12]) -> Self {
+        Self::from_cols_slice(m)
+    }
+
+    /// Creates a `[f64; 12]` array storing data in column major order.
+    #[inline]
+    pub const fn to_cols_array(&self) -> [f64; 12] {
+        let [x_axis_x, x_axis_y, x_axis_z, x_axis_w] = self.x_axis.to_array();
+        let [y_axis_x, y_axis_y, y_axis_z, y_axis_w] = self.y_axis.to_array();
+        let [z_axis_x, z_axis_y, z_axis_z, z_axis_w] = self.z_axis.to_array();
+        [
+            x_axis_x, x_axis_y, x_axis_z, x_axis_w, y_axis_x, y_axis_y, y_axis_z, y_axis_w,
+            z_axis_x, z_axis_y, z_axis_z, z_axis_w,
+        ]
+    }
+
+    /// Creates a 3x4 matrix from a `[[f64; 4]; 3]` 2D array
+    /// stored in column major order.
+    #[inline]
+    pub const fn from_cols_array_2d(m: &[[f64; 4]; 3]) -> Self {
+        Self::from_cols(
+            DVec4::from_array(m[0]),
+            DVec4::from_array(m[1]),
+            DVec4::from_array(m[2]),
+        )
+    }
+
+    /// Creates a `[[f64; 4]; 3]` 2D array storing data in column
+    /// major order.
+    #[inline]
+    pub const fn to_cols_array_2d(&self) -> [[f64; 4]; 3] {
+        [
+            self.x_axis.to_array(),
+            self.y_axis.to_array(),
+            self.z_axis.to_array(),
+        ]
+    }
+
+    /// Creates a 3x4 matrix from the first 12 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 12 elements long.
+    #[inline]
+    pub const fn from_cols_slice(slice: &[f64]) -> Self {
+        Self::from_cols(
+            DVec4::new(slice[0], slice[1], slice[2], slice[3]),
+            DVec4::new(slice[4], slice[5], slice[6], slice[7]),
+            DVec4::new(slice[8], slice[9], slice[10], slice[11]),
+        )
+    }
+
+    /// Writes the columns of `self` to the first 12 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 12 elements long.
+    #[inline]
+    pub fn write_cols_to_slice(self, slice: &mut [f64]) {
+        slice[0] = self.x_axis.x;
+        slice[1] = self.x_axis.y;
+        slice[2] = self.x_axis.z;
+        slice[3] = self.x_axis.w;
+        slice[4] = self.y_axis.x;
+        slice[5] = self.y_axis.y;
+        slice[6] = self.y_axis.z;
+        slice[7] = self.y_axis.w;
+        slice[8] = self.z_axis.x;
+        slice[9] = self.z_axis.y;
+        slice[10] = self.z_axis.z;
+        slice[11] = self.z_axis.w;
+    }
+
+    /// Returns the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn col(&self, index: usize) -> DVec4 {
+        match index {
+            0 => self.x_axis,
+            1 => self.y_axis,
+            2 => self.z_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn col_mut(&mut self, index: usize) -> &mut DVec4 {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns the matrix row for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    pub fn row(&self, index: usize) -> DVec3 {
+        match index {
+            0 => DVec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            1 => DVec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            2 => DVec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+            3 => DVec3::new(self.x_axis.w, self.y_axis.w, self.z_axis.w),
+            _ => panic!("index out of bounds"),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite() && self.z_axis.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x_axis.is_nan() || self.y_axis.is_nan() || self.z_axis.is_nan()
+    }
+
+    /// Returns the transpose of `self`, which is a 4x3 matrix.
+    #[must_use]
+    #[inline]
+    pub fn transpose(&self) -> DMat4x3 {
+        DMat4x3::from_cols(
+            DVec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            DVec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            DVec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+            DVec3::new(self.x_axis.w, self.y_axis.w, self.z_axis.w),
+        )
+    }
+
+    /// Transforms a 3D vector, returning a 4D vector.
+    #[inline]
+    pub fn mul_vec3(&self, rhs: DVec3) -> DVec4 {
+        self.x_axis
+            .mul(rhs.x)
+            .add(self.y_axis.mul(rhs.y))
+            .add(self.z_axis.mul(rhs.z))
+    }
+
+    /// Multiplies `self` by a 3x3 matrix, returning a 3x4 matrix.
+    #[inline]
+    pub fn mul_mat3(&self, rhs: &DMat3) -> Self {
+        Self::from_cols(
+            self.mul_vec3(rhs.x_axis),
+            self.mul_vec3(rhs.y_axis),
+            self.mul_vec3(rhs.z_axis),
+        )
+    }
+
+    /// Multiplies `self` by a 4x3 matrix, returning a 4x4 matrix.
+    #[inline]
+    pub fn mul_mat4x3(&self, rhs: &DMat4x3) -> DMat4 {
+        DMat4::from_cols(
+            self.mul_vec3(rhs.x_axis),
+            self.mul_vec3(rhs.y_axis),
+            self.mul_vec3(rhs.z_axis),
+            self.mul_vec3(rhs.w_axis),
+        )
+    }
+
+    /// Adds two 3x4 matrices.
+    #[inline]
+    pub fn add_mat3x4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.add(rhs.x_axis),
+            self.y_axis.add(rhs.y_axis),
+            self.z_axis.add(rhs.z_axis),
+        )
+    }
+
+    /// Subtracts two 3x4 matrices.
+    #[inline]
+    pub fn sub_mat3x4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.sub(rhs.x_axis),
+            self.y_axis.sub(rhs.y_axis),
+            self.z_axis.sub(rhs.z_axis),
+        )
+    }
+
+    /// Multiplies a 3x4 matrix by a scalar.
+    #[inline]
+    pub fn mul_scalar(&self, rhs: f64) -> Self {
+        Self::from_cols(
+            self.x_axis.mul(rhs),
+            self.y_axis.mul(rhs),
+            self.z_axis.mul(rhs),
+        )
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two matrices contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used
+    /// depends on the values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(rhs.z_axis, max_abs_diff)
+    }
+
+    #[inline]
+    pub fn as_mat3x4(&self) -> Mat3x4 {
+        Mat3x4::from_cols(
+            self.x_axis.as_vec4(),
+            self.y_axis.as_vec4(),
+            self.z_axis.as_vec4(),
+        )
+    }
+}
+
+impl Default for DMat3x4 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Add<DMat3x4> for DMat3x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_mat3x4(&rhs)
+    }
+}
+
+impl AddAssign<DMat3x4> for DMat3x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add_mat3x4(&rhs);
+    }
+}
+
+impl Sub<DMat3x4> for DMat3x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub_mat3x4(&rhs)
+    }
+}
+
+impl SubAssign<DMat3x4> for DMat3x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.sub_mat3x4(&rhs);
+    }
+}
+
+impl Neg for DMat3x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_cols(self.x_axis.neg(), self.y_axis.neg(), self.z_axis.neg())
+    }
+}
+
+impl Mul<DMat3> for DMat3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: DMat3) -> Self::Output {
+        self.mul_mat3(&rhs)
+    }
+}
+
+impl MulAssign<DMat3> for DMat3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DMat3) {
+        *self = self.mul_mat3(&rhs);
+    }
+}
+
+impl Mul<DMat4x3> for DMat3x4 {
+    type Output = DMat4;
+    #[inline]
+    fn mul(self, rhs: DMat4x3) -> Self::Output {
+        self.mul_mat4x3(&rhs)
+    }
+}
+
+impl Mul<DMat3x4> for DMat4 {
+    type Output = DMat3x4;
+    #[inline]
+    fn mul(self, rhs: DMat3x4) -> Self::Output {
+        DMat3x4::from_cols(
+            self.mul_vec4(rhs.x_axis),
+            self.mul_vec4(rhs.y_axis),
+            self.mul_vec4(rhs.z_axis),
+        )
+    }
+}
+
+impl Mul<DVec3> for DMat3x4 {
+    type Output = DVec4;
+    #[inline]
+    fn mul(self, rhs: DVec3) -> Self::Output {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Mul<DMat3x4> for f64 {
+    type Output = DMat3x4;
+    #[inline]
+    fn mul(self, rhs: DMat3x4) -> Self::Output {
+        rhs.mul_scalar(self)
+    }
+}
+
+impl Mul<f64> for DMat3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.mul_scalar(rhs)
+    }
+}
+
+impl MulAssign<f64> for DMat3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = self.mul_scalar(rhs);
+    }
+}
+
+impl Sum<Self> for DMat3x4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for DMat3x4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl PartialEq for DMat3x4 {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.x_axis.eq(&rhs.x_axis) && self.y_axis.eq(&rhs.y_axis) && self.z_axis.eq(&rhs.z_axis)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsRef<[f64; 12]> for DMat3x4 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 12] {
+        unsafe { &*(self as *const Self as *const [f64; 12]) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsMut<[f64; 12]> for DMat3x4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 12] {
+        unsafe { &mut *(self as *mut Self as *mut [f64; 12]) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DMat3x4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DMat3x4))
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("z_axis", &self.z_axis)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DMat3x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+    }
+}
@@ -1,6 +1,6 @@
 // Generated from mat.rs.tera template. Edit the template, not the generated file.
 
-use crate::{f64::math, swizzles::*, DMat3, DQuat, DVec3, DVec4, EulerRot, Mat4};
+use crate::{f64::math, swizzles::*, DMat3, DQuat, DRadians, DVec3, DVec4, EulerRot, Mat4};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -115,23 +115,14 @@ impl DMat4 {
     /// If you require data in row major order `transpose` the matrix first.
     #[inline]
     pub const fn to_cols_array(&self) -> [f64; 16] {
+        let [x_axis_x, x_axis_y, x_axis_z, x_axis_w] = self.x_axis.to_array();
+        let [y_axis_x, y_axis_y, y_axis_z, y_axis_w] = self.y_axis.to_array();
+        let [z_axis_x, z_axis_y, z_axis_z, z_axis_w] = self.z_axis.to_array();
+        let [w_axis_x, w_axis_y, w_axis_z, w_axis_w] = self.w_axis.to_array();
+
         [
-            self.x_axis.x,
-            self.x_axis.y,
-            self.x_axis.z,
-            self.x_axis.w,
-            self.y_axis.x,
-            self.y_axis.y,
-            self.y_axis.z,
-            self.y_axis.w,
-            self.z_axis.x,
-            self.z_axis.y,
-            self.z_axis.z,
-            self.z_axis.w,
-            self.w_axis.x,
-            self.w_axis.y,
-            self.w_axis.z,
-            self.w_axis.w,
+            x_axis_x, x_axis_y, x_axis_z, x_axis_w, y_axis_x, y_axis_y, y_axis_z, y_axis_w,
+            z_axis_x, z_axis_y, z_axis_z, z_axis_w, w_axis_x, w_axis_y, w_axis_z, w_axis_w,
         ]
     }
 
@@ -164,9 +155,10 @@ impl DMat4 {
     #[doc(alias = "scale")]
     #[inline]
     pub const fn from_diagonal(diagonal: DVec4) -> Self {
+        // diagonal.x, diagonal.y etc can't be done in a const-context
+        let [x, y, z, w] = diagonal.to_array();
         Self::new(
-            diagonal.x, 0.0, 0.0, 0.0, 0.0, diagonal.y, 0.0, 0.0, 0.0, 0.0, diagonal.z, 0.0, 0.0,
-            0.0, 0.0, diagonal.w,
+            x, 0.0, 0.0, 0.0, 0.0, y, 0.0, 0.0, 0.0, 0.0, z, 0.0, 0.0, 0.0, 0.0, w,
         )
     }
 
@@ -318,10 +310,10 @@ impl DMat4 {
     ///
     /// Will panic if `axis` is not normalized when `glam_assert` is enabled.
     #[inline]
-    pub fn from_axis_angle(axis: DVec3, angle: f64) -> Self {
+    pub fn from_axis_angle(axis: DVec3, angle: impl Into<DRadians>) -> Self {
         glam_assert!(axis.is_normalized());
 
-        let (sin, cos) = math::sin_cos(angle);
+        let (sin, cos) = math::sin_cos(angle.into().0);
         let axis_sin = axis.mul(sin);
         let axis_sq = axis.mul(axis);
         let omc = 1.0 - cos;
@@ -357,19 +349,43 @@ impl DMat4 {
     ///
     /// The resulting matrix can be used to transform 3D points and vectors. See
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
-    pub fn from_euler(order: EulerRot, a: f64, b: f64, c: f64) -> Self {
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<DRadians>,
+        b: impl Into<DRadians>,
+        c: impl Into<DRadians>,
+    ) -> Self {
         let quat = DQuat::from_euler(order, a, b, c);
         Self::from_quat(quat)
     }
 
+    /// Extracts the angles (in radians) of the given euler rotation sequence from the rotation
+    /// part of `self`.
+    ///
+    /// See [`EulerRot`] for the range of the returned angles and the behavior at gimbal lock.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any of the first three columns of `self` is not normalized when
+    /// `glam_assert` is enabled.
+    #[inline]
+    pub fn to_euler(&self, order: EulerRot) -> (f64, f64, f64) {
+        glam_assert!(
+            self.x_axis.xyz().is_normalized()
+                && self.y_axis.xyz().is_normalized()
+                && self.z_axis.xyz().is_normalized()
+        );
+        DQuat::from_mat4(self).to_euler(order)
+    }
+
     /// Creates an affine transformation matrix containing a 3D rotation around the x axis of
     /// `angle` (in radians).
     ///
     /// The resulting matrix can be used to transform 3D points and vectors. See
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
     #[inline]
-    pub fn from_rotation_x(angle: f64) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_x(angle: impl Into<DRadians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             DVec4::X,
             DVec4::new(0.0, cosa, sina, 0.0),
@@ -384,8 +400,8 @@ impl DMat4 {
     /// The resulting matrix can be used to transform 3D points and vectors. See
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
     #[inline]
-    pub fn from_rotation_y(angle: f64) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_y(angle: impl Into<DRadians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             DVec4::new(cosa, 0.0, -sina, 0.0),
             DVec4::Y,
@@ -400,8 +416,8 @@ impl DMat4 {
     /// The resulting matrix can be used to transform 3D points and vectors. See
     /// [`Self::transform_point3()`] and [`Self::transform_vector3()`].
     #[inline]
-    pub fn from_rotation_z(angle: f64) -> Self {
-        let (sina, cosa) = math::sin_cos(angle);
+    pub fn from_rotation_z(angle: impl Into<DRadians>) -> Self {
+        let (sina, cosa) = math::sin_cos(angle.into().0);
         Self::from_cols(
             DVec4::new(cosa, sina, 0.0, 0.0),
             DVec4::new(-sina, cosa, 0.0, 0.0),
@@ -704,13 +720,13 @@ impl DMat4 {
     /// See <https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluPerspective.xml>
     #[inline]
     pub fn perspective_rh_gl(
-        fov_y_radians: f64,
+        fov_y_radians: impl Into<DRadians>,
         aspect_ratio: f64,
         z_near: f64,
         z_far: f64,
     ) -> Self {
         let inv_length = 1.0 / (z_near - z_far);
-        let f = 1.0 / math::tan(0.5 * fov_y_radians);
+        let f = 1.0 / math::tan(0.5 * fov_y_radians.into().0);
         let a = f / aspect_ratio;
         let b = (z_near + z_far) * inv_length;
         let c = (2.0 * z_near * z_far) * inv_length;
@@ -729,9 +745,14 @@ impl DMat4 {
     /// Will panic if `z_near` or `z_far` are less than or equal to zero when `glam_assert` is
     /// enabled.
     #[inline]
-    pub fn perspective_lh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64, z_far: f64) -> Self {
+    pub fn perspective_lh(
+        fov_y_radians: impl Into<DRadians>,
+        aspect_ratio: f64,
+        z_near: f64,
+        z_far: f64,
+    ) -> Self {
         glam_assert!(z_near > 0.0 && z_far > 0.0);
-        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians);
+        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians.into().0);
         let h = cos_fov / sin_fov;
         let w = h / aspect_ratio;
         let r = z_far / (z_far - z_near);
@@ -750,9 +771,14 @@ impl DMat4 {
     /// Will panic if `z_near` or `z_far` are less than or equal to zero when `glam_assert` is
     /// enabled.
     #[inline]
-    pub fn perspective_rh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64, z_far: f64) -> Self {
+    pub fn perspective_rh(
+        fov_y_radians: impl Into<DRadians>,
+        aspect_ratio: f64,
+        z_near: f64,
+        z_far: f64,
+    ) -> Self {
         glam_assert!(z_near > 0.0 && z_far > 0.0);
-        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians);
+        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians.into().0);
         let h = cos_fov / sin_fov;
         let w = h / aspect_ratio;
         let r = z_far / (z_near - z_far);
@@ -770,9 +796,13 @@ impl DMat4 {
     ///
     /// Will panic if `z_near` is less than or equal to zero when `glam_assert` is enabled.
     #[inline]
-    pub fn perspective_infinite_lh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64) -> Self {
+    pub fn perspective_infinite_lh(
+        fov_y_radians: impl Into<DRadians>,
+        aspect_ratio: f64,
+        z_near: f64,
+    ) -> Self {
         glam_assert!(z_near > 0.0);
-        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians);
+        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians.into().0);
         let h = cos_fov / sin_fov;
         let w = h / aspect_ratio;
         Self::from_cols(
@@ -790,12 +820,12 @@ impl DMat4 {
     /// Will panic if `z_near` is less than or equal to zero when `glam_assert` is enabled.
     #[inline]
     pub fn perspective_infinite_reverse_lh(
-        fov_y_radians: f64,
+        fov_y_radians: impl Into<DRadians>,
         aspect_ratio: f64,
         z_near: f64,
     ) -> Self {
         glam_assert!(z_near > 0.0);
-        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians);
+        let (sin_fov, cos_fov) = math::sin_cos(0.5 * fov_y_radians.into().0);
         let h = cos_fov / sin_fov;
         let w = h / aspect_ratio;
         Self::from_cols(
@@ -809,9 +839,13 @@ impl DMat4 {
     /// Creates an infinite right-handed perspective projection matrix with
     /// `[0,1]` depth range.
     #[inline]
-    pub fn perspective_infinite_rh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64) -> Self {
+    pub fn perspective_infinite_rh(
+        fov_y_radians: impl Into<DRadians>,
+        aspect_ratio: f64,
+        z_near: f64,
+    ) -> Self {
         glam_assert!(z_near > 0.0);
-        let f = 1.0 / math::tan(0.5 * fov_y_radians);
+        let f = 1.0 / math::tan(0.5 * fov_y_radians.into().0);
         Self::from_cols(
             DVec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
             DVec4::new(0.0, f, 0.0, 0.0),
@@ -824,12 +858,12 @@ impl DMat4 {
     /// with `[0,1]` depth range.
     #[inline]
     pub fn perspective_infinite_reverse_rh(
-        fov_y_radians: f64,
+        fov_y_radians: impl Into<DRadians>,
         aspect_ratio: f64,
         z_near: f64,
     ) -> Self {
         glam_assert!(z_near > 0.0);
-        let f = 1.0 / math::tan(0.5 * fov_y_radians);
+        let f = 1.0 / math::tan(0.5 * fov_y_radians.into().0);
         Self::from_cols(
             DVec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
             DVec4::new(0.0, f, 0.0, 0.0),
@@ -975,6 +1009,104 @@ impl DMat4 {
         res.xyz()
     }
 
+    /// Transforms each 3D point in `points` in place using [`Self::project_point3()`].
+    ///
+    /// This method assumes that `self` contains a projective transform.
+    #[inline]
+    pub fn project_points3_slice(&self, points: &mut [DVec3]) {
+        for v in points {
+            *v = self.project_point3(*v);
+        }
+    }
+
+    /// Transforms each 3D point in `src` using [`Self::project_point3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// This method assumes that `self` contains a projective transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    #[inline]
+    pub fn project_points3_slice_to(&self, src: &[DVec3], dst: &mut [DVec3]) {
+        assert_eq!(src.len(), dst.len());
+        for (s, d) in src.iter().zip(dst) {
+            *d = self.project_point3(*s);
+        }
+    }
+
+    /// Transforms each 3D point in `points` in place using [`Self::transform_point3()`].
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_points3_slice(&self, points: &mut [DVec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(DVec4::W, 1e-6));
+
+        for v in points {
+            *v = self.transform_point3(*v);
+        }
+    }
+
+    /// Transforms each 3D point in `src` using [`Self::transform_point3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_points3_slice_to(&self, src: &[DVec3], dst: &mut [DVec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(DVec4::W, 1e-6));
+
+        assert_eq!(src.len(), dst.len());
+        for (s, d) in src.iter().zip(dst) {
+            *d = self.transform_point3(*s);
+        }
+    }
+
+    /// Transforms each 3D vector in `vectors` in place using [`Self::transform_vector3()`].
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vectors3_slice(&self, vectors: &mut [DVec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(DVec4::W, 1e-6));
+
+        for v in vectors {
+            *v = self.transform_vector3(*v);
+        }
+    }
+
+    /// Transforms each 3D vector in `src` using [`Self::transform_vector3()`] and writes the
+    /// results to `dst`.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `src` and `dst` are not the same length.
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
+    #[inline]
+    pub fn transform_vectors3_slice_to(&self, src: &[DVec3], dst: &mut [DVec3]) {
+        glam_assert!(self.row(3).abs_diff_eq(DVec4::W, 1e-6));
+
+        assert_eq!(src.len(), dst.len());
+        for (s, d) in src.iter().zip(dst) {
+            *d = self.transform_vector3(*s);
+        }
+    }
+
     /// Transforms a 4D vector.
     #[inline]
     pub fn mul_vec4(&self, rhs: DVec4) -> DVec4 {
@@ -0,0 +1,125 @@
+// Generated from sphere.rs.tera template. Edit the template, not the generated file.
+
+use crate::{f64::math, DAabb3, DAffine3, DMat4, DVec3};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A 3D sphere with a `center` and a `radius`.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct DSphere {
+    pub center: DVec3,
+    pub radius: f64,
+}
+
+impl DSphere {
+    /// Creates a new sphere from a `center` and a `radius`.
+    #[inline(always)]
+    pub const fn new(center: DVec3, radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns the signed distance of `point` from the surface of the sphere, which is
+    /// negative inside the sphere.
+    #[inline]
+    pub fn signed_distance(&self, point: DVec3) -> f64 {
+        self.center.distance(point) - self.radius
+    }
+
+    /// Returns `true` if `point` is inside or on the surface of the sphere.
+    #[inline]
+    pub fn contains_point(&self, point: DVec3) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// Returns `true` if `self` and `rhs` overlap or touch.
+    #[inline]
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        let radius = self.radius + rhs.radius;
+        self.center.distance_squared(rhs.center) <= radius * radius
+    }
+
+    /// Returns `true` if `self` and `aabb` overlap or touch.
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &DAabb3) -> bool {
+        self.contains_point(aabb.closest_point(self.center))
+    }
+
+    /// Returns the closest point to `point` on the surface of the sphere.
+    ///
+    /// If `point` is the center of the sphere then `center` is returned.
+    #[inline]
+    pub fn project_point(&self, point: DVec3) -> DVec3 {
+        self.center + (point - self.center).normalize_or_zero() * self.radius
+    }
+
+    /// Returns the smallest axis-aligned box containing the sphere.
+    #[inline]
+    pub fn aabb(&self) -> DAabb3 {
+        DAabb3::from_center_half_extents(self.center, DVec3::splat(self.radius))
+    }
+
+    /// Returns `self` transformed by `transform`.
+    ///
+    /// The radius is scaled by the largest singular value of the linear part of `transform`,
+    /// which is the most it can stretch any direction, so the result is the smallest sphere
+    /// containing the transformed sphere.
+    #[inline]
+    pub fn transformed_by(&self, transform: &DAffine3) -> Self {
+        // The squared singular values of `m` are the eigenvalues of `m^T * m`.
+        let m = transform.matrix3;
+        let (eigenvalues, _) = (m.transpose() * m).symmetric_eigen();
+        Self {
+            center: transform.transform_point3(self.center),
+            radius: self.radius * math::sqrt(eigenvalues.x.max(0.0)),
+        }
+    }
+
+    /// Returns `self` transformed by the affine transform `mat`.
+    ///
+    /// See [`Self::transformed_by()`].
+    #[inline]
+    pub fn transformed_by_mat4(&self, mat: &DMat4) -> Self {
+        self.transformed_by(&DAffine3::from_mat4(*mat))
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.center.is_finite() && self.radius.is_finite()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `rhs`
+    /// is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two spheres contain similar elements. It works best when
+    /// comparing with a known value. The `max_abs_diff` that should be used used depends on the
+    /// values being compared against.
+    ///
+    /// For more see
+    /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+    #[inline]
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.center.abs_diff_eq(rhs.center, max_abs_diff)
+            && math::abs(self.radius - rhs.radius) <= max_abs_diff
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for DSphere {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(DSphere))
+            .field("center", &self.center)
+            .field("radius", &self.radius)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for DSphere {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.center, self.radius)
+    }
+}
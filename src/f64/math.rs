@@ -16,11 +16,6 @@ mod libm_math {
         libm::acos(f.clamp(-1.0, 1.0))
     }
 
-    #[inline(always)]
-    pub(crate) fn asin_clamped(f: f64) -> f64 {
-        libm::asin(f.clamp(-1.0, 1.0))
-    }
-
     #[inline(always)]
     pub(crate) fn atan2(f: f64, other: f64) -> f64 {
         libm::atan2(f, other)
@@ -80,6 +75,11 @@ mod libm_math {
         libm::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn ln(f: f64) -> f64 {
+        libm::log(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f64, n: f64) -> f64 {
         libm::pow(f, n)
@@ -89,6 +89,26 @@ mod libm_math {
     pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
         libm::fma(a, b, c)
     }
+
+    #[inline(always)]
+    pub(crate) fn div_euclid(a: f64, b: f64) -> f64 {
+        // Based on https://doc.rust-lang.org/src/std/f64.rs.html
+        let q = libm::trunc(a / b);
+        if a % b < 0.0 {
+            return if b > 0.0 { q - 1.0 } else { q + 1.0 };
+        }
+        q
+    }
+
+    #[inline(always)]
+    pub(crate) fn rem_euclid(a: f64, b: f64) -> f64 {
+        let r = a % b;
+        if r < 0.0 {
+            r + abs(b)
+        } else {
+            r
+        }
+    }
 }
 
 #[cfg(not(feature = "libm"))]
@@ -103,11 +123,6 @@ mod std_math {
         f64::acos(f64::clamp(f, -1.0, 1.0))
     }
 
-    #[inline(always)]
-    pub(crate) fn asin_clamped(f: f64) -> f64 {
-        f64::asin(f64::clamp(f, -1.0, 1.0))
-    }
-
     #[inline(always)]
     pub(crate) fn atan2(f: f64, other: f64) -> f64 {
         f64::atan2(f, other)
@@ -163,6 +178,11 @@ mod std_math {
         f64::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn ln(f: f64) -> f64 {
+        f64::ln(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f64, n: f64) -> f64 {
         f64::powf(f, n)
@@ -172,6 +192,16 @@ mod std_math {
     pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
         f64::mul_add(a, b, c)
     }
+
+    #[inline(always)]
+    pub(crate) fn div_euclid(a: f64, b: f64) -> f64 {
+        f64::div_euclid(a, b)
+    }
+
+    #[inline(always)]
+    pub(crate) fn rem_euclid(a: f64, b: f64) -> f64 {
+        f64::rem_euclid(a, b)
+    }
 }
 
 #[cfg(feature = "libm")]
@@ -179,3 +209,18 @@ pub(crate) use libm_math::*;
 
 #[cfg(not(feature = "libm"))]
 pub(crate) use std_math::*;
+
+/// The maximum number of sweeps over all off-diagonal pairs done by the Jacobi eigenvalue and
+/// singular value methods. 3x3 matrices usually converge in less than 6.
+pub(crate) const JACOBI_MAX_SWEEPS: usize = 16;
+
+/// Returns the cosine and sine of the Jacobi rotation that zeroes the off-diagonal `apq` of the
+/// symmetric 2x2 matrix `[[app, apq], [apq, aqq]]`.
+#[inline]
+pub(crate) fn jacobi_rotation(app: f64, aqq: f64, apq: f64) -> (f64, f64) {
+    // Use the smaller root of `t^2 + 2 * t * theta - 1 = 0` for stability.
+    let theta = (aqq - app) / (2.0 * apq);
+    let t = signum(theta) / (abs(theta) + sqrt(theta * theta + 1.0));
+    let c = 1.0 / sqrt(t * t + 1.0);
+    (c, t * c)
+}
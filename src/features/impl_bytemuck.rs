@@ -1,6 +1,16 @@
+use crate::gpu_layout::{
+    Std140DMat2, Std140DMat2x3, Std140DMat3, Std140DMat3x2, Std140DMat3x4, Std140DMat4,
+    Std140DMat4x3, Std140Mat2, Std140Mat2x3, Std140Mat3, Std140Mat3x2, Std140Mat3x4, Std140Mat4,
+    Std140Mat4x3, Std430DMat2, Std430DMat2x3, Std430DMat3, Std430DMat3x2, Std430DMat3x4,
+    Std430DMat4, Std430DMat4x3, Std430Mat2, Std430Mat2x3, Std430Mat3, Std430Mat3x2, Std430Mat3x4,
+    Std430Mat4, Std430Mat4x3,
+};
 use crate::{
-    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2,
-    IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat2x3, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x3,
+    DQuat, DVec2, DVec3, DVec4, HVec2, HVec3, HVec4, I16Vec2, I16Vec3, I16Vec4, I8Vec2, I8Vec3,
+    I8Vec4, IVec2, IVec3, IVec4, IVec4A, Mat2, Mat2x3, Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3,
+    Quat, U16Vec2, U16Vec3, U16Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec4, UVec4A, Vec2,
+    Vec3, Vec3A, Vec4,
 };
 use bytemuck::{AnyBitPattern, Pod, Zeroable};
 
@@ -18,6 +28,15 @@ unsafe impl Zeroable for Mat3A {}
 unsafe impl Pod for Mat4 {}
 unsafe impl Zeroable for Mat4 {}
 
+unsafe impl Pod for Mat2x3 {}
+unsafe impl Zeroable for Mat2x3 {}
+unsafe impl Pod for Mat3x2 {}
+unsafe impl Zeroable for Mat3x2 {}
+unsafe impl Pod for Mat3x4 {}
+unsafe impl Zeroable for Mat3x4 {}
+unsafe impl Pod for Mat4x3 {}
+unsafe impl Zeroable for Mat4x3 {}
+
 unsafe impl Pod for Quat {}
 unsafe impl Zeroable for Quat {}
 
@@ -42,6 +61,15 @@ unsafe impl Zeroable for DMat3 {}
 unsafe impl Pod for DMat4 {}
 unsafe impl Zeroable for DMat4 {}
 
+unsafe impl Pod for DMat2x3 {}
+unsafe impl Zeroable for DMat2x3 {}
+unsafe impl Pod for DMat3x2 {}
+unsafe impl Zeroable for DMat3x2 {}
+unsafe impl Pod for DMat3x4 {}
+unsafe impl Zeroable for DMat3x4 {}
+unsafe impl Pod for DMat4x3 {}
+unsafe impl Zeroable for DMat4x3 {}
+
 unsafe impl Pod for DQuat {}
 unsafe impl Zeroable for DQuat {}
 
@@ -52,12 +80,49 @@ unsafe impl Zeroable for DVec3 {}
 unsafe impl Pod for DVec4 {}
 unsafe impl Zeroable for DVec4 {}
 
+unsafe impl Pod for HVec2 {}
+unsafe impl Zeroable for HVec2 {}
+unsafe impl Pod for HVec3 {}
+unsafe impl Zeroable for HVec3 {}
+unsafe impl Pod for HVec4 {}
+unsafe impl Zeroable for HVec4 {}
+
+unsafe impl Pod for I8Vec2 {}
+unsafe impl Zeroable for I8Vec2 {}
+unsafe impl Pod for I8Vec3 {}
+unsafe impl Zeroable for I8Vec3 {}
+unsafe impl Pod for I8Vec4 {}
+unsafe impl Zeroable for I8Vec4 {}
+
+unsafe impl Pod for U8Vec2 {}
+unsafe impl Zeroable for U8Vec2 {}
+unsafe impl Pod for U8Vec3 {}
+unsafe impl Zeroable for U8Vec3 {}
+unsafe impl Pod for U8Vec4 {}
+unsafe impl Zeroable for U8Vec4 {}
+
+unsafe impl Pod for I16Vec2 {}
+unsafe impl Zeroable for I16Vec2 {}
+unsafe impl Pod for I16Vec3 {}
+unsafe impl Zeroable for I16Vec3 {}
+unsafe impl Pod for I16Vec4 {}
+unsafe impl Zeroable for I16Vec4 {}
+
+unsafe impl Pod for U16Vec2 {}
+unsafe impl Zeroable for U16Vec2 {}
+unsafe impl Pod for U16Vec3 {}
+unsafe impl Zeroable for U16Vec3 {}
+unsafe impl Pod for U16Vec4 {}
+unsafe impl Zeroable for U16Vec4 {}
+
 unsafe impl Pod for IVec2 {}
 unsafe impl Zeroable for IVec2 {}
 unsafe impl Pod for IVec3 {}
 unsafe impl Zeroable for IVec3 {}
 unsafe impl Pod for IVec4 {}
 unsafe impl Zeroable for IVec4 {}
+unsafe impl Pod for IVec4A {}
+unsafe impl Zeroable for IVec4A {}
 
 unsafe impl Pod for UVec2 {}
 unsafe impl Zeroable for UVec2 {}
@@ -65,13 +130,81 @@ unsafe impl Pod for UVec3 {}
 unsafe impl Zeroable for UVec3 {}
 unsafe impl Pod for UVec4 {}
 unsafe impl Zeroable for UVec4 {}
+unsafe impl Pod for UVec4A {}
+unsafe impl Zeroable for UVec4A {}
+
+unsafe impl Pod for Std140Mat2 {}
+unsafe impl Zeroable for Std140Mat2 {}
+unsafe impl Pod for Std430Mat2 {}
+unsafe impl Zeroable for Std430Mat2 {}
+unsafe impl Pod for Std140Mat2x3 {}
+unsafe impl Zeroable for Std140Mat2x3 {}
+unsafe impl Pod for Std430Mat2x3 {}
+unsafe impl Zeroable for Std430Mat2x3 {}
+unsafe impl Pod for Std140Mat3x2 {}
+unsafe impl Zeroable for Std140Mat3x2 {}
+unsafe impl Pod for Std430Mat3x2 {}
+unsafe impl Zeroable for Std430Mat3x2 {}
+unsafe impl Pod for Std140Mat3 {}
+unsafe impl Zeroable for Std140Mat3 {}
+unsafe impl Pod for Std430Mat3 {}
+unsafe impl Zeroable for Std430Mat3 {}
+unsafe impl Pod for Std140Mat3x4 {}
+unsafe impl Zeroable for Std140Mat3x4 {}
+unsafe impl Pod for Std430Mat3x4 {}
+unsafe impl Zeroable for Std430Mat3x4 {}
+unsafe impl Pod for Std140Mat4x3 {}
+unsafe impl Zeroable for Std140Mat4x3 {}
+unsafe impl Pod for Std430Mat4x3 {}
+unsafe impl Zeroable for Std430Mat4x3 {}
+unsafe impl Pod for Std140Mat4 {}
+unsafe impl Zeroable for Std140Mat4 {}
+unsafe impl Pod for Std430Mat4 {}
+unsafe impl Zeroable for Std430Mat4 {}
+unsafe impl Pod for Std140DMat2 {}
+unsafe impl Zeroable for Std140DMat2 {}
+unsafe impl Pod for Std430DMat2 {}
+unsafe impl Zeroable for Std430DMat2 {}
+unsafe impl Pod for Std140DMat2x3 {}
+unsafe impl Zeroable for Std140DMat2x3 {}
+unsafe impl Pod for Std430DMat2x3 {}
+unsafe impl Zeroable for Std430DMat2x3 {}
+unsafe impl Pod for Std140DMat3x2 {}
+unsafe impl Zeroable for Std140DMat3x2 {}
+unsafe impl Pod for Std430DMat3x2 {}
+unsafe impl Zeroable for Std430DMat3x2 {}
+unsafe impl Pod for Std140DMat3 {}
+unsafe impl Zeroable for Std140DMat3 {}
+unsafe impl Pod for Std430DMat3 {}
+unsafe impl Zeroable for Std430DMat3 {}
+unsafe impl Pod for Std140DMat3x4 {}
+unsafe impl Zeroable for Std140DMat3x4 {}
+unsafe impl Pod for Std430DMat3x4 {}
+unsafe impl Zeroable for Std430DMat3x4 {}
+unsafe impl Pod for Std140DMat4x3 {}
+unsafe impl Zeroable for Std140DMat4x3 {}
+unsafe impl Pod for Std430DMat4x3 {}
+unsafe impl Zeroable for Std430DMat4x3 {}
+unsafe impl Pod for Std140DMat4 {}
+unsafe impl Zeroable for Std140DMat4 {}
+unsafe impl Pod for Std430DMat4 {}
+unsafe impl Zeroable for Std430DMat4 {}
 
 #[cfg(test)]
 mod test {
+    use crate::gpu_layout::{
+        Std140DMat2, Std140DMat2x3, Std140DMat3, Std140DMat3x2, Std140DMat3x4, Std140DMat4,
+        Std140DMat4x3, Std140Mat2, Std140Mat2x3, Std140Mat3, Std140Mat3x2, Std140Mat3x4,
+        Std140Mat4, Std140Mat4x3, Std430DMat2, Std430DMat2x3, Std430DMat3, Std430DMat3x2,
+        Std430DMat3x4, Std430DMat4, Std430DMat4x3, Std430Mat2, Std430Mat2x3, Std430Mat3,
+        Std430Mat3x2, Std430Mat3x4, Std430Mat4, Std430Mat4x3,
+    };
     use crate::{
-        Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4,
-        IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A,
-        Vec4,
+        Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat2x3, DMat3, DMat3x2, DMat3x4, DMat4,
+        DMat4x3, DQuat, DVec2, DVec3, DVec4, HVec2, HVec3, HVec4, I16Vec2, I16Vec3, I16Vec4,
+        I8Vec2, I8Vec3, I8Vec4, IVec2, IVec3, IVec4, IVec4A, Mat2, Mat2x3, Mat3, Mat3A, Mat3x2,
+        Mat3x4, Mat4, Mat4x3, Quat, U16Vec2, U16Vec3, U16Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2,
+        UVec3, UVec4, UVec4A, Vec2, Vec3, Vec3A, Vec4,
     };
     use core::mem;
 
@@ -109,6 +242,10 @@ mod test {
     test_pod_t!(mat3, Mat3);
     test_any_bit_pattern_t!(mat3a, Mat3A);
     test_pod_t!(mat4, Mat4);
+    test_pod_t!(mat2x3, Mat2x3);
+    test_pod_t!(mat3x2, Mat3x2);
+    test_pod_t!(mat3x4, Mat3x4);
+    test_pod_t!(mat4x3, Mat4x3);
     test_pod_t!(quat, Quat);
     test_pod_t!(vec2, Vec2);
     test_pod_t!(vec3, Vec3);
@@ -120,16 +257,71 @@ mod test {
     test_pod_t!(dmat2, DMat2);
     test_pod_t!(dmat3, DMat3);
     test_pod_t!(dmat4, DMat4);
+    test_pod_t!(dmat2x3, DMat2x3);
+    test_pod_t!(dmat3x2, DMat3x2);
+    test_pod_t!(dmat3x4, DMat3x4);
+    test_pod_t!(dmat4x3, DMat4x3);
     test_pod_t!(dquat, DQuat);
     test_pod_t!(dvec2, DVec2);
     test_pod_t!(dvec3, DVec3);
     test_pod_t!(dvec4, DVec4);
 
+    test_pod_t!(hvec2, HVec2);
+    test_pod_t!(hvec3, HVec3);
+    test_pod_t!(hvec4, HVec4);
+
+    test_pod_t!(i8vec2, I8Vec2);
+    test_pod_t!(i8vec3, I8Vec3);
+    test_pod_t!(i8vec4, I8Vec4);
+
+    test_pod_t!(u8vec2, U8Vec2);
+    test_pod_t!(u8vec3, U8Vec3);
+    test_pod_t!(u8vec4, U8Vec4);
+
+    test_pod_t!(i16vec2, I16Vec2);
+    test_pod_t!(i16vec3, I16Vec3);
+    test_pod_t!(i16vec4, I16Vec4);
+
+    test_pod_t!(u16vec2, U16Vec2);
+    test_pod_t!(u16vec3, U16Vec3);
+    test_pod_t!(u16vec4, U16Vec4);
+
     test_pod_t!(ivec2, IVec2);
     test_pod_t!(ivec3, IVec3);
     test_pod_t!(ivec4, IVec4);
+    test_pod_t!(ivec4a, IVec4A);
 
     test_pod_t!(uvec2, UVec2);
     test_pod_t!(uvec3, UVec3);
     test_pod_t!(uvec4, UVec4);
+    test_pod_t!(uvec4a, UVec4A);
+
+    test_pod_t!(std140mat2, Std140Mat2);
+    test_pod_t!(std430mat2, Std430Mat2);
+    test_pod_t!(std140mat2x3, Std140Mat2x3);
+    test_pod_t!(std430mat2x3, Std430Mat2x3);
+    test_pod_t!(std140mat3x2, Std140Mat3x2);
+    test_pod_t!(std430mat3x2, Std430Mat3x2);
+    test_pod_t!(std140mat3, Std140Mat3);
+    test_pod_t!(std430mat3, Std430Mat3);
+    test_pod_t!(std140mat3x4, Std140Mat3x4);
+    test_pod_t!(std430mat3x4, Std430Mat3x4);
+    test_pod_t!(std140mat4x3, Std140Mat4x3);
+    test_pod_t!(std430mat4x3, Std430Mat4x3);
+    test_pod_t!(std140mat4, Std140Mat4);
+    test_pod_t!(std430mat4, Std430Mat4);
+    test_pod_t!(std140dmat2, Std140DMat2);
+    test_pod_t!(std430dmat2, Std430DMat2);
+    test_pod_t!(std140dmat2x3, Std140DMat2x3);
+    test_pod_t!(std430dmat2x3, Std430DMat2x3);
+    test_pod_t!(std140dmat3x2, Std140DMat3x2);
+    test_pod_t!(std430dmat3x2, Std430DMat3x2);
+    test_pod_t!(std140dmat3, Std140DMat3);
+    test_pod_t!(std430dmat3, Std430DMat3);
+    test_pod_t!(std140dmat3x4, Std140DMat3x4);
+    test_pod_t!(std430dmat3x4, Std430DMat3x4);
+    test_pod_t!(std140dmat4x3, Std140DMat4x3);
+    test_pod_t!(std430dmat4x3, Std430DMat4x3);
+    test_pod_t!(std140dmat4, Std140DMat4);
+    test_pod_t!(std430dmat4, Std430DMat4);
 }
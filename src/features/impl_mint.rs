@@ -1,8 +1,10 @@
 use mint::IntoMint;
 
 use crate::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4,
-    Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    DMat2, DMat2x3, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x3, DQuat, DVec2, DVec3, DVec4, I16Vec2,
+    I16Vec3, I16Vec4, I8Vec2, I8Vec3, I8Vec4, IVec2, IVec3, IVec4, IVec4A, Mat2, Mat2x3, Mat3,
+    Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3, Quat, U16Vec2, U16Vec3, U16Vec4, U8Vec2, U8Vec3, U8Vec4,
+    UVec2, UVec3, UVec4, UVec4A, Vec2, Vec3, Vec3A, Vec4,
 };
 
 macro_rules! impl_vec_types {
@@ -71,6 +73,12 @@ macro_rules! impl_vec_types {
             type MintType = mint::Vector3<$t>;
         }
 
+        impl_vec4!($t, $vec4);
+    };
+}
+
+macro_rules! impl_vec4 {
+    ($t:ty, $vec4:ty) => {
         impl From<mint::Vector4<$t>> for $vec4 {
             fn from(v: mint::Vector4<$t>) -> Self {
                 Self::new(v.x, v.y, v.z, v.w)
@@ -234,6 +242,69 @@ macro_rules! impl_float_types {
     };
 }
 
+macro_rules! impl_mat_cxr {
+    ($t:ty, $mat:ident, $transpose:ident, $column:ident, $row:ident,
+        [$($axis:ident => $field:ident),+], [$($row_axis:ident => $row_field:ident),+]) => {
+        impl From<mint::$row<$t>> for $mat {
+            fn from(m: mint::$row<$t>) -> Self {
+                $transpose::from_cols($(m.$row_field.into()),+).transpose()
+            }
+        }
+
+        impl From<$mat> for mint::$row<$t> {
+            fn from(m: $mat) -> Self {
+                let mt = m.transpose();
+                Self {
+                    $($row_field: mt.$row_axis.into()),+
+                }
+            }
+        }
+
+        impl From<mint::$column<$t>> for $mat {
+            fn from(m: mint::$column<$t>) -> Self {
+                Self::from_cols($(m.$field.into()),+)
+            }
+        }
+
+        impl From<$mat> for mint::$column<$t> {
+            fn from(m: $mat) -> Self {
+                Self {
+                    $($field: m.$axis.into()),+
+                }
+            }
+        }
+
+        impl IntoMint for $mat {
+            type MintType = mint::$column<$t>;
+        }
+    };
+}
+
+macro_rules! impl_mat_cxr_types {
+    ($t:ty, $mat2x3:ident, $mat3x2:ident, $mat3x4:ident, $mat4x3:ident) => {
+        impl_mat_cxr!(
+            $t, $mat2x3, $mat3x2, ColumnMatrix3x2, RowMatrix3x2,
+            [x_axis => x, y_axis => y],
+            [x_axis => x, y_axis => y, z_axis => z]
+        );
+        impl_mat_cxr!(
+            $t, $mat3x2, $mat2x3, ColumnMatrix2x3, RowMatrix2x3,
+            [x_axis => x, y_axis => y, z_axis => z],
+            [x_axis => x, y_axis => y]
+        );
+        impl_mat_cxr!(
+            $t, $mat3x4, $mat4x3, ColumnMatrix4x3, RowMatrix4x3,
+            [x_axis => x, y_axis => y, z_axis => z],
+            [x_axis => x, y_axis => y, z_axis => z, w_axis => w]
+        );
+        impl_mat_cxr!(
+            $t, $mat4x3, $mat3x4, ColumnMatrix3x4, RowMatrix3x4,
+            [x_axis => x, y_axis => y, z_axis => z, w_axis => w],
+            [x_axis => x, y_axis => y, z_axis => z]
+        );
+    };
+}
+
 impl From<mint::Point3<f32>> for Vec3A {
     fn from(v: mint::Point3<f32>) -> Self {
         Self::new(v.x, v.y, v.z)
@@ -309,8 +380,16 @@ impl IntoMint for Mat3A {
 
 impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
 impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+impl_mat_cxr_types!(f32, Mat2x3, Mat3x2, Mat3x4, Mat4x3);
+impl_mat_cxr_types!(f64, DMat2x3, DMat3x2, DMat3x4, DMat4x3);
+impl_vec_types!(i8, I8Vec2, I8Vec3, I8Vec4);
+impl_vec_types!(u8, U8Vec2, U8Vec3, U8Vec4);
+impl_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+impl_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
 impl_vec_types!(i32, IVec2, IVec3, IVec4);
+impl_vec4!(i32, IVec4A);
 impl_vec_types!(u32, UVec2, UVec3, UVec4);
+impl_vec4!(u32, UVec4A);
 
 #[cfg(test)]
 mod test {
@@ -450,9 +529,35 @@ mod test {
         };
     }
 
+    macro_rules! impl_mat_cxr_tests {
+        ($t:ty, $mat:ident, $column:ident, $row:ident, $size:literal) => {
+            let mut f = [0.0; $size];
+            for (i, v) in f.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let g = $mat::from_cols_array(&f);
+            let m = mint::$column::from(g);
+            assert_eq!(g.x_axis, m.x.into());
+            assert_eq!(g, $mat::from(m));
+            let mt = mint::$row::from(g);
+            assert_eq!(g.row(0), mt.x.into());
+            assert_eq!(g.row(1), mt.y.into());
+            assert_eq!(g, $mat::from(mt));
+        };
+    }
+
     mod f32 {
         impl_float_tests!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
 
+        #[test]
+        fn test_mat_cxr() {
+            use crate::{Mat2x3, Mat3x2, Mat3x4, Mat4x3};
+            impl_mat_cxr_tests!(f32, Mat2x3, ColumnMatrix3x2, RowMatrix3x2, 6);
+            impl_mat_cxr_tests!(f32, Mat3x2, ColumnMatrix2x3, RowMatrix2x3, 6);
+            impl_mat_cxr_tests!(f32, Mat3x4, ColumnMatrix4x3, RowMatrix4x3, 12);
+            impl_mat_cxr_tests!(f32, Mat4x3, ColumnMatrix3x4, RowMatrix3x4, 12);
+        }
+
         #[test]
         fn test_point3a() {
             use crate::Vec3A;
@@ -516,13 +621,66 @@ mod test {
 
     mod f64 {
         impl_float_tests!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+
+        #[test]
+        fn test_mat_cxr() {
+            use crate::{DMat2x3, DMat3x2, DMat3x4, DMat4x3};
+            impl_mat_cxr_tests!(f64, DMat2x3, ColumnMatrix3x2, RowMatrix3x2, 6);
+            impl_mat_cxr_tests!(f64, DMat3x2, ColumnMatrix2x3, RowMatrix2x3, 6);
+            impl_mat_cxr_tests!(f64, DMat3x4, ColumnMatrix4x3, RowMatrix4x3, 12);
+            impl_mat_cxr_tests!(f64, DMat4x3, ColumnMatrix3x4, RowMatrix3x4, 12);
+        }
+    }
+
+    mod i8 {
+        impl_vec_tests!(i8, I8Vec2, I8Vec3, I8Vec4);
+    }
+
+    mod u8 {
+        impl_vec_tests!(u8, U8Vec2, U8Vec3, U8Vec4);
+    }
+
+    mod i16 {
+        impl_vec_tests!(i16, I16Vec2, I16Vec3, I16Vec4);
+    }
+
+    mod u16 {
+        impl_vec_tests!(u16, U16Vec2, U16Vec3, U16Vec4);
     }
 
     mod i32 {
         impl_vec_tests!(i32, IVec2, IVec3, IVec4);
+
+        #[test]
+        fn test_ivec4a() {
+            use crate::IVec4A;
+            let m = mint::Vector4 {
+                x: 1,
+                y: 2,
+                z: 3,
+                w: 4,
+            };
+            let g = IVec4A::from(m);
+            assert_eq!(g, IVec4A::new(1, 2, 3, 4));
+            assert_eq!(m, g.into());
+        }
     }
 
     mod u32 {
         impl_vec_tests!(u32, UVec2, UVec3, UVec4);
+
+        #[test]
+        fn test_uvec4a() {
+            use crate::UVec4A;
+            let m = mint::Vector4 {
+                x: 1,
+                y: 2,
+                z: 3,
+                w: 4,
+            };
+            let g = UVec4A::from(m);
+            assert_eq!(g, UVec4A::new(1, 2, 3, 4));
+            assert_eq!(m, g.into());
+        }
     }
 }
@@ -1,3 +1,42 @@
+/// Samples unit length 2D vectors uniformly distributed on the unit circle.
+///
+/// Implements [`Distribution`](rand::distributions::Distribution) for [`Vec2`](crate::Vec2) and
+/// [`DVec2`](crate::DVec2).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitCircle;
+
+/// Samples 2D vectors uniformly distributed inside the unit disc.
+///
+/// Implements [`Distribution`](rand::distributions::Distribution) for [`Vec2`](crate::Vec2) and
+/// [`DVec2`](crate::DVec2).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitDisc;
+
+/// Samples unit length 3D vectors uniformly distributed on the surface of the unit sphere.
+///
+/// Implements [`Distribution`](rand::distributions::Distribution) for [`Vec3`](crate::Vec3),
+/// [`Vec3A`](crate::Vec3A) and [`DVec3`](crate::DVec3).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitSphere;
+
+/// Samples 3D vectors uniformly distributed inside the unit ball.
+///
+/// Implements [`Distribution`](rand::distributions::Distribution) for [`Vec3`](crate::Vec3),
+/// [`Vec3A`](crate::Vec3A) and [`DVec3`](crate::DVec3).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitBall;
+
+/// Samples unit length 3D vectors on the hemisphere around the positive Z axis with a
+/// probability density proportional to the cosine of the angle to the Z axis.
+///
+/// To sample around an arbitrary normal rotate the result, e.g. with
+/// [`Quat::from_rotation_arc(Vec3::Z, normal)`](crate::Quat::from_rotation_arc).
+///
+/// Implements [`Distribution`](rand::distributions::Distribution) for [`Vec3`](crate::Vec3),
+/// [`Vec3A`](crate::Vec3A) and [`DVec3`](crate::DVec3).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CosineWeightedHemisphere;
+
 macro_rules! impl_vec_types {
     ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
         impl Distribution<$vec2> for Standard {
@@ -56,8 +95,60 @@ macro_rules! impl_vec_types {
     };
 }
 
+macro_rules! impl_uniform_vec {
+    ($t:ident, $vec:ident, $uniform:ident, $($c:ident),+) => {
+        /// The back-end implementing [`UniformSampler`] for
+        #[doc = concat!("[`", stringify!($vec), "`].")]
+        ///
+        /// Each component is sampled independently, resulting in points uniformly distributed
+        /// in the axis-aligned box between `low` and `high`. The requirements on `low` and
+        /// `high` are the same as for a scalar range, applied per component.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $uniform {
+            $($c: UniformFloat<$t>,)+
+        }
+
+        impl UniformSampler for $uniform {
+            type X = $vec;
+
+            #[inline]
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (*low.borrow(), *high.borrow());
+                Self {
+                    $($c: UniformFloat::new(low.$c, high.$c),)+
+                }
+            }
+
+            #[inline]
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (*low.borrow(), *high.borrow());
+                Self {
+                    $($c: UniformFloat::new_inclusive(low.$c, high.$c),)+
+                }
+            }
+
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                $vec::new($(self.$c.sample(rng)),+)
+            }
+        }
+
+        impl SampleUniform for $vec {
+            type Sampler = $uniform;
+        }
+    };
+}
+
 macro_rules! impl_float_types {
-    ($t:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+    ($t:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident, $uvec2:ident, $uvec3:ident, $uvec4:ident) => {
         impl_vec_types!($t, $vec2, $vec3, $vec4);
 
         impl Distribution<$mat2> for Standard {
@@ -82,15 +173,76 @@ macro_rules! impl_float_types {
         }
 
         impl Distribution<$quat> for Standard {
+            /// Samples a rotation uniformly distributed over SO(3) using Shoemake's method.
             #[inline]
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $quat {
-                let yaw = -PI + rng.gen::<$t>() * 2.0 * PI;
-                let pitch = -PI + rng.gen::<$t>() * 2.0 * PI;
-                let roll = -PI + rng.gen::<$t>() * 2.0 * PI;
-                $quat::from_euler(crate::EulerRot::YXZ, yaw, pitch, roll)
+                let u1 = rng.gen::<$t>();
+                let (sin2, cos2) = math::sin_cos(2.0 * PI * rng.gen::<$t>());
+                let (sin3, cos3) = math::sin_cos(2.0 * PI * rng.gen::<$t>());
+                let r1 = math::sqrt(1.0 - u1);
+                let r2 = math::sqrt(u1);
+                $quat::from_xyzw(r1 * sin2, r1 * cos2, r2 * sin3, r2 * cos3)
+            }
+        }
+
+        impl Distribution<$vec2> for UnitCircle {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec2 {
+                $vec2::from_angle(2.0 * PI * rng.gen::<$t>())
             }
         }
 
+        impl Distribution<$vec2> for UnitDisc {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec2 {
+                loop {
+                    let v = $vec2::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+                    if v.length_squared() <= 1.0 {
+                        return v;
+                    }
+                }
+            }
+        }
+
+        impl Distribution<$vec3> for UnitSphere {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                let z: $t = rng.gen_range(-1.0..=1.0);
+                let (sin, cos) = math::sin_cos(2.0 * PI * rng.gen::<$t>());
+                let r = math::sqrt(1.0 - z * z);
+                $vec3::new(r * cos, r * sin, z)
+            }
+        }
+
+        impl Distribution<$vec3> for UnitBall {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                loop {
+                    let v = $vec3::new(
+                        rng.gen_range(-1.0..=1.0),
+                        rng.gen_range(-1.0..=1.0),
+                        rng.gen_range(-1.0..=1.0),
+                    );
+                    if v.length_squared() <= 1.0 {
+                        return v;
+                    }
+                }
+            }
+        }
+
+        impl Distribution<$vec3> for CosineWeightedHemisphere {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                let d: $vec2 = UnitDisc.sample(rng);
+                let z = math::sqrt((1.0 - d.length_squared()).max(0.0));
+                d.extend(z)
+            }
+        }
+
+        impl_uniform_vec!($t, $vec2, $uvec2, x, y);
+        impl_uniform_vec!($t, $vec3, $uvec3, x, y, z);
+        impl_uniform_vec!($t, $vec4, $uvec4, x, y, z, w);
+
         #[test]
         fn test_mat2_rand() {
             use rand::{Rng, SeedableRng};
@@ -135,18 +287,153 @@ macro_rules! impl_float_types {
             let b: $quat = rng2.gen();
             assert_eq!(a, b);
         }
+
+        #[test]
+        fn test_quat_rand_uniform() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            // The rotation angle of a uniformly distributed rotation has the density
+            // `(1 - cos(angle)) / PI` on `[0, PI]`, so compare a histogram of the sampled angles
+            // with the integral of the density `(angle - sin(angle)) / PI` over each bin.
+            const BINS: usize = 8;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let n = 20000;
+            let mut histogram = [0_u32; BINS];
+            for _ in 0..n {
+                let q: $quat = rng.gen();
+                assert!(q.is_normalized());
+                let angle = 2.0 * math::acos_approx(math::abs(q.w));
+                let bin = ((angle / PI * BINS as $t) as usize).min(BINS - 1);
+                histogram[bin] += 1;
+            }
+            let cdf = |angle: $t| (angle - math::sin(angle)) / PI;
+            for (i, &count) in histogram.iter().enumerate() {
+                let start = PI * i as $t / BINS as $t;
+                let end = PI * (i + 1) as $t / BINS as $t;
+                let expected = (cdf(end) - cdf(start)) * n as $t;
+                assert!(math::abs(count as $t - expected) < 0.01 * n as $t);
+            }
+        }
+
+        #[test]
+        fn test_unit_circle_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..100 {
+                let v: $vec2 = rng.sample(UnitCircle);
+                assert!(v.is_normalized());
+            }
+        }
+
+        #[test]
+        fn test_unit_disc_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..100 {
+                let v: $vec2 = rng.sample(UnitDisc);
+                assert!(v.length_squared() <= 1.0);
+            }
+        }
+
+        #[test]
+        fn test_unit_sphere_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let n = 10000;
+            let mut sum = $vec3::ZERO;
+            for _ in 0..n {
+                let v: $vec3 = rng.sample(UnitSphere);
+                assert!(v.is_normalized());
+                sum += v;
+            }
+            let mean = sum / n as $t;
+            assert!(mean.abs().max_element() < 0.05);
+        }
+
+        #[test]
+        fn test_unit_ball_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..100 {
+                let v: $vec3 = rng.sample(UnitBall);
+                assert!(v.length_squared() <= 1.0);
+            }
+        }
+
+        #[test]
+        fn test_cosine_weighted_hemisphere_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let n = 10000;
+            let mut sum = 0.0;
+            for _ in 0..n {
+                let v: $vec3 = rng.sample(CosineWeightedHemisphere);
+                assert!(v.is_normalized());
+                assert!(v.z >= 0.0);
+                sum += v.z;
+            }
+            // The expected value of cos(theta) for a cosine weighted hemisphere is 2/3.
+            let mean = sum / n as $t;
+            assert!((mean - 2.0 / 3.0).abs() < 0.05);
+        }
+
+        #[test]
+        fn test_vec3_uniform_rand() {
+            use rand::{distributions::Uniform, Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let min = $vec3::new(-1.0, 2.0, -3.0);
+            let max = $vec3::new(1.0, 4.0, 3.0);
+            let dist = Uniform::new(min, max);
+            for _ in 0..100 {
+                let v = rng.sample(dist);
+                assert!(v.cmpge(min).all() && v.cmplt(max).all());
+            }
+            let dist = Uniform::new_inclusive(min, max);
+            for _ in 0..100 {
+                let v = rng.sample(dist);
+                assert!(v.cmpge(min).all() && v.cmple(max).all());
+            }
+        }
     };
 }
 
+pub use self::f32::{UniformVec2, UniformVec3, UniformVec3A, UniformVec4};
+pub use self::f64::{UniformDVec2, UniformDVec3, UniformDVec4};
+
 mod f32 {
+    use super::{CosineWeightedHemisphere, UnitBall, UnitCircle, UnitDisc, UnitSphere};
+    use crate::f32::math;
     use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
     use core::f32::consts::PI;
     use rand::{
-        distributions::{Distribution, Standard},
+        distributions::{
+            uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler},
+            Distribution, Standard,
+        },
         Rng,
     };
 
-    impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+    impl_float_types!(
+        f32,
+        Mat2,
+        Mat3,
+        Mat4,
+        Quat,
+        Vec2,
+        Vec3,
+        Vec4,
+        UniformVec2,
+        UniformVec3,
+        UniformVec4
+    );
+
+    impl_uniform_vec!(f32, Vec3A, UniformVec3A, x, y, z);
 
     impl Distribution<Vec3A> for Standard {
         #[inline]
@@ -155,6 +442,27 @@ mod f32 {
         }
     }
 
+    impl Distribution<Vec3A> for UnitSphere {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+            Distribution::<Vec3>::sample(self, rng).into()
+        }
+    }
+
+    impl Distribution<Vec3A> for UnitBall {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+            Distribution::<Vec3>::sample(self, rng).into()
+        }
+    }
+
+    impl Distribution<Vec3A> for CosineWeightedHemisphere {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+            Distribution::<Vec3>::sample(self, rng).into()
+        }
+    }
+
     #[test]
     fn test_vec3a_rand() {
         use rand::{Rng, SeedableRng};
@@ -165,17 +473,94 @@ mod f32 {
         let b: Vec3A = rng2.gen();
         assert_eq!(a, b.into());
     }
+
+    #[test]
+    fn test_vec3a_distributions_rand() {
+        use rand::{distributions::Uniform, Rng, SeedableRng};
+        use rand_xoshiro::Xoshiro256Plus;
+        let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+        let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+        let a: Vec3A = rng1.sample(UnitSphere);
+        let b: Vec3 = rng2.sample(UnitSphere);
+        assert_eq!(a, Vec3A::from(b));
+        let a: Vec3A = rng1.sample(UnitBall);
+        let b: Vec3 = rng2.sample(UnitBall);
+        assert_eq!(a, Vec3A::from(b));
+        let a: Vec3A = rng1.sample(CosineWeightedHemisphere);
+        let b: Vec3 = rng2.sample(CosineWeightedHemisphere);
+        assert_eq!(a, Vec3A::from(b));
+        let a = rng1.sample(Uniform::new(Vec3A::ZERO, Vec3A::ONE));
+        let b = rng2.sample(Uniform::new(Vec3::ZERO, Vec3::ONE));
+        assert_eq!(a, Vec3A::from(b));
+    }
 }
 
 mod f64 {
+    use super::{CosineWeightedHemisphere, UnitBall, UnitCircle, UnitDisc, UnitSphere};
+    use crate::f64::math;
     use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
     use core::f64::consts::PI;
+    use rand::{
+        distributions::{
+            uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler},
+            Distribution, Standard,
+        },
+        Rng,
+    };
+
+    impl_float_types!(
+        f64,
+        DMat2,
+        DMat3,
+        DMat4,
+        DQuat,
+        DVec2,
+        DVec3,
+        DVec4,
+        UniformDVec2,
+        UniformDVec3,
+        UniformDVec4
+    );
+}
+
+mod i8 {
+    use crate::{I8Vec2, I8Vec3, I8Vec4};
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    impl_vec_types!(i8, I8Vec2, I8Vec3, I8Vec4);
+}
+
+mod u8 {
+    use crate::{U8Vec2, U8Vec3, U8Vec4};
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    impl_vec_types!(u8, U8Vec2, U8Vec3, U8Vec4);
+}
+
+mod i16 {
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    impl_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+}
+
+mod u16 {
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
     use rand::{
         distributions::{Distribution, Standard},
         Rng,
     };
 
-    impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+    impl_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
 }
 
 mod i32 {
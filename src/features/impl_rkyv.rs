@@ -90,22 +90,60 @@ mod f64 {
     impl_rkyv!(DVec4);
 }
 
+mod i8 {
+    use crate::{I8Vec2, I8Vec3, I8Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(I8Vec2);
+    impl_rkyv!(I8Vec3);
+    impl_rkyv!(I8Vec4);
+}
+
+mod u8 {
+    use crate::{U8Vec2, U8Vec3, U8Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(U8Vec2);
+    impl_rkyv!(U8Vec3);
+    impl_rkyv!(U8Vec4);
+}
+
+mod i16 {
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(I16Vec2);
+    impl_rkyv!(I16Vec3);
+    impl_rkyv!(I16Vec4);
+}
+
+mod u16 {
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(U16Vec2);
+    impl_rkyv!(U16Vec3);
+    impl_rkyv!(U16Vec4);
+}
+
 mod i32 {
-    use crate::{IVec2, IVec3, IVec4};
+    use crate::{IVec2, IVec3, IVec4, IVec4A};
     use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
 
     impl_rkyv!(IVec2);
     impl_rkyv!(IVec3);
     impl_rkyv!(IVec4);
+    impl_rkyv!(IVec4A);
 }
 
 mod u32 {
-    use crate::{UVec2, UVec3, UVec4};
+    use crate::{UVec2, UVec3, UVec4, UVec4A};
     use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
 
     impl_rkyv!(UVec2);
     impl_rkyv!(UVec3);
     impl_rkyv!(UVec4);
+    impl_rkyv!(UVec4A);
 }
 
 #[cfg(test)]
@@ -175,14 +213,36 @@ mod test {
         test_archive(&DVec3::new(1.0, 2.0, 3.0));
         test_archive(&DVec4::new(1.0, 2.0, 3.0, 4.0));
 
-        use crate::{IVec2, IVec3, IVec4};
+        use crate::{I8Vec2, I8Vec3, I8Vec4};
+        test_archive(&I8Vec2::new(-1, 2));
+        test_archive(&I8Vec3::new(-1, 2, 3));
+        test_archive(&I8Vec4::new(-1, 2, 3, 4));
+
+        use crate::{U8Vec2, U8Vec3, U8Vec4};
+        test_archive(&U8Vec2::new(1, 2));
+        test_archive(&U8Vec3::new(1, 2, 3));
+        test_archive(&U8Vec4::new(1, 2, 3, 4));
+
+        use crate::{I16Vec2, I16Vec3, I16Vec4};
+        test_archive(&I16Vec2::new(-1, 2));
+        test_archive(&I16Vec3::new(-1, 2, 3));
+        test_archive(&I16Vec4::new(-1, 2, 3, 4));
+
+        use crate::{U16Vec2, U16Vec3, U16Vec4};
+        test_archive(&U16Vec2::new(1, 2));
+        test_archive(&U16Vec3::new(1, 2, 3));
+        test_archive(&U16Vec4::new(1, 2, 3, 4));
+
+        use crate::{IVec2, IVec3, IVec4, IVec4A};
         test_archive(&IVec2::new(-1, 2));
         test_archive(&IVec3::new(-1, 2, 3));
         test_archive(&IVec4::new(-1, 2, 3, 4));
+        test_archive(&IVec4A::new(-1, 2, 3, 4));
 
-        use crate::{UVec2, UVec3, UVec4};
+        use crate::{UVec2, UVec3, UVec4, UVec4A};
         test_archive(&UVec2::new(1, 2));
         test_archive(&UVec3::new(1, 2, 3));
         test_archive(&UVec4::new(1, 2, 3, 4));
+        test_archive(&UVec4A::new(1, 2, 3, 4));
     }
 }
@@ -135,6 +135,9 @@ macro_rules! impl_serde_vec3 {
 
 macro_rules! impl_serde_vec4 {
     ($t:ty, $vec4:ident) => {
+        impl_serde_vec4!($t, $vec4, test_vec4_serde);
+    };
+    ($t:ty, $vec4:ident, $test_name:ident) => {
         impl Serialize for $vec4 {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
@@ -188,7 +191,7 @@ macro_rules! impl_serde_vec4 {
         }
 
         #[test]
-        fn test_vec4_serde() {
+        fn $test_name() {
             let a = $vec4::new(V1, V2, V3, V4);
             let serialized = serde_json::to_string(&a).unwrap();
             assert_eq!(SX4, serialized);
@@ -520,6 +523,74 @@ macro_rules! impl_serde_mat4 {
     };
 }
 
+macro_rules! impl_serde_mat_cxr {
+    ($t:ty, $mat:ident, $size:literal, $test_name:ident) => {
+        impl Serialize for $mat {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_tuple_struct(stringify!($mat), $size)?;
+                for f in self.to_cols_array().iter() {
+                    state.serialize_field(f)?;
+                }
+                state.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $mat {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct MatVisitor;
+
+                impl<'de> Visitor<'de> for MatVisitor {
+                    type Value = $mat;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(concat!("struct ", stringify!($mat)))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$mat, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let mut f = [0.0; $size];
+                        for (i, v) in f.iter_mut().enumerate() {
+                            *v = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                        }
+                        Ok($mat::from_cols_array(&f))
+                    }
+                }
+
+                deserializer.deserialize_tuple_struct(stringify!($mat), $size, MatVisitor)
+            }
+        }
+
+        #[test]
+        fn $test_name() {
+            let mut f = [0.0; $size];
+            for (i, v) in f.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let a = $mat::from_cols_array(&f);
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(serialized, serde_json::to_string(&f).unwrap());
+            let deserialized = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = serde_json::from_str::<$mat>("[]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$mat>("[1.0]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$mat>("[1.0,2.0,3.0,4.0,5.0]");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
 macro_rules! impl_serde_affine2 {
     ($t:ty, $affine2:ident) => {
         impl Serialize for $affine2 {
@@ -726,6 +797,38 @@ mod test_f64 {
     pub const V4: f64 = 4.0;
 }
 
+#[cfg(test)]
+mod test_i8 {
+    pub const V1: i8 = 1;
+    pub const V2: i8 = 2;
+    pub const V3: i8 = 3;
+    pub const V4: i8 = 4;
+}
+
+#[cfg(test)]
+mod test_u8 {
+    pub const V1: u8 = 1;
+    pub const V2: u8 = 2;
+    pub const V3: u8 = 3;
+    pub const V4: u8 = 4;
+}
+
+#[cfg(test)]
+mod test_i16 {
+    pub const V1: i16 = 1;
+    pub const V2: i16 = 2;
+    pub const V3: i16 = 3;
+    pub const V4: i16 = 4;
+}
+
+#[cfg(test)]
+mod test_u16 {
+    pub const V1: u16 = 1;
+    pub const V2: u16 = 2;
+    pub const V3: u16 = 3;
+    pub const V4: u16 = 4;
+}
+
 #[cfg(test)]
 mod test_i32 {
     pub const V1: i32 = 1;
@@ -942,7 +1045,10 @@ mod f32 {
     use super::test_f32::*;
     #[cfg(test)]
     use super::test_float::*;
-    use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    use crate::{
+        Affine2, Affine3A, Mat2, Mat2x3, Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3, Quat, Vec2,
+        Vec3, Vec3A, Vec4,
+    };
     use core::fmt;
     use serde::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -952,6 +1058,10 @@ mod f32 {
     impl_serde_float_types!(f32, Affine2, Affine3A, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
     impl_serde_mat3!(f32, Mat3A, test_mat3a_serde);
     impl_serde_vec3!(f32, Vec3A, test_vec3a_serde);
+    impl_serde_mat_cxr!(f32, Mat2x3, 6, test_mat2x3_serde);
+    impl_serde_mat_cxr!(f32, Mat3x2, 6, test_mat3x2_serde);
+    impl_serde_mat_cxr!(f32, Mat3x4, 12, test_mat3x4_serde);
+    impl_serde_mat_cxr!(f32, Mat4x3, 12, test_mat4x3_serde);
 }
 
 mod f64 {
@@ -959,7 +1069,10 @@ mod f64 {
     use super::test_f64::*;
     #[cfg(test)]
     use super::test_float::*;
-    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+    use crate::{
+        DAffine2, DAffine3, DMat2, DMat2x3, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x3, DQuat, DVec2,
+        DVec3, DVec4,
+    };
     use core::fmt;
     use serde::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -969,6 +1082,165 @@ mod f64 {
     impl_serde_float_types!(
         f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
     );
+    impl_serde_mat_cxr!(f64, DMat2x3, 6, test_dmat2x3_serde);
+    impl_serde_mat_cxr!(f64, DMat3x2, 6, test_dmat3x2_serde);
+    impl_serde_mat_cxr!(f64, DMat3x4, 12, test_dmat3x4_serde);
+    impl_serde_mat_cxr!(f64, DMat4x3, 12, test_dmat4x3_serde);
+}
+
+mod f16 {
+    #[cfg(test)]
+    use super::test_f32::*;
+    #[cfg(test)]
+    use super::test_float::*;
+    use crate::{HVec2, HVec3, HVec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    // Half precision vectors are serialized as their `f32` values, every `f16` value is exactly
+    // representable as an `f32` so this round trips without loss.
+    macro_rules! impl_serde_hvec {
+        ($hvec:ident, $dim:literal, $test_name:ident, $sx:ident, [$($v:ident),+], [$($bad:ident),+]) => {
+            impl Serialize for $hvec {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let mut state = serializer.serialize_tuple_struct(stringify!($hvec), $dim)?;
+                    for v in self.to_array() {
+                        state.serialize_field(&v)?;
+                    }
+                    state.end()
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $hvec {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    struct HVecVisitor;
+
+                    impl<'de> Visitor<'de> for HVecVisitor {
+                        type Value = $hvec;
+
+                        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                            formatter.write_str(concat!("struct ", stringify!($hvec)))
+                        }
+
+                        fn visit_seq<V>(self, mut seq: V) -> Result<$hvec, V::Error>
+                        where
+                            V: SeqAccess<'de>,
+                        {
+                            let mut a = [0.0_f32; $dim];
+                            for (i, v) in a.iter_mut().enumerate() {
+                                *v = seq
+                                    .next_element()?
+                                    .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                            }
+                            Ok($hvec::from_array(a))
+                        }
+                    }
+
+                    deserializer.deserialize_tuple_struct(stringify!($hvec), $dim, HVecVisitor)
+                }
+            }
+
+            #[test]
+            fn $test_name() {
+                let a = $hvec::new($($v),+);
+                let serialized = serde_json::to_string(&a).unwrap();
+                assert_eq!($sx, serialized);
+                let deserialized = serde_json::from_str(&serialized).unwrap();
+                assert_eq!(a, deserialized);
+                $(
+                    let deserialized = serde_json::from_str::<$hvec>($bad);
+                    assert!(deserialized.is_err());
+                )+
+            }
+        };
+    }
+
+    impl_serde_hvec!(HVec2, 2, test_hvec2_serde, SX2, [V1, V2], [SX0, SX1, SX3]);
+    impl_serde_hvec!(
+        HVec3,
+        3,
+        test_hvec3_serde,
+        SX3,
+        [V1, V2, V3],
+        [SX0, SX1, SX2, SX4]
+    );
+    impl_serde_hvec!(
+        HVec4,
+        4,
+        test_hvec4_serde,
+        SX4,
+        [V1, V2, V3, V4],
+        [SX0, SX1, SX2, SX3, SX5]
+    );
+}
+
+mod i8 {
+    #[cfg(test)]
+    use super::test_i8::*;
+    #[cfg(test)]
+    use super::test_int::*;
+    use crate::{I8Vec2, I8Vec3, I8Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(i8, I8Vec2, I8Vec3, I8Vec4);
+}
+
+mod u8 {
+    #[cfg(test)]
+    use super::test_int::*;
+    #[cfg(test)]
+    use super::test_u8::*;
+    use crate::{U8Vec2, U8Vec3, U8Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(u8, U8Vec2, U8Vec3, U8Vec4);
+}
+
+mod i16 {
+    #[cfg(test)]
+    use super::test_i16::*;
+    #[cfg(test)]
+    use super::test_int::*;
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+}
+
+mod u16 {
+    #[cfg(test)]
+    use super::test_int::*;
+    #[cfg(test)]
+    use super::test_u16::*;
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
 }
 
 mod i32 {
@@ -976,7 +1248,7 @@ mod i32 {
     use super::test_i32::*;
     #[cfg(test)]
     use super::test_int::*;
-    use crate::{IVec2, IVec3, IVec4};
+    use crate::{IVec2, IVec3, IVec4, IVec4A};
     use core::fmt;
     use serde::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -984,6 +1256,8 @@ mod i32 {
     };
 
     impl_serde_vec_types!(i32, IVec2, IVec3, IVec4);
+
+    impl_serde_vec4!(i32, IVec4A, test_ivec4a_serde);
 }
 
 mod u32 {
@@ -991,7 +1265,7 @@ mod u32 {
     use super::test_int::*;
     #[cfg(test)]
     use super::test_u32::*;
-    use crate::{UVec2, UVec3, UVec4};
+    use crate::{UVec2, UVec3, UVec4, UVec4A};
     use core::fmt;
     use serde::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -999,33 +1273,56 @@ mod u32 {
     };
 
     impl_serde_vec_types!(u32, UVec2, UVec3, UVec4);
+
+    impl_serde_vec4!(u32, UVec4A, test_uvec4a_serde);
 }
 
 mod euler {
     use crate::EulerRot;
 
+    const VARIANTS: &[&str] = &[
+        "ZYX", "ZXY", "YXZ", "YZX", "XYZ", "XZY", "ZYZ", "ZXZ", "YXY", "YZY", "XYX", "XZX",
+        "ZYXEx", "ZXYEx", "YXZEx", "YZXEx", "XYZEx", "XZYEx", "ZYZEx", "ZXZEx", "YXYEx", "YZYEx",
+        "XYXEx", "XZXEx",
+    ];
+
+    /// The variants of `EulerRot` in declaration order, matching `VARIANTS`.
+    const VALUES: [EulerRot; 24] = [
+        EulerRot::ZYX,
+        EulerRot::ZXY,
+        EulerRot::YXZ,
+        EulerRot::YZX,
+        EulerRot::XYZ,
+        EulerRot::XZY,
+        EulerRot::ZYZ,
+        EulerRot::ZXZ,
+        EulerRot::YXY,
+        EulerRot::YZY,
+        EulerRot::XYX,
+        EulerRot::XZX,
+        EulerRot::ZYXEx,
+        EulerRot::ZXYEx,
+        EulerRot::YXZEx,
+        EulerRot::YZXEx,
+        EulerRot::XYZEx,
+        EulerRot::XZYEx,
+        EulerRot::ZYZEx,
+        EulerRot::ZXZEx,
+        EulerRot::YXYEx,
+        EulerRot::YZYEx,
+        EulerRot::XYXEx,
+        EulerRot::XZXEx,
+    ];
+
     impl serde::Serialize for EulerRot {
         fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-            match *self {
-                EulerRot::ZYX => {
-                    serde::Serializer::serialize_unit_variant(serializer, "EulerRot", 0u32, "ZYX")
-                }
-                EulerRot::ZXY => {
-                    serde::Serializer::serialize_unit_variant(serializer, "EulerRot", 1u32, "ZXY")
-                }
-                EulerRot::YXZ => {
-                    serde::Serializer::serialize_unit_variant(serializer, "EulerRot", 2u32, "YXZ")
-                }
-                EulerRot::YZX => {
-                    serde::Serializer::serialize_unit_variant(serializer, "EulerRot", 3u32, "YZX")
-                }
-                EulerRot::XYZ => {
-                    serde::Serializer::serialize_unit_variant(serializer, "EulerRot", 4u32, "XYZ")
-                }
-                EulerRot::XZY => {
-                    serde::Serializer::serialize_unit_variant(serializer, "EulerRot", 5u32, "XZY")
-                }
-            }
+            let index = *self as usize;
+            serde::Serializer::serialize_unit_variant(
+                serializer,
+                "EulerRot",
+                index as u32,
+                VARIANTS[index],
+            )
         }
     }
 
@@ -1034,15 +1331,7 @@ mod euler {
         where
             D: serde::Deserializer<'de>,
         {
-            #[allow(clippy::upper_case_acronyms)]
-            enum Field {
-                ZYX,
-                ZXY,
-                YXZ,
-                YZX,
-                XYZ,
-                XZY,
-            }
+            struct Field(EulerRot);
             struct FieldVisitor;
 
             impl<'de> serde::de::Visitor<'de> for FieldVisitor {
@@ -1054,45 +1343,33 @@ mod euler {
                 where
                     E: serde::de::Error,
                 {
-                    match value {
-                        0u64 => Ok(Field::ZYX),
-                        1u64 => Ok(Field::ZXY),
-                        2u64 => Ok(Field::YXZ),
-                        3u64 => Ok(Field::YZX),
-                        4u64 => Ok(Field::XYZ),
-                        5u64 => Ok(Field::XZY),
-                        _ => Err(serde::de::Error::invalid_value(
-                            serde::de::Unexpected::Unsigned(value),
-                            &"variant index 0 <= i < 6",
-                        )),
-                    }
+                    VALUES
+                        .get(value as usize)
+                        .map(|&euler| Field(euler))
+                        .ok_or_else(|| {
+                            serde::de::Error::invalid_value(
+                                serde::de::Unexpected::Unsigned(value),
+                                &"variant index 0 <= i < 24",
+                            )
+                        })
                 }
                 fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
                 where
                     E: serde::de::Error,
                 {
-                    match value {
-                        "ZYX" => Ok(Field::ZYX),
-                        "ZXY" => Ok(Field::ZXY),
-                        "YXZ" => Ok(Field::YXZ),
-                        "YZX" => Ok(Field::YZX),
-                        "XYZ" => Ok(Field::XYZ),
-                        "XZY" => Ok(Field::XZY),
-                        _ => Err(serde::de::Error::unknown_variant(value, VARIANTS)),
-                    }
+                    VARIANTS
+                        .iter()
+                        .position(|&name| name == value)
+                        .map(|index| Field(VALUES[index]))
+                        .ok_or_else(|| serde::de::Error::unknown_variant(value, VARIANTS))
                 }
                 fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
                 where
                     E: serde::de::Error,
                 {
-                    match value {
-                        b"ZYX" => Ok(Field::ZYX),
-                        b"ZXY" => Ok(Field::ZXY),
-                        b"YXZ" => Ok(Field::YXZ),
-                        b"YZX" => Ok(Field::YZX),
-                        b"XYZ" => Ok(Field::XYZ),
-                        b"XZY" => Ok(Field::XZY),
-                        _ => {
+                    match VARIANTS.iter().position(|name| name.as_bytes() == value) {
+                        Some(index) => Ok(Field(VALUES[index])),
+                        None => {
                             #[cfg(feature = "std")]
                             let value = &String::from_utf8_lossy(value);
                             #[cfg(not(feature = "std"))]
@@ -1128,35 +1405,11 @@ mod euler {
                 where
                     A: serde::de::EnumAccess<'de>,
                 {
-                    match serde::de::EnumAccess::variant(data)? {
-                        (Field::ZYX, variant) => {
-                            serde::de::VariantAccess::unit_variant(variant)?;
-                            Ok(EulerRot::ZYX)
-                        }
-                        (Field::ZXY, variant) => {
-                            serde::de::VariantAccess::unit_variant(variant)?;
-                            Ok(EulerRot::ZXY)
-                        }
-                        (Field::YXZ, variant) => {
-                            serde::de::VariantAccess::unit_variant(variant)?;
-                            Ok(EulerRot::YXZ)
-                        }
-                        (Field::YZX, variant) => {
-                            serde::de::VariantAccess::unit_variant(variant)?;
-                            Ok(EulerRot::YZX)
-                        }
-                        (Field::XYZ, variant) => {
-                            serde::de::VariantAccess::unit_variant(variant)?;
-                            Ok(EulerRot::XYZ)
-                        }
-                        (Field::XZY, variant) => {
-                            serde::de::VariantAccess::unit_variant(variant)?;
-                            Ok(EulerRot::XZY)
-                        }
-                    }
+                    let (Field(euler), variant) = serde::de::EnumAccess::variant(data)?;
+                    serde::de::VariantAccess::unit_variant(variant)?;
+                    Ok(euler)
                 }
             }
-            const VARIANTS: &[&str] = &["ZYX", "ZXY", "YXZ", "YZX", "XYZ", "XZY"];
             serde::Deserializer::deserialize_enum(
                 deserializer,
                 "EulerRot",
@@ -1176,5 +1429,39 @@ mod euler {
         assert_eq!("\"XYZ\"", serialized);
         let deserialized = serde_json::from_str(&serialized).unwrap();
         assert_eq!(a, deserialized);
+
+        let expected = [
+            ("\"ZYX\"", EulerRot::ZYX),
+            ("\"ZXY\"", EulerRot::ZXY),
+            ("\"YXZ\"", EulerRot::YXZ),
+            ("\"YZX\"", EulerRot::YZX),
+            ("\"XYZ\"", EulerRot::XYZ),
+            ("\"XZY\"", EulerRot::XZY),
+            ("\"ZYZ\"", EulerRot::ZYZ),
+            ("\"ZXZ\"", EulerRot::ZXZ),
+            ("\"YXY\"", EulerRot::YXY),
+            ("\"YZY\"", EulerRot::YZY),
+            ("\"XYX\"", EulerRot::XYX),
+            ("\"XZX\"", EulerRot::XZX),
+            ("\"ZYXEx\"", EulerRot::ZYXEx),
+            ("\"ZXYEx\"", EulerRot::ZXYEx),
+            ("\"YXZEx\"", EulerRot::YXZEx),
+            ("\"YZXEx\"", EulerRot::YZXEx),
+            ("\"XYZEx\"", EulerRot::XYZEx),
+            ("\"XZYEx\"", EulerRot::XZYEx),
+            ("\"ZYZEx\"", EulerRot::ZYZEx),
+            ("\"ZXZEx\"", EulerRot::ZXZEx),
+            ("\"YXYEx\"", EulerRot::YXYEx),
+            ("\"YZYEx\"", EulerRot::YZYEx),
+            ("\"XYXEx\"", EulerRot::XYXEx),
+            ("\"XZXEx\"", EulerRot::XZXEx),
+        ];
+        for (name, euler) in expected {
+            let serialized = serde_json::to_string(&euler).unwrap();
+            assert_eq!(name, serialized);
+            let deserialized: EulerRot = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(euler, deserialized);
+        }
+        assert!(serde_json::from_str::<EulerRot>("\"XYZW\"").is_err());
     }
 }
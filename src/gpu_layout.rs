@@ -0,0 +1,488 @@
+/*!
+std140 and std430 GPU buffer layouts.
+
+The memory layout of some `glam` types does not match the layout used by GLSL uniform (std140)
+and storage (std430) buffers. For example [`Mat3`] is 36 bytes, while a std140 or std430 `mat3`
+is 48 bytes, with each column padded to 16 bytes. Copying a [`Mat3`] into a uniform buffer will
+silently misplace every element after the first column.
+
+The [`GpuLayout`] trait describes the std140 and std430 size and alignment of the GLSL type
+matching each `glam` type, which can be used to compute or validate member offsets.
+
+Matrix types whose columns need padding or extra alignment have wrapper types such as
+[`Std140Mat3`] and [`Std430Mat3`], which have exactly the size and alignment required by the
+layout and can be converted to and from the `glam` type with [`From`].
+
+Vector types do not have wrapper types, note that a std140 or std430 `vec3` is 16 byte aligned
+but only 12 bytes in size, so a following scalar can be placed in its last 4 bytes. Use
+[`Vec3A`] or [`Vec4`] when a `vec3` is followed by another vector or matrix.
+
+GLSL has no affine transform type, so [`Affine2`] and [`Affine3A`] are laid out like the
+`mat3x2` and `mat4x3` with the same columns, and can be converted to the matching padded
+matrix types. The same applies to [`DAffine2`] and [`DAffine3`].
+
+A GLSL `bool` is 4 bytes in a buffer, so bool vectors such as [`BVec3`] describe the layout of
+a GLSL `bvec3` but cannot be copied into a buffer directly. Store them as a [`UVec3`] instead,
+e.g. using [`UVec3::select()`].
+
+Types without a GLSL counterpart do not implement [`GpuLayout`]. These are the 8, 16 and 64-bit
+integer vectors and the half precision vectors, which need GLSL extensions, and the geometric
+types such as [`Aabb3`](crate::Aabb3) and [`Isometry3`](crate::Isometry3).
+*/
+
+use crate::{
+    Affine2, Affine3A, BVec2, BVec3, BVec3A, BVec4, DAffine2, DAffine3, DMat2, DMat2x3, DMat3,
+    DMat3x2, DMat3x4, DMat4, DMat4x3, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, IVec4A,
+    Mat2, Mat2x3, Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3, Quat, UVec2, UVec3, UVec4, UVec4A,
+    Vec2, Vec3, Vec3A, Vec4,
+};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// Rounds `size` up to the next multiple of `align`.
+const fn round_up(size: usize, align: usize) -> usize {
+    (size + align - 1) / align * align
+}
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// The size and alignment of a type in the std140 and std430 GPU buffer layouts.
+///
+/// Sizes are those of the matching GLSL type, which may be smaller than the size of the Rust
+/// type. For example the std140 size of [`Vec3A`] is 12 bytes, the size of a GLSL `vec3`.
+///
+/// Matrices are column major, as in `glam`.
+pub trait GpuLayout {
+    /// The size in bytes of the type in the std140 layout.
+    const STD140_SIZE: usize;
+
+    /// The base alignment in bytes of the type in the std140 layout.
+    const STD140_ALIGN: usize;
+
+    /// The size in bytes of the type in the std430 layout.
+    const STD430_SIZE: usize;
+
+    /// The base alignment in bytes of the type in the std430 layout.
+    const STD430_ALIGN: usize;
+
+    /// The stride in bytes between elements of an array of the type in the std140 layout.
+    ///
+    /// Array elements are rounded up to the alignment of a `vec4`.
+    const STD140_ARRAY_STRIDE: usize = round_up(Self::STD140_SIZE, max(Self::STD140_ALIGN, 16));
+
+    /// The stride in bytes between elements of an array of the type in the std430 layout.
+    const STD430_ARRAY_STRIDE: usize = round_up(Self::STD430_SIZE, Self::STD430_ALIGN);
+}
+
+macro_rules! impl_gpu_layout_scalar {
+    ($($t:ty),+) => {
+        $(
+            impl GpuLayout for $t {
+                const STD140_SIZE: usize = core::mem::size_of::<$t>();
+                const STD140_ALIGN: usize = core::mem::size_of::<$t>();
+                const STD430_SIZE: usize = core::mem::size_of::<$t>();
+                const STD430_ALIGN: usize = core::mem::size_of::<$t>();
+            }
+        )+
+    };
+}
+
+macro_rules! impl_gpu_layout_vec {
+    ($t:ident, $len:expr, $($vec:ty),+) => {
+        $(
+            impl GpuLayout for $vec {
+                const STD140_SIZE: usize = $len * <$t as GpuLayout>::STD140_SIZE;
+                // three component vectors are aligned like four component vectors
+                const STD140_ALIGN: usize =
+                    (if $len == 3 { 4 } else { $len }) * <$t as GpuLayout>::STD140_ALIGN;
+                const STD430_SIZE: usize = Self::STD140_SIZE;
+                const STD430_ALIGN: usize = Self::STD140_ALIGN;
+            }
+        )+
+    };
+}
+
+macro_rules! impl_gpu_layout_mat {
+    ($cols:expr, $col:ty, $($mat:ty),+) => {
+        $(
+            // matrices are laid out like an array of their column vectors
+            impl GpuLayout for $mat {
+                const STD140_SIZE: usize = $cols * <$col as GpuLayout>::STD140_ARRAY_STRIDE;
+                const STD140_ALIGN: usize = max(<$col as GpuLayout>::STD140_ALIGN, 16);
+                const STD430_SIZE: usize = $cols * <$col as GpuLayout>::STD430_ARRAY_STRIDE;
+                const STD430_ALIGN: usize = <$col as GpuLayout>::STD430_ALIGN;
+            }
+        )+
+    };
+}
+
+impl_gpu_layout_scalar!(f32, f64, i32, u32);
+
+// a GLSL `bool` is stored as a 32-bit value in buffers
+impl GpuLayout for bool {
+    const STD140_SIZE: usize = 4;
+    const STD140_ALIGN: usize = 4;
+    const STD430_SIZE: usize = 4;
+    const STD430_ALIGN: usize = 4;
+}
+
+impl_gpu_layout_vec!(f32, 2, Vec2);
+impl_gpu_layout_vec!(f32, 3, Vec3, Vec3A);
+impl_gpu_layout_vec!(f32, 4, Vec4, Quat);
+impl_gpu_layout_vec!(f64, 2, DVec2);
+impl_gpu_layout_vec!(f64, 3, DVec3);
+impl_gpu_layout_vec!(f64, 4, DVec4, DQuat);
+impl_gpu_layout_vec!(i32, 2, IVec2);
+impl_gpu_layout_vec!(i32, 3, IVec3);
+impl_gpu_layout_vec!(i32, 4, IVec4, IVec4A);
+impl_gpu_layout_vec!(u32, 2, UVec2);
+impl_gpu_layout_vec!(u32, 3, UVec3);
+impl_gpu_layout_vec!(u32, 4, UVec4, UVec4A);
+impl_gpu_layout_vec!(bool, 2, BVec2);
+impl_gpu_layout_vec!(bool, 3, BVec3, BVec3A);
+impl_gpu_layout_vec!(bool, 4, BVec4);
+#[cfg(not(feature = "scalar-math"))]
+impl_gpu_layout_vec!(bool, 4, crate::BVec4A);
+
+impl_gpu_layout_mat!(2, Vec2, Mat2);
+impl_gpu_layout_mat!(2, Vec3, Mat2x3);
+impl_gpu_layout_mat!(3, Vec2, Mat3x2, Affine2);
+impl_gpu_layout_mat!(3, Vec3, Mat3, Mat3A);
+impl_gpu_layout_mat!(3, Vec4, Mat3x4);
+impl_gpu_layout_mat!(4, Vec3, Mat4x3, Affine3A);
+impl_gpu_layout_mat!(4, Vec4, Mat4);
+impl_gpu_layout_mat!(2, DVec2, DMat2);
+impl_gpu_layout_mat!(2, DVec3, DMat2x3);
+impl_gpu_layout_mat!(3, DVec2, DMat3x2, DAffine2);
+impl_gpu_layout_mat!(3, DVec3, DMat3);
+impl_gpu_layout_mat!(3, DVec4, DMat3x4);
+impl_gpu_layout_mat!(4, DVec3, DMat4x3, DAffine3);
+impl_gpu_layout_mat!(4, DVec4, DMat4);
+
+macro_rules! impl_padded_mat {
+    ($(#[$meta:meta])* $padded:ident, $mat:ident, $t:ty, $col:ident, [$($axis:ident),+], $len:literal, $stride:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, PartialEq)]
+        pub struct $padded {
+            cols: [[$t; $stride]; [$(stringify!($axis)),+].len()],
+        }
+
+        impl From<$mat> for $padded {
+            #[inline]
+            fn from(m: $mat) -> Self {
+                Self {
+                    cols: [$({
+                        let mut col = [0.0; $stride];
+                        col[..$len].copy_from_slice(&m.$axis.to_array());
+                        col
+                    }),+],
+                }
+            }
+        }
+
+        impl From<$padded> for $mat {
+            #[inline]
+            fn from(m: $padded) -> Self {
+                let [$($axis),+] = m.cols;
+                Self::from_cols($($col::from_slice(&$axis[..$len])),+)
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $padded {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt.debug_tuple(stringify!($padded))
+                    .field(&$mat::from(*self))
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_padded_mat!(
+    /// A [`Mat2`] in the std140 layout of a GLSL `mat2`, with each column padded to 16 bytes.
+    #[repr(C, align(16))]
+    Std140Mat2, Mat2, f32, Vec2, [x_axis, y_axis], 2, 4
+);
+impl_padded_mat!(
+    /// A [`Mat2`] in the std430 layout of a GLSL `mat2`, aligned to 8 bytes.
+    #[repr(C, align(8))]
+    Std430Mat2, Mat2, f32, Vec2, [x_axis, y_axis], 2, 2
+);
+impl_padded_mat!(
+    /// A [`Mat2x3`] in the std140 layout of a GLSL `mat2x3`, with each column padded to 16 bytes.
+    #[repr(C, align(16))]
+    Std140Mat2x3, Mat2x3, f32, Vec3, [x_axis, y_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`Mat2x3`] in the std430 layout of a GLSL `mat2x3`, with each column padded to 16 bytes.
+    #[repr(C, align(16))]
+    Std430Mat2x3, Mat2x3, f32, Vec3, [x_axis, y_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`Mat3x2`] in the std140 layout of a GLSL `mat3x2`, with each column padded to 16 bytes.
+    #[repr(C, align(16))]
+    Std140Mat3x2, Mat3x2, f32, Vec2, [x_axis, y_axis, z_axis], 2, 4
+);
+impl_padded_mat!(
+    /// A [`Mat3x2`] in the std430 layout of a GLSL `mat3x2`, aligned to 8 bytes.
+    #[repr(C, align(8))]
+    Std430Mat3x2, Mat3x2, f32, Vec2, [x_axis, y_axis, z_axis], 2, 2
+);
+impl_padded_mat!(
+    /// A [`Mat3`] in the std140 layout of a GLSL `mat3`, with each column padded to 16 bytes.
+    ///
+    /// This has the same layout as [`Mat3A`].
+    #[repr(C, align(16))]
+    Std140Mat3, Mat3, f32, Vec3, [x_axis, y_axis, z_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`Mat3`] in the std430 layout of a GLSL `mat3`, with each column padded to 16 bytes.
+    ///
+    /// This has the same layout as [`Mat3A`].
+    #[repr(C, align(16))]
+    Std430Mat3, Mat3, f32, Vec3, [x_axis, y_axis, z_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`Mat3x4`] in the std140 layout of a GLSL `mat3x4`, aligned to 16 bytes.
+    #[repr(C, align(16))]
+    Std140Mat3x4, Mat3x4, f32, Vec4, [x_axis, y_axis, z_axis], 4, 4
+);
+impl_padded_mat!(
+    /// A [`Mat3x4`] in the std430 layout of a GLSL `mat3x4`, aligned to 16 bytes.
+    #[repr(C, align(16))]
+    Std430Mat3x4, Mat3x4, f32, Vec4, [x_axis, y_axis, z_axis], 4, 4
+);
+impl_padded_mat!(
+    /// A [`Mat4x3`] in the std140 layout of a GLSL `mat4x3`, with each column padded to 16 bytes.
+    #[repr(C, align(16))]
+    Std140Mat4x3, Mat4x3, f32, Vec3, [x_axis, y_axis, z_axis, w_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`Mat4x3`] in the std430 layout of a GLSL `mat4x3`, with each column padded to 16 bytes.
+    #[repr(C, align(16))]
+    Std430Mat4x3, Mat4x3, f32, Vec3, [x_axis, y_axis, z_axis, w_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`Mat4`] in the std140 layout of a GLSL `mat4`, aligned to 16 bytes.
+    #[repr(C, align(16))]
+    Std140Mat4, Mat4, f32, Vec4, [x_axis, y_axis, z_axis, w_axis], 4, 4
+);
+impl_padded_mat!(
+    /// A [`Mat4`] in the std430 layout of a GLSL `mat4`, aligned to 16 bytes.
+    #[repr(C, align(16))]
+    Std430Mat4, Mat4, f32, Vec4, [x_axis, y_axis, z_axis, w_axis], 4, 4
+);
+
+impl_padded_mat!(
+    /// A [`DMat2`] in the std140 layout of a GLSL `dmat2`, aligned to 16 bytes.
+    #[repr(C, align(16))]
+    Std140DMat2, DMat2, f64, DVec2, [x_axis, y_axis], 2, 2
+);
+impl_padded_mat!(
+    /// A [`DMat2`] in the std430 layout of a GLSL `dmat2`, aligned to 16 bytes.
+    #[repr(C, align(16))]
+    Std430DMat2, DMat2, f64, DVec2, [x_axis, y_axis], 2, 2
+);
+impl_padded_mat!(
+    /// A [`DMat2x3`] in the std140 layout of a GLSL `dmat2x3`, with each column padded to 32
+    /// bytes.
+    #[repr(C, align(32))]
+    Std140DMat2x3, DMat2x3, f64, DVec3, [x_axis, y_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`DMat2x3`] in the std430 layout of a GLSL `dmat2x3`, with each column padded to 32
+    /// bytes.
+    #[repr(C, align(32))]
+    Std430DMat2x3, DMat2x3, f64, DVec3, [x_axis, y_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`DMat3x2`] in the std140 layout of a GLSL `dmat3x2`, aligned to 16 bytes.
+    #[repr(C, align(16))]
+    Std140DMat3x2, DMat3x2, f64, DVec2, [x_axis, y_axis, z_axis], 2, 2
+);
+impl_padded_mat!(
+    /// A [`DMat3x2`] in the std430 layout of a GLSL `dmat3x2`, aligned to 16 bytes.
+    #[repr(C, align(16))]
+    Std430DMat3x2, DMat3x2, f64, DVec2, [x_axis, y_axis, z_axis], 2, 2
+);
+impl_padded_mat!(
+    /// A [`DMat3`] in the std140 layout of a GLSL `dmat3`, with each column padded to 32 bytes.
+    #[repr(C, align(32))]
+    Std140DMat3, DMat3, f64, DVec3, [x_axis, y_axis, z_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`DMat3`] in the std430 layout of a GLSL `dmat3`, with each column padded to 32 bytes.
+    #[repr(C, align(32))]
+    Std430DMat3, DMat3, f64, DVec3, [x_axis, y_axis, z_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`DMat3x4`] in the std140 layout of a GLSL `dmat3x4`, aligned to 32 bytes.
+    #[repr(C, align(32))]
+    Std140DMat3x4, DMat3x4, f64, DVec4, [x_axis, y_axis, z_axis], 4, 4
+);
+impl_padded_mat!(
+    /// A [`DMat3x4`] in the std430 layout of a GLSL `dmat3x4`, aligned to 32 bytes.
+    #[repr(C, align(32))]
+    Std430DMat3x4, DMat3x4, f64, DVec4, [x_axis, y_axis, z_axis], 4, 4
+);
+impl_padded_mat!(
+    /// A [`DMat4x3`] in the std140 layout of a GLSL `dmat4x3`, with each column padded to 32
+    /// bytes.
+    #[repr(C, align(32))]
+    Std140DMat4x3, DMat4x3, f64, DVec3, [x_axis, y_axis, z_axis, w_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`DMat4x3`] in the std430 layout of a GLSL `dmat4x3`, with each column padded to 32
+    /// bytes.
+    #[repr(C, align(32))]
+    Std430DMat4x3, DMat4x3, f64, DVec3, [x_axis, y_axis, z_axis, w_axis], 3, 4
+);
+impl_padded_mat!(
+    /// A [`DMat4`] in the std140 layout of a GLSL `dmat4`, aligned to 32 bytes.
+    #[repr(C, align(32))]
+    Std140DMat4, DMat4, f64, DVec4, [x_axis, y_axis, z_axis, w_axis], 4, 4
+);
+impl_padded_mat!(
+    /// A [`DMat4`] in the std430 layout of a GLSL `dmat4`, aligned to 32 bytes.
+    #[repr(C, align(32))]
+    Std430DMat4, DMat4, f64, DVec4, [x_axis, y_axis, z_axis, w_axis], 4, 4
+);
+
+// conversions for types which share the layout of a padded matrix type, via that matrix
+macro_rules! impl_padded_from {
+    ($t:ident, $mat:ident, $($padded:ident),+) => {
+        $(
+            impl From<$t> for $padded {
+                #[inline]
+                fn from(m: $t) -> Self {
+                    $mat::from(m).into()
+                }
+            }
+
+            impl From<$padded> for $t {
+                #[inline]
+                fn from(m: $padded) -> Self {
+                    $mat::from(m).into()
+                }
+            }
+        )+
+    };
+}
+
+impl_padded_from!(Mat3A, Mat3, Std140Mat3, Std430Mat3);
+impl_padded_from!(Affine2, Mat3x2, Std140Mat3x2, Std430Mat3x2);
+impl_padded_from!(Affine3A, Mat4x3, Std140Mat4x3, Std430Mat4x3);
+impl_padded_from!(DAffine2, DMat3x2, Std140DMat3x2, Std430DMat3x2);
+impl_padded_from!(DAffine3, DMat4x3, Std140DMat4x3, Std430DMat4x3);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    macro_rules! const_assert_layout {
+        ($t:ty, $std140_size:expr, $std140_align:expr, $std430_size:expr, $std430_align:expr) => {
+            const_assert_eq!($std140_size, <$t as GpuLayout>::STD140_SIZE);
+            const_assert_eq!($std140_align, <$t as GpuLayout>::STD140_ALIGN);
+            const_assert_eq!($std430_size, <$t as GpuLayout>::STD430_SIZE);
+            const_assert_eq!($std430_align, <$t as GpuLayout>::STD430_ALIGN);
+        };
+    }
+
+    macro_rules! const_assert_padded {
+        ($mat:ty, $std140:ty, $std430:ty) => {
+            const_assert_eq!(<$mat as GpuLayout>::STD140_SIZE, size_of::<$std140>());
+            const_assert_eq!(<$mat as GpuLayout>::STD140_ALIGN, align_of::<$std140>());
+            const_assert_eq!(<$mat as GpuLayout>::STD430_SIZE, size_of::<$std430>());
+            const_assert_eq!(<$mat as GpuLayout>::STD430_ALIGN, align_of::<$std430>());
+        };
+    }
+
+    // expected values are from the OpenGL 4.6 specification, section 7.6.2.2
+    const_assert_layout!(f32, 4, 4, 4, 4);
+    const_assert_layout!(f64, 8, 8, 8, 8);
+    const_assert_layout!(i32, 4, 4, 4, 4);
+    const_assert_layout!(u32, 4, 4, 4, 4);
+
+    const_assert_layout!(Vec2, 8, 8, 8, 8);
+    const_assert_layout!(Vec3, 12, 16, 12, 16);
+    const_assert_layout!(Vec3A, 12, 16, 12, 16);
+    const_assert_layout!(Vec4, 16, 16, 16, 16);
+    const_assert_layout!(Quat, 16, 16, 16, 16);
+    const_assert_layout!(DVec2, 16, 16, 16, 16);
+    const_assert_layout!(DVec3, 24, 32, 24, 32);
+    const_assert_layout!(DVec4, 32, 32, 32, 32);
+    const_assert_layout!(DQuat, 32, 32, 32, 32);
+    const_assert_layout!(IVec2, 8, 8, 8, 8);
+    const_assert_layout!(IVec3, 12, 16, 12, 16);
+    const_assert_layout!(IVec4, 16, 16, 16, 16);
+    const_assert_layout!(IVec4A, 16, 16, 16, 16);
+    const_assert_layout!(UVec2, 8, 8, 8, 8);
+    const_assert_layout!(UVec3, 12, 16, 12, 16);
+    const_assert_layout!(UVec4, 16, 16, 16, 16);
+    const_assert_layout!(UVec4A, 16, 16, 16, 16);
+    const_assert_layout!(bool, 4, 4, 4, 4);
+    const_assert_layout!(BVec2, 8, 8, 8, 8);
+    const_assert_layout!(BVec3, 12, 16, 12, 16);
+    const_assert_layout!(BVec3A, 12, 16, 12, 16);
+    const_assert_layout!(BVec4, 16, 16, 16, 16);
+    #[cfg(not(feature = "scalar-math"))]
+    const_assert_layout!(crate::BVec4A, 16, 16, 16, 16);
+
+    const_assert_layout!(Mat2, 32, 16, 16, 8);
+    const_assert_layout!(Mat2x3, 32, 16, 32, 16);
+    const_assert_layout!(Mat3x2, 48, 16, 24, 8);
+    const_assert_layout!(Mat3, 48, 16, 48, 16);
+    const_assert_layout!(Mat3A, 48, 16, 48, 16);
+    const_assert_layout!(Mat3x4, 48, 16, 48, 16);
+    const_assert_layout!(Mat4x3, 64, 16, 64, 16);
+    const_assert_layout!(Mat4, 64, 16, 64, 16);
+    const_assert_layout!(Affine2, 48, 16, 24, 8);
+    const_assert_layout!(Affine3A, 64, 16, 64, 16);
+    const_assert_layout!(DMat2, 32, 16, 32, 16);
+    const_assert_layout!(DMat2x3, 64, 32, 64, 32);
+    const_assert_layout!(DMat3x2, 48, 16, 48, 16);
+    const_assert_layout!(DMat3, 96, 32, 96, 32);
+    const_assert_layout!(DMat3x4, 96, 32, 96, 32);
+    const_assert_layout!(DMat4x3, 128, 32, 128, 32);
+    const_assert_layout!(DMat4, 128, 32, 128, 32);
+    const_assert_layout!(DAffine2, 48, 16, 48, 16);
+    const_assert_layout!(DAffine3, 128, 32, 128, 32);
+
+    const_assert_eq!(16, <f32 as GpuLayout>::STD140_ARRAY_STRIDE);
+    const_assert_eq!(4, <f32 as GpuLayout>::STD430_ARRAY_STRIDE);
+    const_assert_eq!(16, <Vec2 as GpuLayout>::STD140_ARRAY_STRIDE);
+    const_assert_eq!(8, <Vec2 as GpuLayout>::STD430_ARRAY_STRIDE);
+    const_assert_eq!(16, <Vec3 as GpuLayout>::STD140_ARRAY_STRIDE);
+    const_assert_eq!(16, <Vec3 as GpuLayout>::STD430_ARRAY_STRIDE);
+    const_assert_eq!(32, <DVec3 as GpuLayout>::STD430_ARRAY_STRIDE);
+    const_assert_eq!(32, <Mat2 as GpuLayout>::STD140_ARRAY_STRIDE);
+    const_assert_eq!(16, <Mat2 as GpuLayout>::STD430_ARRAY_STRIDE);
+
+    const_assert_padded!(Mat2, Std140Mat2, Std430Mat2);
+    const_assert_padded!(Mat2x3, Std140Mat2x3, Std430Mat2x3);
+    const_assert_padded!(Mat3x2, Std140Mat3x2, Std430Mat3x2);
+    const_assert_padded!(Mat3, Std140Mat3, Std430Mat3);
+    const_assert_padded!(Mat3x4, Std140Mat3x4, Std430Mat3x4);
+    const_assert_padded!(Mat4x3, Std140Mat4x3, Std430Mat4x3);
+    const_assert_padded!(Mat4, Std140Mat4, Std430Mat4);
+    const_assert_padded!(Affine2, Std140Mat3x2, Std430Mat3x2);
+    const_assert_padded!(Affine3A, Std140Mat4x3, Std430Mat4x3);
+    const_assert_padded!(DMat2, Std140DMat2, Std430DMat2);
+    const_assert_padded!(DMat2x3, Std140DMat2x3, Std430DMat2x3);
+    const_assert_padded!(DMat3x2, Std140DMat3x2, Std430DMat3x2);
+    const_assert_padded!(DMat3, Std140DMat3, Std430DMat3);
+    const_assert_padded!(DMat3x4, Std140DMat3x4, Std430DMat3x4);
+    const_assert_padded!(DMat4x3, Std140DMat4x3, Std430DMat4x3);
+    const_assert_padded!(DMat4, Std140DMat4, Std430DMat4);
+    const_assert_padded!(DAffine2, Std140DMat3x2, Std430DMat3x2);
+    const_assert_padded!(DAffine3, Std140DMat4x3, Std430DMat4x3);
+}
@@ -1,15 +1,71 @@
+mod iaabb3;
+mod irect;
 mod ivec2;
 mod ivec3;
 mod ivec4;
 
+#[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
+mod coresimd;
+
+#[cfg(any(
+    not(any(feature = "core-simd", target_feature = "sse2")),
+    feature = "scalar-math"
+))]
+mod scalar;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod sse2;
+
+#[cfg(any(
+    not(any(feature = "core-simd", target_feature = "sse2")),
+    feature = "scalar-math"
+))]
+use scalar::*;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use sse2::*;
+
+#[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
+use coresimd::*;
+
+pub use iaabb3::IAabb3;
+pub use irect::IRect;
 pub use ivec2::{ivec2, IVec2};
 pub use ivec3::{ivec3, IVec3};
 pub use ivec4::{ivec4, IVec4};
+pub use ivec4a::{ivec4a, IVec4A};
 
 #[cfg(not(target_arch = "spirv"))]
 mod test {
     use super::*;
 
+    mod const_test_ivec4a {
+        const_assert_eq!(16, core::mem::align_of::<super::IVec4A>());
+        const_assert_eq!(16, core::mem::size_of::<super::IVec4A>());
+    }
+
+    mod const_test_irect {
+        const_assert_eq!(
+            core::mem::align_of::<super::IVec2>(),
+            core::mem::align_of::<super::IRect>()
+        );
+        const_assert_eq!(16, core::mem::size_of::<super::IRect>());
+    }
+
+    mod const_test_iaabb3 {
+        const_assert_eq!(
+            core::mem::align_of::<i32>(),
+            core::mem::align_of::<super::IAabb3>()
+        );
+        const_assert_eq!(24, core::mem::size_of::<super::IAabb3>());
+    }
+
     mod const_test_ivec2 {
         #[cfg(not(feature = "cuda"))]
         const_assert_eq!(
@@ -0,0 +1 @@
+pub mod ivec4a;
@@ -0,0 +1,166 @@
+// Generated from aabb.rs.tera template. Edit the template, not the generated file.
+
+use crate::IVec2;
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+
+/// A 2D axis-aligned integer bounding rectangle, defined by its minimum and maximum
+/// corners.
+///
+/// The bounds are inclusive, a point lying on the boundary is contained by the rectangle.
+/// A rectangle where `min` is greater than `max` on any axis is empty.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
+#[derive(Eq)]
+#[repr(C)]
+pub struct IRect {
+    pub min: IVec2,
+    pub max: IVec2,
+}
+
+impl IRect {
+    /// An empty rectangle, with `min` set to the largest and `max` set to the smallest
+    /// representable value.
+    ///
+    /// The union of this with any other rectangle or point is that rectangle or point,
+    /// which makes it a good initial value when accumulating bounds.
+    pub const EMPTY: Self = Self {
+        min: IVec2::splat(i32::MAX),
+        max: IVec2::splat(i32::MIN),
+    };
+
+    /// Creates a new rectangle from its `min` and `max` corners.
+    #[inline(always)]
+    pub const fn new(min: IVec2, max: IVec2) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates the smallest rectangle containing all of the given `points`.
+    ///
+    /// Returns [`Self::EMPTY`] if `points` is empty.
+    #[inline]
+    pub fn from_points(points: &[IVec2]) -> Self {
+        points
+            .iter()
+            .fold(Self::EMPTY, |aabb, point| aabb.union_point(*point))
+    }
+
+    /// Returns `true` if `min` is greater than `max` on any axis.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.cmpgt(self.max).any()
+    }
+
+    /// Returns the center of `self`, rounded towards negative infinity.
+    #[inline]
+    pub fn center(&self) -> IVec2 {
+        // Sum in 64 bits so that corners near the limits of `i32` do not overflow.
+        (self.min.as_i64vec2() + self.max.as_i64vec2())
+            .floor_div_scalar(2)
+            .as_ivec2()
+    }
+
+    /// Returns the half extents of `self`, which is half of its size rounded down.
+    ///
+    /// Returns zero if `self` is empty.
+    #[inline]
+    pub fn half_extents(&self) -> IVec2 {
+        self.size() / 2
+    }
+
+    /// Returns the size of `self`, which is the difference between `max` and `min`.
+    ///
+    /// Returns zero if `self` is empty. Sizes larger than `i32::MAX` are saturated.
+    #[inline]
+    pub fn size(&self) -> IVec2 {
+        if self.is_empty() {
+            IVec2::ZERO
+        } else {
+            self.max.saturating_sub(self.min)
+        }
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `rhs`.
+    #[inline]
+    pub fn union(&self, rhs: &Self) -> Self {
+        Self {
+            min: self.min.min(rhs.min),
+            max: self.max.max(rhs.max),
+        }
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `point`.
+    #[inline]
+    pub fn union_point(&self, point: IVec2) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    /// Returns the rectangle where `self` and `rhs` overlap.
+    ///
+    /// If `self` and `rhs` do not intersect the result will be empty, see [`Self::is_empty()`].
+    #[inline]
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        Self {
+            min: self.min.max(rhs.min),
+            max: self.max.min(rhs.max),
+        }
+    }
+
+    /// Returns `true` if `point` is inside or on the boundary of `self`.
+    #[inline]
+    pub fn contains_point(&self, point: IVec2) -> bool {
+        (point.cmpge(self.min) & point.cmple(self.max)).all()
+    }
+
+    /// Returns `true` if `rhs` is entirely inside `self`.
+    #[inline]
+    pub fn contains(&self, rhs: &Self) -> bool {
+        (rhs.min.cmpge(self.min) & rhs.max.cmple(self.max)).all()
+    }
+
+    /// Returns `true` if `self` and `rhs` overlap or touch.
+    #[inline]
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        (self.min.cmple(rhs.max) & self.max.cmpge(rhs.min)).all()
+    }
+
+    /// Returns the point inside or on the boundary of `self` which is closest to `point`.
+    ///
+    /// If `point` is inside `self` then `point` is returned.
+    #[inline]
+    pub fn closest_point(&self, point: IVec2) -> IVec2 {
+        point.max(self.min).min(self.max)
+    }
+
+    /// Returns `self` with `amount` subtracted from `min` and added to `max` on every axis.
+    ///
+    /// A negative `amount` shrinks the rectangle. Corners are saturated at the limits of `i32`.
+    #[inline]
+    pub fn grow(&self, amount: i32) -> Self {
+        Self {
+            min: self.min.saturating_sub(IVec2::splat(amount)),
+            max: self.max.saturating_add(IVec2::splat(amount)),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for IRect {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(stringify!(IRect))
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for IRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
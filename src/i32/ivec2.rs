@@ -286,13 +286,97 @@ impl IVec2 {
         (self - rhs).length_squared()
     }
 
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::div_euclid
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(self.x.div_euclid(rhs.x), self.y.div_euclid(rhs.y))
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by the scalar `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::div_euclid
+    #[inline]
+    pub fn div_euclid_scalar(self, rhs: i32) -> Self {
+        self.div_euclid(Self::splat(rhs))
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// Each element of the result is non-negative, which makes this suitable for wrapping
+    /// coordinates into a tile or chunk.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::rem_euclid
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by the scalar
+    /// `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::rem_euclid
+    #[inline]
+    pub fn rem_euclid_scalar(self, rhs: i32) -> Self {
+        self.rem_euclid(Self::splat(rhs))
+    }
+
+    /// Returns the element-wise quotient of `self` divided by `rhs`, rounded towards negative
+    /// infinity.
+    ///
+    /// This differs from [`Self::div_euclid()`] when an element of `rhs` is negative.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    #[inline]
+    pub fn floor_div(self, rhs: Self) -> Self {
+        Self::new(
+            {
+                let q = self.x / rhs.x;
+                if (self.x % rhs.x != 0) && ((self.x < 0) != (rhs.x < 0)) {
+                    q - 1
+                } else {
+                    q
+                }
+            },
+            {
+                let q = self.y / rhs.y;
+                if (self.y % rhs.y != 0) && ((self.y < 0) != (rhs.y < 0)) {
+                    q - 1
+                } else {
+                    q
+                }
+            },
+        )
+    }
+
+    /// Returns the element-wise quotient of `self` divided by the scalar `rhs`, rounded towards
+    /// negative infinity.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is 0 or the division results in overflow.
+    #[inline]
+    pub fn floor_div_scalar(self, rhs: i32) -> Self {
+        self.floor_div(Self::splat(rhs))
+    }
+
     /// Returns a vector that is equal to `self` rotated by 90 degrees.
     #[inline]
     pub fn perp(self) -> Self {
-        Self {
-            x: -self.y,
-            y: self.x,
-        }
+        Self::new(-self.y, self.x)
     }
 
     /// The perpendicular dot product of `self` and `rhs`.
@@ -311,10 +395,10 @@ impl IVec2 {
     #[must_use]
     #[inline]
     pub fn rotate(self, rhs: Self) -> Self {
-        Self {
-            x: self.x * rhs.x - self.y * rhs.y,
-            y: self.y * rhs.x + self.x * rhs.y,
-        }
+        Self::new(
+            self.x * rhs.x - self.y * rhs.y,
+            self.y * rhs.x + self.x * rhs.y,
+        )
     }
 
     /// Casts all elements of `self` to `f32`.
@@ -329,6 +413,30 @@ impl IVec2 {
         crate::DVec2::new(self.x as f64, self.y as f64)
     }
 
+    /// Casts all elements of `self` to `i8`.
+    #[inline]
+    pub fn as_i8vec2(&self) -> crate::I8Vec2 {
+        crate::I8Vec2::new(self.x as i8, self.y as i8)
+    }
+
+    /// Casts all elements of `self` to `u8`.
+    #[inline]
+    pub fn as_u8vec2(&self) -> crate::U8Vec2 {
+        crate::U8Vec2::new(self.x as u8, self.y as u8)
+    }
+
+    /// Casts all elements of `self` to `i16`.
+    #[inline]
+    pub fn as_i16vec2(&self) -> crate::I16Vec2 {
+        crate::I16Vec2::new(self.x as i16, self.y as i16)
+    }
+
+    /// Casts all elements of `self` to `u16`.
+    #[inline]
+    pub fn as_u16vec2(&self) -> crate::U16Vec2 {
+        crate::U16Vec2::new(self.x as u16, self.y as u16)
+    }
+
     /// Casts all elements of `self` to `u32`.
     #[inline]
     pub fn as_uvec2(&self) -> crate::UVec2 {
@@ -346,6 +454,194 @@ impl IVec2 {
     pub fn as_u64vec2(&self) -> crate::U64Vec2 {
         crate::U64Vec2::new(self.x as u64, self.y as u64)
     }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(rhs.x),
+            y: self.y.wrapping_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if any element of the result overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    /// Returns a vector containing the overflowing addition of `self` and `rhs`, along
+    /// with a mask indicating which elements overflowed.
+    ///
+    /// Overflowed elements contain the wrapped value.
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, BVec2) {
+        let (x, overflow_x) = self.x.overflowing_add(rhs.x);
+        let (y, overflow_y) = self.y.overflowing_add(rhs.y);
+        (Self { x, y }, BVec2::new(overflow_x, overflow_y))
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(rhs.x),
+            y: self.y.wrapping_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if any element of the result overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+
+    /// Returns a vector containing the overflowing subtraction of `self` and `rhs`, along
+    /// with a mask indicating which elements overflowed.
+    ///
+    /// Overflowed elements contain the wrapped value.
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, BVec2) {
+        let (x, overflow_x) = self.x.overflowing_sub(rhs.x);
+        let (y, overflow_y) = self.y.overflowing_sub(rhs.y);
+        (Self { x, y }, BVec2::new(overflow_x, overflow_y))
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_mul(rhs.x), self.y.wrapping_mul(rhs.y), ..]`.
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_mul(rhs.x),
+            y: self.y.wrapping_mul(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_mul(rhs.x), self.y.saturating_mul(rhs.y), ..]`.
+    #[inline]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_mul(rhs.x),
+            y: self.y.saturating_mul(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the checked multiplication of `self` and `rhs`.
+    ///
+    /// Returns `None` if any element of the result overflows.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_mul(rhs.x)?,
+            y: self.y.checked_mul(rhs.y)?,
+        })
+    }
+
+    /// Returns a vector containing the overflowing multiplication of `self` and `rhs`, along
+    /// with a mask indicating which elements overflowed.
+    ///
+    /// Overflowed elements contain the wrapped value.
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, BVec2) {
+        let (x, overflow_x) = self.x.overflowing_mul(rhs.x);
+        let (y, overflow_y) = self.y.overflowing_mul(rhs.y);
+        (Self { x, y }, BVec2::new(overflow_x, overflow_y))
+    }
+
+    /// Returns a vector containing the wrapping division of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_div(rhs.x), self.y.wrapping_div(rhs.y), ..]`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any element of `rhs` is zero.
+    #[inline]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_div(rhs.x),
+            y: self.y.wrapping_div(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating division of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_div(rhs.x), self.y.saturating_div(rhs.y), ..]`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any element of `rhs` is zero.
+    #[inline]
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_div(rhs.x),
+            y: self.y.saturating_div(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the checked division of `self` and `rhs`.
+    ///
+    /// Returns `None` if any element of the result overflows or any element of `rhs` is zero.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_div(rhs.x)?,
+            y: self.y.checked_div(rhs.y)?,
+        })
+    }
+
+    /// Returns a vector containing the overflowing division of `self` and `rhs`, along
+    /// with a mask indicating which elements overflowed.
+    ///
+    /// Overflowed elements contain the wrapped value.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any element of `rhs` is zero.
+    #[inline]
+    pub const fn overflowing_div(self, rhs: Self) -> (Self, BVec2) {
+        let (x, overflow_x) = self.x.overflowing_div(rhs.x);
+        let (y, overflow_y) = self.y.overflowing_div(rhs.y);
+        (Self { x, y }, BVec2::new(overflow_x, overflow_y))
+    }
 }
 
 impl Default for IVec2 {
@@ -0,0 +1,1677 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use crate::{sse2::*, BVec4A, IVec2, IVec3, IVec4};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::*;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(C)]
+union UnionCast {
+    a: [i32; 4],
+    v: IVec4A,
+}
+
+/// Creates a 4-dimensional vector.
+#[inline(always)]
+pub const fn ivec4a(x: i32, y: i32, z: i32, w: i32) -> IVec4A {
+    IVec4A::new(x, y, z, w)
+}
+
+/// A 4-dimensional vector.
+///
+/// SIMD vector types are used for storage on supported platforms for better
+/// performance than the [`IVec4`] type.
+///
+/// It is possible to convert between [`IVec4`] and [`IVec4A`] types using [`From`]
+/// or [`Into`] trait implementations.
+///
+/// Arithmetic, negation and shift operators have the same semantics as [`IVec4`], so
+/// they panic on overflow when debug assertions are enabled and wrap otherwise. Use the
+/// `wrapping_*` methods for arithmetic that wraps in all builds.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct IVec4A(pub(crate) __m128i);
+
+impl IVec4A {
+    /// All zeroes.
+    pub const ZERO: Self = Self::splat(0);
+
+    /// All ones.
+    pub const ONE: Self = Self::splat(1);
+
+    /// All negative ones.
+    pub const NEG_ONE: Self = Self::splat(-1);
+
+    /// A unit-length vector pointing along the positive X axis.
+    pub const X: Self = Self::new(1, 0, 0, 0);
+
+    /// A unit-length vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(0, 1, 0, 0);
+
+    /// A unit-length vector pointing along the positive Z axis.
+    pub const Z: Self = Self::new(0, 0, 1, 0);
+
+    /// A unit-length vector pointing along the positive W axis.
+    pub const W: Self = Self::new(0, 0, 0, 1);
+
+    /// A unit-length vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::new(-1, 0, 0, 0);
+
+    /// A unit-length vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::new(0, -1, 0, 0);
+
+    /// A unit-length vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self::new(0, 0, -1, 0);
+
+    /// A unit-length vector pointing along the negative W axis.
+    pub const NEG_W: Self = Self::new(0, 0, 0, -1);
+
+    /// The unit axes.
+    pub const AXES: [Self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
+
+    /// Creates a new vector.
+    #[inline(always)]
+    pub const fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        unsafe { UnionCast { a: [x, y, z, w] }.v }
+    }
+
+    /// Creates a vector with all elements set to `v`.
+    #[inline]
+    pub const fn splat(v: i32) -> Self {
+        unsafe { UnionCast { a: [v; 4] }.v }
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`.
+    #[inline]
+    pub fn select(mask: BVec4A, if_true: Self, if_false: Self) -> Self {
+        Self(unsafe {
+            _mm_castps_si128(m128_select(
+                mask.0,
+                _mm_castsi128_ps(if_true.0),
+                _mm_castsi128_ps(if_false.0),
+            ))
+        })
+    }
+
+    /// Creates a new vector from an array.
+    #[inline]
+    pub const fn from_array(a: [i32; 4]) -> Self {
+        Self::new(a[0], a[1], a[2], a[3])
+    }
+
+    /// `[x, y, z, w]`
+    #[inline]
+    pub const fn to_array(&self) -> [i32; 4] {
+        unsafe { *(self as *const IVec4A as *const [i32; 4]) }
+    }
+
+    /// Creates a vector from the first 4 values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub const fn from_slice(slice: &[i32]) -> Self {
+        Self::new(slice[0], slice[1], slice[2], slice[3])
+    }
+
+    /// Writes the elements of `self` to the first 4 elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [i32]) {
+        unsafe {
+            assert!(slice.len() >= 4);
+            _mm_storeu_si128(slice.as_mut_ptr().cast(), self.0);
+        }
+    }
+
+    /// Creates a 3D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
+    #[inline]
+    pub fn truncate(self) -> IVec3 {
+        IVec3::new(self.x, self.y, self.z)
+    }
+
+    /// Computes the dot product of `self` and `rhs`.
+    #[inline]
+    pub fn dot(self, rhs: Self) -> i32 {
+        if cfg!(debug_assertions) {
+            (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z) + (self.w * rhs.w)
+        } else {
+            unsafe { m128i_reduce_epi32(m128i_mullo_epi32(self.0, rhs.0), _mm_add_epi32) }
+        }
+    }
+
+    /// Returns a vector where every component is the dot product of `self` and `rhs`.
+    #[inline]
+    pub fn dot_into_vec(self, rhs: Self) -> Self {
+        Self::splat(self.dot(rhs))
+    }
+
+    /// Returns a vector containing the minimum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.min(rhs.x), self.y.min(rhs.y), ..]`.
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        Self(unsafe { m128i_min_epi32(self.0, rhs.0) })
+    }
+
+    /// Returns a vector containing the maximum values for each element of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.max(rhs.x), self.y.max(rhs.y), ..]`.
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        Self(unsafe { m128i_max_epi32(self.0, rhs.0) })
+    }
+
+    /// Component-wise clamping of values, similar to [`i32::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all(), "clamp: expected min <= max");
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`.
+    ///
+    /// In other words this computes `min(x, y, ..)`.
+    #[inline]
+    pub fn min_element(self) -> i32 {
+        unsafe { m128i_reduce_epi32(self.0, m128i_min_epi32) }
+    }
+
+    /// Returns the horizontal maximum of `self`.
+    ///
+    /// In other words this computes `max(x, y, ..)`.
+    #[inline]
+    pub fn max_element(self) -> i32 {
+        unsafe { m128i_reduce_epi32(self.0, m128i_max_epi32) }
+    }
+
+    /// Returns a vector mask containing the result of a `==` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words, this computes `[self.x == rhs.x, self.y == rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpeq(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(_mm_cmpeq_epi32(self.0, rhs.0)) })
+    }
+
+    /// Returns a vector mask containing the result of a `!=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x != rhs.x, self.y != rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpne(self, rhs: Self) -> BVec4A {
+        !self.cmpeq(rhs)
+    }
+
+    /// Returns a vector mask containing the result of a `>=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x >= rhs.x, self.y >= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpge(self, rhs: Self) -> BVec4A {
+        !self.cmplt(rhs)
+    }
+
+    /// Returns a vector mask containing the result of a `>` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x > rhs.x, self.y > rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmpgt(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(_mm_cmpgt_epi32(self.0, rhs.0)) })
+    }
+
+    /// Returns a vector mask containing the result of a `<=` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x <= rhs.x, self.y <= rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmple(self, rhs: Self) -> BVec4A {
+        !self.cmpgt(rhs)
+    }
+
+    /// Returns a vector mask containing the result of a `<` comparison for each element of
+    /// `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x < rhs.x, self.y < rhs.y, ..]` for all
+    /// elements.
+    #[inline]
+    pub fn cmplt(self, rhs: Self) -> BVec4A {
+        BVec4A(unsafe { _mm_castsi128_ps(_mm_cmplt_epi32(self.0, rhs.0)) })
+    }
+
+    /// Returns a vector containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        if cfg!(debug_assertions) {
+            Self::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
+        } else {
+            unsafe {
+                let sign = _mm_srai_epi32(self.0, 31);
+                Self(_mm_sub_epi32(_mm_xor_si128(self.0, sign), sign))
+            }
+        }
+    }
+
+    /// Returns a vector with elements representing the sign of `self`.
+    ///
+    ///  - `0` if the number is zero
+    ///  - `1` if the number is positive
+    ///  - `-1` if the number is negative
+    #[inline]
+    pub fn signum(self) -> Self {
+        unsafe {
+            let zero = _mm_setzero_si128();
+            // comparison masks are -1 when true, so `lt - gt` is the sign
+            Self(_mm_sub_epi32(
+                _mm_cmplt_epi32(self.0, zero),
+                _mm_cmpgt_epi32(self.0, zero),
+            ))
+        }
+    }
+
+    /// Returns a vector with signs of `rhs` and the magnitudes of `self`.
+    #[inline]
+    pub fn copysign(self, rhs: Self) -> Self {
+        Self::select(rhs.cmpge(Self::ZERO), self, -self)
+    }
+
+    /// Returns a bitmask with the lowest 4 bits set to the sign bits from the elements of `self`.
+    ///
+    /// A negative element results in a `1` bit and a positive element in a `0` bit.  Element `x` goes
+    /// into the first lowest bit, element `y` into the second, etc.
+    #[inline]
+    pub fn is_negative_bitmask(self) -> u32 {
+        unsafe { _mm_movemask_ps(_mm_castsi128_ps(self.0)) as u32 }
+    }
+
+    /// Computes the squared length of `self`.
+    #[doc(alias = "magnitude2")]
+    #[inline]
+    pub fn length_squared(self) -> i32 {
+        self.dot(self)
+    }
+
+    /// Compute the squared euclidean distance between two points in space.
+    #[inline]
+    pub fn distance_squared(self, rhs: Self) -> i32 {
+        (self - rhs).length_squared()
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::div_euclid
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.div_euclid(rhs.x),
+            self.y.div_euclid(rhs.y),
+            self.z.div_euclid(rhs.z),
+            self.w.div_euclid(rhs.w),
+        )
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by the scalar `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::div_euclid
+    #[inline]
+    pub fn div_euclid_scalar(self, rhs: i32) -> Self {
+        self.div_euclid(Self::splat(rhs))
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// Each element of the result is non-negative, which makes this suitable for wrapping
+    /// coordinates into a tile or chunk.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::rem_euclid
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.rem_euclid(rhs.x),
+            self.y.rem_euclid(rhs.y),
+            self.z.rem_euclid(rhs.z),
+            self.w.rem_euclid(rhs.w),
+        )
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by the scalar
+    /// `rhs`.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::rem_euclid
+    #[inline]
+    pub fn rem_euclid_scalar(self, rhs: i32) -> Self {
+        self.rem_euclid(Self::splat(rhs))
+    }
+
+    /// Returns the element-wise quotient of `self` divided by `rhs`, rounded towards negative
+    /// infinity.
+    ///
+    /// This differs from [`Self::div_euclid()`] when an element of `rhs` is negative.
+    ///
+    /// # Panics
+    /// This function will panic if any `rhs` element is 0 or the division results in overflow.
+    #[inline]
+    pub fn floor_div(self, rhs: Self) -> Self {
+        Self::new(
+            {
+                let q = self.x / rhs.x;
+                if (self.x % rhs.x != 0) && ((self.x < 0) != (rhs.x < 0)) {
+                    q - 1
+                } else {
+                    q
+                }
+            },
+            {
+                let q = self.y / rhs.y;
+                if (self.y % rhs.y != 0) && ((self.y < 0) != (rhs.y < 0)) {
+                    q - 1
+                } else {
+                    q
+                }
+            },
+            {
+                let q = self.z / rhs.z;
+                if (self.z % rhs.z != 0) && ((self.z < 0) != (rhs.z < 0)) {
+                    q - 1
+                } else {
+                    q
+                }
+            },
+            {
+                let q = self.w / rhs.w;
+                if (self.w % rhs.w != 0) && ((self.w < 0) != (rhs.w < 0)) {
+                    q - 1
+                } else {
+                    q
+                }
+            },
+        )
+    }
+
+    /// Returns the element-wise quotient of `self` divided by the scalar `rhs`, rounded towards
+    /// negative infinity.
+    ///
+    /// # Panics
+    /// This function will panic if `rhs` is 0 or the division results in overflow.
+    #[inline]
+    pub fn floor_div_scalar(self, rhs: i32) -> Self {
+        self.floor_div(Self::splat(rhs))
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_vec4(&self) -> crate::Vec4 {
+        crate::Vec4(unsafe { _mm_cvtepi32_ps(self.0) })
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_dvec4(&self) -> crate::DVec4 {
+        crate::DVec4::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+
+    /// Casts all elements of `self` to `i8`.
+    #[inline]
+    pub fn as_i8vec4(&self) -> crate::I8Vec4 {
+        crate::I8Vec4::new(self.x as i8, self.y as i8, self.z as i8, self.w as i8)
+    }
+
+    /// Casts all elements of `self` to `u8`.
+    #[inline]
+    pub fn as_u8vec4(&self) -> crate::U8Vec4 {
+        crate::U8Vec4::new(self.x as u8, self.y as u8, self.z as u8, self.w as u8)
+    }
+
+    /// Casts all elements of `self` to `i16`.
+    #[inline]
+    pub fn as_i16vec4(&self) -> crate::I16Vec4 {
+        crate::I16Vec4::new(self.x as i16, self.y as i16, self.z as i16, self.w as i16)
+    }
+
+    /// Casts all elements of `self` to `u16`.
+    #[inline]
+    pub fn as_u16vec4(&self) -> crate::U16Vec4 {
+        crate::U16Vec4::new(self.x as u16, self.y as u16, self.z as u16, self.w as u16)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    #[inline]
+    pub fn as_uvec4(&self) -> crate::UVec4 {
+        crate::UVec4::new(self.x as u32, self.y as u32, self.z as u32, self.w as u32)
+    }
+
+    /// Casts all elements of `self` to `i64`.
+    #[inline]
+    pub fn as_i64vec4(&self) -> crate::I64Vec4 {
+        crate::I64Vec4::new(self.x as i64, self.y as i64, self.z as i64, self.w as i64)
+    }
+
+    /// Casts all elements of `self` to `u64`.
+    #[inline]
+    pub fn as_u64vec4(&self) -> crate::U64Vec4 {
+        crate::U64Vec4::new(self.x as u64, self.y as u64, self.z as u64, self.w as u64)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    ///
+    /// This reinterprets the bits of each element, in the same way as the `as` operator.
+    #[inline]
+    pub fn as_uvec4a(&self) -> crate::UVec4A {
+        crate::UVec4A(self.0)
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::new(
+            a[0].wrapping_add(b[0]),
+            a[1].wrapping_add(b[1]),
+            a[2].wrapping_add(b[2]),
+            a[3].wrapping_add(b[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::new(
+            a[0].saturating_add(b[0]),
+            a[1].saturating_add(b[1]),
+            a[2].saturating_add(b[2]),
+            a[3].saturating_add(b[3]),
+        )
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if any element of the result overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+            self.z.checked_add(rhs.z)?,
+            self.w.checked_add(rhs.w)?,
+        ))
+    }
+
+    /// Returns a vector containing the overflowing addition of `self` and `rhs`, along
+    /// with a mask indicating which elements overflowed.
+    ///
+    /// Overflowed elements contain the wrapped value.
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, BVec4A) {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+
+        let (x, overflow_x) = a[0].overflowing_add(b[0]);
+        let (y, overflow_y) = a[1].overflowing_add(b[1]);
+        let (z, overflow_z) = a[2].overflowing_add(b[2]);
+        let (w, overflow_w) = a[3].overflowing_add(b[3]);
+        (
+            Self::new(x, y, z, w),
+            BVec4A::new(overflow_x, overflow_y, overflow_z, overflow_w),
+        )
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::new(
+            a[0].wrapping_sub(b[0]),
+            a[1].wrapping_sub(b[1]),
+            a[2].wrapping_sub(b[2]),
+            a[3].wrapping_sub(b[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::new(
+            a[0].saturating_sub(b[0]),
+            a[1].saturating_sub(b[1]),
+            a[2].saturating_sub(b[2]),
+            a[3].saturating_sub(b[3]),
+        )
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if any element of the result overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+            self.z.checked_sub(rhs.z)?,
+            self.w.checked_sub(rhs.w)?,
+        ))
+    }
+
+    /// Returns a vector containing the overflowing subtraction of `self` and `rhs`, along
+    /// with a mask indicating which elements overflowed.
+    ///
+    /// Overflowed elements contain the wrapped value.
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, BVec4A) {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+
+        let (x, overflow_x) = a[0].overflowing_sub(b[0]);
+        let (y, overflow_y) = a[1].overflowing_sub(b[1]);
+        let (z, overflow_z) = a[2].overflowing_sub(b[2]);
+        let (w, overflow_w) = a[3].overflowing_sub(b[3]);
+        (
+            Self::new(x, y, z, w),
+            BVec4A::new(overflow_x, overflow_y, overflow_z, overflow_w),
+        )
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_mul(rhs.x), self.y.wrapping_mul(rhs.y), ..]`.
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::new(
+            a[0].wrapping_mul(b[0]),
+            a[1].wrapping_mul(b[1]),
+            a[2].wrapping_mul(b[2]),
+            a[3].wrapping_mul(b[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating multiplication of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_mul(rhs.x), self.y.saturating_mul(rhs.y), ..]`.
+    #[inline]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::new(
+            a[0].saturating_mul(b[0]),
+            a[1].saturating_mul(b[1]),
+            a[2].saturating_mul(b[2]),
+            a[3].saturating_mul(b[3]),
+        )
+    }
+
+    /// Returns a vector containing the checked multiplication of `self` and `rhs`.
+    ///
+    /// Returns `None` if any element of the result overflows.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(rhs.x)?,
+            self.y.checked_mul(rhs.y)?,
+            self.z.checked_mul(rhs.z)?,
+            self.w.checked_mul(rhs.w)?,
+        ))
+    }
+
+    /// Returns a vector containing the overflowing multiplication of `self` and `rhs`, along
+    /// with a mask indicating which elements overflowed.
+    ///
+    /// Overflowed elements contain the wrapped value.
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, BVec4A) {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+
+        let (x, overflow_x) = a[0].overflowing_mul(b[0]);
+        let (y, overflow_y) = a[1].overflowing_mul(b[1]);
+        let (z, overflow_z) = a[2].overflowing_mul(b[2]);
+        let (w, overflow_w) = a[3].overflowing_mul(b[3]);
+        (
+            Self::new(x, y, z, w),
+            BVec4A::new(overflow_x, overflow_y, overflow_z, overflow_w),
+        )
+    }
+
+    /// Returns a vector containing the wrapping division of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_div(rhs.x), self.y.wrapping_div(rhs.y), ..]`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any element of `rhs` is zero.
+    #[inline]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::new(
+            a[0].wrapping_div(b[0]),
+            a[1].wrapping_div(b[1]),
+            a[2].wrapping_div(b[2]),
+            a[3].wrapping_div(b[3]),
+        )
+    }
+
+    /// Returns a vector containing the saturating division of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_div(rhs.x), self.y.saturating_div(rhs.y), ..]`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any element of `rhs` is zero.
+    #[inline]
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::new(
+            a[0].saturating_div(b[0]),
+            a[1].saturating_div(b[1]),
+            a[2].saturating_div(b[2]),
+            a[3].saturating_div(b[3]),
+        )
+    }
+
+    /// Returns a vector containing the checked division of `self` and `rhs`.
+    ///
+    /// Returns `None` if any element of the result overflows or any element of `rhs` is zero.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_div(rhs.x)?,
+            self.y.checked_div(rhs.y)?,
+            self.z.checked_div(rhs.z)?,
+            self.w.checked_div(rhs.w)?,
+        ))
+    }
+
+    /// Returns a vector containing the overflowing division of `self` and `rhs`, along
+    /// with a mask indicating which elements overflowed.
+    ///
+    /// Overflowed elements contain the wrapped value.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any element of `rhs` is zero.
+    #[inline]
+    pub const fn overflowing_div(self, rhs: Self) -> (Self, BVec4A) {
+        // self.x, self.y can't be done in a const-context
+        let (a, b) = (self.to_array(), rhs.to_array());
+
+        let (x, overflow_x) = a[0].overflowing_div(b[0]);
+        let (y, overflow_y) = a[1].overflowing_div(b[1]);
+        let (z, overflow_z) = a[2].overflowing_div(b[2]);
+        let (w, overflow_w) = a[3].overflowing_div(b[3]);
+        (
+            Self::new(x, y, z, w),
+            BVec4A::new(overflow_x, overflow_y, overflow_z, overflow_w),
+        )
+    }
+}
+
+impl Default for IVec4A {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl PartialEq for IVec4A {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmpeq(*rhs).all()
+    }
+}
+
+impl Eq for IVec4A {}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::hash::Hash for IVec4A {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(&self.x, state);
+        core::hash::Hash::hash(&self.y, state);
+        core::hash::Hash::hash(&self.z, state);
+        core::hash::Hash::hash(&self.w, state);
+    }
+}
+
+impl Div<IVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.div(rhs.x),
+            self.y.div(rhs.y),
+            self.z.div(rhs.z),
+            self.w.div(rhs.w),
+        )
+    }
+}
+
+impl DivAssign<IVec4A> for IVec4A {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div(rhs);
+    }
+}
+
+impl Div<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: i32) -> Self {
+        self.div(Self::splat(rhs))
+    }
+}
+
+impl DivAssign<i32> for IVec4A {
+    #[inline]
+    fn div_assign(&mut self, rhs: i32) {
+        *self = self.div(Self::splat(rhs));
+    }
+}
+
+impl Div<IVec4A> for i32 {
+    type Output = IVec4A;
+    #[inline]
+    fn div(self, rhs: IVec4A) -> IVec4A {
+        IVec4A::splat(self).div(rhs)
+    }
+}
+
+impl Mul<IVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.mul(rhs.x),
+                self.y.mul(rhs.y),
+                self.z.mul(rhs.z),
+                self.w.mul(rhs.w),
+            )
+        } else {
+            Self(unsafe { m128i_mullo_epi32(self.0, rhs.0) })
+        }
+    }
+}
+
+impl MulAssign<IVec4A> for IVec4A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl Mul<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self {
+        self.mul(Self::splat(rhs))
+    }
+}
+
+impl MulAssign<i32> for IVec4A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = self.mul(Self::splat(rhs));
+    }
+}
+
+impl Mul<IVec4A> for i32 {
+    type Output = IVec4A;
+    #[inline]
+    fn mul(self, rhs: IVec4A) -> IVec4A {
+        IVec4A::splat(self).mul(rhs)
+    }
+}
+
+impl Add<IVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.add(rhs.x),
+                self.y.add(rhs.y),
+                self.z.add(rhs.z),
+                self.w.add(rhs.w),
+            )
+        } else {
+            Self(unsafe { _mm_add_epi32(self.0, rhs.0) })
+        }
+    }
+}
+
+impl AddAssign<IVec4A> for IVec4A {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl Add<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: i32) -> Self {
+        self.add(Self::splat(rhs))
+    }
+}
+
+impl AddAssign<i32> for IVec4A {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        *self = self.add(Self::splat(rhs));
+    }
+}
+
+impl Add<IVec4A> for i32 {
+    type Output = IVec4A;
+    #[inline]
+    fn add(self, rhs: IVec4A) -> IVec4A {
+        IVec4A::splat(self).add(rhs)
+    }
+}
+
+impl Sub<IVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.sub(rhs.x),
+                self.y.sub(rhs.y),
+                self.z.sub(rhs.z),
+                self.w.sub(rhs.w),
+            )
+        } else {
+            Self(unsafe { _mm_sub_epi32(self.0, rhs.0) })
+        }
+    }
+}
+
+impl SubAssign<IVec4A> for IVec4A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: IVec4A) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl Sub<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: i32) -> Self {
+        self.sub(Self::splat(rhs))
+    }
+}
+
+impl SubAssign<i32> for IVec4A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = self.sub(Self::splat(rhs));
+    }
+}
+
+impl Sub<IVec4A> for i32 {
+    type Output = IVec4A;
+    #[inline]
+    fn sub(self, rhs: IVec4A) -> IVec4A {
+        IVec4A::splat(self).sub(rhs)
+    }
+}
+
+impl Rem<IVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.rem(rhs.x),
+            self.y.rem(rhs.y),
+            self.z.rem(rhs.z),
+            self.w.rem(rhs.w),
+        )
+    }
+}
+
+impl RemAssign<IVec4A> for IVec4A {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.rem(rhs);
+    }
+}
+
+impl Rem<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: i32) -> Self {
+        self.rem(Self::splat(rhs))
+    }
+}
+
+impl RemAssign<i32> for IVec4A {
+    #[inline]
+    fn rem_assign(&mut self, rhs: i32) {
+        *self = self.rem(Self::splat(rhs));
+    }
+}
+
+impl Rem<IVec4A> for i32 {
+    type Output = IVec4A;
+    #[inline]
+    fn rem(self, rhs: IVec4A) -> IVec4A {
+        IVec4A::splat(self).rem(rhs)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsRef<[i32; 4]> for IVec4A {
+    #[inline]
+    fn as_ref(&self) -> &[i32; 4] {
+        unsafe { &*(self as *const IVec4A as *const [i32; 4]) }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl AsMut<[i32; 4]> for IVec4A {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [i32; 4] {
+        unsafe { &mut *(self as *mut IVec4A as *mut [i32; 4]) }
+    }
+}
+
+impl Sum for IVec4A {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, Self::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for IVec4A {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+impl Product for IVec4A {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ONE, Self::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for IVec4A {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ONE, |a, &b| Self::mul(a, b))
+    }
+}
+
+impl Neg for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        if cfg!(debug_assertions) {
+            Self::new(self.x.neg(), self.y.neg(), self.z.neg(), self.w.neg())
+        } else {
+            Self(unsafe { _mm_sub_epi32(_mm_setzero_si128(), self.0) })
+        }
+    }
+}
+
+impl Not for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self::Output {
+        Self(unsafe { _mm_xor_si128(self.0, _mm_set1_epi32(-1)) })
+    }
+}
+
+impl BitAnd for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_and_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitOr for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_or_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitXor for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(unsafe { _mm_xor_si128(self.0, rhs.0) })
+    }
+}
+
+impl BitAnd<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: i32) -> Self::Output {
+        self.bitand(Self::splat(rhs))
+    }
+}
+
+impl BitOr<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: i32) -> Self::Output {
+        self.bitor(Self::splat(rhs))
+    }
+}
+
+impl BitXor<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: i32) -> Self::Output {
+        self.bitxor(Self::splat(rhs))
+    }
+}
+
+impl Shl<i8> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i8) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sll_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shr<i8> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i8) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sra_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shl<i16> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i16) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sll_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shr<i16> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i16) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sra_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shl<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sll_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shr<i32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sra_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shl<i64> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: i64) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sll_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shr<i64> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: i64) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sra_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shl<u8> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u8) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sll_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shr<u8> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u8) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sra_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shl<u16> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u16) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sll_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shr<u16> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u16) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sra_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shl<u32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sll_epi32(self.0, m128i_shift_count(rhs)) })
+        }
+    }
+}
+
+impl Shr<u32> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sra_epi32(self.0, m128i_shift_count(rhs)) })
+        }
+    }
+}
+
+impl Shl<u64> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: u64) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs),
+                self.y.shl(rhs),
+                self.z.shl(rhs),
+                self.w.shl(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sll_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shr<u64> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: u64) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs),
+                self.y.shr(rhs),
+                self.z.shr(rhs),
+                self.w.shr(rhs),
+            )
+        } else {
+            Self(unsafe { _mm_sra_epi32(self.0, m128i_shift_count(rhs as u32)) })
+        }
+    }
+}
+
+impl Shl<crate::IVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: crate::IVec4A) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs.x),
+                self.y.shl(rhs.y),
+                self.z.shl(rhs.z),
+                self.w.shl(rhs.w),
+            )
+        } else {
+            #[cfg(target_feature = "avx2")]
+            {
+                Self(unsafe { _mm_sllv_epi32(self.0, _mm_and_si128(rhs.0, _mm_set1_epi32(31))) })
+            }
+
+            #[cfg(not(target_feature = "avx2"))]
+            {
+                Self::new(
+                    self.x.wrapping_shl(rhs.x as u32),
+                    self.y.wrapping_shl(rhs.y as u32),
+                    self.z.wrapping_shl(rhs.z as u32),
+                    self.w.wrapping_shl(rhs.w as u32),
+                )
+            }
+        }
+    }
+}
+
+impl Shr<crate::IVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: crate::IVec4A) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs.x),
+                self.y.shr(rhs.y),
+                self.z.shr(rhs.z),
+                self.w.shr(rhs.w),
+            )
+        } else {
+            #[cfg(target_feature = "avx2")]
+            {
+                Self(unsafe { _mm_srav_epi32(self.0, _mm_and_si128(rhs.0, _mm_set1_epi32(31))) })
+            }
+
+            #[cfg(not(target_feature = "avx2"))]
+            {
+                Self::new(
+                    self.x.wrapping_shr(rhs.x as u32),
+                    self.y.wrapping_shr(rhs.y as u32),
+                    self.z.wrapping_shr(rhs.z as u32),
+                    self.w.wrapping_shr(rhs.w as u32),
+                )
+            }
+        }
+    }
+}
+
+impl Shl<crate::UVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: crate::UVec4A) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shl(rhs.x),
+                self.y.shl(rhs.y),
+                self.z.shl(rhs.z),
+                self.w.shl(rhs.w),
+            )
+        } else {
+            #[cfg(target_feature = "avx2")]
+            {
+                Self(unsafe { _mm_sllv_epi32(self.0, _mm_and_si128(rhs.0, _mm_set1_epi32(31))) })
+            }
+
+            #[cfg(not(target_feature = "avx2"))]
+            {
+                Self::new(
+                    self.x.wrapping_shl(rhs.x),
+                    self.y.wrapping_shl(rhs.y),
+                    self.z.wrapping_shl(rhs.z),
+                    self.w.wrapping_shl(rhs.w),
+                )
+            }
+        }
+    }
+}
+
+impl Shr<crate::UVec4A> for IVec4A {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: crate::UVec4A) -> Self::Output {
+        if cfg!(debug_assertions) {
+            Self::new(
+                self.x.shr(rhs.x),
+                self.y.shr(rhs.y),
+                self.z.shr(rhs.z),
+                self.w.shr(rhs.w),
+            )
+        } else {
+            #[cfg(target_feature = "avx2")]
+            {
+                Self(unsafe { _mm_srav_epi32(self.0, _mm_and_si128(rhs.0, _mm_set1_epi32(31))) })
+            }
+
+            #[cfg(not(target_feature = "avx2"))]
+            {
+                Self::new(
+                    self.x.wrapping_shr(rhs.x),
+                    self.y.wrapping_shr(rhs.y),
+                    self.z.wrapping_shr(rhs.z),
+                    self.w.wrapping_shr(rhs.w),
+                )
+            }
+        }
+    }
+}
+
+impl Index<usize> for IVec4A {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for IVec4A {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for IVec4A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Debug for IVec4A {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple(stringify!(IVec4A))
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+
+impl From<IVec4A> for __m128i {
+    #[inline]
+    fn from(t: IVec4A) -> Self {
+        t.0
+    }
+}
+
+impl From<__m128i> for IVec4A {
+    #[inline]
+    fn from(t: __m128i) -> Self {
+        Self(t)
+    }
+}
+
+impl From<[i32; 4]> for IVec4A {
+    #[inline]
+    fn from(a: [i32; 4]) -> Self {
+        Self(unsafe { _mm_loadu_si128(a.as_ptr().cast()) })
+    }
+}
+
+impl From<IVec4A> for [i32; 4] {
+    #[inline]
+    fn from(v: IVec4A) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for IVec4A {
+    #[inline]
+    fn from(t: (i32, i32, i32, i32)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<IVec4A> for (i32, i32, i32, i32) {
+    #[inline]
+    fn from(v: IVec4A) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<IVec4> for IVec4A {
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<IVec4A> for IVec4 {
+    #[inline]
+    fn from(v: IVec4A) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<(IVec3, i32)> for IVec4A {
+    #[inline]
+    fn from((v, w): (IVec3, i32)) -> Self {
+        Self::new(v.x, v.y, v.z, w)
+    }
+}
+
+impl From<(i32, IVec3)> for IVec4A {
+    #[inline]
+    fn from((x, v): (i32, IVec3)) -> Self {
+        Self::new(x, v.x, v.y, v.z)
+    }
+}
+
+impl From<(IVec2, i32, i32)> for IVec4A {
+    #[inline]
+    fn from((v, z, w): (IVec2, i32, i32)) -> Self {
+        Self::new(v.x, v.y, z, w)
+    }
+}
+
+impl From<(IVec2, IVec2)> for IVec4A {
+    #[inline]
+    fn from((v, u): (IVec2, IVec2)) -> Self {
+        Self::new(v.x, v.y, u.x, u.y)
+    }
+}
+
+impl Deref for IVec4A {
+    type Target = crate::deref::Vec4<i32>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self).cast() }
+    }
+}
+
+impl DerefMut for IVec4A {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+}
@@ -8,23 +8,62 @@
 * [`f32`](mod@f32) types
   * vectors: [`Vec2`], [`Vec3`], [`Vec3A`] and [`Vec4`]
   * square matrices: [`Mat2`], [`Mat3`], [`Mat3A`] and [`Mat4`]
+  * non-square matrices: [`Mat2x3`], [`Mat3x2`], [`Mat3x4`] and [`Mat4x3`]
   * a quaternion type: [`Quat`]
+  * a 2D rotation type: [`Rot2`]
+  * angle types: [`Radians`] and [`Degrees`]
+  * a dual quaternion type: [`DualQuat`]
   * affine transformation types: [`Affine2`] and [`Affine3A`]
+  * axis-aligned bounding box types: [`Aabb2`], [`Aabb3`] and [`Aabb3A`]
+  * geometric primitives: [`Ray3`], [`Plane3`] and [`Sphere`]
+  * a view frustum type: [`Frustum`]
+  * rigid and similarity transformation types: [`Isometry2`], [`Isometry3`] and [`Similarity3`]
 * [`f64`](mod@f64) types
   * vectors: [`DVec2`], [`DVec3`] and [`DVec4`]
   * square matrices: [`DMat2`], [`DMat3`] and [`DMat4`]
+  * non-square matrices: [`DMat2x3`], [`DMat3x2`], [`DMat3x4`] and [`DMat4x3`]
   * a quaternion type: [`DQuat`]
+  * a 2D rotation type: [`DRot2`]
+  * angle types: [`DRadians`] and [`DDegrees`]
+  * a dual quaternion type: [`DDualQuat`]
   * affine transformation types: [`DAffine2`] and [`DAffine3`]
+  * an axis-aligned bounding box type: [`DAabb3`]
+  * geometric primitives: [`DRay3`], [`DPlane3`] and [`DSphere`]
+  * a view frustum type: [`DFrustum`]
+  * rigid and similarity transformation types: [`DIsometry2`], [`DIsometry3`] and [`DSimilarity3`]
+* [`f16`](mod@f16) types
+  * vector storage: [`HVec2`], [`HVec3`] and [`HVec4`]
+* [`i8`](mod@i8) types
+  * vectors: [`I8Vec2`], [`I8Vec3`] and [`I8Vec4`]
+* [`u8`](mod@u8) types
+  * vectors: [`U8Vec2`], [`U8Vec3`] and [`U8Vec4`]
+* [`i16`](mod@i16) types
+  * vectors: [`I16Vec2`], [`I16Vec3`] and [`I16Vec4`]
+* [`u16`](mod@u16) types
+  * vectors: [`U16Vec2`], [`U16Vec3`] and [`U16Vec4`]
 * [`i32`](mod@i32) types
-  * vectors: [`IVec2`], [`IVec3`] and [`IVec4`]
+  * vectors: [`IVec2`], [`IVec3`], [`IVec4`] and [`IVec4A`]
+  * axis-aligned bounding box types: [`IRect`] and [`IAabb3`]
 * [`u32`](mod@u32) types
-  * vectors: [`UVec2`], [`UVec3`] and [`UVec4`]
+  * vectors: [`UVec2`], [`UVec3`], [`UVec4`] and [`UVec4A`]
 * [`i64`](mod@i64) types
   * vectors: [`I64Vec2`], [`I64Vec3`] and [`I64Vec4`]
 * [`u64`](mod@u64) types
   * vectors: [`U64Vec2`], [`U64Vec3`] and [`U64Vec4`]
 * [`bool`](mod@bool) types
   * vectors: [`BVec2`], [`BVec3`] and [`BVec4`]
+* [`gpu_layout`] std140 and std430 buffer layouts
+  * the [`GpuLayout`](gpu_layout::GpuLayout) size and alignment trait
+  * padded matrix types such as [`Std140Mat3`](gpu_layout::Std140Mat3)
+* [`wide`] structure-of-arrays types for batch math
+  * 4 and 8 lane types: [`F32x4`](wide::F32x4) and [`F32x8`](wide::F32x8)
+  * vectors and quaternions such as [`Vec3x4`](wide::Vec3x4), [`Vec4x8`](wide::Vec4x8) and
+    [`Quatx4`](wide::Quatx4)
+* [`curves`] curve segments and splines
+  * Bézier, Hermite, Catmull-Rom and B-spline segments such as
+    [`CubicBezier`](curves::CubicBezier) and [`CatmullRom`](curves::CatmullRom)
+  * the [`CubicSpline`](curves::CubicSpline) multi-segment curve with arc length and closest
+    point queries
 
 ## SIMD
 
@@ -44,6 +83,22 @@ other SIMD architectures once they appear in stable Rust.
 
 Currently only SSE2 on x86/x86_64 is supported as this is what stable Rust supports.
 
+When the `sse4.1` target feature is enabled the `f32` SIMD types use SSE4.1 instructions for
+rounding, `select` and the 3D dot product, which give the same results as the SSE2 versions. The 4D
+dot product and fused multiply-adds from the `fma` target feature change the rounding of results
+so are only used when the `fast-math` feature is also enabled.
+
+When the `avx` target feature is enabled `DVec4` and `DQuat` use 256-bit AVX vector types and
+`DVec2` uses a 128-bit SSE2 vector type for storage. These types are then 32 and 16 byte aligned
+respectively, which also affects the alignment of `f64` types that contain them such as `DMat4`.
+Arithmetic and dot products give the same results as the scalar implementation, with the exception
+of `min` and `max` when an element is `NaN`.
+
+[`IVec4A`] and [`UVec4A`] are SIMD versions of [`IVec4`] and [`UVec4`] which use a 128-bit integer
+vector for storage. Unlike the scalar integer types their arithmetic always wraps on overflow. The
+`sse4.1` target feature is used for multiplication, `min` and `max` and the `avx2` target feature
+for shifting by a vector when available.
+
 ## Vec3A and Mat3A
 
 `Vec3A` is a SIMD optimized version of the `Vec3` type, which due to 16 byte alignment results
@@ -220,7 +275,8 @@ and benchmarks.
 * `bytemuck` - for casting into slices of bytes
 * `libm` - uses `libm` math functions instead of `std`, required to compile with `no_std`
 * `mint` - for interoperating with other 3D math libraries
-* `rand` - implementations of `Distribution` trait for all `glam` types.
+* `rand` - implementations of `Distribution` trait for all `glam` types, uniform rotation
+  sampling and the geometric distributions in the `distributions` module.
 * `rkyv` - implementations of `Archive`, `Serialize` and `Deserialize` for all
   `glam` types. Note that serialization is not interoperable with and without the
   `scalar-math` feature. It should work between all other builds of `glam`.
@@ -289,6 +345,12 @@ mod wasm32;
 #[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
 mod coresimd;
 
+#[cfg(all(
+    target_feature = "avx",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod avx;
+
 #[cfg(all(
     target_feature = "sse2",
     not(any(feature = "core-simd", feature = "scalar-math"))
@@ -307,6 +369,26 @@ pub use self::f32::*;
 pub mod f64;
 pub use self::f64::*;
 
+/** `f16` vector storage types. */
+pub mod f16;
+pub use self::f16::*;
+
+/** `i8` vector types. */
+pub mod i8;
+pub use self::i8::*;
+
+/** `u8` vector types. */
+pub mod u8;
+pub use self::u8::*;
+
+/** `i16` vector types. */
+pub mod i16;
+pub use self::i16::*;
+
+/** `u16` vector types. */
+pub mod u16;
+pub use self::u16::*;
+
 /** `i32` vector types. */
 pub mod i32;
 pub use self::i32::*;
@@ -327,5 +409,20 @@ pub use self::u64::*;
 pub mod swizzles;
 pub use self::swizzles::{Vec2Swizzles, Vec3Swizzles, Vec4Swizzles};
 
+pub mod gpu_layout;
+
+pub mod wide;
+
+pub mod curves;
+
 /** Rotation Helper */
 pub use euler::EulerRot;
+
+/** Random distributions for sampling `glam` types, requires the `rand` feature. */
+#[cfg(feature = "rand")]
+pub mod distributions {
+    pub use crate::features::impl_rand::{
+        CosineWeightedHemisphere, UniformDVec2, UniformDVec3, UniformDVec4, UniformVec2,
+        UniformVec3, UniformVec3A, UniformVec4, UnitBall, UnitCircle, UnitDisc, UnitSphere,
+    };
+}
@@ -14,7 +14,7 @@ pub const fn m128_from_f32x4(f32x4: [f32; 4]) -> __m128 {
     unsafe { UnionCast { f32x4 }.m128 }
 }
 
-const fn m128_from_u32x4(u32x4: [u32; 4]) -> __m128 {
+pub(crate) const fn m128_from_u32x4(u32x4: [u32; 4]) -> __m128 {
     unsafe { UnionCast { u32x4 }.m128 }
 }
 
@@ -39,6 +39,22 @@ const PS_RECIPROCAL_TWO_PI: __m128 = m128_from_f32x4([0.159_154_94; 4]);
 /// Calculates the vector 3 dot product and returns answer in x lane of __m128.
 #[inline(always)]
 pub(crate) unsafe fn dot3_in_x(lhs: __m128, rhs: __m128) -> __m128 {
+    // `_mm_dp_ps` sums `(x + y) + z` which matches the SSE2 implementation, apart from the sign
+    // of a zero result.
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_dp_ps(lhs, rhs, 0b0111_0001)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        dot3_in_x_sse2(lhs, rhs)
+    }
+}
+
+#[cfg_attr(target_feature = "sse4.1", allow(dead_code))]
+#[inline(always)]
+unsafe fn dot3_in_x_sse2(lhs: __m128, rhs: __m128) -> __m128 {
     let x2_y2_z2_w2 = _mm_mul_ps(lhs, rhs);
     let y2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_01);
     let z2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_10);
@@ -49,6 +65,25 @@ pub(crate) unsafe fn dot3_in_x(lhs: __m128, rhs: __m128) -> __m128 {
 /// Calculates the vector 4 dot product and returns answer in x lane of __m128.
 #[inline(always)]
 pub(crate) unsafe fn dot4_in_x(lhs: __m128, rhs: __m128) -> __m128 {
+    // `_mm_dp_ps` sums `(x + y) + (z + w)` rather than `(x + z) + (y + w)` so is only used with
+    // "fast-math" to preserve cross-platform determinism.
+    #[cfg(all(feature = "fast-math", target_feature = "sse4.1"))]
+    {
+        _mm_dp_ps(lhs, rhs, 0b1111_0001)
+    }
+
+    #[cfg(any(not(feature = "fast-math"), not(target_feature = "sse4.1")))]
+    {
+        dot4_in_x_sse2(lhs, rhs)
+    }
+}
+
+#[cfg_attr(
+    all(feature = "fast-math", target_feature = "sse4.1"),
+    allow(dead_code)
+)]
+#[inline(always)]
+unsafe fn dot4_in_x_sse2(lhs: __m128, rhs: __m128) -> __m128 {
     let x2_y2_z2_w2 = _mm_mul_ps(lhs, rhs);
     let z2_w2_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_11_10);
     let x2z2_y2w2_0_0 = _mm_add_ps(x2_y2_z2_w2, z2_w2_0_0);
@@ -63,8 +98,16 @@ pub(crate) unsafe fn dot3(lhs: __m128, rhs: __m128) -> f32 {
 
 #[inline]
 pub(crate) unsafe fn dot3_into_m128(lhs: __m128, rhs: __m128) -> __m128 {
-    let dot_in_x = dot3_in_x(lhs, rhs);
-    _mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00)
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_dp_ps(lhs, rhs, 0b0111_1111)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        let dot_in_x = dot3_in_x(lhs, rhs);
+        _mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00)
+    }
 }
 
 #[inline]
@@ -74,12 +117,34 @@ pub(crate) unsafe fn dot4(lhs: __m128, rhs: __m128) -> f32 {
 
 #[inline]
 pub(crate) unsafe fn dot4_into_m128(lhs: __m128, rhs: __m128) -> __m128 {
-    let dot_in_x = dot4_in_x(lhs, rhs);
-    _mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00)
+    #[cfg(all(feature = "fast-math", target_feature = "sse4.1"))]
+    {
+        _mm_dp_ps(lhs, rhs, 0b1111_1111)
+    }
+
+    #[cfg(any(not(feature = "fast-math"), not(target_feature = "sse4.1")))]
+    {
+        let dot_in_x = dot4_in_x(lhs, rhs);
+        _mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00)
+    }
 }
 
 #[inline]
 pub(crate) unsafe fn m128_floor(v: __m128) -> __m128 {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_floor_ps(v)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        m128_floor_sse2(v)
+    }
+}
+
+#[cfg_attr(target_feature = "sse4.1", allow(dead_code))]
+#[inline]
+unsafe fn m128_floor_sse2(v: __m128) -> __m128 {
     // Based on https://github.com/microsoft/DirectXMath `XMVectorFloor`
     // To handle NAN, INF and numbers greater than 8388608, use masking
     let test = _mm_and_si128(_mm_castps_si128(v), _mm_castps_si128(PS_INV_SIGN_MASK));
@@ -100,6 +165,20 @@ pub(crate) unsafe fn m128_floor(v: __m128) -> __m128 {
 
 #[inline]
 pub(crate) unsafe fn m128_ceil(v: __m128) -> __m128 {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_ceil_ps(v)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        m128_ceil_sse2(v)
+    }
+}
+
+#[cfg_attr(target_feature = "sse4.1", allow(dead_code))]
+#[inline]
+unsafe fn m128_ceil_sse2(v: __m128) -> __m128 {
     // Based on https://github.com/microsoft/DirectXMath `XMVectorCeil`
     // To handle NAN, INF and numbers greater than 8388608, use masking
     let test = _mm_and_si128(_mm_castps_si128(v), _mm_castps_si128(PS_INV_SIGN_MASK));
@@ -138,6 +217,22 @@ pub(crate) unsafe fn m128_mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
     }
 }
 
+/// Selects lanes from `if_true` where the sign bit of the corresponding `mask` lane is set and
+/// from `if_false` otherwise. Comparison masks have all bits set or clear so this works for both
+/// `_mm_blendv_ps` and the SSE2 fallback.
+#[inline(always)]
+pub(crate) unsafe fn m128_select(mask: __m128, if_true: __m128, if_false: __m128) -> __m128 {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_blendv_ps(if_false, if_true, mask)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        _mm_or_ps(_mm_andnot_ps(mask, if_false), _mm_and_ps(if_true, mask))
+    }
+}
+
 #[inline(always)]
 pub(crate) unsafe fn m128_neg_mul_sub(a: __m128, b: __m128, c: __m128) -> __m128 {
     _mm_sub_ps(c, _mm_mul_ps(a, b))
@@ -145,6 +240,20 @@ pub(crate) unsafe fn m128_neg_mul_sub(a: __m128, b: __m128, c: __m128) -> __m128
 
 #[inline]
 pub(crate) unsafe fn m128_round(v: __m128) -> __m128 {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_round_ps(v, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        m128_round_sse2(v)
+    }
+}
+
+#[cfg_attr(target_feature = "sse4.1", allow(dead_code))]
+#[inline]
+unsafe fn m128_round_sse2(v: __m128) -> __m128 {
     // Based on https://github.com/microsoft/DirectXMath `XMVectorRound`
     let sign = _mm_and_ps(v, PS_SIGN_MASK);
     let s_magic = _mm_or_ps(PS_NO_FRACTION, sign);
@@ -157,6 +266,119 @@ pub(crate) unsafe fn m128_round(v: __m128) -> __m128 {
     _mm_xor_ps(r1, r2)
 }
 
+/// Multiplies the 32-bit integer lanes of `a` and `b`, keeping the low 32 bits of each result.
+#[inline]
+pub(crate) unsafe fn m128i_mullo_epi32(a: __m128i, b: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_mullo_epi32(a, b)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        let a0b0_a2b2 = _mm_mul_epu32(a, b);
+        let a1b1_a3b3 = _mm_mul_epu32(_mm_srli_epi64(a, 32), _mm_srli_epi64(b, 32));
+        _mm_unpacklo_epi32(
+            _mm_shuffle_epi32(a0b0_a2b2, 0b00_00_10_00),
+            _mm_shuffle_epi32(a1b1_a3b3, 0b00_00_10_00),
+        )
+    }
+}
+
+#[inline(always)]
+#[cfg_attr(target_feature = "sse4.1", allow(dead_code))]
+unsafe fn m128i_select(mask: __m128i, if_true: __m128i, if_false: __m128i) -> __m128i {
+    _mm_or_si128(
+        _mm_and_si128(mask, if_true),
+        _mm_andnot_si128(mask, if_false),
+    )
+}
+
+/// Flips the sign bit of each lane so that signed comparisons order unsigned values.
+#[inline(always)]
+unsafe fn m128i_flip_sign_epi32(v: __m128i) -> __m128i {
+    _mm_xor_si128(v, _mm_set1_epi32(i32::MIN))
+}
+
+#[inline]
+pub(crate) unsafe fn m128i_cmpgt_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_cmpgt_epi32(m128i_flip_sign_epi32(a), m128i_flip_sign_epi32(b))
+}
+
+#[inline]
+pub(crate) unsafe fn m128i_cmplt_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_cmplt_epi32(m128i_flip_sign_epi32(a), m128i_flip_sign_epi32(b))
+}
+
+#[inline]
+pub(crate) unsafe fn m128i_min_epi32(a: __m128i, b: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_min_epi32(a, b)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        m128i_select(_mm_cmplt_epi32(a, b), a, b)
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn m128i_max_epi32(a: __m128i, b: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_max_epi32(a, b)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        m128i_select(_mm_cmpgt_epi32(a, b), a, b)
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn m128i_min_epu32(a: __m128i, b: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_min_epu32(a, b)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        m128i_select(m128i_cmplt_epu32(a, b), a, b)
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn m128i_max_epu32(a: __m128i, b: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_max_epu32(a, b)
+    }
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        m128i_select(m128i_cmpgt_epu32(a, b), a, b)
+    }
+}
+
+/// Returns the shift count for the `_mm_sll_epi32` family of intrinsics, masked to the low 5 bits.
+#[inline(always)]
+pub(crate) unsafe fn m128i_shift_count(count: u32) -> __m128i {
+    _mm_cvtsi32_si128((count & 31) as i32)
+}
+
+/// Applies `f` pairwise across the lanes of `v` and returns the result in the x lane.
+#[inline(always)]
+pub(crate) unsafe fn m128i_reduce_epi32(
+    v: __m128i,
+    f: unsafe fn(__m128i, __m128i) -> __m128i,
+) -> i32 {
+    let v = f(v, _mm_shuffle_epi32(v, 0b01_00_11_10));
+    let v = f(v, _mm_shuffle_epi32(v, 0b10_11_00_01));
+    _mm_cvtsi128_si32(v)
+}
+
 /// Returns a vector whose components are the corresponding components of Angles modulo 2PI.
 #[inline]
 pub(crate) unsafe fn m128_mod_angles(angles: __m128) -> __m128 {
@@ -237,3 +459,136 @@ fn test_sse2_m128_sin() {
         a += step;
     }
 }
+
+#[cfg(test)]
+const TEST_VALUES: [f32; 20] = [
+    0.0,
+    -0.0,
+    0.25,
+    -0.25,
+    0.5,
+    -0.5,
+    0.499_999_97,
+    -0.499_999_97,
+    1.5,
+    -1.5,
+    2.5,
+    -2.5,
+    123.456,
+    -123.456,
+    8388607.5,
+    -8388607.5,
+    16777217.0,
+    -1.0e10,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+];
+
+#[cfg(test)]
+fn assert_m128_eq(expected: __m128, actual: __m128) {
+    // `==` also treats `0.0` and `-0.0` as equal which is the only way the paths may differ.
+    let expected = unsafe { UnionCast { m128: expected }.f32x4 };
+    let actual = unsafe { UnionCast { m128: actual }.f32x4 };
+    for (e, a) in expected.iter().zip(actual.iter()) {
+        assert!(
+            e == a || (e.is_nan() && a.is_nan()),
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+    }
+}
+
+#[test]
+fn test_sse2_m128_rounding() {
+    let nans = [f32::NAN, -f32::NAN, 0.75, -0.75];
+    for v in TEST_VALUES.chunks(4).chain(core::iter::once(&nans[..])) {
+        let v = m128_from_f32x4([v[0], v[1], v[2], v[3]]);
+        unsafe {
+            assert_m128_eq(m128_floor_sse2(v), m128_floor(v));
+            assert_m128_eq(m128_ceil_sse2(v), m128_ceil(v));
+            assert_m128_eq(m128_round_sse2(v), m128_round(v));
+        }
+    }
+}
+
+#[test]
+fn test_sse2_m128_select() {
+    let a = m128_from_f32x4([1.0, 2.0, 3.0, 4.0]);
+    let b = m128_from_f32x4([5.0, 6.0, 7.0, 8.0]);
+    let mask = m128_from_u32x4([!0, 0, 0, !0]);
+    unsafe {
+        assert_m128_eq(
+            m128_from_f32x4([1.0, 6.0, 7.0, 4.0]),
+            m128_select(mask, a, b),
+        );
+    }
+}
+
+#[test]
+fn test_sse2_dot() {
+    let finite = &TEST_VALUES[..16];
+    for a in finite.chunks(4) {
+        for b in finite.chunks(4) {
+            let sum_abs: f32 = a
+                .iter()
+                .rev()
+                .zip(b.iter())
+                .map(|(a, b)| (a * b).abs())
+                .sum();
+            let a = m128_from_f32x4([a[3], a[2], a[1], a[0]]);
+            let b = m128_from_f32x4([b[0], b[1], b[2], b[3]]);
+            unsafe {
+                // The vector 3 dot product sums in the same order on all paths.
+                let expected = _mm_cvtss_f32(dot3_in_x_sse2(a, b));
+                assert_eq!(expected, dot3(a, b));
+                assert_m128_eq(_mm_set_ps1(expected), dot3_into_m128(a, b));
+
+                // With "fast-math" and SSE4.1 the vector 4 dot product is summed in a different
+                // order, the difference is bounded by `2 * EPSILON` of the sum of absolute products.
+                let expected = _mm_cvtss_f32(dot4_in_x_sse2(a, b));
+                let tolerance = 2.0 * f32::EPSILON * sum_abs;
+                assert!((expected - dot4(a, b)).abs() <= tolerance);
+                let lanes = UnionCast {
+                    m128: dot4_into_m128(a, b),
+                }
+                .f32x4;
+                for lane in lanes.iter() {
+                    assert!((expected - lane).abs() <= tolerance);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sse2_m128_mul_add() {
+    // With "fast-math" and FMA the product is not rounded before the add, the difference is
+    // bounded by the rounding errors of the separate product and sum.
+    let finite = &TEST_VALUES[..16];
+    for a in finite.chunks(4) {
+        for c in finite.chunks(4) {
+            let a = m128_from_f32x4([a[0], a[1], a[2], a[3]]);
+            let b = m128_from_f32x4([c[3], c[2], c[1], c[0]]);
+            let c = m128_from_f32x4([c[0], c[1], c[2], c[3]]);
+            unsafe {
+                let expected = UnionCast {
+                    m128: _mm_add_ps(_mm_mul_ps(a, b), c),
+                }
+                .f32x4;
+                let products = UnionCast {
+                    m128: _mm_mul_ps(a, b),
+                }
+                .f32x4;
+                let actual = UnionCast {
+                    m128: m128_mul_add(a, b, c),
+                }
+                .f32x4;
+                for ((e, a), p) in expected.iter().zip(actual.iter()).zip(products.iter()) {
+                    let tolerance = f32::EPSILON * (p.abs() + e.abs());
+                    assert!((e - a).abs() <= tolerance, "{} != {}", e, a);
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,22 @@ mod dvec2_impl;
 mod dvec3_impl;
 mod dvec4_impl;
 
+mod i8vec2_impl;
+mod i8vec3_impl;
+mod i8vec4_impl;
+
+mod u8vec2_impl;
+mod u8vec3_impl;
+mod u8vec4_impl;
+
+mod i16vec2_impl;
+mod i16vec3_impl;
+mod i16vec4_impl;
+
+mod u16vec2_impl;
+mod u16vec3_impl;
+mod u16vec4_impl;
+
 mod ivec2_impl;
 mod ivec3_impl;
 mod ivec4_impl;
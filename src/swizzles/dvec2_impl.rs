@@ -9,34 +9,22 @@ impl Vec2Swizzles for DVec2 {
 
     #[inline]
     fn xx(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.x,
-        }
+        DVec2::new(self.x, self.x)
     }
 
     #[inline]
     fn xy(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.y,
-        }
+        DVec2::new(self.x, self.y)
     }
 
     #[inline]
     fn yx(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.x,
-        }
+        DVec2::new(self.y, self.x)
     }
 
     #[inline]
     fn yy(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.y,
-        }
+        DVec2::new(self.y, self.y)
     }
 
     #[inline]
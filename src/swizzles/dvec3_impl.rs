@@ -9,74 +9,47 @@ impl Vec3Swizzles for DVec3 {
 
     #[inline]
     fn xx(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.x,
-        }
+        DVec2::new(self.x, self.x)
     }
 
     #[inline]
     fn xy(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.y,
-        }
+        DVec2::new(self.x, self.y)
     }
 
     #[inline]
     fn xz(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.z,
-        }
+        DVec2::new(self.x, self.z)
     }
 
     #[inline]
     fn yx(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.x,
-        }
+        DVec2::new(self.y, self.x)
     }
 
     #[inline]
     fn yy(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.y,
-        }
+        DVec2::new(self.y, self.y)
     }
 
     #[inline]
     fn yz(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.z,
-        }
+        DVec2::new(self.y, self.z)
     }
 
     #[inline]
     fn zx(self) -> DVec2 {
-        DVec2 {
-            x: self.z,
-            y: self.x,
-        }
+        DVec2::new(self.z, self.x)
     }
 
     #[inline]
     fn zy(self) -> DVec2 {
-        DVec2 {
-            x: self.z,
-            y: self.y,
-        }
+        DVec2::new(self.z, self.y)
     }
 
     #[inline]
     fn zz(self) -> DVec2 {
-        DVec2 {
-            x: self.z,
-            y: self.z,
-        }
+        DVec2::new(self.z, self.z)
     }
 
     #[inline]
@@ -9,130 +9,82 @@ impl Vec4Swizzles for DVec4 {
 
     #[inline]
     fn xx(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.x,
-        }
+        DVec2::new(self.x, self.x)
     }
 
     #[inline]
     fn xy(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.y,
-        }
+        DVec2::new(self.x, self.y)
     }
 
     #[inline]
     fn xz(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.z,
-        }
+        DVec2::new(self.x, self.z)
     }
 
     #[inline]
     fn xw(self) -> DVec2 {
-        DVec2 {
-            x: self.x,
-            y: self.w,
-        }
+        DVec2::new(self.x, self.w)
     }
 
     #[inline]
     fn yx(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.x,
-        }
+        DVec2::new(self.y, self.x)
     }
 
     #[inline]
     fn yy(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.y,
-        }
+        DVec2::new(self.y, self.y)
     }
 
     #[inline]
     fn yz(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.z,
-        }
+        DVec2::new(self.y, self.z)
     }
 
     #[inline]
     fn yw(self) -> DVec2 {
-        DVec2 {
-            x: self.y,
-            y: self.w,
-        }
+        DVec2::new(self.y, self.w)
     }
 
     #[inline]
     fn zx(self) -> DVec2 {
-        DVec2 {
-            x: self.z,
-            y: self.x,
-        }
+        DVec2::new(self.z, self.x)
     }
 
     #[inline]
     fn zy(self) -> DVec2 {
-        DVec2 {
-            x: self.z,
-            y: self.y,
-        }
+        DVec2::new(self.z, self.y)
     }
 
     #[inline]
     fn zz(self) -> DVec2 {
-        DVec2 {
-            x: self.z,
-            y: self.z,
-        }
+        DVec2::new(self.z, self.z)
     }
 
     #[inline]
     fn zw(self) -> DVec2 {
-        DVec2 {
-            x: self.z,
-            y: self.w,
-        }
+        DVec2::new(self.z, self.w)
     }
 
     #[inline]
     fn wx(self) -> DVec2 {
-        DVec2 {
-            x: self.w,
-            y: self.x,
-        }
+        DVec2::new(self.w, self.x)
     }
 
     #[inline]
     fn wy(self) -> DVec2 {
-        DVec2 {
-            x: self.w,
-            y: self.y,
-        }
+        DVec2::new(self.w, self.y)
     }
 
     #[inline]
     fn wz(self) -> DVec2 {
-        DVec2 {
-            x: self.w,
-            y: self.z,
-        }
+        DVec2::new(self.w, self.z)
     }
 
     #[inline]
     fn ww(self) -> DVec2 {
-        DVec2 {
-            x: self.w,
-            y: self.w,
-        }
+        DVec2::new(self.w, self.w)
     }
 
     #[inline]
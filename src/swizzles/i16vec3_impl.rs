@@ -0,0 +1,729 @@
+// Generated from swizzle_impl.rs.tera template. Edit the template, not the generated file.
+
+use crate::{I16Vec2, I16Vec3, I16Vec4, Vec3Swizzles};
+
+impl Vec3Swizzles for I16Vec3 {
+    type Vec2 = I16Vec2;
+
+    type Vec4 = I16Vec4;
+
+    #[inline]
+    fn xx(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn xy(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn xz(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn yx(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn yy(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn yz(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn zx(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.z,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn zy(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.z,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn zz(self) -> I16Vec2 {
+        I16Vec2 {
+            x: self.z,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn xxx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xxy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xxz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xyx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xyy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xyz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xzx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xzy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xzz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yxx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yxy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yxz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yyx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yyy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yyz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yzx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yzy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yzz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zxx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zxy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zxz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zyx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zyy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zyz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zzx(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zzy(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zzz(self) -> I16Vec3 {
+        I16Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xxxx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn xxxy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn xxxz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn xxyx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn xxyy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn xxyz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn xxzx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn xxzy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn xxzz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn xyxx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn xyxy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn xyxz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn xyyx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn xyyy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn xyyz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn xyzx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn xyzy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn xyzz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn xzxx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn xzxy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn xzxz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn xzyx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn xzyy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn xzyz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn xzzx(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn xzzy(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn xzzz(self) -> I16Vec4 {
+        I16Vec4::new(self.x, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn yxxx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn yxxy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn yxxz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn yxyx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn yxyy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn yxyz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn yxzx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn yxzy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn yxzz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn yyxx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn yyxy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn yyxz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn yyyx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn yyyy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn yyyz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn yyzx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn yyzy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn yyzz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn yzxx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn yzxy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn yzxz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn yzyx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn yzyy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn yzyz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn yzzx(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn yzzy(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn yzzz(self) -> I16Vec4 {
+        I16Vec4::new(self.y, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn zxxx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn zxxy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn zxxz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn zxyx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn zxyy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn zxyz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn zxzx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn zxzy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn zxzz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn zyxx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn zyxy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn zyxz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn zyyx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn zyyy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn zyyz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn zyzx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn zyzy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn zyzz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn zzxx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn zzxy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn zzxz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn zzyx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn zzyy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn zzyz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn zzzx(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn zzzy(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn zzzz(self) -> I16Vec4 {
+        I16Vec4::new(self.z, self.z, self.z, self.z)
+    }
+}
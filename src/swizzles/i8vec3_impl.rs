@@ -0,0 +1,729 @@
+// Generated from swizzle_impl.rs.tera template. Edit the template, not the generated file.
+
+use crate::{I8Vec2, I8Vec3, I8Vec4, Vec3Swizzles};
+
+impl Vec3Swizzles for I8Vec3 {
+    type Vec2 = I8Vec2;
+
+    type Vec4 = I8Vec4;
+
+    #[inline]
+    fn xx(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn xy(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn xz(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn yx(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn yy(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn yz(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn zx(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.z,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn zy(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.z,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn zz(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.z,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn xxx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xxy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xxz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xyx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xyy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xyz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xzx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xzy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xzz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yxx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yxy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yxz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yyx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yyy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yyz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yzx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yzy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yzz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zxx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zxy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zxz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zyx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zyy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zyz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zzx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zzy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zzz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xxxx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn xxxy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn xxxz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn xxyx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn xxyy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn xxyz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn xxzx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn xxzy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn xxzz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn xyxx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn xyxy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn xyxz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn xyyx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn xyyy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn xyyz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn xyzx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn xyzy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn xyzz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn xzxx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn xzxy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn xzxz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn xzyx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn xzyy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn xzyz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn xzzx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn xzzy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn xzzz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn yxxx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn yxxy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn yxxz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn yxyx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn yxyy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn yxyz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn yxzx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn yxzy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn yxzz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn yyxx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn yyxy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn yyxz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn yyyx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn yyyy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn yyyz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn yyzx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn yyzy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn yyzz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn yzxx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn yzxy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn yzxz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn yzyx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn yzyy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn yzyz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn yzzx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn yzzy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn yzzz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn zxxx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn zxxy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn zxxz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn zxyx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn zxyy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn zxyz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn zxzx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn zxzy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn zxzz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn zyxx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn zyxy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn zyxz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn zyyx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn zyyy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn zyyz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn zyzx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn zyzy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn zyzz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn zzxx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn zzxy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn zzxz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn zzyx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn zzyy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn zzyz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn zzzx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn zzzy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn zzzz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.z, self.z)
+    }
+}
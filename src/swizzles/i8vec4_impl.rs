@@ -0,0 +1,1993 @@
+// Generated from swizzle_impl.rs.tera template. Edit the template, not the generated file.
+
+use crate::{I8Vec2, I8Vec3, I8Vec4, Vec4Swizzles};
+
+impl Vec4Swizzles for I8Vec4 {
+    type Vec2 = I8Vec2;
+
+    type Vec3 = I8Vec3;
+
+    #[inline]
+    fn xx(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn xy(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn xz(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn xw(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.x,
+            y: self.w,
+        }
+    }
+
+    #[inline]
+    fn yx(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn yy(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn yz(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn yw(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.y,
+            y: self.w,
+        }
+    }
+
+    #[inline]
+    fn zx(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.z,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn zy(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.z,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn zz(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.z,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn zw(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.z,
+            y: self.w,
+        }
+    }
+
+    #[inline]
+    fn wx(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.w,
+            y: self.x,
+        }
+    }
+
+    #[inline]
+    fn wy(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.w,
+            y: self.y,
+        }
+    }
+
+    #[inline]
+    fn wz(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.w,
+            y: self.z,
+        }
+    }
+
+    #[inline]
+    fn ww(self) -> I8Vec2 {
+        I8Vec2 {
+            x: self.w,
+            y: self.w,
+        }
+    }
+
+    #[inline]
+    fn xxx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xxy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xxz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xxw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.x,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn xyx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xyy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xyz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xyw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn xzx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xzy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xzz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xzw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.z,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn xwx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.w,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn xwy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.w,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn xwz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.w,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn xww(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.x,
+            y: self.w,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn yxx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yxy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yxz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yxw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.x,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn yyx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yyy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yyz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yyw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.y,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn yzx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn yzy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn yzz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yzw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.z,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn ywx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.w,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn ywy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.w,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn ywz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.w,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn yww(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.y,
+            y: self.w,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn zxx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zxy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zxz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zxw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.x,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn zyx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zyy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zyz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zyw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.y,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn zzx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zzy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zzz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zzw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.z,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn zwx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.w,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn zwy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.w,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn zwz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.w,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn zww(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.z,
+            y: self.w,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn wxx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.x,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn wxy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.x,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn wxz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn wxw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.x,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn wyx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn wyy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.y,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn wyz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn wyw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.y,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn wzx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.z,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn wzy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.z,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn wzz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.z,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn wzw(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.z,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn wwx(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.w,
+            z: self.x,
+        }
+    }
+
+    #[inline]
+    fn wwy(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.w,
+            z: self.y,
+        }
+    }
+
+    #[inline]
+    fn wwz(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.w,
+            z: self.z,
+        }
+    }
+
+    #[inline]
+    fn www(self) -> I8Vec3 {
+        I8Vec3 {
+            x: self.w,
+            y: self.w,
+            z: self.w,
+        }
+    }
+
+    #[inline]
+    fn xxxx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn xxxy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn xxxz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn xxxw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.x, self.w)
+    }
+
+    #[inline]
+    fn xxyx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn xxyy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn xxyz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn xxyw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.y, self.w)
+    }
+
+    #[inline]
+    fn xxzx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn xxzy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn xxzz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn xxzw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.z, self.w)
+    }
+
+    #[inline]
+    fn xxwx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.w, self.x)
+    }
+
+    #[inline]
+    fn xxwy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.w, self.y)
+    }
+
+    #[inline]
+    fn xxwz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.w, self.z)
+    }
+
+    #[inline]
+    fn xxww(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.x, self.w, self.w)
+    }
+
+    #[inline]
+    fn xyxx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn xyxy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn xyxz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn xyxw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.x, self.w)
+    }
+
+    #[inline]
+    fn xyyx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn xyyy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn xyyz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn xyyw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.y, self.w)
+    }
+
+    #[inline]
+    fn xyzx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn xyzy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn xyzz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn xyzw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.z, self.w)
+    }
+
+    #[inline]
+    fn xywx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.w, self.x)
+    }
+
+    #[inline]
+    fn xywy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.w, self.y)
+    }
+
+    #[inline]
+    fn xywz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.w, self.z)
+    }
+
+    #[inline]
+    fn xyww(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.y, self.w, self.w)
+    }
+
+    #[inline]
+    fn xzxx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn xzxy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn xzxz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn xzxw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.x, self.w)
+    }
+
+    #[inline]
+    fn xzyx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn xzyy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn xzyz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn xzyw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.y, self.w)
+    }
+
+    #[inline]
+    fn xzzx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn xzzy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn xzzz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn xzzw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.z, self.w)
+    }
+
+    #[inline]
+    fn xzwx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.w, self.x)
+    }
+
+    #[inline]
+    fn xzwy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.w, self.y)
+    }
+
+    #[inline]
+    fn xzwz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.w, self.z)
+    }
+
+    #[inline]
+    fn xzww(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.z, self.w, self.w)
+    }
+
+    #[inline]
+    fn xwxx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.x, self.x)
+    }
+
+    #[inline]
+    fn xwxy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.x, self.y)
+    }
+
+    #[inline]
+    fn xwxz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.x, self.z)
+    }
+
+    #[inline]
+    fn xwxw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.x, self.w)
+    }
+
+    #[inline]
+    fn xwyx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.y, self.x)
+    }
+
+    #[inline]
+    fn xwyy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.y, self.y)
+    }
+
+    #[inline]
+    fn xwyz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.y, self.z)
+    }
+
+    #[inline]
+    fn xwyw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.y, self.w)
+    }
+
+    #[inline]
+    fn xwzx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.z, self.x)
+    }
+
+    #[inline]
+    fn xwzy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.z, self.y)
+    }
+
+    #[inline]
+    fn xwzz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.z, self.z)
+    }
+
+    #[inline]
+    fn xwzw(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.z, self.w)
+    }
+
+    #[inline]
+    fn xwwx(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.w, self.x)
+    }
+
+    #[inline]
+    fn xwwy(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.w, self.y)
+    }
+
+    #[inline]
+    fn xwwz(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.w, self.z)
+    }
+
+    #[inline]
+    fn xwww(self) -> I8Vec4 {
+        I8Vec4::new(self.x, self.w, self.w, self.w)
+    }
+
+    #[inline]
+    fn yxxx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn yxxy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn yxxz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn yxxw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.x, self.w)
+    }
+
+    #[inline]
+    fn yxyx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn yxyy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn yxyz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn yxyw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.y, self.w)
+    }
+
+    #[inline]
+    fn yxzx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn yxzy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn yxzz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn yxzw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.z, self.w)
+    }
+
+    #[inline]
+    fn yxwx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.w, self.x)
+    }
+
+    #[inline]
+    fn yxwy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.w, self.y)
+    }
+
+    #[inline]
+    fn yxwz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.w, self.z)
+    }
+
+    #[inline]
+    fn yxww(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.x, self.w, self.w)
+    }
+
+    #[inline]
+    fn yyxx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn yyxy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn yyxz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn yyxw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.x, self.w)
+    }
+
+    #[inline]
+    fn yyyx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn yyyy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn yyyz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn yyyw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.y, self.w)
+    }
+
+    #[inline]
+    fn yyzx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn yyzy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn yyzz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn yyzw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.z, self.w)
+    }
+
+    #[inline]
+    fn yywx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.w, self.x)
+    }
+
+    #[inline]
+    fn yywy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.w, self.y)
+    }
+
+    #[inline]
+    fn yywz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.w, self.z)
+    }
+
+    #[inline]
+    fn yyww(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.y, self.w, self.w)
+    }
+
+    #[inline]
+    fn yzxx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn yzxy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn yzxz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn yzxw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.x, self.w)
+    }
+
+    #[inline]
+    fn yzyx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn yzyy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn yzyz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn yzyw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.y, self.w)
+    }
+
+    #[inline]
+    fn yzzx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn yzzy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn yzzz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn yzzw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.z, self.w)
+    }
+
+    #[inline]
+    fn yzwx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.w, self.x)
+    }
+
+    #[inline]
+    fn yzwy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.w, self.y)
+    }
+
+    #[inline]
+    fn yzwz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.w, self.z)
+    }
+
+    #[inline]
+    fn yzww(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.z, self.w, self.w)
+    }
+
+    #[inline]
+    fn ywxx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.x, self.x)
+    }
+
+    #[inline]
+    fn ywxy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.x, self.y)
+    }
+
+    #[inline]
+    fn ywxz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.x, self.z)
+    }
+
+    #[inline]
+    fn ywxw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.x, self.w)
+    }
+
+    #[inline]
+    fn ywyx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.y, self.x)
+    }
+
+    #[inline]
+    fn ywyy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.y, self.y)
+    }
+
+    #[inline]
+    fn ywyz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.y, self.z)
+    }
+
+    #[inline]
+    fn ywyw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.y, self.w)
+    }
+
+    #[inline]
+    fn ywzx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.z, self.x)
+    }
+
+    #[inline]
+    fn ywzy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.z, self.y)
+    }
+
+    #[inline]
+    fn ywzz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.z, self.z)
+    }
+
+    #[inline]
+    fn ywzw(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.z, self.w)
+    }
+
+    #[inline]
+    fn ywwx(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.w, self.x)
+    }
+
+    #[inline]
+    fn ywwy(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.w, self.y)
+    }
+
+    #[inline]
+    fn ywwz(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.w, self.z)
+    }
+
+    #[inline]
+    fn ywww(self) -> I8Vec4 {
+        I8Vec4::new(self.y, self.w, self.w, self.w)
+    }
+
+    #[inline]
+    fn zxxx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn zxxy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn zxxz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn zxxw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.x, self.w)
+    }
+
+    #[inline]
+    fn zxyx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn zxyy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn zxyz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn zxyw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.y, self.w)
+    }
+
+    #[inline]
+    fn zxzx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn zxzy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn zxzz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn zxzw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.z, self.w)
+    }
+
+    #[inline]
+    fn zxwx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.w, self.x)
+    }
+
+    #[inline]
+    fn zxwy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.w, self.y)
+    }
+
+    #[inline]
+    fn zxwz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.w, self.z)
+    }
+
+    #[inline]
+    fn zxww(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.x, self.w, self.w)
+    }
+
+    #[inline]
+    fn zyxx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn zyxy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn zyxz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn zyxw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.x, self.w)
+    }
+
+    #[inline]
+    fn zyyx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn zyyy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn zyyz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn zyyw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.y, self.w)
+    }
+
+    #[inline]
+    fn zyzx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn zyzy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn zyzz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn zyzw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.z, self.w)
+    }
+
+    #[inline]
+    fn zywx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.w, self.x)
+    }
+
+    #[inline]
+    fn zywy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.w, self.y)
+    }
+
+    #[inline]
+    fn zywz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.w, self.z)
+    }
+
+    #[inline]
+    fn zyww(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.y, self.w, self.w)
+    }
+
+    #[inline]
+    fn zzxx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn zzxy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn zzxz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn zzxw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.x, self.w)
+    }
+
+    #[inline]
+    fn zzyx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn zzyy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn zzyz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn zzyw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.y, self.w)
+    }
+
+    #[inline]
+    fn zzzx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn zzzy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn zzzz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn zzzw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.z, self.w)
+    }
+
+    #[inline]
+    fn zzwx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.w, self.x)
+    }
+
+    #[inline]
+    fn zzwy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.w, self.y)
+    }
+
+    #[inline]
+    fn zzwz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.w, self.z)
+    }
+
+    #[inline]
+    fn zzww(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.z, self.w, self.w)
+    }
+
+    #[inline]
+    fn zwxx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.x, self.x)
+    }
+
+    #[inline]
+    fn zwxy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.x, self.y)
+    }
+
+    #[inline]
+    fn zwxz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.x, self.z)
+    }
+
+    #[inline]
+    fn zwxw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.x, self.w)
+    }
+
+    #[inline]
+    fn zwyx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.y, self.x)
+    }
+
+    #[inline]
+    fn zwyy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.y, self.y)
+    }
+
+    #[inline]
+    fn zwyz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.y, self.z)
+    }
+
+    #[inline]
+    fn zwyw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.y, self.w)
+    }
+
+    #[inline]
+    fn zwzx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.z, self.x)
+    }
+
+    #[inline]
+    fn zwzy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.z, self.y)
+    }
+
+    #[inline]
+    fn zwzz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.z, self.z)
+    }
+
+    #[inline]
+    fn zwzw(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.z, self.w)
+    }
+
+    #[inline]
+    fn zwwx(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.w, self.x)
+    }
+
+    #[inline]
+    fn zwwy(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.w, self.y)
+    }
+
+    #[inline]
+    fn zwwz(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.w, self.z)
+    }
+
+    #[inline]
+    fn zwww(self) -> I8Vec4 {
+        I8Vec4::new(self.z, self.w, self.w, self.w)
+    }
+
+    #[inline]
+    fn wxxx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn wxxy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn wxxz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn wxxw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.x, self.w)
+    }
+
+    #[inline]
+    fn wxyx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn wxyy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn wxyz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn wxyw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.y, self.w)
+    }
+
+    #[inline]
+    fn wxzx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn wxzy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn wxzz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn wxzw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.z, self.w)
+    }
+
+    #[inline]
+    fn wxwx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.w, self.x)
+    }
+
+    #[inline]
+    fn wxwy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.w, self.y)
+    }
+
+    #[inline]
+    fn wxwz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.w, self.z)
+    }
+
+    #[inline]
+    fn wxww(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.x, self.w, self.w)
+    }
+
+    #[inline]
+    fn wyxx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn wyxy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn wyxz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn wyxw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.x, self.w)
+    }
+
+    #[inline]
+    fn wyyx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn wyyy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn wyyz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn wyyw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.y, self.w)
+    }
+
+    #[inline]
+    fn wyzx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn wyzy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn wyzz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn wyzw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.z, self.w)
+    }
+
+    #[inline]
+    fn wywx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.w, self.x)
+    }
+
+    #[inline]
+    fn wywy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.w, self.y)
+    }
+
+    #[inline]
+    fn wywz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.w, self.z)
+    }
+
+    #[inline]
+    fn wyww(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.y, self.w, self.w)
+    }
+
+    #[inline]
+    fn wzxx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn wzxy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn wzxz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn wzxw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.x, self.w)
+    }
+
+    #[inline]
+    fn wzyx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn wzyy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn wzyz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn wzyw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.y, self.w)
+    }
+
+    #[inline]
+    fn wzzx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn wzzy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn wzzz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn wzzw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.z, self.w)
+    }
+
+    #[inline]
+    fn wzwx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.w, self.x)
+    }
+
+    #[inline]
+    fn wzwy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.w, self.y)
+    }
+
+    #[inline]
+    fn wzwz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.w, self.z)
+    }
+
+    #[inline]
+    fn wzww(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.z, self.w, self.w)
+    }
+
+    #[inline]
+    fn wwxx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.x, self.x)
+    }
+
+    #[inline]
+    fn wwxy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.x, self.y)
+    }
+
+    #[inline]
+    fn wwxz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.x, self.z)
+    }
+
+    #[inline]
+    fn wwxw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.x, self.w)
+    }
+
+    #[inline]
+    fn wwyx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.y, self.x)
+    }
+
+    #[inline]
+    fn wwyy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.y, self.y)
+    }
+
+    #[inline]
+    fn wwyz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.y, self.z)
+    }
+
+    #[inline]
+    fn wwyw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.y, self.w)
+    }
+
+    #[inline]
+    fn wwzx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.z, self.x)
+    }
+
+    #[inline]
+    fn wwzy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.z, self.y)
+    }
+
+    #[inline]
+    fn wwzz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.z, self.z)
+    }
+
+    #[inline]
+    fn wwzw(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.z, self.w)
+    }
+
+    #[inline]
+    fn wwwx(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.w, self.x)
+    }
+
+    #[inline]
+    fn wwwy(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.w, self.y)
+    }
+
+    #[inline]
+    fn wwwz(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.w, self.z)
+    }
+
+    #[inline]
+    fn wwww(self) -> I8Vec4 {
+        I8Vec4::new(self.w, self.w, self.w, self.w)
+    }
+}
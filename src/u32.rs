@@ -2,13 +2,49 @@ mod uvec2;
 mod uvec3;
 mod uvec4;
 
+#[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
+mod coresimd;
+
+#[cfg(any(
+    not(any(feature = "core-simd", target_feature = "sse2")),
+    feature = "scalar-math"
+))]
+mod scalar;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod sse2;
+
+#[cfg(any(
+    not(any(feature = "core-simd", target_feature = "sse2")),
+    feature = "scalar-math"
+))]
+use scalar::*;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+use sse2::*;
+
+#[cfg(all(feature = "core-simd", not(feature = "scalar-math")))]
+use coresimd::*;
+
 pub use uvec2::{uvec2, UVec2};
 pub use uvec3::{uvec3, UVec3};
 pub use uvec4::{uvec4, UVec4};
+pub use uvec4a::{uvec4a, UVec4A};
 
 #[cfg(not(target_arch = "spirv"))]
 mod test {
     use super::*;
+
+    mod const_test_uvec4a {
+        const_assert_eq!(16, core::mem::align_of::<super::UVec4A>());
+        const_assert_eq!(16, core::mem::size_of::<super::UVec4A>());
+    }
     mod const_test_uvec2 {
         #[cfg(not(feature = "cuda"))]
         const_assert_eq!(
@@ -0,0 +1 @@
+pub mod uvec4a;
@@ -0,0 +1,1121 @@
+/*!
+Structure-of-arrays vector types for batch math.
+
+Types such as [`Vec3A`](crate::Vec3A) store the components of a single vector in one SIMD
+register, which is an array-of-structures layout. Operations such as dot products then need
+shuffles and horizontal adds, and only 3 of the 4 lanes do useful work.
+
+The types in this module store 4 or 8 vectors at once with one SIMD register per component, so
+[`Vec3x4`] has one [`F32x4`] for the `x` components of all four vectors, one for the `y`
+components and so on. Every operation works on all vectors in parallel without any shuffles,
+which makes these types a good fit for transforming large numbers of particles or vertices.
+Slices of [`Vec3`] can also be transformed in batches without using these types directly with
+methods such as [`Mat4::transform_points3_slice()`] and [`Quat::mul_vec3_slice()`].
+
+```
+use glam::wide::Vec3x4;
+use glam::{Mat4, Vec3};
+
+let mat = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+let points = Vec3x4::from_array([Vec3::X, Vec3::Y, Vec3::Z, Vec3::ONE]);
+let [a, b, c, d] = points.transform_point3(&mat).to_array();
+assert_eq!(a, Vec3::new(2.0, 2.0, 3.0));
+assert_eq!(d, Vec3::new(2.0, 3.0, 4.0));
+```
+
+The lane types [`F32x4`] and [`F32x8`] use SSE2 and AVX registers respectively when those target
+features are enabled. Otherwise [`F32x4`] falls back to scalar math and [`F32x8`] is made of two
+[`F32x4`] values. The alignment of the lane types is always 16 and 32 bytes.
+
+Comparisons return lane masks such as [`B32x4`], which can be passed to the `select` methods to
+choose between two values per lane.
+
+Like the rest of `glam`, arithmetic is performed in the same order as the scalar types so each
+lane gives the same result as the equivalent [`Vec3`], [`Vec4`] or [`Quat`] operation. The
+exception is `min` and `max` when a lane is `NaN`.
+*/
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod sse2;
+
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+mod scalar;
+
+#[cfg(all(
+    target_feature = "avx",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+mod avx;
+
+#[cfg(any(
+    not(target_feature = "avx"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+mod split;
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+pub use sse2::{B32x4, F32x4};
+
+#[cfg(any(
+    not(target_feature = "sse2"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+pub use scalar::{B32x4, F32x4};
+
+#[cfg(all(
+    target_feature = "avx",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+pub use avx::{B32x8, F32x8};
+
+#[cfg(any(
+    not(target_feature = "avx"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+pub use split::{B32x8, F32x8};
+
+use crate::{Mat4, Quat, Vec3, Vec4};
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::*;
+
+macro_rules! impl_wide_lanes {
+    ($f:ident, $b:ident, $n:literal) => {
+        impl $f {
+            /// The number of lanes.
+            pub const LANES: usize = $n;
+
+            /// All lanes set to `0.0`.
+            pub const ZERO: Self = Self::splat(0.0);
+
+            /// All lanes set to `1.0`.
+            pub const ONE: Self = Self::splat(1.0);
+
+            /// All lanes set to `-1.0`.
+            pub const NEG_ONE: Self = Self::splat(-1.0);
+
+            /// All lanes set to `f32::INFINITY`.
+            pub const INFINITY: Self = Self::splat(f32::INFINITY);
+
+            /// Returns `1.0 / self` for each lane.
+            #[inline]
+            pub fn recip(self) -> Self {
+                Self::ONE / self
+            }
+
+            /// Returns a mask of the lanes that are neither infinite nor `NaN`.
+            #[inline]
+            pub fn is_finite(self) -> $b {
+                self.abs().cmplt(Self::INFINITY)
+            }
+        }
+
+        impl Default for $f {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl PartialEq for $f {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.to_array() == rhs.to_array()
+            }
+        }
+
+        impl AddAssign for $f {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $f {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $f {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $f {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl From<[f32; $n]> for $f {
+            #[inline]
+            fn from(a: [f32; $n]) -> Self {
+                Self::from_array(a)
+            }
+        }
+
+        impl From<$f> for [f32; $n] {
+            #[inline]
+            fn from(v: $f) -> Self {
+                v.to_array()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $f {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut t = f.debug_tuple(stringify!($f));
+                for v in self.to_array() {
+                    t.field(&v);
+                }
+                t.finish()
+            }
+        }
+
+        impl $b {
+            /// All lanes false.
+            pub const FALSE: Self = Self::splat(false);
+
+            /// All lanes true.
+            pub const TRUE: Self = Self::splat(true);
+
+            /// Returns true if any of the lanes are true, false otherwise.
+            #[inline]
+            pub fn any(self) -> bool {
+                self.bitmask() != 0
+            }
+
+            /// Returns true if all the lanes are true, false otherwise.
+            #[inline]
+            pub fn all(self) -> bool {
+                self.bitmask() == (1 << $n) - 1
+            }
+
+            /// Returns the lanes of `self` as an array.
+            #[inline]
+            pub fn to_array(&self) -> [bool; $n] {
+                let bitmask = self.bitmask();
+                let mut out = [false; $n];
+                for (i, v) in out.iter_mut().enumerate() {
+                    *v = bitmask & (1 << i) != 0;
+                }
+                out
+            }
+        }
+
+        impl Default for $b {
+            #[inline]
+            fn default() -> Self {
+                Self::FALSE
+            }
+        }
+
+        impl PartialEq for $b {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.bitmask() == rhs.bitmask()
+            }
+        }
+
+        impl Eq for $b {}
+
+        impl BitAndAssign for $b {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl BitOrAssign for $b {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl BitXorAssign for $b {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: Self) {
+                *self = *self ^ rhs;
+            }
+        }
+
+        impl From<[bool; $n]> for $b {
+            #[inline]
+            fn from(a: [bool; $n]) -> Self {
+                Self::from_array(a)
+            }
+        }
+
+        impl From<$b> for [bool; $n] {
+            #[inline]
+            fn from(m: $b) -> Self {
+                m.to_array()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $b {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut t = f.debug_tuple(stringify!($b));
+                for v in self.to_array() {
+                    t.field(&v);
+                }
+                t.finish()
+            }
+        }
+    };
+}
+
+macro_rules! impl_wide_vec_ops {
+    ($f:ident, $vec:ident, $($c:ident),+) => {
+        impl Add for $vec {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    $($c: self.$c + rhs.$c,)+
+                }
+            }
+        }
+
+        impl Sub for $vec {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self {
+                    $($c: self.$c - rhs.$c,)+
+                }
+            }
+        }
+
+        impl Mul for $vec {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self {
+                    $($c: self.$c * rhs.$c,)+
+                }
+            }
+        }
+
+        impl Div for $vec {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                Self {
+                    $($c: self.$c / rhs.$c,)+
+                }
+            }
+        }
+
+        impl Mul<$f> for $vec {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: $f) -> Self {
+                Self {
+                    $($c: self.$c * rhs,)+
+                }
+            }
+        }
+
+        impl Div<$f> for $vec {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: $f) -> Self {
+                Self {
+                    $($c: self.$c / rhs,)+
+                }
+            }
+        }
+
+        impl Neg for $vec {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self {
+                    $($c: -self.$c,)+
+                }
+            }
+        }
+
+        impl AddAssign for $vec {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $vec {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $vec {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $vec {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl MulAssign<$f> for $vec {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $f) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign<$f> for $vec {
+            #[inline]
+            fn div_assign(&mut self, rhs: $f) {
+                *self = *self / rhs;
+            }
+        }
+    };
+}
+
+macro_rules! impl_wide_types {
+    ($n:literal, $f:ident, $b:ident, $vec3:ident, $vec4:ident, $quat:ident) => {
+        impl_wide_lanes!($f, $b, $n);
+
+        #[doc = concat!("Structure-of-arrays storage for ", stringify!($n), " [`Vec3`] values.")]
+        #[derive(Clone, Copy, Default, PartialEq)]
+        #[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+        #[repr(C)]
+        pub struct $vec3 {
+            pub x: $f,
+            pub y: $f,
+            pub z: $f,
+        }
+
+        impl $vec3 {
+            /// All zeroes.
+            pub const ZERO: Self = Self::splat_const(Vec3::ZERO);
+
+            /// All ones.
+            pub const ONE: Self = Self::splat_const(Vec3::ONE);
+
+            /// A unit vector pointing along the positive X axis in every lane.
+            pub const X: Self = Self::splat_const(Vec3::X);
+
+            /// A unit vector pointing along the positive Y axis in every lane.
+            pub const Y: Self = Self::splat_const(Vec3::Y);
+
+            /// A unit vector pointing along the positive Z axis in every lane.
+            pub const Z: Self = Self::splat_const(Vec3::Z);
+
+            /// Creates a new value from the lanes of each component.
+            #[inline(always)]
+            pub const fn new(x: $f, y: $f, z: $f) -> Self {
+                Self { x, y, z }
+            }
+
+            const fn splat_const(v: Vec3) -> Self {
+                Self::new($f::splat(v.x), $f::splat(v.y), $f::splat(v.z))
+            }
+
+            /// Creates a value with all lanes set to `v`.
+            #[inline]
+            pub fn splat(v: Vec3) -> Self {
+                Self::splat_const(v)
+            }
+
+            /// Transposes an array of vectors into structure-of-arrays storage.
+            #[inline]
+            pub fn from_array(a: [Vec3; $n]) -> Self {
+                Self::new(
+                    $f::from_array(a.map(|v| v.x)),
+                    $f::from_array(a.map(|v| v.y)),
+                    $f::from_array(a.map(|v| v.z)),
+                )
+            }
+
+            /// Transposes `self` into an array of vectors.
+            #[inline]
+            pub fn to_array(&self) -> [Vec3; $n] {
+                let (x, y, z) = (self.x.to_array(), self.y.to_array(), self.z.to_array());
+                let mut out = [Vec3::ZERO; $n];
+                for (i, v) in out.iter_mut().enumerate() {
+                    *v = Vec3::new(x[i], y[i], z[i]);
+                }
+                out
+            }
+
+            #[doc = concat!("Transposes the first ", stringify!($n), " vectors in `slice` into structure-of-arrays storage.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if `slice` is less than ", stringify!($n), " elements long.")]
+            #[inline]
+            pub fn from_slice(slice: &[Vec3]) -> Self {
+                let mut a = [Vec3::ZERO; $n];
+                a.copy_from_slice(&slice[..$n]);
+                Self::from_array(a)
+            }
+
+            #[doc = concat!("Transposes `self` and writes the vectors to the first ", stringify!($n), " elements in `slice`.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if `slice` is less than ", stringify!($n), " elements long.")]
+            #[inline]
+            pub fn write_to_slice(self, slice: &mut [Vec3]) {
+                slice[..$n].copy_from_slice(&self.to_array());
+            }
+
+            /// Creates a 4D vector from `self` and the given `w` value.
+            #[inline]
+            pub fn extend(self, w: $f) -> $vec4 {
+                $vec4::new(self.x, self.y, self.z, w)
+            }
+
+            /// Creates a value from the lanes of `if_true` where `mask` is true and from
+            /// `if_false` otherwise.
+            #[inline]
+            pub fn select(mask: $b, if_true: Self, if_false: Self) -> Self {
+                Self::new(
+                    $f::select(mask, if_true.x, if_false.x),
+                    $f::select(mask, if_true.y, if_false.y),
+                    $f::select(mask, if_true.z, if_false.z),
+                )
+            }
+
+            /// Computes the dot product of each lane of `self` and `rhs`.
+            #[inline]
+            pub fn dot(self, rhs: Self) -> $f {
+                (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z)
+            }
+
+            /// Computes the cross product of each lane of `self` and `rhs`.
+            #[inline]
+            pub fn cross(self, rhs: Self) -> Self {
+                Self::new(
+                    self.y * rhs.z - rhs.y * self.z,
+                    self.z * rhs.x - rhs.z * self.x,
+                    self.x * rhs.y - rhs.x * self.y,
+                )
+            }
+
+            /// Returns the minimum of each component of `self` and `rhs`.
+            #[inline]
+            pub fn min(self, rhs: Self) -> Self {
+                Self::new(self.x.min(rhs.x), self.y.min(rhs.y), self.z.min(rhs.z))
+            }
+
+            /// Returns the maximum of each component of `self` and `rhs`.
+            #[inline]
+            pub fn max(self, rhs: Self) -> Self {
+                Self::new(self.x.max(rhs.x), self.y.max(rhs.y), self.z.max(rhs.z))
+            }
+
+            /// Computes the squared length of each lane.
+            #[inline]
+            pub fn length_squared(self) -> $f {
+                self.dot(self)
+            }
+
+            /// Computes the length of each lane.
+            #[inline]
+            pub fn length(self) -> $f {
+                self.dot(self).sqrt()
+            }
+
+            /// Computes `1.0 / length()` of each lane.
+            #[inline]
+            pub fn length_recip(self) -> $f {
+                self.length().recip()
+            }
+
+            /// Computes the Euclidean distance between each lane of `self` and `rhs`.
+            #[inline]
+            pub fn distance(self, rhs: Self) -> $f {
+                (self - rhs).length()
+            }
+
+            /// Computes the squared Euclidean distance between each lane of `self` and `rhs`.
+            #[inline]
+            pub fn distance_squared(self, rhs: Self) -> $f {
+                (self - rhs).length_squared()
+            }
+
+            /// Returns each lane normalized to length 1.0.
+            ///
+            /// Lanes of length zero will contain non-finite values.
+            #[inline]
+            pub fn normalize(self) -> Self {
+                self * self.length_recip()
+            }
+
+            /// Returns each lane normalized to length 1.0, or zero if the lane is of length zero
+            /// or can't be normalized.
+            #[inline]
+            pub fn normalize_or_zero(self) -> Self {
+                let rcp = self.length_recip();
+                let mask = rcp.is_finite() & rcp.cmpgt($f::ZERO);
+                Self::select(mask, self * rcp, Self::ZERO)
+            }
+
+            /// Performs a linear interpolation between each lane of `self` and `rhs` based on
+            /// the lanes of `s`.
+            #[inline]
+            pub fn lerp(self, rhs: Self, s: $f) -> Self {
+                self + ((rhs - self) * s)
+            }
+
+            /// Transforms each lane of `self` as a 3D point by `mat`.
+            ///
+            /// This is the equivalent of calling [`Mat4::transform_point3()`] for each lane.
+            #[inline]
+            pub fn transform_point3(self, mat: &Mat4) -> Self {
+                let mut res = $vec4::splat(mat.x_axis) * self.x;
+                res = $vec4::splat(mat.y_axis) * self.y + res;
+                res = $vec4::splat(mat.z_axis) * self.z + res;
+                res = $vec4::splat(mat.w_axis) + res;
+                res.truncate()
+            }
+
+            /// Transforms each lane of `self` as a 3D direction by `mat`, ignoring translation.
+            ///
+            /// This is the equivalent of calling [`Mat4::transform_vector3()`] for each lane.
+            #[inline]
+            pub fn transform_vector3(self, mat: &Mat4) -> Self {
+                let mut res = $vec4::splat(mat.x_axis) * self.x;
+                res = $vec4::splat(mat.y_axis) * self.y + res;
+                res = $vec4::splat(mat.z_axis) * self.z + res;
+                res.truncate()
+            }
+
+            /// Transforms each lane of `self` as a 3D point by `mat`, applying perspective
+            /// correction.
+            ///
+            /// This is the equivalent of calling [`Mat4::project_point3()`] for each lane.
+            #[inline]
+            pub fn project_point3(self, mat: &Mat4) -> Self {
+                let mut res = $vec4::splat(mat.x_axis) * self.x;
+                res = $vec4::splat(mat.y_axis) * self.y + res;
+                res = $vec4::splat(mat.z_axis) * self.z + res;
+                res = $vec4::splat(mat.w_axis) + res;
+                res.truncate() * res.w.recip()
+            }
+        }
+
+        impl_wide_vec_ops!($f, $vec3, x, y, z);
+
+        impl From<[Vec3; $n]> for $vec3 {
+            #[inline]
+            fn from(a: [Vec3; $n]) -> Self {
+                Self::from_array(a)
+            }
+        }
+
+        impl From<$vec3> for [Vec3; $n] {
+            #[inline]
+            fn from(v: $vec3) -> Self {
+                v.to_array()
+            }
+        }
+
+        #[doc = concat!("Structure-of-arrays storage for ", stringify!($n), " [`Vec4`] values.")]
+        #[derive(Clone, Copy, Default, PartialEq)]
+        #[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+        #[repr(C)]
+        pub struct $vec4 {
+            pub x: $f,
+            pub y: $f,
+            pub z: $f,
+            pub w: $f,
+        }
+
+        impl $vec4 {
+            /// All zeroes.
+            pub const ZERO: Self = Self::new($f::ZERO, $f::ZERO, $f::ZERO, $f::ZERO);
+
+            /// All ones.
+            pub const ONE: Self = Self::new($f::ONE, $f::ONE, $f::ONE, $f::ONE);
+
+            /// Creates a new value from the lanes of each component.
+            #[inline(always)]
+            pub const fn new(x: $f, y: $f, z: $f, w: $f) -> Self {
+                Self { x, y, z, w }
+            }
+
+            /// Creates a value with all lanes set to `v`.
+            #[inline]
+            pub fn splat(v: Vec4) -> Self {
+                Self::new(
+                    $f::splat(v.x),
+                    $f::splat(v.y),
+                    $f::splat(v.z),
+                    $f::splat(v.w),
+                )
+            }
+
+            /// Transposes an array of vectors into structure-of-arrays storage.
+            #[inline]
+            pub fn from_array(a: [Vec4; $n]) -> Self {
+                Self::new(
+                    $f::from_array(a.map(|v| v.x)),
+                    $f::from_array(a.map(|v| v.y)),
+                    $f::from_array(a.map(|v| v.z)),
+                    $f::from_array(a.map(|v| v.w)),
+                )
+            }
+
+            /// Transposes `self` into an array of vectors.
+            #[inline]
+            pub fn to_array(&self) -> [Vec4; $n] {
+                let (x, y, z, w) = (
+                    self.x.to_array(),
+                    self.y.to_array(),
+                    self.z.to_array(),
+                    self.w.to_array(),
+                );
+                let mut out = [Vec4::ZERO; $n];
+                for (i, v) in out.iter_mut().enumerate() {
+                    *v = Vec4::new(x[i], y[i], z[i], w[i]);
+                }
+                out
+            }
+
+            /// Creates a 3D vector from the `x`, `y` and `z` components of `self`, discarding
+            /// `w`.
+            #[inline]
+            pub fn truncate(self) -> $vec3 {
+                $vec3::new(self.x, self.y, self.z)
+            }
+
+            /// Creates a value from the lanes of `if_true` where `mask` is true and from
+            /// `if_false` otherwise.
+            #[inline]
+            pub fn select(mask: $b, if_true: Self, if_false: Self) -> Self {
+                Self::new(
+                    $f::select(mask, if_true.x, if_false.x),
+                    $f::select(mask, if_true.y, if_false.y),
+                    $f::select(mask, if_true.z, if_false.z),
+                    $f::select(mask, if_true.w, if_false.w),
+                )
+            }
+
+            /// Computes the dot product of each lane of `self` and `rhs`.
+            #[inline]
+            pub fn dot(self, rhs: Self) -> $f {
+                (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z) + (self.w * rhs.w)
+            }
+
+            /// Returns the minimum of each component of `self` and `rhs`.
+            #[inline]
+            pub fn min(self, rhs: Self) -> Self {
+                Self::new(
+                    self.x.min(rhs.x),
+                    self.y.min(rhs.y),
+                    self.z.min(rhs.z),
+                    self.w.min(rhs.w),
+                )
+            }
+
+            /// Returns the maximum of each component of `self` and `rhs`.
+            #[inline]
+            pub fn max(self, rhs: Self) -> Self {
+                Self::new(
+                    self.x.max(rhs.x),
+                    self.y.max(rhs.y),
+                    self.z.max(rhs.z),
+                    self.w.max(rhs.w),
+                )
+            }
+
+            /// Computes the squared length of each lane.
+            #[inline]
+            pub fn length_squared(self) -> $f {
+                self.dot(self)
+            }
+
+            /// Computes the length of each lane.
+            #[inline]
+            pub fn length(self) -> $f {
+                self.dot(self).sqrt()
+            }
+
+            /// Computes `1.0 / length()` of each lane.
+            #[inline]
+            pub fn length_recip(self) -> $f {
+                self.length().recip()
+            }
+
+            /// Computes the Euclidean distance between each lane of `self` and `rhs`.
+            #[inline]
+            pub fn distance(self, rhs: Self) -> $f {
+                (self - rhs).length()
+            }
+
+            /// Computes the squared Euclidean distance between each lane of `self` and `rhs`.
+            #[inline]
+            pub fn distance_squared(self, rhs: Self) -> $f {
+                (self - rhs).length_squared()
+            }
+
+            /// Returns each lane normalized to length 1.0.
+            ///
+            /// Lanes of length zero will contain non-finite values.
+            #[inline]
+            pub fn normalize(self) -> Self {
+                self * self.length_recip()
+            }
+
+            /// Returns each lane normalized to length 1.0, or zero if the lane is of length zero
+            /// or can't be normalized.
+            #[inline]
+            pub fn normalize_or_zero(self) -> Self {
+                let rcp = self.length_recip();
+                let mask = rcp.is_finite() & rcp.cmpgt($f::ZERO);
+                Self::select(mask, self * rcp, Self::ZERO)
+            }
+
+            /// Performs a linear interpolation between each lane of `self` and `rhs` based on
+            /// the lanes of `s`.
+            #[inline]
+            pub fn lerp(self, rhs: Self, s: $f) -> Self {
+                self + ((rhs - self) * s)
+            }
+        }
+
+        impl_wide_vec_ops!($f, $vec4, x, y, z, w);
+
+        impl Mul<$vec4> for Mat4 {
+            type Output = $vec4;
+            #[inline]
+            fn mul(self, rhs: $vec4) -> $vec4 {
+                let mut res = $vec4::splat(self.x_axis) * rhs.x;
+                res += $vec4::splat(self.y_axis) * rhs.y;
+                res += $vec4::splat(self.z_axis) * rhs.z;
+                res += $vec4::splat(self.w_axis) * rhs.w;
+                res
+            }
+        }
+
+        impl From<[Vec4; $n]> for $vec4 {
+            #[inline]
+            fn from(a: [Vec4; $n]) -> Self {
+                Self::from_array(a)
+            }
+        }
+
+        impl From<$vec4> for [Vec4; $n] {
+            #[inline]
+            fn from(v: $vec4) -> Self {
+                v.to_array()
+            }
+        }
+
+        #[doc = concat!("Structure-of-arrays storage for ", stringify!($n), " [`Quat`] values.")]
+        #[derive(Clone, Copy, PartialEq)]
+        #[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+        #[repr(C)]
+        pub struct $quat {
+            pub x: $f,
+            pub y: $f,
+            pub z: $f,
+            pub w: $f,
+        }
+
+        impl $quat {
+            /// The identity quaternion in every lane.
+            pub const IDENTITY: Self = Self::from_xyzw($f::ZERO, $f::ZERO, $f::ZERO, $f::ONE);
+
+            /// Creates a new value from the lanes of each component.
+            #[inline(always)]
+            pub const fn from_xyzw(x: $f, y: $f, z: $f, w: $f) -> Self {
+                Self { x, y, z, w }
+            }
+
+            /// Creates a value with all lanes set to `q`.
+            #[inline]
+            pub fn splat(q: Quat) -> Self {
+                Self::from_xyzw(
+                    $f::splat(q.x),
+                    $f::splat(q.y),
+                    $f::splat(q.z),
+                    $f::splat(q.w),
+                )
+            }
+
+            /// Transposes an array of quaternions into structure-of-arrays storage.
+            #[inline]
+            pub fn from_array(a: [Quat; $n]) -> Self {
+                Self::from_xyzw(
+                    $f::from_array(a.map(|q| q.x)),
+                    $f::from_array(a.map(|q| q.y)),
+                    $f::from_array(a.map(|q| q.z)),
+                    $f::from_array(a.map(|q| q.w)),
+                )
+            }
+
+            /// Transposes `self` into an array of quaternions.
+            #[inline]
+            pub fn to_array(&self) -> [Quat; $n] {
+                let (x, y, z, w) = (
+                    self.x.to_array(),
+                    self.y.to_array(),
+                    self.z.to_array(),
+                    self.w.to_array(),
+                );
+                let mut out = [Quat::IDENTITY; $n];
+                for (i, q) in out.iter_mut().enumerate() {
+                    *q = Quat::from_xyzw(x[i], y[i], z[i], w[i]);
+                }
+                out
+            }
+
+            /// Creates a value from the lanes of `if_true` where `mask` is true and from
+            /// `if_false` otherwise.
+            #[inline]
+            pub fn select(mask: $b, if_true: Self, if_false: Self) -> Self {
+                Self::from_xyzw(
+                    $f::select(mask, if_true.x, if_false.x),
+                    $f::select(mask, if_true.y, if_false.y),
+                    $f::select(mask, if_true.z, if_false.z),
+                    $f::select(mask, if_true.w, if_false.w),
+                )
+            }
+
+            /// Returns the quaternion conjugate of each lane.
+            #[inline]
+            pub fn conjugate(self) -> Self {
+                Self::from_xyzw(-self.x, -self.y, -self.z, self.w)
+            }
+
+            /// Computes the dot product of each lane of `self` and `rhs`.
+            #[inline]
+            pub fn dot(self, rhs: Self) -> $f {
+                (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z) + (self.w * rhs.w)
+            }
+
+            /// Computes the squared length of each lane.
+            #[inline]
+            pub fn length_squared(self) -> $f {
+                self.dot(self)
+            }
+
+            /// Computes the length of each lane.
+            #[inline]
+            pub fn length(self) -> $f {
+                self.dot(self).sqrt()
+            }
+
+            /// Returns each lane normalized to length 1.0.
+            ///
+            /// Lanes of length zero will contain non-finite values.
+            #[inline]
+            pub fn normalize(self) -> Self {
+                let rcp = self.length().recip();
+                Self::from_xyzw(self.x * rcp, self.y * rcp, self.z * rcp, self.w * rcp)
+            }
+
+            /// Multiplies each lane of `rhs` by the quaternion in the same lane of `self`.
+            ///
+            /// The quaternions should be normalized for the result to be a rotation.
+            #[inline]
+            pub fn mul_vec3(self, rhs: $vec3) -> $vec3 {
+                let w = self.w;
+                let b = $vec3::new(self.x, self.y, self.z);
+                let b2 = b.dot(b);
+                let two = $f::splat(2.0);
+                rhs * (w * w - b2) + b * (rhs.dot(b) * two) + b.cross(rhs) * (w * two)
+            }
+
+            /// Multiplies each lane of `self` by the quaternion in the same lane of `rhs`.
+            #[inline]
+            pub fn mul_quat(self, rhs: Self) -> Self {
+                let (x0, y0, z0, w0) = (self.x, self.y, self.z, self.w);
+                let (x1, y1, z1, w1) = (rhs.x, rhs.y, rhs.z, rhs.w);
+                Self::from_xyzw(
+                    w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+                    w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+                    w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+                    w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+                )
+            }
+        }
+
+        impl Default for $quat {
+            #[inline]
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl Mul for $quat {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.mul_quat(rhs)
+            }
+        }
+
+        impl MulAssign for $quat {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = self.mul_quat(rhs);
+            }
+        }
+
+        impl Mul<$vec3> for $quat {
+            type Output = $vec3;
+            #[inline]
+            fn mul(self, rhs: $vec3) -> $vec3 {
+                self.mul_vec3(rhs)
+            }
+        }
+
+        impl Mul<$vec3> for Quat {
+            type Output = $vec3;
+            #[inline]
+            fn mul(self, rhs: $vec3) -> $vec3 {
+                $quat::splat(self).mul_vec3(rhs)
+            }
+        }
+
+        impl Neg for $quat {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self::from_xyzw(-self.x, -self.y, -self.z, -self.w)
+            }
+        }
+
+        impl From<[Quat; $n]> for $quat {
+            #[inline]
+            fn from(a: [Quat; $n]) -> Self {
+                Self::from_array(a)
+            }
+        }
+
+        impl From<$quat> for [Quat; $n] {
+            #[inline]
+            fn from(q: $quat) -> Self {
+                q.to_array()
+            }
+        }
+    };
+}
+
+impl_wide_types!(4, F32x4, B32x4, Vec3x4, Vec4x4, Quatx4);
+impl_wide_types!(8, F32x8, B32x8, Vec3x8, Vec4x8, Quatx8);
+
+#[cfg(all(
+    target_feature = "avx",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+type BatchVec3 = Vec3x8;
+
+#[cfg(any(
+    not(target_feature = "avx"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+type BatchVec3 = Vec3x4;
+
+#[cfg(all(
+    target_feature = "avx",
+    not(any(feature = "core-simd", feature = "scalar-math"))
+))]
+const BATCH_LANES: usize = 8;
+
+#[cfg(any(
+    not(target_feature = "avx"),
+    feature = "core-simd",
+    feature = "scalar-math"
+))]
+const BATCH_LANES: usize = 4;
+
+/// Replaces each element of `points` with the result of `batch` for as many whole batches of
+/// points as possible, then `single` for the remaining points.
+///
+/// Used by the `*_slice` methods of the `f32` types.
+#[inline]
+pub(crate) fn map_vec3_slice(
+    points: &mut [Vec3],
+    batch: impl Fn(BatchVec3) -> BatchVec3,
+    single: impl Fn(Vec3) -> Vec3,
+) {
+    let mut chunks = points.chunks_exact_mut(BATCH_LANES);
+    for chunk in &mut chunks {
+        batch(BatchVec3::from_slice(chunk)).write_to_slice(chunk);
+    }
+    for p in chunks.into_remainder() {
+        *p = single(*p);
+    }
+}
+
+/// Like [`map_vec3_slice`] but reads from `src` and writes the results to `dst`.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` are not the same length.
+#[inline]
+pub(crate) fn map_vec3_slice_to(
+    src: &[Vec3],
+    dst: &mut [Vec3],
+    batch: impl Fn(BatchVec3) -> BatchVec3,
+    single: impl Fn(Vec3) -> Vec3,
+) {
+    assert_eq!(src.len(), dst.len());
+    let mut src_chunks = src.chunks_exact(BATCH_LANES);
+    let mut dst_chunks = dst.chunks_exact_mut(BATCH_LANES);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        batch(BatchVec3::from_slice(s)).write_to_slice(d);
+    }
+    for (s, d) in src_chunks
+        .remainder()
+        .iter()
+        .zip(dst_chunks.into_remainder())
+    {
+        *d = single(*s);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wide_align() {
+        use core::mem;
+        assert_eq!(16, mem::align_of::<F32x4>());
+        assert_eq!(16, mem::size_of::<F32x4>());
+        assert_eq!(32, mem::align_of::<F32x8>());
+        assert_eq!(32, mem::size_of::<F32x8>());
+        assert_eq!(48, mem::size_of::<Vec3x4>());
+        assert_eq!(96, mem::size_of::<Vec3x8>());
+        assert_eq!(64, mem::size_of::<Vec4x4>());
+        assert_eq!(128, mem::size_of::<Quatx8>());
+    }
+}
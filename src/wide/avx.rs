@@ -0,0 +1,215 @@
+use crate::avx::*;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core::ops::*;
+
+/// Eight `f32` lanes stored in a 256-bit AVX vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct F32x8(pub(crate) __m256);
+
+/// An eight lane mask, the result of comparing two [`F32x8`] values.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct B32x8(pub(crate) __m256);
+
+impl F32x8 {
+    /// Creates a value with all lanes set to `v`.
+    #[inline]
+    pub const fn splat(v: f32) -> Self {
+        Self(m256_from_f32x8([v; 8]))
+    }
+
+    /// Creates a value from an array of lanes.
+    #[inline]
+    pub const fn from_array(a: [f32; 8]) -> Self {
+        Self(m256_from_f32x8(a))
+    }
+
+    /// Returns the lanes of `self` as an array.
+    #[inline]
+    pub fn to_array(&self) -> [f32; 8] {
+        let mut out = [0.0; 8];
+        unsafe { _mm256_storeu_ps(out.as_mut_ptr(), self.0) };
+        out
+    }
+
+    /// Returns the square root of each lane.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        Self(unsafe { _mm256_sqrt_ps(self.0) })
+    }
+
+    /// Returns the absolute value of each lane.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(unsafe {
+            _mm256_and_ps(
+                self.0,
+                _mm256_castsi256_ps(_mm256_set1_epi32(0x7f_ff_ff_ff)),
+            )
+        })
+    }
+
+    /// Returns the minimum of each lane of `self` and `rhs`.
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_min_ps(self.0, rhs.0) })
+    }
+
+    /// Returns the maximum of each lane of `self` and `rhs`.
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_max_ps(self.0, rhs.0) })
+    }
+
+    /// Returns a mask of the lanes where `self == rhs`.
+    #[inline]
+    pub fn cmpeq(self, rhs: Self) -> B32x8 {
+        B32x8(unsafe { _mm256_cmp_ps(self.0, rhs.0, _CMP_EQ_OQ) })
+    }
+
+    /// Returns a mask of the lanes where `self != rhs`.
+    #[inline]
+    pub fn cmpne(self, rhs: Self) -> B32x8 {
+        B32x8(unsafe { _mm256_cmp_ps(self.0, rhs.0, _CMP_NEQ_UQ) })
+    }
+
+    /// Returns a mask of the lanes where `self >= rhs`.
+    #[inline]
+    pub fn cmpge(self, rhs: Self) -> B32x8 {
+        B32x8(unsafe { _mm256_cmp_ps(self.0, rhs.0, _CMP_GE_OS) })
+    }
+
+    /// Returns a mask of the lanes where `self > rhs`.
+    #[inline]
+    pub fn cmpgt(self, rhs: Self) -> B32x8 {
+        B32x8(unsafe { _mm256_cmp_ps(self.0, rhs.0, _CMP_GT_OS) })
+    }
+
+    /// Returns a mask of the lanes where `self <= rhs`.
+    #[inline]
+    pub fn cmple(self, rhs: Self) -> B32x8 {
+        B32x8(unsafe { _mm256_cmp_ps(self.0, rhs.0, _CMP_LE_OS) })
+    }
+
+    /// Returns a mask of the lanes where `self < rhs`.
+    #[inline]
+    pub fn cmplt(self, rhs: Self) -> B32x8 {
+        B32x8(unsafe { _mm256_cmp_ps(self.0, rhs.0, _CMP_LT_OS) })
+    }
+
+    /// Creates a value from the lanes of `if_true` where `mask` is true and from `if_false`
+    /// otherwise.
+    #[inline]
+    pub fn select(mask: B32x8, if_true: Self, if_false: Self) -> Self {
+        Self(unsafe { _mm256_blendv_ps(if_false.0, if_true.0, mask.0) })
+    }
+}
+
+impl Add for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_add_ps(self.0, rhs.0) })
+    }
+}
+
+impl Sub for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_sub_ps(self.0, rhs.0) })
+    }
+}
+
+impl Mul for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_mul_ps(self.0, rhs.0) })
+    }
+}
+
+impl Div for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_div_ps(self.0, rhs.0) })
+    }
+}
+
+impl Neg for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe { _mm256_xor_ps(_mm256_set1_ps(-0.0), self.0) })
+    }
+}
+
+impl B32x8 {
+    /// Creates a mask with all lanes set to `v`.
+    #[inline]
+    pub const fn splat(v: bool) -> Self {
+        Self(m256_from_u32x8([MASK[v as usize]; 8]))
+    }
+
+    /// Creates a mask from an array of lanes.
+    #[inline]
+    pub const fn from_array(a: [bool; 8]) -> Self {
+        Self(m256_from_u32x8([
+            MASK[a[0] as usize],
+            MASK[a[1] as usize],
+            MASK[a[2] as usize],
+            MASK[a[3] as usize],
+            MASK[a[4] as usize],
+            MASK[a[5] as usize],
+            MASK[a[6] as usize],
+            MASK[a[7] as usize],
+        ]))
+    }
+
+    /// Returns a bitmask with the lowest bit set if the first lane is true and so on.
+    #[inline]
+    pub fn bitmask(self) -> u32 {
+        unsafe { _mm256_movemask_ps(self.0) as u32 }
+    }
+}
+
+impl BitAnd for B32x8 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_and_ps(self.0, rhs.0) })
+    }
+}
+
+impl BitOr for B32x8 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_or_ps(self.0, rhs.0) })
+    }
+}
+
+impl BitXor for B32x8 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(unsafe { _mm256_xor_ps(self.0, rhs.0) })
+    }
+}
+
+impl Not for B32x8 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self(unsafe { _mm256_xor_ps(self.0, _mm256_castsi256_ps(_mm256_set1_epi32(-1))) })
+    }
+}
+
+const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
@@ -0,0 +1,237 @@
+use crate::f32::math;
+
+use core::ops::*;
+
+/// Four `f32` lanes.
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+pub struct F32x4(pub(crate) [f32; 4]);
+
+/// A four lane mask, the result of comparing two [`F32x4`] values.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct B32x4(pub(crate) [bool; 4]);
+
+impl F32x4 {
+    /// Creates a value with all lanes set to `v`.
+    #[inline]
+    pub const fn splat(v: f32) -> Self {
+        Self([v; 4])
+    }
+
+    /// Creates a value from an array of lanes.
+    #[inline]
+    pub const fn from_array(a: [f32; 4]) -> Self {
+        Self(a)
+    }
+
+    /// Returns the lanes of `self` as an array.
+    #[inline]
+    pub fn to_array(&self) -> [f32; 4] {
+        self.0
+    }
+
+    #[inline]
+    fn map(self, f: impl Fn(f32) -> f32) -> Self {
+        Self([f(self.0[0]), f(self.0[1]), f(self.0[2]), f(self.0[3])])
+    }
+
+    #[inline]
+    fn zip(self, rhs: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        Self([
+            f(self.0[0], rhs.0[0]),
+            f(self.0[1], rhs.0[1]),
+            f(self.0[2], rhs.0[2]),
+            f(self.0[3], rhs.0[3]),
+        ])
+    }
+
+    #[inline]
+    fn cmp(self, rhs: Self, f: impl Fn(&f32, &f32) -> bool) -> B32x4 {
+        B32x4([
+            f(&self.0[0], &rhs.0[0]),
+            f(&self.0[1], &rhs.0[1]),
+            f(&self.0[2], &rhs.0[2]),
+            f(&self.0[3], &rhs.0[3]),
+        ])
+    }
+
+    /// Returns the square root of each lane.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        self.map(math::sqrt)
+    }
+
+    /// Returns the absolute value of each lane.
+    #[inline]
+    pub fn abs(self) -> Self {
+        self.map(math::abs)
+    }
+
+    /// Returns the minimum of each lane of `self` and `rhs`.
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        self.zip(rhs, f32::min)
+    }
+
+    /// Returns the maximum of each lane of `self` and `rhs`.
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        self.zip(rhs, f32::max)
+    }
+
+    /// Returns a mask of the lanes where `self == rhs`.
+    #[inline]
+    pub fn cmpeq(self, rhs: Self) -> B32x4 {
+        self.cmp(rhs, PartialEq::eq)
+    }
+
+    /// Returns a mask of the lanes where `self != rhs`.
+    #[inline]
+    pub fn cmpne(self, rhs: Self) -> B32x4 {
+        self.cmp(rhs, PartialEq::ne)
+    }
+
+    /// Returns a mask of the lanes where `self >= rhs`.
+    #[inline]
+    pub fn cmpge(self, rhs: Self) -> B32x4 {
+        self.cmp(rhs, PartialOrd::ge)
+    }
+
+    /// Returns a mask of the lanes where `self > rhs`.
+    #[inline]
+    pub fn cmpgt(self, rhs: Self) -> B32x4 {
+        self.cmp(rhs, PartialOrd::gt)
+    }
+
+    /// Returns a mask of the lanes where `self <= rhs`.
+    #[inline]
+    pub fn cmple(self, rhs: Self) -> B32x4 {
+        self.cmp(rhs, PartialOrd::le)
+    }
+
+    /// Returns a mask of the lanes where `self < rhs`.
+    #[inline]
+    pub fn cmplt(self, rhs: Self) -> B32x4 {
+        self.cmp(rhs, PartialOrd::lt)
+    }
+
+    /// Creates a value from the lanes of `if_true` where `mask` is true and from `if_false`
+    /// otherwise.
+    #[inline]
+    pub fn select(mask: B32x4, if_true: Self, if_false: Self) -> Self {
+        let f = |i: usize| {
+            if mask.0[i] {
+                if_true.0[i]
+            } else {
+                if_false.0[i]
+            }
+        };
+        Self([f(0), f(1), f(2), f(3)])
+    }
+}
+
+impl Add for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.zip(rhs, f32::add)
+    }
+}
+
+impl Sub for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.zip(rhs, f32::sub)
+    }
+}
+
+impl Mul for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.zip(rhs, f32::mul)
+    }
+}
+
+impl Div for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self.zip(rhs, f32::div)
+    }
+}
+
+impl Neg for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self.map(f32::neg)
+    }
+}
+
+impl B32x4 {
+    /// Creates a mask with all lanes set to `v`.
+    #[inline]
+    pub const fn splat(v: bool) -> Self {
+        Self([v; 4])
+    }
+
+    /// Creates a mask from an array of lanes.
+    #[inline]
+    pub const fn from_array(a: [bool; 4]) -> Self {
+        Self(a)
+    }
+
+    /// Returns a bitmask with the lowest bit set if the first lane is true and so on.
+    #[inline]
+    pub fn bitmask(self) -> u32 {
+        (self.0[0] as u32)
+            | (self.0[1] as u32) << 1
+            | (self.0[2] as u32) << 2
+            | (self.0[3] as u32) << 3
+    }
+
+    #[inline]
+    fn zip(self, rhs: Self, f: impl Fn(bool, bool) -> bool) -> Self {
+        Self([
+            f(self.0[0], rhs.0[0]),
+            f(self.0[1], rhs.0[1]),
+            f(self.0[2], rhs.0[2]),
+            f(self.0[3], rhs.0[3]),
+        ])
+    }
+}
+
+impl BitAnd for B32x4 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        self.zip(rhs, bool::bitand)
+    }
+}
+
+impl BitOr for B32x4 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.zip(rhs, bool::bitor)
+    }
+}
+
+impl BitXor for B32x4 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        self.zip(rhs, bool::bitxor)
+    }
+}
+
+impl Not for B32x4 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self([!self.0[0], !self.0[1], !self.0[2], !self.0[3]])
+    }
+}
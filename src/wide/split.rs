@@ -0,0 +1,272 @@
+use super::{B32x4, F32x4};
+
+use core::ops::*;
+
+/// Eight `f32` lanes stored as two [`F32x4`] values.
+#[derive(Clone, Copy)]
+#[repr(C, align(32))]
+pub struct F32x8 {
+    lo: F32x4,
+    hi: F32x4,
+}
+
+/// An eight lane mask, the result of comparing two [`F32x8`] values.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct B32x8 {
+    lo: B32x4,
+    hi: B32x4,
+}
+
+impl F32x8 {
+    /// Creates a value with all lanes set to `v`.
+    #[inline]
+    pub const fn splat(v: f32) -> Self {
+        Self {
+            lo: F32x4::splat(v),
+            hi: F32x4::splat(v),
+        }
+    }
+
+    /// Creates a value from an array of lanes.
+    #[inline]
+    pub const fn from_array(a: [f32; 8]) -> Self {
+        Self {
+            lo: F32x4::from_array([a[0], a[1], a[2], a[3]]),
+            hi: F32x4::from_array([a[4], a[5], a[6], a[7]]),
+        }
+    }
+
+    /// Returns the lanes of `self` as an array.
+    #[inline]
+    pub fn to_array(&self) -> [f32; 8] {
+        let [a, b, c, d] = self.lo.to_array();
+        let [e, f, g, h] = self.hi.to_array();
+        [a, b, c, d, e, f, g, h]
+    }
+
+    /// Returns the square root of each lane.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        Self {
+            lo: self.lo.sqrt(),
+            hi: self.hi.sqrt(),
+        }
+    }
+
+    /// Returns the absolute value of each lane.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            lo: self.lo.abs(),
+            hi: self.hi.abs(),
+        }
+    }
+
+    /// Returns the minimum of each lane of `self` and `rhs`.
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo.min(rhs.lo),
+            hi: self.hi.min(rhs.hi),
+        }
+    }
+
+    /// Returns the maximum of each lane of `self` and `rhs`.
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo.max(rhs.lo),
+            hi: self.hi.max(rhs.hi),
+        }
+    }
+
+    /// Returns a mask of the lanes where `self == rhs`.
+    #[inline]
+    pub fn cmpeq(self, rhs: Self) -> B32x8 {
+        B32x8 {
+            lo: self.lo.cmpeq(rhs.lo),
+            hi: self.hi.cmpeq(rhs.hi),
+        }
+    }
+
+    /// Returns a mask of the lanes where `self != rhs`.
+    #[inline]
+    pub fn cmpne(self, rhs: Self) -> B32x8 {
+        B32x8 {
+            lo: self.lo.cmpne(rhs.lo),
+            hi: self.hi.cmpne(rhs.hi),
+        }
+    }
+
+    /// Returns a mask of the lanes where `self >= rhs`.
+    #[inline]
+    pub fn cmpge(self, rhs: Self) -> B32x8 {
+        B32x8 {
+            lo: self.lo.cmpge(rhs.lo),
+            hi: self.hi.cmpge(rhs.hi),
+        }
+    }
+
+    /// Returns a mask of the lanes where `self > rhs`.
+    #[inline]
+    pub fn cmpgt(self, rhs: Self) -> B32x8 {
+        B32x8 {
+            lo: self.lo.cmpgt(rhs.lo),
+            hi: self.hi.cmpgt(rhs.hi),
+        }
+    }
+
+    /// Returns a mask of the lanes where `self <= rhs`.
+    #[inline]
+    pub fn cmple(self, rhs: Self) -> B32x8 {
+        B32x8 {
+            lo: self.lo.cmple(rhs.lo),
+            hi: self.hi.cmple(rhs.hi),
+        }
+    }
+
+    /// Returns a mask of the lanes where `self < rhs`.
+    #[inline]
+    pub fn cmplt(self, rhs: Self) -> B32x8 {
+        B32x8 {
+            lo: self.lo.cmplt(rhs.lo),
+            hi: self.hi.cmplt(rhs.hi),
+        }
+    }
+
+    /// Creates a value from the lanes of `if_true` where `mask` is true and from `if_false`
+    /// otherwise.
+    #[inline]
+    pub fn select(mask: B32x8, if_true: Self, if_false: Self) -> Self {
+        Self {
+            lo: F32x4::select(mask.lo, if_true.lo, if_false.lo),
+            hi: F32x4::select(mask.hi, if_true.hi, if_false.hi),
+        }
+    }
+}
+
+impl Add for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo + rhs.lo,
+            hi: self.hi + rhs.hi,
+        }
+    }
+}
+
+impl Sub for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo - rhs.lo,
+            hi: self.hi - rhs.hi,
+        }
+    }
+}
+
+impl Mul for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo * rhs.lo,
+            hi: self.hi * rhs.hi,
+        }
+    }
+}
+
+impl Div for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo / rhs.lo,
+            hi: self.hi / rhs.hi,
+        }
+    }
+}
+
+impl Neg for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.lo,
+            hi: -self.hi,
+        }
+    }
+}
+
+impl B32x8 {
+    /// Creates a mask with all lanes set to `v`.
+    #[inline]
+    pub const fn splat(v: bool) -> Self {
+        Self {
+            lo: B32x4::splat(v),
+            hi: B32x4::splat(v),
+        }
+    }
+
+    /// Creates a mask from an array of lanes.
+    #[inline]
+    pub const fn from_array(a: [bool; 8]) -> Self {
+        Self {
+            lo: B32x4::from_array([a[0], a[1], a[2], a[3]]),
+            hi: B32x4::from_array([a[4], a[5], a[6], a[7]]),
+        }
+    }
+
+    /// Returns a bitmask with the lowest bit set if the first lane is true and so on.
+    #[inline]
+    pub fn bitmask(self) -> u32 {
+        self.lo.bitmask() | self.hi.bitmask() << 4
+    }
+}
+
+impl BitAnd for B32x8 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo & rhs.lo,
+            hi: self.hi & rhs.hi,
+        }
+    }
+}
+
+impl BitOr for B32x8 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo | rhs.lo,
+            hi: self.hi | rhs.hi,
+        }
+    }
+}
+
+impl BitXor for B32x8 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self {
+            lo: self.lo ^ rhs.lo,
+            hi: self.hi ^ rhs.hi,
+        }
+    }
+}
+
+impl Not for B32x8 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self {
+            lo: !self.lo,
+            hi: !self.hi,
+        }
+    }
+}
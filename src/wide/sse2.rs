@@ -0,0 +1,206 @@
+use crate::sse2::*;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core::ops::*;
+
+/// Four `f32` lanes stored in a 128-bit SIMD vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct F32x4(pub(crate) __m128);
+
+/// A four lane mask, the result of comparing two [`F32x4`] values.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct B32x4(pub(crate) __m128);
+
+impl F32x4 {
+    /// Creates a value with all lanes set to `v`.
+    #[inline]
+    pub const fn splat(v: f32) -> Self {
+        Self(m128_from_f32x4([v; 4]))
+    }
+
+    /// Creates a value from an array of lanes.
+    #[inline]
+    pub const fn from_array(a: [f32; 4]) -> Self {
+        Self(m128_from_f32x4(a))
+    }
+
+    /// Returns the lanes of `self` as an array.
+    #[inline]
+    pub fn to_array(&self) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), self.0) };
+        out
+    }
+
+    /// Returns the square root of each lane.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        Self(unsafe { _mm_sqrt_ps(self.0) })
+    }
+
+    /// Returns the absolute value of each lane.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(unsafe { m128_abs(self.0) })
+    }
+
+    /// Returns the minimum of each lane of `self` and `rhs`.
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_min_ps(self.0, rhs.0) })
+    }
+
+    /// Returns the maximum of each lane of `self` and `rhs`.
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_max_ps(self.0, rhs.0) })
+    }
+
+    /// Returns a mask of the lanes where `self == rhs`.
+    #[inline]
+    pub fn cmpeq(self, rhs: Self) -> B32x4 {
+        B32x4(unsafe { _mm_cmpeq_ps(self.0, rhs.0) })
+    }
+
+    /// Returns a mask of the lanes where `self != rhs`.
+    #[inline]
+    pub fn cmpne(self, rhs: Self) -> B32x4 {
+        B32x4(unsafe { _mm_cmpneq_ps(self.0, rhs.0) })
+    }
+
+    /// Returns a mask of the lanes where `self >= rhs`.
+    #[inline]
+    pub fn cmpge(self, rhs: Self) -> B32x4 {
+        B32x4(unsafe { _mm_cmpge_ps(self.0, rhs.0) })
+    }
+
+    /// Returns a mask of the lanes where `self > rhs`.
+    #[inline]
+    pub fn cmpgt(self, rhs: Self) -> B32x4 {
+        B32x4(unsafe { _mm_cmpgt_ps(self.0, rhs.0) })
+    }
+
+    /// Returns a mask of the lanes where `self <= rhs`.
+    #[inline]
+    pub fn cmple(self, rhs: Self) -> B32x4 {
+        B32x4(unsafe { _mm_cmple_ps(self.0, rhs.0) })
+    }
+
+    /// Returns a mask of the lanes where `self < rhs`.
+    #[inline]
+    pub fn cmplt(self, rhs: Self) -> B32x4 {
+        B32x4(unsafe { _mm_cmplt_ps(self.0, rhs.0) })
+    }
+
+    /// Creates a value from the lanes of `if_true` where `mask` is true and from `if_false`
+    /// otherwise.
+    #[inline]
+    pub fn select(mask: B32x4, if_true: Self, if_false: Self) -> Self {
+        Self(unsafe { m128_select(mask.0, if_true.0, if_false.0) })
+    }
+}
+
+impl Add for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_add_ps(self.0, rhs.0) })
+    }
+}
+
+impl Sub for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_sub_ps(self.0, rhs.0) })
+    }
+}
+
+impl Mul for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_mul_ps(self.0, rhs.0) })
+    }
+}
+
+impl Div for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_div_ps(self.0, rhs.0) })
+    }
+}
+
+impl Neg for F32x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe { _mm_xor_ps(_mm_set1_ps(-0.0), self.0) })
+    }
+}
+
+impl B32x4 {
+    /// Creates a mask with all lanes set to `v`.
+    #[inline]
+    pub const fn splat(v: bool) -> Self {
+        Self(m128_from_u32x4([MASK[v as usize]; 4]))
+    }
+
+    /// Creates a mask from an array of lanes.
+    #[inline]
+    pub const fn from_array(a: [bool; 4]) -> Self {
+        Self(m128_from_u32x4([
+            MASK[a[0] as usize],
+            MASK[a[1] as usize],
+            MASK[a[2] as usize],
+            MASK[a[3] as usize],
+        ]))
+    }
+
+    /// Returns a bitmask with the lowest bit set if the first lane is true and so on.
+    #[inline]
+    pub fn bitmask(self) -> u32 {
+        unsafe { _mm_movemask_ps(self.0) as u32 }
+    }
+}
+
+impl BitAnd for B32x4 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_and_ps(self.0, rhs.0) })
+    }
+}
+
+impl BitOr for B32x4 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_or_ps(self.0, rhs.0) })
+    }
+}
+
+impl BitXor for B32x4 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_xor_ps(self.0, rhs.0) })
+    }
+}
+
+impl Not for B32x4 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self(unsafe { _mm_xor_ps(self.0, _mm_castsi128_ps(_mm_set1_epi32(-1))) })
+    }
+}
+
+const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
@@ -0,0 +1,361 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_aabb_tests {
+    ($aabb:ident, $vec:ident) => {
+        glam_test!(test_new, {
+            let one = $vec::ONE;
+            let two = one + one;
+            let a = $aabb::new(one, two);
+            assert_eq!(one, a.min);
+            assert_eq!(two, a.max);
+            assert!(!a.is_empty());
+            assert!($aabb::new(two, one).is_empty());
+            assert!($aabb::EMPTY.is_empty());
+            assert!(!$aabb::new(one, one).is_empty());
+        });
+
+        glam_test!(test_from_points, {
+            let one = $vec::ONE;
+            let two = one + one;
+            let a = $aabb::from_points(&[one, $vec::ZERO, two, $vec::X]);
+            assert_eq!($aabb::new($vec::ZERO, two), a);
+            assert_eq!($aabb::new(one, one), $aabb::from_points(&[one]));
+            assert_eq!($aabb::EMPTY, $aabb::from_points(&[]));
+        });
+
+        glam_test!(test_center_size, {
+            let one = $vec::ONE;
+            let two = one + one;
+            let a = $aabb::new($vec::ZERO, two);
+            assert_eq!(one, a.center());
+            assert_eq!(one, a.half_extents());
+            assert_eq!(two, a.size());
+        });
+
+        glam_test!(test_union_intersection, {
+            let one = $vec::ONE;
+            let two = one + one;
+            let three = two + one;
+            let a = $aabb::new($vec::ZERO, two);
+            let b = $aabb::new(one, three);
+            assert_eq!($aabb::new($vec::ZERO, three), a.union(&b));
+            assert_eq!($aabb::new(one, two), a.intersection(&b));
+            assert_eq!(a.union(&b), b.union(&a));
+            assert_eq!(a.intersection(&b), b.intersection(&a));
+
+            assert_eq!(a, $aabb::EMPTY.union(&a));
+            assert_eq!($aabb::new(one, three), b.union_point(three));
+            assert_eq!($aabb::new($vec::ZERO, three), b.union_point($vec::ZERO));
+
+            let c = $aabb::new(three, three + one);
+            assert!(a.intersection(&c).is_empty());
+        });
+
+        glam_test!(test_contains_intersects, {
+            let one = $vec::ONE;
+            let two = one + one;
+            let three = two + one;
+            let a = $aabb::new($vec::ZERO, two);
+            assert!(a.contains_point(one));
+            assert!(a.contains_point($vec::ZERO));
+            assert!(a.contains_point(two));
+            assert!(a.contains_point($vec::X));
+            assert!(!a.contains_point(three));
+            assert!(!a.contains_point($vec::NEG_ONE));
+            assert!(!a.contains_point(two + $vec::X));
+            assert!(!$aabb::EMPTY.contains_point($vec::ZERO));
+
+            assert!(a.contains(&a));
+            assert!(a.contains(&$aabb::new(one, two)));
+            assert!(!a.contains(&$aabb::new(one, three)));
+
+            assert!(a.intersects(&$aabb::new(one, three)));
+            assert!(a.intersects(&$aabb::new(two, three)));
+            assert!(!a.intersects(&$aabb::new(three, three)));
+            assert!(!a.intersects(&$aabb::new(two + $vec::X, three)));
+            assert!(!a.intersects(&$aabb::EMPTY));
+        });
+
+        glam_test!(test_closest_point, {
+            let one = $vec::ONE;
+            let two = one + one;
+            let three = two + one;
+            let a = $aabb::new($vec::ZERO, two);
+            assert_eq!(one, a.closest_point(one));
+            assert_eq!(two, a.closest_point(three));
+            assert_eq!($vec::ZERO, a.closest_point($vec::NEG_ONE));
+            assert_eq!(
+                two - two * $vec::X,
+                a.closest_point(three - three * $vec::X)
+            );
+        });
+
+        glam_test!(test_grow, {
+            let one = $vec::ONE;
+            let two = one + one;
+            let three = two + one;
+            let a = $aabb::new($vec::ZERO, two);
+            assert_eq!($aabb::new($vec::NEG_ONE, three), a.grow(one.x));
+            assert_eq!($aabb::new(one, one), a.grow(-one.x));
+            assert!(a.grow(-two.x).is_empty());
+        });
+
+        glam_test!(test_fmt, {
+            let a = $aabb::new($vec::ZERO, $vec::ONE);
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    "{} {{ min: {:?}, max: {:?} }}",
+                    stringify!($aabb),
+                    a.min,
+                    a.max
+                )
+            );
+            assert_eq!(format!("{}", a), format!("[{}, {}]", a.min, a.max));
+        });
+    };
+}
+
+macro_rules! impl_aabb_float_tests {
+    ($aabb:ident, $vec:ident) => {
+        impl_aabb_tests!($aabb, $vec);
+
+        glam_test!(test_from_center_half_extents, {
+            let a = $aabb::from_center_half_extents($vec::splat(1.5), $vec::splat(0.5));
+            assert_eq!($aabb::new($vec::ONE, $vec::splat(2.0)), a);
+            assert_eq!($vec::splat(1.5), a.center());
+            assert_eq!($vec::splat(0.5), a.half_extents());
+        });
+
+        glam_test!(test_nan, {
+            let a = $aabb::new($vec::NAN, $vec::ONE);
+            assert!(a.is_nan());
+            assert!(!a.is_finite());
+            assert!(!$aabb::new($vec::ZERO, $vec::ONE).is_nan());
+            assert!($aabb::new($vec::ZERO, $vec::ONE).is_finite());
+            assert!(!$aabb::EMPTY.is_finite());
+        });
+
+        glam_test!(test_abs_diff_eq, {
+            let a = $aabb::new($vec::ZERO, $vec::ONE);
+            let b = $aabb::new($vec::splat(0.01), $vec::ONE);
+            assert!(a.abs_diff_eq(b, 0.1));
+            assert!(!a.abs_diff_eq(b, 0.001));
+        });
+    };
+}
+
+macro_rules! impl_aabb3_transform_tests {
+    ($t:ident, $aabb:ident, $vec:ident, $quat:ident, $affine3:ident, $mat4:ident) => {
+        glam_test!(test_transformed_by, {
+            let a = $aabb::new($vec::new(-1.0, 0.0, 2.0), $vec::new(1.0, 3.0, 4.0));
+
+            // a rotation about z swaps the x and y extents
+            let t = $affine3::from_rotation_translation(
+                $quat::from_rotation_z(core::$t::consts::FRAC_PI_2),
+                $vec::new(10.0, 0.0, 0.0).into(),
+            );
+            let b = a.transformed_by(&t);
+            assert_approx_eq!($vec::new(7.0, -1.0, 2.0), b.min, 1e-5);
+            assert_approx_eq!($vec::new(10.0, 1.0, 4.0), b.max, 1e-5);
+
+            // compare against transforming each of the corners
+            let t = $affine3::from_scale_rotation_translation(
+                $vec::new(2.0, 0.5, 1.5).into(),
+                $quat::from_euler(glam::EulerRot::YXZ, 0.3, -1.2, 2.1),
+                $vec::new(1.0, -2.0, 3.0).into(),
+            );
+            let corners = [
+                $vec::new(a.min.x, a.min.y, a.min.z),
+                $vec::new(a.max.x, a.min.y, a.min.z),
+                $vec::new(a.min.x, a.max.y, a.min.z),
+                $vec::new(a.max.x, a.max.y, a.min.z),
+                $vec::new(a.min.x, a.min.y, a.max.z),
+                $vec::new(a.max.x, a.min.y, a.max.z),
+                $vec::new(a.min.x, a.max.y, a.max.z),
+                $vec::new(a.max.x, a.max.y, a.max.z),
+            ]
+            .map(|p| t.transform_point3(p.into()).into());
+            let expected = $aabb::from_points(&corners);
+            let b = a.transformed_by(&t);
+            assert_approx_eq!(expected.min, b.min, 1e-5);
+            assert_approx_eq!(expected.max, b.max, 1e-5);
+
+            let b = a.transformed_by_mat4(&$mat4::from(t));
+            assert_approx_eq!(expected.min, b.min, 1e-5);
+            assert_approx_eq!(expected.max, b.max, 1e-5);
+
+            assert_eq!(a, a.transformed_by(&$affine3::IDENTITY));
+        });
+    };
+}
+
+mod aabb2 {
+    use glam::{Aabb2, Affine2, Mat3, Vec2};
+
+    impl_aabb_float_tests!(Aabb2, Vec2);
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<Aabb2>());
+        assert_eq!(mem::align_of::<Vec2>(), mem::align_of::<Aabb2>());
+    });
+
+    glam_test!(test_transformed_by, {
+        let a = Aabb2::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 3.0));
+        let t = Affine2::from_angle_translation(core::f32::consts::FRAC_PI_2, Vec2::new(10.0, 0.0));
+        let b = a.transformed_by(&t);
+        assert_approx_eq!(Vec2::new(7.0, -1.0), b.min, 1e-5);
+        assert_approx_eq!(Vec2::new(10.0, 1.0), b.max, 1e-5);
+
+        let t = Affine2::from_scale_angle_translation(Vec2::new(2.0, 0.5), 0.7, Vec2::ONE);
+        let expected = Aabb2::from_points(&[
+            t.transform_point2(a.min),
+            t.transform_point2(a.max),
+            t.transform_point2(Vec2::new(a.min.x, a.max.y)),
+            t.transform_point2(Vec2::new(a.max.x, a.min.y)),
+        ]);
+        let b = a.transformed_by(&t);
+        assert_approx_eq!(expected.min, b.min, 1e-5);
+        assert_approx_eq!(expected.max, b.max, 1e-5);
+
+        let b = a.transformed_by_mat3(&Mat3::from(t));
+        assert_approx_eq!(expected.min, b.min, 1e-5);
+        assert_approx_eq!(expected.max, b.max, 1e-5);
+    });
+}
+
+mod aabb3 {
+    use glam::{Aabb3, Aabb3A, Affine3A, Mat4, Quat, Vec3, Vec3A};
+
+    impl_aabb_float_tests!(Aabb3, Vec3);
+    impl_aabb3_transform_tests!(f32, Aabb3, Vec3, Quat, Affine3A, Mat4);
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(24, mem::size_of::<Aabb3>());
+        assert_eq!(mem::align_of::<Vec3>(), mem::align_of::<Aabb3>());
+    });
+
+    glam_test!(test_from_aabb3a, {
+        let a = Aabb3A::new(Vec3A::new(1.0, 2.0, 3.0), Vec3A::new(4.0, 5.0, 6.0));
+        let b = Aabb3::from(a);
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), b.min);
+        assert_eq!(Vec3::new(4.0, 5.0, 6.0), b.max);
+        assert_eq!(a, Aabb3A::from(b));
+    });
+}
+
+mod aabb3a {
+    use glam::{Aabb3A, Affine3A, Mat4, Quat, Vec3A};
+
+    impl_aabb_float_tests!(Aabb3A, Vec3A);
+    impl_aabb3_transform_tests!(f32, Aabb3A, Vec3A, Quat, Affine3A, Mat4);
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(32, mem::size_of::<Aabb3A>());
+        assert_eq!(mem::align_of::<Vec3A>(), mem::align_of::<Aabb3A>());
+    });
+}
+
+mod daabb3 {
+    use glam::{DAabb3, DAffine3, DMat4, DQuat, DVec3};
+
+    impl_aabb_float_tests!(DAabb3, DVec3);
+    impl_aabb3_transform_tests!(f64, DAabb3, DVec3, DQuat, DAffine3, DMat4);
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(48, mem::size_of::<DAabb3>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DAabb3>());
+    });
+}
+
+mod irect {
+    use glam::{IRect, IVec2};
+
+    impl_aabb_tests!(IRect, IVec2);
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<IRect>());
+        assert_eq!(mem::align_of::<IVec2>(), mem::align_of::<IRect>());
+    });
+
+    glam_test!(test_center_rounding, {
+        let a = IRect::new(IVec2::ZERO, IVec2::new(3, 5));
+        assert_eq!(IVec2::new(1, 2), a.center());
+        assert_eq!(IVec2::new(1, 2), a.half_extents());
+        let a = IRect::new(IVec2::new(-3, -5), IVec2::ZERO);
+        assert_eq!(IVec2::new(-2, -3), a.center());
+    });
+
+    glam_test!(test_empty_size, {
+        assert_eq!(IVec2::ZERO, IRect::EMPTY.size());
+        assert_eq!(IVec2::ZERO, IRect::EMPTY.half_extents());
+        assert_eq!(IVec2::ZERO, IRect::from_points(&[]).half_extents());
+        assert_eq!(IVec2::splat(-1), IRect::EMPTY.center());
+        assert!(IRect::EMPTY.grow(1).is_empty());
+    });
+
+    glam_test!(test_limits, {
+        let a = IRect::new(IVec2::splat(i32::MAX - 2), IVec2::splat(i32::MAX));
+        assert_eq!(IVec2::splat(i32::MAX - 1), a.center());
+        assert_eq!(IVec2::splat(2), a.size());
+        assert_eq!(IVec2::ONE, a.half_extents());
+        assert_eq!(IVec2::splat(i32::MAX), a.grow(5).max);
+
+        let a = IRect::new(IVec2::splat(i32::MIN), IVec2::splat(i32::MAX));
+        assert_eq!(IVec2::splat(-1), a.center());
+        assert_eq!(IVec2::splat(i32::MAX), a.size());
+        assert_eq!(IVec2::splat(i32::MIN), a.grow(1).min);
+    });
+
+    glam_test!(test_hash, {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        let hash = |r: IRect| {
+            let mut hasher = DefaultHasher::new();
+            r.hash(&mut hasher);
+            hasher.finish()
+        };
+        let a = IRect::new(IVec2::ZERO, IVec2::ONE);
+        assert_eq!(hash(a), hash(IRect::new(IVec2::ZERO, IVec2::ONE)));
+        assert_ne!(hash(a), hash(IRect::new(IVec2::ONE, IVec2::ZERO)));
+    });
+}
+
+mod iaabb3 {
+    use glam::{IAabb3, IVec3};
+
+    impl_aabb_tests!(IAabb3, IVec3);
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(24, mem::size_of::<IAabb3>());
+        assert_eq!(mem::align_of::<i32>(), mem::align_of::<IAabb3>());
+    });
+
+    glam_test!(test_empty_size, {
+        assert_eq!(IVec3::ZERO, IAabb3::EMPTY.size());
+        assert_eq!(IVec3::ZERO, IAabb3::EMPTY.half_extents());
+        assert_eq!(IVec3::ZERO, IAabb3::from_points(&[]).half_extents());
+        assert_eq!(IVec3::splat(-1), IAabb3::EMPTY.center());
+        assert!(IAabb3::EMPTY.grow(1).is_empty());
+    });
+
+    glam_test!(test_limits, {
+        let a = IAabb3::new(IVec3::splat(i32::MAX - 2), IVec3::splat(i32::MAX));
+        assert_eq!(IVec3::splat(i32::MAX - 1), a.center());
+        assert_eq!(IVec3::splat(2), a.size());
+        assert_eq!(IVec3::ONE, a.half_extents());
+        assert_eq!(IVec3::splat(i32::MAX), a.grow(5).max);
+
+        let a = IAabb3::new(IVec3::splat(i32::MIN), IVec3::splat(i32::MAX));
+        assert_eq!(IVec3::splat(-1), a.center());
+        assert_eq!(IVec3::splat(i32::MAX), a.size());
+        assert_eq!(IVec3::splat(i32::MIN), a.grow(1).min);
+    });
+}
@@ -262,7 +262,7 @@ mod daffine2 {
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(48, mem::size_of::<DAffine2>());
-        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DAffine2>());
+        assert_eq!(mem::align_of::<DVec2>(), mem::align_of::<DAffine2>());
     });
 
     #[cfg(feature = "cuda")]
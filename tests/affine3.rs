@@ -130,6 +130,28 @@ macro_rules! impl_affine3_tests {
             should_glam_assert!({ $affine3::from_quat($quat::IDENTITY * 2.0) });
         });
 
+        glam_test!(test_affine3_euler, {
+            use glam::EulerRot;
+            let (a, b, c) = (deg(30.0), deg(60.0), deg(90.0));
+            for order in [
+                EulerRot::YXZ,
+                EulerRot::ZXZ,
+                EulerRot::XYZEx,
+                EulerRot::YZYEx,
+            ] {
+                let m = $affine3::from_euler(order, a, b, c);
+                assert_approx_eq!(
+                    m,
+                    $affine3::from_quat($quat::from_euler(order, a, b, c)),
+                    1e-6
+                );
+                let (a1, b1, c1) = ($affine3::from_translation($vec3::ONE) * m).to_euler(order);
+                assert_approx_eq!(a, a1, 1e-5);
+                assert_approx_eq!(b, b1, 1e-5);
+                assert_approx_eq!(c, c1, 1e-5);
+            }
+        });
+
         glam_test!(test_affine3_mul, {
             let m = $affine3::from_axis_angle($vec3::Z, deg(90.0));
             let result3 = m.transform_vector3($vec3::Y);
@@ -343,6 +365,41 @@ macro_rules! impl_affine3_tests {
             assert!(!$affine3::from_scale($vec3::new(1.0, NAN, 1.0)).is_finite());
             assert!(!$affine3::from_scale($vec3::new(1.0, 1.0, NEG_INFINITY)).is_finite());
         });
+
+        glam_test!(test_affine3_transform_slice, {
+            let m = $affine3::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, 2.0),
+                $quat::from_rotation_y(deg(30.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            for len in 0..=11 {
+                let src: Vec<$vec3> = (0..len)
+                    .map(|i| {
+                        let i = i as $t;
+                        $vec3::new(i - 5.0, 2.0 - i * 0.5, i * 0.25 - 10.0)
+                    })
+                    .collect();
+
+                let mut points = src.clone();
+                m.transform_points3_slice(&mut points);
+                let mut vectors = src.clone();
+                m.transform_vectors3_slice(&mut vectors);
+                for i in 0..len {
+                    assert_approx_eq!(m.transform_point3(src[i]), points[i], 1e-5);
+                    assert_approx_eq!(m.transform_vector3(src[i]), vectors[i], 1e-5);
+                }
+
+                let mut dst = vec![$vec3::ZERO; len];
+                m.transform_points3_slice_to(&src, &mut dst);
+                assert_eq!(points, dst);
+                m.transform_vectors3_slice_to(&src, &mut dst);
+                assert_eq!(vectors, dst);
+            }
+
+            should_panic!({
+                m.transform_vectors3_slice_to(&[$vec3::ZERO; 2], &mut [$vec3::ZERO; 3])
+            });
+        });
     };
 }
 
@@ -0,0 +1,228 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_angle_tests {
+    ($t:ident, $radians:ident, $degrees:ident, $quat:ident, $mat2:ident, $mat3:ident, $mat4:ident, $affine2:ident, $affine3:ident, $vec2:ident, $vec3:ident, $rot2:ident) => {
+        use core::$t::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+        glam_test!(test_consts, {
+            assert_eq!($radians::default(), $radians::ZERO);
+            assert_eq!($radians(FRAC_PI_2), $radians::QUARTER_TURN);
+            assert_eq!($radians(PI), $radians::HALF_TURN);
+            assert_eq!($radians(TAU), $radians::FULL_TURN);
+            assert_eq!($degrees::default(), $degrees::ZERO);
+            assert_eq!($degrees(90.0), $degrees::QUARTER_TURN);
+            assert_eq!($degrees(180.0), $degrees::HALF_TURN);
+            assert_eq!($degrees(360.0), $degrees::FULL_TURN);
+            assert_eq!($radians(1.0), $radians::new(1.0));
+            assert_eq!($degrees(1.0), $degrees::new(1.0));
+        });
+
+        glam_test!(test_conversion, {
+            assert_approx_eq!($radians::QUARTER_TURN, $degrees(90.0).to_radians());
+            assert_approx_eq!($radians::HALF_TURN, $radians::from($degrees(180.0)));
+            assert_approx_eq!($degrees(90.0), $radians::QUARTER_TURN.to_degrees());
+            assert_approx_eq!($degrees(-45.0), $degrees::from($radians(-FRAC_PI_4)));
+            assert_eq!($radians(0.5), $radians::from(0.5));
+            assert_eq!($radians(0.5), 0.5.into());
+        });
+
+        glam_test!(test_trig, {
+            let r = $radians(0.5);
+            assert_eq!((0.5 as $t).sin(), r.sin());
+            assert_eq!((0.5 as $t).cos(), r.cos());
+            assert_eq!((0.5 as $t).tan(), r.tan());
+            assert_eq!(((0.5 as $t).sin(), (0.5 as $t).cos()), r.sin_cos());
+            let d = $degrees(30.0);
+            assert_approx_eq!(0.5, d.sin());
+            assert_approx_eq!(d.to_radians().cos(), d.cos());
+            assert_approx_eq!(d.to_radians().tan(), d.tan());
+        });
+
+        glam_test!(test_ops, {
+            let a = $radians(1.0);
+            let b = $radians(0.25);
+            assert_eq!($radians(1.25), a + b);
+            assert_eq!($radians(0.75), a - b);
+            assert_eq!($radians(-1.0), -a);
+            assert_eq!($radians(2.0), a * 2.0);
+            assert_eq!($radians(2.0), 2.0 * a);
+            assert_eq!($radians(0.5), a / 2.0);
+            assert_eq!(4.0, a / b);
+
+            let mut c = a;
+            c += b;
+            assert_eq!($radians(1.25), c);
+            c -= b;
+            assert_eq!(a, c);
+            c *= 4.0;
+            assert_eq!($radians(4.0), c);
+            c /= 8.0;
+            assert_eq!($radians(0.5), c);
+
+            assert!(b < a);
+            assert_eq!($radians(1.0), $radians(-1.0).abs());
+            assert_eq!($degrees(270.0), $degrees(90.0) + $degrees(180.0));
+            assert_eq!($degrees(45.0), $degrees(90.0) / 2.0);
+
+            let angles = [$degrees(10.0), $degrees(20.0), $degrees(30.0)];
+            assert_eq!($degrees(60.0), angles.iter().sum());
+            assert_eq!($degrees(60.0), angles.iter().copied().sum());
+        });
+
+        glam_test!(test_wrap, {
+            assert_eq!($radians::ZERO, $radians::ZERO.wrap());
+            assert_approx_eq!($radians(0.5), $radians(0.5 + TAU).wrap(), 1e-6);
+            assert_approx_eq!($radians(0.5), $radians(0.5 - 2.0 * TAU).wrap(), 1e-5);
+            assert_approx_eq!($radians(-FRAC_PI_2), $radians(3.0 * FRAC_PI_2).wrap(), 1e-6);
+            // the range is `(-PI, PI]`
+            assert_eq!($radians::HALF_TURN, $radians(PI).wrap());
+            assert_eq!($radians::HALF_TURN, $radians(-PI).wrap());
+            assert_eq!(
+                $radians::HALF_TURN,
+                $radians(-$t::EPSILON * 0.1 - PI).wrap()
+            );
+
+            assert_eq!($degrees(170.0), $degrees(-190.0).wrap());
+            assert_eq!($degrees(-170.0), $degrees(190.0).wrap());
+            assert_eq!($degrees(180.0), $degrees(-180.0).wrap());
+            assert_eq!($degrees(180.0), $degrees(540.0).wrap());
+            assert_eq!($degrees(-90.0), $degrees(630.0).wrap());
+            assert_eq!($degrees(0.0), $degrees(720.0).wrap());
+        });
+
+        glam_test!(test_angle_to, {
+            assert_eq!($degrees(20.0), $degrees(170.0).angle_to($degrees(-170.0)));
+            assert_eq!($degrees(-20.0), $degrees(-170.0).angle_to($degrees(170.0)));
+            assert_eq!($degrees(90.0), $degrees(0.0).angle_to($degrees(450.0)));
+            assert_eq!($degrees(180.0), $degrees(0.0).angle_to($degrees(180.0)));
+            assert_eq!($degrees(180.0), $degrees(0.0).angle_to($degrees(-180.0)));
+            assert_approx_eq!(
+                $radians(0.2),
+                $radians(PI - 0.1).angle_to($radians(-PI + 0.1)),
+                1e-6
+            );
+        });
+
+        glam_test!(test_lerp, {
+            let a = $degrees(170.0);
+            let b = $degrees(-170.0);
+            assert_eq!(a, a.lerp(b, 0.0));
+            assert_eq!($degrees(180.0), a.lerp(b, 0.5));
+            // not wrapped
+            assert_eq!($degrees(190.0), a.lerp(b, 1.0));
+            assert_eq!(b, a.lerp(b, 1.0).wrap());
+            assert_eq!($degrees(-175.0), b.lerp(a, 0.25));
+            assert_approx_eq!(
+                $radians(0.25),
+                $radians(0.0).lerp($radians(1.0), 0.25),
+                1e-6
+            );
+        });
+
+        glam_test!(test_finite, {
+            assert!($radians(1.0).is_finite());
+            assert!(!$radians($t::INFINITY).is_finite());
+            assert!(!$degrees($t::NAN).is_finite());
+            assert!($degrees($t::NAN).is_nan());
+            assert!(!$radians(1.0).is_nan());
+        });
+
+        glam_test!(test_fmt, {
+            assert_eq!(
+                format!("{:?}", $radians(1.5)),
+                format!("{}(1.5)", stringify!($radians))
+            );
+            assert_eq!(
+                format!("{:?}", $degrees(90.0)),
+                format!("{}(90.0)", stringify!($degrees))
+            );
+            assert_eq!(format!("{}", $radians(1.5)), "1.5 rad");
+            assert_eq!(format!("{}", $degrees(90.0)), "90°");
+            assert_eq!(format!("{:.2}", $degrees(90.0)), "90.00°");
+        });
+
+        glam_test!(test_typed_constructors, {
+            let d = $degrees(30.0);
+            let r = d.to_radians().0;
+            assert_eq!($quat::from_rotation_x(r), $quat::from_rotation_x(d));
+            assert_eq!($quat::from_rotation_y(r), $quat::from_rotation_y(d));
+            assert_eq!($quat::from_rotation_z(r), $quat::from_rotation_z(d));
+            assert_eq!(
+                $quat::from_rotation_z(r),
+                $quat::from_rotation_z($radians(r))
+            );
+            assert_eq!(
+                $quat::from_axis_angle($vec3::Y, r),
+                $quat::from_axis_angle($vec3::Y, d)
+            );
+            assert_eq!(
+                $quat::from_euler(glam::EulerRot::YXZ, r, 0.5, r),
+                $quat::from_euler(glam::EulerRot::YXZ, d, $radians(0.5), d)
+            );
+
+            assert_eq!($mat2::from_angle(r), $mat2::from_angle(d));
+            assert_eq!(
+                $mat2::from_scale_angle($vec2::ONE, r),
+                $mat2::from_scale_angle($vec2::ONE, d)
+            );
+            assert_eq!($mat3::from_angle(r), $mat3::from_angle(d));
+            assert_eq!($mat3::from_rotation_x(r), $mat3::from_rotation_x(d));
+            assert_eq!(
+                $mat3::from_axis_angle($vec3::Z, r),
+                $mat3::from_axis_angle($vec3::Z, d)
+            );
+            assert_eq!(
+                $mat3::from_euler(glam::EulerRot::ZXZ, d, d, d),
+                $mat3::from_euler(glam::EulerRot::ZXZ, r, r, r)
+            );
+            assert_eq!($mat4::from_rotation_y(r), $mat4::from_rotation_y(d));
+            assert_eq!(
+                $mat4::perspective_rh(r, 1.5, 0.1, 100.0),
+                $mat4::perspective_rh(d, 1.5, 0.1, 100.0)
+            );
+            assert_eq!(
+                $mat4::perspective_infinite_reverse_lh(r, 1.5, 0.1),
+                $mat4::perspective_infinite_reverse_lh(d, 1.5, 0.1)
+            );
+
+            assert_eq!($affine2::from_angle(r), $affine2::from_angle(d));
+            assert_eq!(
+                $affine2::from_angle_translation(r, $vec2::X),
+                $affine2::from_angle_translation(d, $vec2::X)
+            );
+            assert_eq!($affine3::from_rotation_z(r), $affine3::from_rotation_z(d));
+
+            assert_eq!($vec2::from_angle(r), $vec2::from_angle(d));
+            assert_eq!($rot2::from_angle(r), $rot2::from_angle(d));
+            assert_approx_eq!($vec2::Y, $vec2::from_angle($degrees(90.0)), 1e-6);
+        });
+    };
+}
+
+mod angle {
+    use glam::{Affine2, Affine3A, Degrees, Mat2, Mat3, Mat4, Quat, Radians, Rot2, Vec2, Vec3};
+
+    glam_test!(test_as, {
+        use glam::{DDegrees, DRadians};
+        assert_eq!(DRadians(0.5), Radians(0.5).as_f64());
+        assert_eq!(Radians(0.5), DRadians(0.5).as_f32());
+        assert_eq!(DDegrees(90.0), Degrees(90.0).as_f64());
+        assert_eq!(Degrees(90.0), DDegrees(90.0).as_f32());
+    });
+
+    impl_angle_tests!(
+        f32, Radians, Degrees, Quat, Mat2, Mat3, Mat4, Affine2, Affine3A, Vec2, Vec3, Rot2
+    );
+}
+
+mod dangle {
+    use glam::{
+        DAffine2, DAffine3, DDegrees, DMat2, DMat3, DMat4, DQuat, DRadians, DRot2, DVec2, DVec3,
+    };
+
+    impl_angle_tests!(
+        f64, DRadians, DDegrees, DQuat, DMat2, DMat3, DMat4, DAffine2, DAffine3, DVec2, DVec3,
+        DRot2
+    );
+}
@@ -0,0 +1,334 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_curves_tests {
+    ($t:ident, $vec:ident) => {
+        use glam::curves::{
+            BSpline, CatmullRom, CubicBezier, CubicSpline, Hermite, Parameterization,
+            QuadraticBezier,
+        };
+
+        fn point(x: $t, y: $t) -> $vec {
+            $vec::X * x + $vec::Y * y
+        }
+
+        fn cubic() -> CubicBezier<$vec> {
+            CubicBezier::new(
+                point(0.0, 0.0),
+                point(1.0, 2.0),
+                point(3.0, 2.0),
+                point(4.0, 0.0),
+            )
+        }
+
+        // Central difference approximation of the derivative of `f` at `t`.
+        fn derivative(f: impl Fn($t) -> $vec, t: $t) -> $vec {
+            let h = 1e-3;
+            (f(t + h) - f(t - h)) / (2.0 * h)
+        }
+
+        glam_test!(test_quadratic_bezier, {
+            let q = QuadraticBezier::new(point(0.0, 0.0), point(1.0, 2.0), point(2.0, 0.0));
+            assert_eq!(q.p0, q.position(0.0));
+            assert_eq!(q.p2, q.position(1.0));
+            assert_approx_eq!(point(1.0, 1.0), q.position(0.5), 1e-6);
+            assert_approx_eq!(point(2.0, 4.0), q.velocity(0.0), 1e-6);
+            assert_approx_eq!(point(2.0, -4.0), q.velocity(1.0), 1e-6);
+            assert_approx_eq!(point(0.0, -8.0), q.acceleration(0.3), 1e-6);
+            assert_approx_eq!(derivative(|t| q.position(t), 0.3), q.velocity(0.3), 1e-2);
+
+            let c = q.to_cubic_bezier();
+            for &t in &[0.0, 0.25, 0.5, 0.8, 1.0] {
+                assert_approx_eq!(q.position(t), c.position(t), 1e-5);
+                assert_approx_eq!(q.velocity(t), c.velocity(t), 1e-5);
+            }
+
+            let (a, b) = q.split(0.25);
+            assert_eq!(a.p0, q.p0);
+            assert_eq!(a.p2, b.p0);
+            assert_eq!(b.p2, q.p2);
+            assert_approx_eq!(q.position(0.125), a.position(0.5), 1e-6);
+            assert_approx_eq!(q.position(0.625), b.position(0.5), 1e-6);
+        });
+
+        glam_test!(test_cubic_bezier, {
+            let c = cubic();
+            assert_eq!(c.p0, c.position(0.0));
+            assert_eq!(c.p3, c.position(1.0));
+            assert_approx_eq!(point(2.0, 1.5), c.position(0.5), 1e-6);
+            assert_approx_eq!((c.p1 - c.p0) * 3.0, c.velocity(0.0), 1e-6);
+            assert_approx_eq!((c.p3 - c.p2) * 3.0, c.velocity(1.0), 1e-6);
+            for &t in &[0.1, 0.5, 0.7] {
+                assert_approx_eq!(derivative(|t| c.position(t), t), c.velocity(t), 1e-2);
+                assert_approx_eq!(derivative(|t| c.velocity(t), t), c.acceleration(t), 1e-2);
+            }
+
+            let (a, b) = c.split(0.4);
+            assert_eq!(a.p3, b.p0);
+            for &t in &[0.0, 0.3, 1.0] {
+                assert_approx_eq!(c.position(0.4 * t), a.position(t), 1e-5);
+                assert_approx_eq!(c.position(0.4 + 0.6 * t), b.position(t), 1e-5);
+                assert_approx_eq!(c.velocity(0.4 * t) * 0.4, a.velocity(t), 1e-5);
+            }
+
+            let h = c.to_hermite();
+            let b = h.to_cubic_bezier();
+            assert_approx_eq!(c.p1, b.p1, 1e-6);
+            assert_approx_eq!(c.p2, b.p2, 1e-6);
+            for &t in &[0.0, 0.2, 0.6, 1.0] {
+                assert_approx_eq!(c.position(t), h.position(t), 1e-5);
+                assert_approx_eq!(c.velocity(t), h.velocity(t), 1e-5);
+                assert_approx_eq!(c.acceleration(t), h.acceleration(t), 1e-5);
+            }
+        });
+
+        glam_test!(test_cubic_bezier_length, {
+            // a straight line with uneven speed
+            let line = CubicBezier::new(
+                point(0.0, 0.0),
+                point(0.5, 0.0),
+                point(1.0, 0.0),
+                point(4.0, 0.0),
+            );
+            assert_approx_eq!(4.0, line.length(), 1e-5);
+            assert_eq!(0.0, line.param_at_length(-1.0));
+            assert_eq!(0.0, line.param_at_length(0.0));
+            assert_eq!(1.0, line.param_at_length(4.0));
+            assert_eq!(1.0, line.param_at_length(5.0));
+            for &s in &[0.5, 1.0, 2.0, 3.5] {
+                let t = line.param_at_length(s);
+                assert_approx_eq!(point(s, 0.0), line.position(t), 1e-4);
+            }
+
+            // compare with a fine polyline
+            let c = cubic();
+            let mut polyline = 0.0;
+            for i in 0..1000 {
+                polyline += c
+                    .position(i as $t / 1000.0)
+                    .distance(c.position((i + 1) as $t / 1000.0));
+            }
+            assert_approx_eq!(polyline, c.length(), 1e-4);
+            let t = c.param_at_length(0.5 * c.length());
+            // the curve is symmetric
+            assert_approx_eq!(0.5, t, 1e-5);
+
+            let degenerate = CubicBezier::new($vec::ONE, $vec::ONE, $vec::ONE, $vec::ONE);
+            assert_eq!(0.0, degenerate.length());
+            assert_eq!(0.0, degenerate.param_at_length(0.0));
+            assert_eq!(1.0, degenerate.param_at_length(1.0));
+        });
+
+        glam_test!(test_cubic_bezier_closest, {
+            let c = cubic();
+            assert_eq!(0.0, c.closest_param(point(-1.0, -1.0)));
+            assert_eq!(1.0, c.closest_param(point(5.0, -1.0)));
+            assert_approx_eq!(0.5, c.closest_param(point(2.0, 3.0)), 1e-5);
+            assert_approx_eq!(point(2.0, 1.5), c.closest_point(point(2.0, 3.0)), 1e-5);
+            for &t in &[0.1, 0.3, 0.75] {
+                // move away from the curve along the normal
+                let v = c.velocity(t);
+                let normal = point(v.y, -v.x).normalize();
+                let p = c.position(t) + normal * 0.25;
+                assert_approx_eq!(t, c.closest_param(p), 1e-4);
+            }
+        });
+
+        glam_test!(test_hermite, {
+            let h = Hermite::new(
+                point(0.0, 0.0),
+                point(3.0, 0.0),
+                point(2.0, 2.0),
+                point(0.0, 3.0),
+            );
+            assert_eq!(h.p0, h.position(0.0));
+            assert_eq!(h.p1, h.position(1.0));
+            assert_eq!(h.v0, h.velocity(0.0));
+            assert_eq!(h.v1, h.velocity(1.0));
+            for &t in &[0.2, 0.5, 0.9] {
+                assert_approx_eq!(derivative(|t| h.position(t), t), h.velocity(t), 1e-2);
+                assert_approx_eq!(derivative(|t| h.velocity(t), t), h.acceleration(t), 1e-2);
+            }
+
+            let c = h.to_cubic_bezier();
+            assert_approx_eq!(h.p0 + h.v0 / 3.0, c.p1, 1e-6);
+            assert_approx_eq!(h.p1 - h.v1 / 3.0, c.p2, 1e-6);
+            for &t in &[0.0, 0.4, 1.0] {
+                assert_approx_eq!(h.position(t), c.position(t), 1e-5);
+            }
+
+            let (a, b) = h.split(0.3);
+            assert_eq!(a.p1, b.p0);
+            for &t in &[0.0, 0.5, 1.0] {
+                assert_approx_eq!(h.position(0.3 * t), a.position(t), 1e-5);
+                assert_approx_eq!(h.position(0.3 + 0.7 * t), b.position(t), 1e-5);
+                assert_approx_eq!(h.velocity(0.3 + 0.7 * t) * 0.7, b.velocity(t), 1e-5);
+            }
+        });
+
+        glam_test!(test_catmull_rom, {
+            let p = [
+                point(0.0, 0.0),
+                point(1.0, 1.0),
+                point(3.0, 1.0),
+                point(4.0, 3.0),
+            ];
+            let uniform = CatmullRom::new(p[0], p[1], p[2], p[3]);
+            assert_eq!(Parameterization::Uniform, uniform.parameterization);
+            assert_eq!(
+                CatmullRom::<$vec>::default().parameterization,
+                uniform.parameterization
+            );
+            assert_approx_eq!(p[1], uniform.position(0.0), 1e-6);
+            assert_approx_eq!(p[2], uniform.position(1.0), 1e-6);
+            assert_approx_eq!((p[2] - p[0]) * 0.5, uniform.velocity(0.0), 1e-6);
+            assert_approx_eq!((p[3] - p[1]) * 0.5, uniform.velocity(1.0), 1e-6);
+
+            let centripetal = CatmullRom::centripetal(p[0], p[1], p[2], p[3]);
+            assert_eq!(Parameterization::Centripetal, centripetal.parameterization);
+            assert_approx_eq!(p[1], centripetal.position(0.0), 1e-6);
+            assert_approx_eq!(p[2], centripetal.position(1.0), 1e-6);
+            for &t in &[0.0, 0.3, 1.0] {
+                let c = centripetal.to_cubic_bezier();
+                assert_approx_eq!(centripetal.position(t), c.position(t), 1e-5);
+                assert_approx_eq!(centripetal.velocity(t), c.velocity(t), 1e-5);
+                assert_approx_eq!(centripetal.acceleration(t), c.acceleration(t), 1e-5);
+            }
+
+            // evenly spaced points have the same tangents with both parameterizations
+            let even = [
+                point(0.0, 0.0),
+                point(1.0, 0.0),
+                point(1.0, 1.0),
+                point(2.0, 1.0),
+            ];
+            let a = CatmullRom::new(even[0], even[1], even[2], even[3]).to_hermite();
+            let b = CatmullRom::centripetal(even[0], even[1], even[2], even[3]).to_hermite();
+            assert_approx_eq!(a.v0, b.v0, 1e-5);
+            assert_approx_eq!(a.v1, b.v1, 1e-5);
+
+            // coincident points
+            let h = CatmullRom::centripetal(p[1], p[1], p[2], p[2]).to_hermite();
+            assert!(h.v0.is_finite() && h.v1.is_finite());
+            let h = CatmullRom::centripetal(p[0], p[1], p[1], p[2]).to_hermite();
+            assert!(h.v0.is_finite() && h.v1.is_finite());
+            assert_eq!(p[1], h.position(1.0));
+            assert!(h.position(0.5).is_finite());
+        });
+
+        glam_test!(test_bspline, {
+            let p = [
+                point(0.0, 0.0),
+                point(1.0, 2.0),
+                point(3.0, 2.0),
+                point(4.0, 0.0),
+                point(6.0, 1.0),
+            ];
+            let a = BSpline::new(p[0], p[1], p[2], p[3]);
+            let b = BSpline::new(p[1], p[2], p[3], p[4]);
+            assert_approx_eq!((p[0] + p[1] * 4.0 + p[2]) / 6.0, a.position(0.0), 1e-6);
+            assert_approx_eq!((p[2] - p[0]) * 0.5, a.velocity(0.0), 1e-6);
+            assert_approx_eq!(p[0] - p[1] * 2.0 + p[2], a.acceleration(0.0), 1e-6);
+
+            // continuous up to the second derivative between segments
+            assert_approx_eq!(a.position(1.0), b.position(0.0), 1e-6);
+            assert_approx_eq!(a.velocity(1.0), b.velocity(0.0), 1e-6);
+            assert_approx_eq!(a.acceleration(1.0), b.acceleration(0.0), 1e-6);
+
+            let c = a.to_cubic_bezier();
+            for &t in &[0.0, 0.2, 0.5, 1.0] {
+                assert_approx_eq!(a.position(t), c.position(t), 1e-5);
+                assert_approx_eq!(a.velocity(t), c.velocity(t), 1e-5);
+                assert_approx_eq!(a.acceleration(t), c.acceleration(t), 1e-5);
+                assert_approx_eq!(derivative(|t| a.position(t), t), a.velocity(t), 1e-2);
+            }
+        });
+
+        glam_test!(test_cubic_spline, {
+            let (first, second) = cubic().split(0.5);
+            let third = CubicBezier::new(
+                point(4.0, 0.0),
+                point(5.0, -2.0),
+                point(6.0, -2.0),
+                point(7.0, 0.0),
+            );
+            let segments = [first, second, third];
+            let spline = CubicSpline::new(&segments);
+            assert_eq!(&segments[..], spline.segments());
+
+            assert_eq!(first.p0, spline.position(0.0));
+            assert_eq!(second.p0, spline.position(1.0));
+            assert_eq!(third.p3, spline.position(3.0));
+            assert_eq!(second.position(0.25), spline.position(1.25));
+            assert_eq!(third.velocity(0.5), spline.velocity(2.5));
+            assert_eq!(first.acceleration(0.5), spline.acceleration(0.5));
+            // clamped
+            assert_eq!(first.p0, spline.position(-1.0));
+            assert_eq!(third.p3, spline.position(4.0));
+
+            let length = spline.length();
+            assert_approx_eq!(cubic().length() + third.length(), length, 1e-5);
+            assert_eq!(0.0, spline.param_at_length(0.0));
+            assert_eq!(3.0, spline.param_at_length(length + 1.0));
+            // the first two segments are half of the symmetric cubic
+            assert_approx_eq!(1.0, spline.param_at_length(cubic().length() * 0.5), 1e-4);
+            assert_approx_eq!(
+                2.5,
+                spline.param_at_length(length - third.length() * 0.5),
+                1e-4
+            );
+            assert_approx_eq!(
+                spline.position(2.5),
+                spline.position_at_length(length - third.length() * 0.5),
+                1e-3
+            );
+
+            // evenly spaced samples
+            let n = 20;
+            let mut prev = spline.position_at_length(0.0);
+            let mut total = 0.0;
+            for i in 1..=n {
+                let p = spline.position_at_length(length * i as $t / n as $t);
+                let d = p.distance(prev);
+                assert!(d <= length / n as $t + 1e-3);
+                total += d;
+                prev = p;
+            }
+            assert_approx_eq!(third.p3, prev, 1e-5);
+            assert!(total > length * 0.99);
+
+            assert_approx_eq!(1.0, spline.closest_param(point(2.0, 3.0)), 1e-4);
+            assert_approx_eq!(2.5, spline.closest_param(point(5.5, -3.0)), 1e-4);
+            assert_approx_eq!(
+                point(5.5, -1.5),
+                spline.closest_point(point(5.5, -3.0)),
+                1e-4
+            );
+            assert_eq!(3.0, spline.closest_param(point(9.0, 0.0)));
+        });
+
+        glam_test!(test_cubic_spline_empty, {
+            should_panic!({ CubicSpline::<$vec>::new(&[]) });
+        });
+    };
+}
+
+mod vec2 {
+    use glam::Vec2;
+    impl_curves_tests!(f32, Vec2);
+}
+
+mod vec3 {
+    use glam::Vec3;
+    impl_curves_tests!(f32, Vec3);
+}
+
+mod vec3a {
+    use glam::Vec3A;
+    impl_curves_tests!(f32, Vec3A);
+}
+
+mod dvec3 {
+    use glam::DVec3;
+    impl_curves_tests!(f64, DVec3);
+}
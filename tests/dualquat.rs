@@ -0,0 +1,258 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_dualquat_tests {
+    ($t:ident, $dualquat:ident, $quat:ident, $vec3:ident, $mat4:ident, $affine3:ident) => {
+        use core::$t::consts::FRAC_PI_2;
+        use core::$t::NAN;
+
+        glam_test!(test_identity, {
+            let dq = $dualquat::IDENTITY;
+            assert_eq!($dualquat::default(), dq);
+            assert_eq!($quat::IDENTITY, dq.rotation());
+            assert_eq!($vec3::ZERO, dq.translation());
+            assert!(dq.is_normalized());
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_eq!(p, dq.transform_point3(p));
+            assert_eq!(p, dq.transform_vector3(p));
+        });
+
+        glam_test!(test_nan, {
+            assert!($dualquat::NAN.is_nan());
+            assert!(!$dualquat::NAN.is_finite());
+            assert!($dualquat::from_translation($vec3::new(NAN, 0.0, 0.0)).is_nan());
+            assert!($dualquat::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_from_rotation_translation, {
+            let r = $quat::from_rotation_z(FRAC_PI_2);
+            let t = $vec3::new(1.0, 2.0, 3.0);
+            let dq = $dualquat::from_rotation_translation(r, t);
+            assert!(dq.is_normalized());
+            assert_eq!(r, dq.rotation());
+            assert_approx_eq!(t, dq.translation(), 1e-6);
+            let (r1, t1) = dq.to_rotation_translation();
+            assert_eq!(r, r1);
+            assert_approx_eq!(t, t1, 1e-6);
+
+            // rotation is applied before translation
+            assert_approx_eq!(
+                $vec3::new(1.0, 3.0, 3.0),
+                dq.transform_point3($vec3::X),
+                1e-6
+            );
+            assert_approx_eq!($vec3::Y, dq.transform_vector3($vec3::X), 1e-6);
+
+            assert_eq!(
+                $dualquat::from_rotation_translation(r, $vec3::ZERO),
+                $dualquat::from_quat(r)
+            );
+            assert_approx_eq!(
+                $dualquat::from_rotation_translation($quat::IDENTITY, t),
+                $dualquat::from_translation(t),
+                1e-6
+            );
+        });
+
+        glam_test!(test_mul, {
+            let a = $dualquat::from_rotation_translation(
+                $quat::from_rotation_x(0.5),
+                $vec3::new(1.0, -2.0, 0.5),
+            );
+            let b = $dualquat::from_rotation_translation(
+                $quat::from_rotation_y(-1.25),
+                $vec3::new(0.0, 4.0, -3.0),
+            );
+            let p = $vec3::new(0.25, 3.0, -2.0);
+            let ab = a * b;
+            assert!(ab.is_normalized());
+            assert_approx_eq!(
+                a.transform_point3(b.transform_point3(p)),
+                ab.transform_point3(p),
+                1e-5
+            );
+            assert_approx_eq!($mat4::from(a) * $mat4::from(b), $mat4::from(ab), 1e-5);
+            let mut c = a;
+            c *= b;
+            assert_eq!(ab, c);
+            assert_eq!(ab, a.mul_dual_quat(b));
+        });
+
+        glam_test!(test_inverse, {
+            let dq = $dualquat::from_rotation_translation(
+                $quat::from_rotation_x(0.5),
+                $vec3::new(1.0, -2.0, 0.5),
+            );
+            let inv = dq.inverse();
+            assert_eq!(dq.conjugate(), inv);
+            assert_approx_eq!($dualquat::IDENTITY, dq * inv, 1e-6);
+            assert_approx_eq!($dualquat::IDENTITY, inv * dq, 1e-6);
+            let p = $vec3::new(0.25, 3.0, -2.0);
+            assert_approx_eq!(p, inv.transform_point3(dq.transform_point3(p)), 1e-5);
+            should_glam_assert!({ ($dualquat::IDENTITY * 2.0).inverse() });
+        });
+
+        glam_test!(test_normalize, {
+            let dq = $dualquat::from_rotation_translation(
+                $quat::from_rotation_y(1.0),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let scaled = dq * 3.0;
+            assert!(!scaled.is_normalized());
+            assert_approx_eq!(dq, scaled.normalize(), 1e-6);
+
+            // a dual part that is not orthogonal to the real part is corrected
+            let skewed = $dualquat::from_real_dual(dq.real, dq.dual + dq.real * 0.25);
+            assert!(!skewed.is_normalized());
+            assert!(skewed.normalize().is_normalized());
+            assert_approx_eq!(dq, skewed.normalize(), 1e-6);
+        });
+
+        glam_test!(test_affine_mat4_conversions, {
+            let r = $quat::from_rotation_y(0.75);
+            let t = $vec3::new(-1.0, 2.0, 5.0);
+            let dq = $dualquat::from_rotation_translation(r, t);
+            let m = $mat4::from_rotation_translation(r, t);
+            let a = $affine3::from_rotation_translation(r, t);
+            assert_approx_eq!(m, $mat4::from(dq), 1e-6);
+            assert_approx_eq!(m, $mat4::from($affine3::from(dq)), 1e-6);
+            assert_approx_eq!(dq, $dualquat::from_mat4(&m), 1e-6);
+            assert_approx_eq!(dq, $dualquat::from_affine3(&a), 1e-6);
+
+            // scale is discarded
+            let m = $mat4::from_scale_rotation_translation($vec3::splat(2.0), r, t);
+            assert_approx_eq!(dq, $dualquat::from_mat4(&m), 1e-6);
+        });
+
+        glam_test!(test_lerp, {
+            let a = $dualquat::from_translation($vec3::new(2.0, 0.0, 0.0));
+            let b = $dualquat::from_rotation_translation(
+                $quat::from_rotation_z(FRAC_PI_2),
+                $vec3::new(0.0, 2.0, 0.0),
+            );
+            assert_approx_eq!(a, a.lerp(b, 0.0), 1e-6);
+            assert_approx_eq!(b, a.lerp(b, 1.0), 1e-6);
+            let mid = a.lerp(b, 0.5);
+            assert!(mid.is_normalized());
+            assert_approx_eq!(a.real.lerp(b.real, 0.5), mid.rotation(), 1e-6);
+
+            // takes the shortest path
+            assert_approx_eq!(mid, a.lerp(-b, 0.5), 1e-6);
+        });
+
+        glam_test!(test_sclerp, {
+            // a screw motion around and along the z axis
+            let a = $dualquat::IDENTITY;
+            let b = $dualquat::from_rotation_translation(
+                $quat::from_rotation_z(FRAC_PI_2),
+                $vec3::new(0.0, 0.0, 4.0),
+            );
+            assert_approx_eq!(a, a.sclerp(b, 0.0), 1e-6);
+            assert_approx_eq!(b, a.sclerp(b, 1.0), 1e-6);
+            let mid = a.sclerp(b, 0.5);
+            assert!(mid.is_normalized());
+            assert_approx_eq!(
+                $quat::from_rotation_z(FRAC_PI_2 * 0.5),
+                mid.rotation(),
+                1e-6
+            );
+            assert_approx_eq!($vec3::new(0.0, 0.0, 2.0), mid.translation(), 1e-5);
+
+            // a rotation about an axis offset from the origin moves points along an arc
+            let pivot = $vec3::new(1.0, 0.0, 0.0);
+            let rotate_about_pivot = $dualquat::from_translation(pivot)
+                * $dualquat::from_quat($quat::from_rotation_z(FRAC_PI_2))
+                * $dualquat::from_translation(-pivot);
+            let start = $dualquat::from_translation($vec3::new(0.0, 1.0, 0.0));
+            let end = rotate_about_pivot * start;
+            let mid = start.sclerp(end, 0.5);
+            let expected = $dualquat::from_translation(pivot)
+                * $dualquat::from_quat($quat::from_rotation_z(FRAC_PI_2 * 0.5))
+                * $dualquat::from_translation(-pivot)
+                * start;
+            assert_approx_eq!(expected, mid, 1e-5);
+            assert_approx_eq!(mid, start.sclerp(-end, 0.5), 1e-5);
+
+            // pure translations fall back to linear blending
+            let c = $dualquat::from_translation($vec3::new(2.0, 4.0, 6.0));
+            assert_approx_eq!(
+                $vec3::new(1.0, 2.0, 3.0),
+                a.sclerp(c, 0.5).translation(),
+                1e-6
+            );
+        });
+
+        glam_test!(test_ops, {
+            let a = $dualquat::from_translation($vec3::new(1.0, 2.0, 3.0));
+            let b = $dualquat::from_quat($quat::from_rotation_x(1.0));
+            assert_eq!(a.real + b.real, (a + b).real);
+            assert_eq!(a.dual + b.dual, (a + b).dual);
+            assert_eq!(a.real - b.real, (a - b).real);
+            assert_eq!(a.dual * 2.0, (a * 2.0).dual);
+            assert_eq!(-a.real, (-a).real);
+            assert_eq!(a.real.dot(b.real), a.dot(b));
+        });
+
+        glam_test!(test_fmt, {
+            let a = $dualquat::IDENTITY;
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    "{}{{ real: {:?}, dual: {:?} }}",
+                    stringify!($dualquat),
+                    a.real,
+                    a.dual
+                )
+                .replace("{ ", " { ")
+            );
+            assert_eq!(format!("{}", a), "[[0, 0, 0, 1], [0, 0, 0, 0]]");
+        });
+    };
+}
+
+mod dualquat {
+    use glam::{Affine3A, DualQuat, Mat4, Quat, Vec3, Vec3A};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(32, mem::size_of::<DualQuat>());
+        assert_eq!(mem::align_of::<Quat>(), mem::align_of::<DualQuat>());
+    });
+
+    glam_test!(test_vec3a, {
+        let dq = DualQuat::from_rotation_translation(
+            Quat::from_rotation_z(core::f32::consts::FRAC_PI_2),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        assert_approx_eq!(
+            Vec3A::new(1.0, 3.0, 3.0),
+            dq.transform_point3a(Vec3A::X),
+            1e-6
+        );
+        assert_approx_eq!(Vec3A::Y, dq.transform_vector3a(Vec3A::X), 1e-6);
+    });
+
+    glam_test!(test_as, {
+        use glam::DDualQuat;
+        let dq = DualQuat::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            DDualQuat::from_translation(glam::DVec3::new(1.0, 2.0, 3.0)),
+            dq.as_f64()
+        );
+        assert_eq!(dq, dq.as_f64().as_f32());
+    });
+
+    impl_dualquat_tests!(f32, DualQuat, Quat, Vec3, Mat4, Affine3A);
+}
+
+mod ddualquat {
+    use glam::{DAffine3, DDualQuat, DMat4, DQuat, DVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(64, mem::size_of::<DDualQuat>());
+        assert_eq!(mem::align_of::<DQuat>(), mem::align_of::<DDualQuat>());
+    });
+
+    impl_dualquat_tests!(f64, DDualQuat, DQuat, DVec3, DMat4, DAffine3);
+}
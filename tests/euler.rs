@@ -83,6 +83,141 @@ macro_rules! impl_3axis_test {
     };
 }
 
+macro_rules! impl_2axis_test {
+    ($name:ident, $t:ty, $quat:ident, $euler:path, $U:path, $V:path, $W:path, $vec:ident) => {
+        glam_test!($name, {
+            let euler = $euler;
+            assert!($U == $W); // First and last axis must be equal for two axis
+            for u in (-176..=176).step_by(44) {
+                for v in (4..=176).step_by(43) {
+                    for w in (-176..=176).step_by(44) {
+                        let u1 = (u as $t).to_radians();
+                        let v1 = (v as $t).to_radians();
+                        let w1 = (w as $t).to_radians();
+
+                        let q1: $quat = ($quat::from_axis_angle($U, u1)
+                            * $quat::from_axis_angle($V, v1)
+                            * $quat::from_axis_angle($W, w1))
+                        .normalize();
+
+                        // Test if the rotation is the expected
+                        let q2: $quat = $quat::from_euler(euler, u1, v1, w1).normalize();
+                        assert_approx_eq!(q1, q2, 1e-5);
+
+                        // Test angle reconstruction
+                        let (u2, v2, w2) = q1.to_euler(euler);
+                        let q3 = $quat::from_euler(euler, u2, v2, w2).normalize();
+
+                        assert_approx_angle!(u1, u2, 1e-4 as $t);
+                        assert_approx_angle!(v1, v2, 1e-4 as $t);
+                        assert_approx_angle!(w1, w2, 1e-4 as $t);
+
+                        assert_approx_eq!(q1 * $vec::X, q3 * $vec::X, 1e-4);
+                        assert_approx_eq!(q1 * $vec::Y, q3 * $vec::Y, 1e-4);
+                        assert_approx_eq!(q1 * $vec::Z, q3 * $vec::Z, 1e-4);
+                    }
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_extrinsic_test {
+    ($name:ident, $t:ty, $quat:ident, $euler:path, $U:path, $V:path, $W:path, $vec:ident) => {
+        glam_test!($name, {
+            let euler = $euler;
+            let (v_min, v_max) = if $U == $W { (4, 176) } else { (-88, 88) };
+            for u in (-176..=176).step_by(44) {
+                for v in (v_min..=v_max).step_by(43) {
+                    for w in (-176..=176).step_by(44) {
+                        let u1 = (u as $t).to_radians();
+                        let v1 = (v as $t).to_radians();
+                        let w1 = (w as $t).to_radians();
+
+                        // Extrinsic rotations apply the first angle first
+                        let q1: $quat = ($quat::from_axis_angle($W, w1)
+                            * $quat::from_axis_angle($V, v1)
+                            * $quat::from_axis_angle($U, u1))
+                        .normalize();
+
+                        // Test if the rotation is the expected
+                        let q2: $quat = $quat::from_euler(euler, u1, v1, w1).normalize();
+                        assert_approx_eq!(q1, q2, 1e-5);
+
+                        // Test angle reconstruction
+                        let (u2, v2, w2) = q1.to_euler(euler);
+
+                        assert_approx_angle!(u1, u2, 1e-4 as $t);
+                        assert_approx_angle!(v1, v2, 1e-4 as $t);
+                        assert_approx_angle!(w1, w2, 1e-4 as $t);
+                    }
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_gimbal_lock_test {
+    ($name:ident, $t:ty, $quat:ident, $vec:ident, [$($euler:path),+], [$($v:expr),+]) => {
+        glam_test!($name, {
+            for euler in [$($euler),+] {
+                for v in [$($v),+] {
+                    for u in (-176..=176).step_by(44) {
+                        for w in (-176..=176).step_by(44) {
+                            let u1 = (u as $t).to_radians();
+                            let v1 = (v as $t).to_radians();
+                            let w1 = (w as $t).to_radians();
+
+                            let q1 = $quat::from_euler(euler, u1, v1, w1);
+                            let (u2, v2, w2) = q1.to_euler(euler);
+                            assert!(u2.is_finite() && v2.is_finite() && w2.is_finite());
+
+                            // Only the sum or difference of the first and third angle is defined at
+                            // gimbal lock, the third angle is returned as zero
+                            assert_approx_angle!(v1, v2, 1e-4 as $t);
+                            assert_eq!(0.0, w2, "{:?}", euler);
+
+                            let q2 = $quat::from_euler(euler, u2, v2, w2);
+                            assert_approx_eq!(q1 * $vec::X, q2 * $vec::X, 1e-4);
+                            assert_approx_eq!(q1 * $vec::Y, q2 * $vec::Y, 1e-4);
+                            assert_approx_eq!(q1 * $vec::Z, q2 * $vec::Z, 1e-4);
+                        }
+                    }
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_near_gimbal_lock_test {
+    ($name:ident, $t:ty, $quat:ident, $vec:ident, [$($euler:path),+], [$($v:expr),+], $angle_eps:expr) => {
+        glam_test!($name, {
+            for euler in [$($euler),+] {
+                for v1 in [$($v),+] {
+                    for u in (-176..=176).step_by(44) {
+                        for w in (-176..=176).step_by(44) {
+                            let u1 = (u as $t).to_radians();
+                            let w1 = (w as $t).to_radians();
+
+                            // Close to, but not at gimbal lock all three angles are still defined
+                            let q1 = $quat::from_euler(euler, u1, v1, w1);
+                            let (u2, v2, w2) = q1.to_euler(euler);
+                            assert_approx_angle!(u1, u2, $angle_eps as $t);
+                            assert_approx_angle!(v1, v2, 1e-5 as $t);
+                            assert_approx_angle!(w1, w2, $angle_eps as $t);
+
+                            let q2 = $quat::from_euler(euler, u2, v2, w2);
+                            assert_approx_eq!(q1 * $vec::X, q2 * $vec::X, 1e-5);
+                            assert_approx_eq!(q1 * $vec::Y, q2 * $vec::Y, 1e-5);
+                            assert_approx_eq!(q1 * $vec::Z, q2 * $vec::Z, 1e-5);
+                        }
+                    }
+                }
+            }
+        });
+    };
+}
+
 macro_rules! impl_all_quat_tests_three_axis {
     ($t:ty, $q:ident, $v:ident) => {
         impl_3axis_test!(test_euler_zyx, $t, $q, ER::ZYX, $v::Z, $v::Y, $v::X, $v);
@@ -94,6 +229,233 @@ macro_rules! impl_all_quat_tests_three_axis {
     };
 }
 
+macro_rules! impl_all_quat_tests_two_axis {
+    ($t:ty, $q:ident, $v:ident) => {
+        impl_2axis_test!(test_euler_zyz, $t, $q, ER::ZYZ, $v::Z, $v::Y, $v::Z, $v);
+        impl_2axis_test!(test_euler_zxz, $t, $q, ER::ZXZ, $v::Z, $v::X, $v::Z, $v);
+        impl_2axis_test!(test_euler_yxy, $t, $q, ER::YXY, $v::Y, $v::X, $v::Y, $v);
+        impl_2axis_test!(test_euler_yzy, $t, $q, ER::YZY, $v::Y, $v::Z, $v::Y, $v);
+        impl_2axis_test!(test_euler_xyx, $t, $q, ER::XYX, $v::X, $v::Y, $v::X, $v);
+        impl_2axis_test!(test_euler_xzx, $t, $q, ER::XZX, $v::X, $v::Z, $v::X, $v);
+    };
+}
+
+macro_rules! impl_all_quat_tests_extrinsic {
+    ($t:ty, $q:ident, $v:ident) => {
+        impl_extrinsic_test!(
+            test_euler_zyx_ex,
+            $t,
+            $q,
+            ER::ZYXEx,
+            $v::Z,
+            $v::Y,
+            $v::X,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_zxy_ex,
+            $t,
+            $q,
+            ER::ZXYEx,
+            $v::Z,
+            $v::X,
+            $v::Y,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_yxz_ex,
+            $t,
+            $q,
+            ER::YXZEx,
+            $v::Y,
+            $v::X,
+            $v::Z,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_yzx_ex,
+            $t,
+            $q,
+            ER::YZXEx,
+            $v::Y,
+            $v::Z,
+            $v::X,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_xyz_ex,
+            $t,
+            $q,
+            ER::XYZEx,
+            $v::X,
+            $v::Y,
+            $v::Z,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_xzy_ex,
+            $t,
+            $q,
+            ER::XZYEx,
+            $v::X,
+            $v::Z,
+            $v::Y,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_zyz_ex,
+            $t,
+            $q,
+            ER::ZYZEx,
+            $v::Z,
+            $v::Y,
+            $v::Z,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_zxz_ex,
+            $t,
+            $q,
+            ER::ZXZEx,
+            $v::Z,
+            $v::X,
+            $v::Z,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_yxy_ex,
+            $t,
+            $q,
+            ER::YXYEx,
+            $v::Y,
+            $v::X,
+            $v::Y,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_yzy_ex,
+            $t,
+            $q,
+            ER::YZYEx,
+            $v::Y,
+            $v::Z,
+            $v::Y,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_xyx_ex,
+            $t,
+            $q,
+            ER::XYXEx,
+            $v::X,
+            $v::Y,
+            $v::X,
+            $v
+        );
+        impl_extrinsic_test!(
+            test_euler_xzx_ex,
+            $t,
+            $q,
+            ER::XZXEx,
+            $v::X,
+            $v::Z,
+            $v::X,
+            $v
+        );
+    };
+}
+
+macro_rules! impl_all_quat_tests_gimbal_lock {
+    ($t:ty, $q:ident, $v:ident, $angle_eps:expr) => {
+        impl_gimbal_lock_test!(
+            test_euler_gimbal_lock_three_axis,
+            $t,
+            $q,
+            $v,
+            [
+                ER::ZYX,
+                ER::ZXY,
+                ER::YXZ,
+                ER::YZX,
+                ER::XYZ,
+                ER::XZY,
+                ER::ZYXEx,
+                ER::ZXYEx,
+                ER::YXZEx,
+                ER::YZXEx,
+                ER::XYZEx,
+                ER::XZYEx
+            ],
+            [-90, 90]
+        );
+        impl_gimbal_lock_test!(
+            test_euler_gimbal_lock_two_axis,
+            $t,
+            $q,
+            $v,
+            [
+                ER::ZYZ,
+                ER::ZXZ,
+                ER::YXY,
+                ER::YZY,
+                ER::XYX,
+                ER::XZX,
+                ER::ZYZEx,
+                ER::ZXZEx,
+                ER::YXYEx,
+                ER::YZYEx,
+                ER::XYXEx,
+                ER::XZXEx
+            ],
+            [0, 180]
+        );
+        impl_near_gimbal_lock_test!(
+            test_euler_near_gimbal_lock_three_axis,
+            $t,
+            $q,
+            $v,
+            [
+                ER::ZYX,
+                ER::ZXY,
+                ER::YXZ,
+                ER::YZX,
+                ER::XYZ,
+                ER::XZY,
+                ER::ZYXEx,
+                ER::ZXYEx,
+                ER::YXZEx,
+                ER::YZXEx,
+                ER::XYZEx,
+                ER::XZYEx
+            ],
+            [-FRAC_PI_2 + 1e-4, FRAC_PI_2 - 1e-4],
+            $angle_eps
+        );
+        impl_near_gimbal_lock_test!(
+            test_euler_near_gimbal_lock_two_axis,
+            $t,
+            $q,
+            $v,
+            [
+                ER::ZYZ,
+                ER::ZXZ,
+                ER::YXY,
+                ER::YZY,
+                ER::XYX,
+                ER::XZX,
+                ER::ZYZEx,
+                ER::ZXZEx,
+                ER::YXYEx,
+                ER::YZYEx,
+                ER::XYXEx,
+                ER::XZXEx
+            ],
+            [1e-4, PI - 1e-4],
+            $angle_eps
+        );
+    };
+}
+
 mod euler {
     use super::AngleDiff;
     use glam::*;
@@ -101,13 +463,21 @@ mod euler {
 
     mod quat {
         use super::*;
+        use core::f32::consts::{FRAC_PI_2, PI};
 
         impl_all_quat_tests_three_axis!(f32, Quat, Vec3);
+        impl_all_quat_tests_two_axis!(f32, Quat, Vec3);
+        impl_all_quat_tests_extrinsic!(f32, Quat, Vec3);
+        impl_all_quat_tests_gimbal_lock!(f32, Quat, Vec3, 5e-3);
     }
 
     mod dquat {
         use super::*;
+        use core::f64::consts::{FRAC_PI_2, PI};
 
         impl_all_quat_tests_three_axis!(f64, DQuat, DVec3);
+        impl_all_quat_tests_two_axis!(f64, DQuat, DVec3);
+        impl_all_quat_tests_extrinsic!(f64, DQuat, DVec3);
+        impl_all_quat_tests_gimbal_lock!(f64, DQuat, DVec3, 1e-8);
     }
 }
@@ -0,0 +1,165 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_frustum_tests {
+    ($t:ident, $frustum:ident, $vec3:ident, $mat4:ident, $plane3:ident, $sphere:ident, $aabb3:ident) => {
+        use core::$t::consts::FRAC_PI_2;
+
+        fn ndc_corners(near: $t, far: $t) -> [$vec3; 8] {
+            [
+                $vec3::new(-1.0, -1.0, near),
+                $vec3::new(1.0, -1.0, near),
+                $vec3::new(1.0, 1.0, near),
+                $vec3::new(-1.0, 1.0, near),
+                $vec3::new(-1.0, -1.0, far),
+                $vec3::new(1.0, -1.0, far),
+                $vec3::new(1.0, 1.0, far),
+                $vec3::new(-1.0, 1.0, far),
+            ]
+        }
+
+        fn assert_corners(frustum: &$frustum, m: &$mat4, near: $t, far: $t) {
+            let inverse = m.inverse();
+            for (corner, ndc) in frustum.corners().iter().zip(ndc_corners(near, far).iter()) {
+                assert_approx_eq!(inverse.project_point3(*ndc), *corner, 1e-4);
+            }
+        }
+
+        glam_test!(test_from_mat4, {
+            let m = $mat4::perspective_rh(FRAC_PI_2, 1.0, 1.0, 10.0);
+            let f = $frustum::from_mat4(&m);
+            let s = core::$t::consts::FRAC_1_SQRT_2;
+            assert_approx_eq!($plane3::new($vec3::new(s, 0.0, -s), 0.0), f.left());
+            assert_approx_eq!($plane3::new($vec3::new(-s, 0.0, -s), 0.0), f.right());
+            assert_approx_eq!($plane3::new($vec3::new(0.0, s, -s), 0.0), f.bottom());
+            assert_approx_eq!($plane3::new($vec3::new(0.0, -s, -s), 0.0), f.top());
+            assert_approx_eq!($plane3::new($vec3::NEG_Z, -1.0), f.near(), 1e-6);
+            assert_approx_eq!($plane3::new($vec3::Z, 10.0), f.far(), 1e-5);
+            assert_eq!(
+                [f.left(), f.right(), f.bottom(), f.top(), f.near(), f.far()],
+                f.planes()
+            );
+            assert_corners(&f, &m, 0.0, 1.0);
+
+            assert!(f.contains_point($vec3::new(0.0, 0.0, -5.0)));
+            assert!(f.contains_point($vec3::new(4.0, -4.0, -5.0)));
+            assert!(!f.contains_point($vec3::new(6.0, 0.0, -5.0)));
+            assert!(!f.contains_point($vec3::new(0.0, 0.0, 5.0)));
+            assert!(!f.contains_point($vec3::new(0.0, 0.0, -0.5)));
+            assert!(!f.contains_point($vec3::new(0.0, 0.0, -11.0)));
+
+            let m = $mat4::perspective_lh(FRAC_PI_2, 1.0, 1.0, 10.0);
+            let f = $frustum::from_mat4(&m);
+            assert!(f.contains_point($vec3::new(0.0, 0.0, 5.0)));
+            assert!(!f.contains_point($vec3::new(0.0, 0.0, -5.0)));
+            assert_corners(&f, &m, 0.0, 1.0);
+        });
+
+        glam_test!(test_from_mat4_gl, {
+            let m = $mat4::perspective_rh_gl(FRAC_PI_2, 2.0, 1.0, 10.0);
+            let f = $frustum::from_mat4_gl(&m);
+            assert_approx_eq!($plane3::new($vec3::NEG_Z, -1.0), f.near(), 1e-6);
+            assert_approx_eq!($plane3::new($vec3::Z, 10.0), f.far(), 1e-5);
+            assert_corners(&f, &m, -1.0, 1.0);
+            assert!(f.contains_point($vec3::new(9.0, 0.0, -5.0)));
+            assert!(!f.contains_point($vec3::new(11.0, 0.0, -5.0)));
+
+            let m = $mat4::orthographic_rh_gl(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0);
+            let f = $frustum::from_mat4_gl(&m);
+            assert_approx_eq!($plane3::new($vec3::X, 2.0), f.left(), 1e-6);
+            assert_approx_eq!($plane3::new($vec3::NEG_Y, 1.0), f.top(), 1e-6);
+            assert_corners(&f, &m, -1.0, 1.0);
+        });
+
+        glam_test!(test_from_mat4_reverse_z, {
+            let m = $mat4::perspective_infinite_reverse_rh(FRAC_PI_2, 1.0, 1.0);
+            let f = $frustum::from_mat4_reverse_z(&m);
+            assert_approx_eq!($plane3::new($vec3::NEG_Z, -1.0), f.near(), 1e-6);
+            assert_eq!($vec3::ZERO, f.far().normal);
+            assert!(f.contains_point($vec3::new(0.0, 0.0, -1e6)));
+            assert!(!f.contains_point($vec3::new(0.0, 0.0, -0.5)));
+            let corners = f.corners();
+            assert_approx_eq!($vec3::new(-1.0, -1.0, -1.0), corners[0], 1e-6);
+            assert_approx_eq!($vec3::new(1.0, 1.0, -1.0), corners[2], 1e-6);
+            assert!(!corners[4].is_finite());
+            assert!(f.is_finite());
+        });
+
+        glam_test!(test_view_projection, {
+            let proj = $mat4::perspective_rh(FRAC_PI_2, 1.0, 1.0, 10.0);
+            let view = $mat4::look_at_rh($vec3::new(5.0, 0.0, 0.0), $vec3::ZERO, $vec3::Y);
+            let m = proj * view;
+            let f = $frustum::from_mat4(&m);
+            assert!(f.contains_point($vec3::ZERO));
+            assert!(f.contains_point($vec3::new(-4.0, 0.0, 0.0)));
+            assert!(!f.contains_point($vec3::new(-6.0, 0.0, 0.0)));
+            assert!(!f.contains_point($vec3::new(6.0, 0.0, 0.0)));
+            assert_corners(&f, &m, 0.0, 1.0);
+        });
+
+        glam_test!(test_from_planes, {
+            let f = $frustum::from_mat4(&$mat4::perspective_rh(FRAC_PI_2, 1.0, 1.0, 10.0));
+            assert!(f.abs_diff_eq($frustum::from_planes(f.planes()), 1e-6));
+            let mut planes = f.planes();
+            planes[4] = $plane3::new($vec3::new(0.0, 0.0, -2.0), -4.0);
+            let g = $frustum::from_planes(planes);
+            assert!(g.is_finite());
+            assert!(g.near().is_normalized());
+            assert_approx_eq!($plane3::new($vec3::NEG_Z, -2.0), g.near());
+            assert!(!f.abs_diff_eq(g, 0.5));
+            assert!(f.abs_diff_eq(g, 1.0));
+        });
+
+        glam_test!(test_sphere, {
+            let f = $frustum::from_mat4(&$mat4::perspective_rh(FRAC_PI_2, 1.0, 1.0, 10.0));
+            let s = $sphere::new($vec3::new(0.0, 0.0, -5.0), 1.0);
+            assert!(f.contains_sphere(&s));
+            assert!(f.intersects_sphere(&s));
+            let s = $sphere::new($vec3::new(0.0, 0.0, -0.5), 1.0);
+            assert!(!f.contains_sphere(&s));
+            assert!(f.intersects_sphere(&s));
+            let s = $sphere::new($vec3::new(0.0, 0.0, 2.0), 1.0);
+            assert!(!f.contains_sphere(&s));
+            assert!(!f.intersects_sphere(&s));
+            let s = $sphere::new($vec3::new(7.0, 0.0, -5.0), 1.0);
+            assert!(!f.intersects_sphere(&s));
+        });
+
+        glam_test!(test_aabb, {
+            let f = $frustum::from_mat4(&$mat4::perspective_rh(FRAC_PI_2, 1.0, 1.0, 10.0));
+            let aabb = $aabb3::new($vec3::new(-1.0, -1.0, -6.0), $vec3::new(1.0, 1.0, -4.0));
+            assert!(f.contains_aabb(&aabb));
+            assert!(f.intersects_aabb(&aabb));
+            let aabb = $aabb3::new($vec3::new(-1.0, -1.0, -6.0), $vec3::new(6.0, 1.0, -4.0));
+            assert!(!f.contains_aabb(&aabb));
+            assert!(f.intersects_aabb(&aabb));
+            let aabb = $aabb3::new($vec3::new(-1.0, -1.0, 1.0), $vec3::new(1.0, 1.0, 2.0));
+            assert!(!f.contains_aabb(&aabb));
+            assert!(!f.intersects_aabb(&aabb));
+            let aabb = $aabb3::new($vec3::new(6.0, -1.0, -5.0), $vec3::new(7.0, 1.0, -4.0));
+            assert!(!f.intersects_aabb(&aabb));
+            assert!(!f.intersects_aabb(&$aabb3::EMPTY));
+        });
+
+        glam_test!(test_fmt, {
+            let f = $frustum::from_mat4(&$mat4::perspective_rh(FRAC_PI_2, 1.0, 1.0, 10.0));
+            assert!(format!("{:?}", f).starts_with(&format!(
+                "{} {{ left: {:?}, right: ",
+                stringify!($frustum),
+                f.left()
+            )));
+        });
+    };
+}
+
+mod frustum {
+    use glam::{Aabb3, Frustum, Mat4, Plane3, Sphere, Vec3};
+
+    impl_frustum_tests!(f32, Frustum, Vec3, Mat4, Plane3, Sphere, Aabb3);
+}
+
+mod dfrustum {
+    use glam::{DAabb3, DFrustum, DMat4, DPlane3, DSphere, DVec3};
+
+    impl_frustum_tests!(f64, DFrustum, DVec3, DMat4, DPlane3, DSphere, DAabb3);
+}
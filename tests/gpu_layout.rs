@@ -0,0 +1,210 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_padded_mat_tests {
+    ($t:ident, $mat:ident, $std140:ident, $std430:ident) => {
+        glam_test!(test_from, {
+            let len = core::mem::size_of::<$mat>() / core::mem::size_of::<$t>();
+            let mut array = [0.0; 16];
+            for (i, v) in array.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            let m = $mat::from_cols_slice(&array[..len]);
+
+            let std140 = $std140::from(m);
+            assert_eq!(m, $mat::from(std140));
+            let std430 = $std430::from(m);
+            assert_eq!(m, $mat::from(std430));
+
+            assert_eq!($std140::default(), $std140::from($mat::ZERO));
+            assert_eq!($std430::default(), $std430::from($mat::ZERO));
+            assert_ne!($std140::default(), std140);
+        });
+
+        glam_test!(test_layout, {
+            use glam::gpu_layout::GpuLayout;
+            assert_eq!($mat::STD140_SIZE, core::mem::size_of::<$std140>());
+            assert_eq!($mat::STD140_ALIGN, core::mem::align_of::<$std140>());
+            assert_eq!($mat::STD430_SIZE, core::mem::size_of::<$std430>());
+            assert_eq!($mat::STD430_ALIGN, core::mem::align_of::<$std430>());
+        });
+
+        glam_test!(test_fmt, {
+            let m = $std140::from($mat::IDENTITY);
+            assert_eq!(
+                format!("{:?}", m),
+                format!("{}({:?})", stringify!($std140), $mat::IDENTITY)
+            );
+        });
+    };
+}
+
+mod std_mat2 {
+    use glam::gpu_layout::{Std140Mat2, Std430Mat2};
+    use glam::Mat2;
+    impl_padded_mat_tests!(f32, Mat2, Std140Mat2, Std430Mat2);
+}
+
+mod std_mat2x3 {
+    use glam::gpu_layout::{Std140Mat2x3, Std430Mat2x3};
+    use glam::Mat2x3;
+    impl_padded_mat_tests!(f32, Mat2x3, Std140Mat2x3, Std430Mat2x3);
+}
+
+mod std_mat3x2 {
+    use glam::gpu_layout::{Std140Mat3x2, Std430Mat3x2};
+    use glam::{Affine2, Mat3x2, Vec2};
+    impl_padded_mat_tests!(f32, Mat3x2, Std140Mat3x2, Std430Mat3x2);
+
+    glam_test!(test_affine2, {
+        let a = Affine2::from_cols(
+            Vec2::new(1.0, 2.0),
+            Vec2::new(3.0, 4.0),
+            Vec2::new(5.0, 6.0),
+        );
+        assert_eq!(a, Affine2::from(Std140Mat3x2::from(a)));
+        assert_eq!(a, Affine2::from(Std430Mat3x2::from(a)));
+        assert_eq!(Mat3x2::from(a), Mat3x2::from(Std140Mat3x2::from(a)));
+    });
+}
+
+mod std_mat3 {
+    use glam::gpu_layout::{Std140Mat3, Std430Mat3};
+    use glam::{Mat3, Mat3A};
+    impl_padded_mat_tests!(f32, Mat3, Std140Mat3, Std430Mat3);
+
+    glam_test!(test_mat3a, {
+        let m = Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(m, Mat3A::from(Std140Mat3::from(m)));
+        assert_eq!(m, Mat3A::from(Std430Mat3::from(m)));
+        assert_eq!(Mat3::from(m), Mat3::from(Std140Mat3::from(m)));
+    });
+}
+
+mod std_mat3x4 {
+    use glam::gpu_layout::{Std140Mat3x4, Std430Mat3x4};
+    use glam::Mat3x4;
+    impl_padded_mat_tests!(f32, Mat3x4, Std140Mat3x4, Std430Mat3x4);
+}
+
+mod std_mat4x3 {
+    use glam::gpu_layout::{Std140Mat4x3, Std430Mat4x3};
+    use glam::{Affine3A, Mat4x3, Vec3A};
+    impl_padded_mat_tests!(f32, Mat4x3, Std140Mat4x3, Std430Mat4x3);
+
+    glam_test!(test_affine3a, {
+        let a = Affine3A::from_cols(
+            Vec3A::new(1.0, 2.0, 3.0),
+            Vec3A::new(4.0, 5.0, 6.0),
+            Vec3A::new(7.0, 8.0, 9.0),
+            Vec3A::new(10.0, 11.0, 12.0),
+        );
+        assert_eq!(a, Affine3A::from(Std140Mat4x3::from(a)));
+        assert_eq!(a, Affine3A::from(Std430Mat4x3::from(a)));
+        assert_eq!(Mat4x3::from(a), Mat4x3::from(Std140Mat4x3::from(a)));
+    });
+}
+
+mod std_mat4 {
+    use glam::gpu_layout::{Std140Mat4, Std430Mat4};
+    use glam::Mat4;
+    impl_padded_mat_tests!(f32, Mat4, Std140Mat4, Std430Mat4);
+}
+
+mod std_dmat2 {
+    use glam::gpu_layout::{Std140DMat2, Std430DMat2};
+    use glam::DMat2;
+    impl_padded_mat_tests!(f64, DMat2, Std140DMat2, Std430DMat2);
+}
+
+mod std_dmat2x3 {
+    use glam::gpu_layout::{Std140DMat2x3, Std430DMat2x3};
+    use glam::DMat2x3;
+    impl_padded_mat_tests!(f64, DMat2x3, Std140DMat2x3, Std430DMat2x3);
+}
+
+mod std_dmat3x2 {
+    use glam::gpu_layout::{Std140DMat3x2, Std430DMat3x2};
+    use glam::{DAffine2, DMat3x2, DVec2};
+    impl_padded_mat_tests!(f64, DMat3x2, Std140DMat3x2, Std430DMat3x2);
+
+    glam_test!(test_daffine2, {
+        let a = DAffine2::from_cols(
+            DVec2::new(1.0, 2.0),
+            DVec2::new(3.0, 4.0),
+            DVec2::new(5.0, 6.0),
+        );
+        assert_eq!(a, DAffine2::from(Std140DMat3x2::from(a)));
+        assert_eq!(a, DAffine2::from(Std430DMat3x2::from(a)));
+    });
+}
+
+mod std_dmat3 {
+    use glam::gpu_layout::{Std140DMat3, Std430DMat3};
+    use glam::DMat3;
+    impl_padded_mat_tests!(f64, DMat3, Std140DMat3, Std430DMat3);
+}
+
+mod std_dmat3x4 {
+    use glam::gpu_layout::{Std140DMat3x4, Std430DMat3x4};
+    use glam::DMat3x4;
+    impl_padded_mat_tests!(f64, DMat3x4, Std140DMat3x4, Std430DMat3x4);
+}
+
+mod std_dmat4x3 {
+    use glam::gpu_layout::{Std140DMat4x3, Std430DMat4x3};
+    use glam::{DAffine3, DMat4x3, DVec3};
+    impl_padded_mat_tests!(f64, DMat4x3, Std140DMat4x3, Std430DMat4x3);
+
+    glam_test!(test_daffine3, {
+        let a = DAffine3::from_cols(
+            DVec3::new(1.0, 2.0, 3.0),
+            DVec3::new(4.0, 5.0, 6.0),
+            DVec3::new(7.0, 8.0, 9.0),
+            DVec3::new(10.0, 11.0, 12.0),
+        );
+        assert_eq!(a, DAffine3::from(Std140DMat4x3::from(a)));
+        assert_eq!(a, DAffine3::from(Std430DMat4x3::from(a)));
+    });
+}
+
+mod std_dmat4 {
+    use glam::gpu_layout::{Std140DMat4, Std430DMat4};
+    use glam::DMat4;
+    impl_padded_mat_tests!(f64, DMat4, Std140DMat4, Std430DMat4);
+}
+
+mod uniform_struct {
+    use glam::gpu_layout::{GpuLayout, Std140Mat3};
+    use glam::{Mat3, Vec2, Vec4};
+
+    // layout(std140) uniform Block { vec2 a; mat3 b; vec4 c; };
+    #[repr(C)]
+    struct Block {
+        a: [f32; 2],
+        b: Std140Mat3,
+        c: [f32; 4],
+    }
+
+    fn align(offset: usize, align: usize) -> usize {
+        (offset + align - 1) / align * align
+    }
+
+    glam_test!(test_offsets, {
+        let a = 0;
+        let b = align(a + Vec2::STD140_SIZE, Mat3::STD140_ALIGN);
+        let c = align(b + Mat3::STD140_SIZE, Vec4::STD140_ALIGN);
+        assert_eq!(16, b);
+        assert_eq!(64, c);
+
+        let block = Block {
+            a: [0.0; 2],
+            b: Std140Mat3::from(Mat3::IDENTITY),
+            c: [0.0; 4],
+        };
+        let base = &block as *const Block as usize;
+        assert_eq!(a, &block.a as *const _ as usize - base);
+        assert_eq!(b, &block.b as *const _ as usize - base);
+        assert_eq!(c, &block.c as *const _ as usize - base);
+    });
+}
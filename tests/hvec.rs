@@ -0,0 +1,222 @@
+#[macro_use]
+mod support;
+
+mod conversion {
+    use glam::{DVec2, HVec2, Vec2};
+
+    fn to_f16(v: f32) -> u16 {
+        HVec2::new(v, 0.0).x
+    }
+
+    fn to_f16_from_f64(v: f64) -> u16 {
+        HVec2::from_dvec2(DVec2::new(v, 0.0)).x
+    }
+
+    fn to_f32(bits: u16) -> f32 {
+        HVec2::from_bits(bits, 0).as_vec2().x
+    }
+
+    fn to_f64(bits: u16) -> f64 {
+        HVec2::from_bits(bits, 0).as_dvec2().x
+    }
+
+    glam_test!(test_round_trip_all_bit_patterns, {
+        for bits in 0..=u16::MAX {
+            let f = to_f32(bits);
+            let d = to_f64(bits);
+            if f.is_nan() {
+                assert!(d.is_nan());
+                assert_eq!(bits & 0x8000, to_f16(f) & 0x8000);
+                assert!(to_f32(to_f16(f)).is_nan());
+                assert!(to_f32(to_f16_from_f64(d)).is_nan());
+            } else {
+                assert_eq!(f as f64, d);
+                assert_eq!(bits, to_f16(f));
+                assert_eq!(bits, to_f16_from_f64(d));
+            }
+        }
+    });
+
+    glam_test!(test_special_values, {
+        assert_eq!(0x0000, to_f16(0.0));
+        assert_eq!(0x8000, to_f16(-0.0));
+        assert_eq!(0x3c00, to_f16(1.0));
+        assert_eq!(0xc000, to_f16(-2.0));
+        assert_eq!(0x7c00, to_f16(f32::INFINITY));
+        assert_eq!(0xfc00, to_f16(f32::NEG_INFINITY));
+        assert!(to_f32(to_f16(f32::NAN)).is_nan());
+        assert!(to_f32(to_f16_from_f64(f64::NAN)).is_nan());
+        // a NaN with only low payload bits set must not become infinity
+        assert!(to_f32(to_f16(f32::from_bits(0x7f80_0001))).is_nan());
+        assert!(to_f32(to_f16_from_f64(f64::from_bits(0x7ff0_0000_0000_0001))).is_nan());
+        assert_eq!(f32::INFINITY, to_f32(0x7c00));
+        assert_eq!(f32::NEG_INFINITY, to_f32(0xfc00));
+        assert_eq!(65504.0, to_f32(0x7bff));
+        assert_eq!(2.0_f32.powi(-24), to_f32(0x0001));
+        assert_eq!(2.0_f32.powi(-14), to_f32(0x0400));
+    });
+
+    glam_test!(test_round_to_nearest_even, {
+        let ulp = 2.0_f32.powi(-10);
+        // ties round to even
+        assert_eq!(0x3c00, to_f16(1.0 + 0.5 * ulp));
+        assert_eq!(0x3c02, to_f16(1.0 + 1.5 * ulp));
+        // otherwise round to nearest
+        assert_eq!(0x3c01, to_f16(1.0 + 0.75 * ulp));
+        assert_eq!(0x3c00, to_f16(1.0 + 0.25 * ulp));
+        // overflow
+        assert_eq!(0x7bff, to_f16(65519.0));
+        assert_eq!(0x7c00, to_f16(65520.0));
+        assert_eq!(0xfc00, to_f16(-1.0e10));
+        assert_eq!(0x7c00, to_f16_from_f64(1.0e300));
+        // underflow to subnormals and zero
+        assert_eq!(0x0000, to_f16(2.0_f32.powi(-25)));
+        assert_eq!(0x0001, to_f16(1.5 * 2.0_f32.powi(-25)));
+        assert_eq!(0x0002, to_f16(3.0 * 2.0_f32.powi(-25)));
+        assert_eq!(0x8000, to_f16(-1.0e-10));
+        assert_eq!(0x0000, to_f16(f32::from_bits(1)));
+        assert_eq!(0x0000, to_f16_from_f64(1.0e-300));
+        // rounding from the largest subnormal up to the smallest normal
+        assert_eq!(0x0400, to_f16(2.0_f32.powi(-14) - 2.0_f32.powi(-26)));
+    });
+
+    glam_test!(test_f64_rounds_once, {
+        // Narrowing via `f32` would round twice and produce 1.0 here.
+        let v = 1.0 + 2.0_f64.powi(-11) + 2.0_f64.powi(-40);
+        assert_eq!(0x3c00, to_f16(v as f32));
+        assert_eq!(0x3c01, to_f16_from_f64(v));
+    });
+
+    glam_test!(test_vec_conversion, {
+        let v = Vec2::new(0.5, -3.25);
+        let h = HVec2::from_vec2(v);
+        assert_eq!(v, h.as_vec2());
+        assert_eq!(h, HVec2::from(v));
+        assert_eq!(v, Vec2::from(h));
+        assert_eq!(v.as_dvec2(), h.as_dvec2());
+        assert_eq!(h, HVec2::from(v.as_dvec2()));
+    });
+}
+
+mod hvec2 {
+    use glam::{HVec2, Vec2};
+
+    glam_test!(test_consts, {
+        assert_eq!(Vec2::ZERO, HVec2::ZERO.as_vec2());
+        assert_eq!(Vec2::ONE, HVec2::ONE.as_vec2());
+        assert_eq!(Vec2::NEG_ONE, HVec2::NEG_ONE.as_vec2());
+        assert_eq!(Vec2::X, HVec2::X.as_vec2());
+        assert_eq!(Vec2::NEG_Y, HVec2::NEG_Y.as_vec2());
+        assert_eq!(Vec2::splat(f32::INFINITY), HVec2::INFINITY.as_vec2());
+        assert!(HVec2::NAN.is_nan());
+        assert!(!HVec2::INFINITY.is_finite());
+        assert!(!HVec2::NEG_INFINITY.is_nan());
+        assert!(HVec2::ONE.is_finite());
+        assert_eq!(HVec2::ZERO, HVec2::default());
+    });
+
+    glam_test!(test_new, {
+        let v = HVec2::new(1.0, 2.0);
+        assert_eq!([1.0, 2.0], v.to_array());
+        assert_eq!(v, HVec2::from_array([1.0, 2.0]));
+        assert_eq!(v, HVec2::from([1.0, 2.0]));
+        assert_eq!([1.0, 2.0], <[f32; 2]>::from(v));
+        assert_eq!([0x3c00, 0x4000], v.to_bits_array());
+        assert_eq!(v, HVec2::from_bits_array([0x3c00, 0x4000]));
+        assert_eq!(v, HVec2::from_bits(0x3c00, 0x4000));
+        assert_eq!(HVec2::new(2.0, 2.0), HVec2::splat(2.0));
+        assert_eq!(HVec2::splat(2.0), HVec2::splat_bits(0x4000));
+    });
+
+    glam_test!(test_eq, {
+        // equality is bitwise
+        assert_ne!(HVec2::splat(0.0), HVec2::splat(-0.0));
+        assert_eq!(HVec2::NAN, HVec2::NAN);
+    });
+
+    glam_test!(test_fmt, {
+        let a = HVec2::new(1.0, 2.5);
+        assert_eq!(format!("{:?}", a), "HVec2(1.0, 2.5)");
+        assert_eq!(format!("{}", a), "[1, 2.5]");
+    });
+
+    glam_test!(test_slices, {
+        let src = [Vec2::new(1.0, 2.0), Vec2::new(0.1, -0.1)];
+        let mut halves = [HVec2::ZERO; 2];
+        HVec2::from_vec2_slice(&src, &mut halves);
+        assert_eq!(HVec2::from_vec2(src[0]), halves[0]);
+        assert_eq!(HVec2::from_vec2(src[1]), halves[1]);
+        let mut dst = [Vec2::ZERO; 2];
+        HVec2::as_vec2_slice(&halves, &mut dst);
+        assert_eq!(halves[0].as_vec2(), dst[0]);
+        assert_eq!(halves[1].as_vec2(), dst[1]);
+        should_panic!({ HVec2::as_vec2_slice(&halves, &mut [Vec2::ZERO; 3]) });
+    });
+}
+
+mod hvec3 {
+    use glam::{DVec3, HVec3, Vec3, Vec3A};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(6, mem::size_of::<HVec3>());
+        assert_eq!(2, mem::align_of::<HVec3>());
+    });
+
+    glam_test!(test_consts, {
+        assert_eq!(Vec3::ONE, HVec3::ONE.as_vec3());
+        assert_eq!(Vec3::Z, HVec3::Z.as_vec3());
+        assert_eq!(Vec3::NEG_X, HVec3::NEG_X.as_vec3());
+    });
+
+    glam_test!(test_conversion, {
+        let v = Vec3::new(1.0, -2.0, 0.25);
+        let h = HVec3::from_vec3(v);
+        assert_eq!(v, h.as_vec3());
+        assert_eq!(Vec3A::from(v), h.as_vec3a());
+        assert_eq!(h, HVec3::from_vec3a(v.into()));
+        assert_eq!(DVec3::new(1.0, -2.0, 0.25), h.as_dvec3());
+        assert_eq!(h, HVec3::from_dvec3(h.as_dvec3()));
+        assert_eq!(format!("{}", h), "[1, -2, 0.25]");
+    });
+
+    glam_test!(test_slices, {
+        let src = [Vec3A::new(1.0, 2.0, 3.0), Vec3A::new(-0.5, 0.1, 1.0e6)];
+        let mut halves = [HVec3::ZERO; 2];
+        HVec3::from_vec3a_slice(&src, &mut halves);
+        assert_eq!(HVec3::from_vec3a(src[1]), halves[1]);
+        assert!(!halves[1].is_finite());
+        let mut dst = [DVec3::ZERO; 2];
+        HVec3::as_dvec3_slice(&halves, &mut dst);
+        assert_eq!(halves[0].as_dvec3(), dst[0]);
+    });
+}
+
+mod hvec4 {
+    use glam::{HVec4, Vec4};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(8, mem::size_of::<HVec4>());
+        assert_eq!(2, mem::align_of::<HVec4>());
+    });
+
+    glam_test!(test_conversion, {
+        let v = Vec4::new(1.0, -2.0, 0.25, 8.0);
+        let h = HVec4::from_vec4(v);
+        assert_eq!(v, h.as_vec4());
+        assert_eq!(v.as_dvec4(), h.as_dvec4());
+        assert_eq!(HVec4::W, HVec4::from_vec4(Vec4::W));
+        assert_eq!(format!("{:?}", h), "HVec4(1.0, -2.0, 0.25, 8.0)");
+    });
+
+    glam_test!(test_slices, {
+        let src = [Vec4::ONE; 3];
+        let mut halves = [HVec4::ZERO; 3];
+        HVec4::from_vec4_slice(&src, &mut halves);
+        assert_eq!([HVec4::ONE; 3], halves);
+        let mut dst = [Vec4::ZERO; 3];
+        HVec4::as_vec4_slice(&halves, &mut dst);
+        assert_eq!(src, dst);
+    });
+}
@@ -0,0 +1,292 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_isometry2_tests {
+    ($t:ident, $isometry2:ident, $vec2:ident, $mat3:ident, $affine2:ident) => {
+        use core::$t::consts::FRAC_PI_2;
+        use core::$t::NAN;
+
+        glam_test!(test_identity, {
+            let iso = $isometry2::IDENTITY;
+            assert_eq!($isometry2::default(), iso);
+            assert_eq!(0.0, iso.angle);
+            assert_eq!($vec2::ZERO, iso.translation);
+            let p = $vec2::new(1.0, 2.0);
+            assert_eq!(p, iso.transform_point2(p));
+            assert_eq!(p, iso.transform_vector2(p));
+        });
+
+        glam_test!(test_nan, {
+            assert!($isometry2::NAN.is_nan());
+            assert!(!$isometry2::NAN.is_finite());
+            assert!($isometry2::from_angle(NAN).is_nan());
+            assert!($isometry2::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_from_angle_translation, {
+            let t = $vec2::new(1.0, 2.0);
+            let iso = $isometry2::from_angle_translation(FRAC_PI_2, t);
+            assert_eq!(FRAC_PI_2, iso.angle);
+            assert_eq!(t, iso.translation);
+
+            // rotation is applied before translation
+            assert_approx_eq!($vec2::new(1.0, 3.0), iso.transform_point2($vec2::X));
+            assert_approx_eq!($vec2::Y, iso.transform_vector2($vec2::X));
+
+            assert_eq!(
+                $isometry2::from_angle_translation(FRAC_PI_2, $vec2::ZERO),
+                $isometry2::from_angle(FRAC_PI_2)
+            );
+            assert_eq!(
+                $isometry2::from_angle_translation(0.0, t),
+                $isometry2::from_translation(t)
+            );
+        });
+
+        glam_test!(test_mul, {
+            let a = $isometry2::from_angle_translation(0.5, $vec2::new(1.0, -2.0));
+            let b = $isometry2::from_angle_translation(-1.25, $vec2::new(4.0, -3.0));
+            let p = $vec2::new(0.25, 3.0);
+            let ab = a * b;
+            assert_approx_eq!(
+                a.transform_point2(b.transform_point2(p)),
+                ab.transform_point2(p),
+                1e-5
+            );
+            assert_approx_eq!($mat3::from(a) * $mat3::from(b), $mat3::from(ab), 1e-5);
+            let mut c = a;
+            c *= b;
+            assert_eq!(ab, c);
+            assert_eq!(ab, a.mul_isometry(&b));
+        });
+
+        glam_test!(test_inverse, {
+            let iso = $isometry2::from_angle_translation(0.5, $vec2::new(1.0, -2.0));
+            let inv = iso.inverse();
+            assert_approx_eq!($isometry2::IDENTITY, iso * inv, 1e-6);
+            assert_approx_eq!($isometry2::IDENTITY, inv * iso, 1e-6);
+            assert_approx_eq!($mat3::from(iso).inverse(), $mat3::from(inv), 1e-6);
+        });
+
+        glam_test!(test_lerp, {
+            let a = $isometry2::from_angle_translation(0.0, $vec2::new(2.0, 0.0));
+            let b = $isometry2::from_angle_translation(FRAC_PI_2, $vec2::new(0.0, 2.0));
+            assert_eq!(a, a.lerp(&b, 0.0));
+            assert_eq!(b, a.lerp(&b, 1.0));
+            assert_approx_eq!(
+                $isometry2::from_angle_translation(FRAC_PI_2 * 0.5, $vec2::new(1.0, 1.0)),
+                a.lerp(&b, 0.5)
+            );
+        });
+
+        glam_test!(test_conversions, {
+            let iso = $isometry2::from_angle_translation(0.75, $vec2::new(-1.0, 2.0));
+            let m = $mat3::from_angle(0.75);
+            let m = $mat3::from_translation($vec2::new(-1.0, 2.0)) * m;
+            assert_approx_eq!(m, $mat3::from(iso));
+            assert_approx_eq!(m, $mat3::from($affine2::from(iso)));
+        });
+
+        glam_test!(test_fmt, {
+            let a = $isometry2::from_angle_translation(1.0, $vec2::new(2.0, 3.0));
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    "{} {{ angle: 1.0, translation: {:?} }}",
+                    stringify!($isometry2),
+                    a.translation
+                )
+            );
+            assert_eq!(format!("{}", a), "[1, [2, 3]]");
+        });
+    };
+}
+
+macro_rules! impl_isometry3_tests {
+    ($t:ident, $isometry3:ident, $quat:ident, $vec3:ident, $mat4:ident, $affine3:ident) => {
+        use core::$t::consts::FRAC_PI_2;
+        use core::$t::NAN;
+
+        glam_test!(test_identity, {
+            let iso = $isometry3::IDENTITY;
+            assert_eq!($isometry3::default(), iso);
+            assert_eq!($quat::IDENTITY, iso.rotation);
+            assert_eq!($vec3::ZERO, iso.translation);
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_eq!(p, iso.transform_point3(p));
+            assert_eq!(p, iso.transform_vector3(p));
+        });
+
+        glam_test!(test_nan, {
+            assert!($isometry3::NAN.is_nan());
+            assert!(!$isometry3::NAN.is_finite());
+            assert!($isometry3::from_translation($vec3::new(NAN, 0.0, 0.0)).is_nan());
+            assert!($isometry3::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_from_rotation_translation, {
+            let r = $quat::from_rotation_z(FRAC_PI_2);
+            let t = $vec3::new(1.0, 2.0, 3.0);
+            let iso = $isometry3::from_rotation_translation(r, t);
+            assert_eq!(r, iso.rotation);
+            assert_eq!(t, iso.translation);
+
+            // rotation is applied before translation
+            assert_approx_eq!(
+                $vec3::new(1.0, 3.0, 3.0),
+                iso.transform_point3($vec3::X),
+                1e-6
+            );
+            assert_approx_eq!($vec3::Y, iso.transform_vector3($vec3::X), 1e-6);
+
+            assert_eq!(
+                $isometry3::from_rotation_translation(r, $vec3::ZERO),
+                $isometry3::from_quat(r)
+            );
+            assert_eq!(
+                $isometry3::from_rotation_translation($quat::IDENTITY, t),
+                $isometry3::from_translation(t)
+            );
+            should_glam_assert!({ $isometry3::from_quat($quat::from_xyzw(0.0, 0.0, 0.0, 2.0)) });
+        });
+
+        glam_test!(test_mul, {
+            let a = $isometry3::from_rotation_translation(
+                $quat::from_rotation_x(0.5),
+                $vec3::new(1.0, -2.0, 0.5),
+            );
+            let b = $isometry3::from_rotation_translation(
+                $quat::from_rotation_y(-1.25),
+                $vec3::new(0.0, 4.0, -3.0),
+            );
+            let p = $vec3::new(0.25, 3.0, -2.0);
+            let ab = a * b;
+            assert_approx_eq!(
+                a.transform_point3(b.transform_point3(p)),
+                ab.transform_point3(p),
+                1e-5
+            );
+            assert_approx_eq!($mat4::from(a) * $mat4::from(b), $mat4::from(ab), 1e-5);
+            let mut c = a;
+            c *= b;
+            assert_eq!(ab, c);
+            assert_eq!(ab, a.mul_isometry(&b));
+        });
+
+        glam_test!(test_inverse, {
+            let iso = $isometry3::from_rotation_translation(
+                $quat::from_rotation_x(0.5),
+                $vec3::new(1.0, -2.0, 0.5),
+            );
+            let inv = iso.inverse();
+            assert_approx_eq!($isometry3::IDENTITY, iso * inv, 1e-6);
+            assert_approx_eq!($isometry3::IDENTITY, inv * iso, 1e-6);
+            assert_approx_eq!($mat4::from(iso).inverse(), $mat4::from(inv), 1e-5);
+        });
+
+        glam_test!(test_lerp, {
+            let a = $isometry3::from_translation($vec3::new(2.0, 0.0, 0.0));
+            let b = $isometry3::from_rotation_translation(
+                $quat::from_rotation_z(FRAC_PI_2),
+                $vec3::new(0.0, 2.0, 0.0),
+            );
+            assert_approx_eq!(a, a.lerp(&b, 0.0), 1e-6);
+            assert_approx_eq!(b, a.lerp(&b, 1.0), 1e-6);
+            assert_approx_eq!(
+                $isometry3::from_rotation_translation(
+                    $quat::from_rotation_z(FRAC_PI_2 * 0.5),
+                    $vec3::new(1.0, 1.0, 0.0)
+                ),
+                a.lerp(&b, 0.5),
+                1e-6
+            );
+        });
+
+        glam_test!(test_conversions, {
+            let r = $quat::from_rotation_y(0.75);
+            let t = $vec3::new(-1.0, 2.0, 5.0);
+            let iso = $isometry3::from_rotation_translation(r, t);
+            let m = $mat4::from_rotation_translation(r, t);
+            let a = $affine3::from_rotation_translation(r, t);
+            assert_approx_eq!(m, $mat4::from(iso), 1e-6);
+            assert_approx_eq!(m, $mat4::from($affine3::from(iso)), 1e-6);
+            assert_approx_eq!(iso, $isometry3::from_mat4(&m), 1e-6);
+            assert_approx_eq!(iso, $isometry3::from_affine3(&a), 1e-6);
+
+            // scale is discarded
+            let m = $mat4::from_scale_rotation_translation($vec3::splat(2.0), r, t);
+            assert_approx_eq!(iso, $isometry3::from_mat4(&m), 1e-6);
+        });
+
+        glam_test!(test_fmt, {
+            let a = $isometry3::from_translation($vec3::new(1.0, 2.0, 3.0));
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    "{} {{ rotation: {:?}, translation: {:?} }}",
+                    stringify!($isometry3),
+                    a.rotation,
+                    a.translation
+                )
+            );
+            assert_eq!(format!("{}", a), "[[0, 0, 0, 1], [1, 2, 3]]");
+        });
+    };
+}
+
+mod isometry2 {
+    use glam::{Affine2, Isometry2, Mat3, Vec2};
+
+    glam_test!(test_as, {
+        use glam::{DIsometry2, DVec2};
+        let iso = Isometry2::from_angle_translation(1.0, Vec2::new(2.0, 3.0));
+        assert_eq!(
+            DIsometry2::from_angle_translation(1.0, DVec2::new(2.0, 3.0)),
+            iso.as_f64()
+        );
+        assert_eq!(iso, iso.as_f64().as_f32());
+    });
+
+    impl_isometry2_tests!(f32, Isometry2, Vec2, Mat3, Affine2);
+}
+
+mod disometry2 {
+    use glam::{DAffine2, DIsometry2, DMat3, DVec2};
+
+    impl_isometry2_tests!(f64, DIsometry2, DVec2, DMat3, DAffine2);
+}
+
+mod isometry3 {
+    use glam::{Affine3A, Isometry3, Mat4, Quat, Vec3, Vec3A};
+
+    glam_test!(test_vec3a, {
+        let iso = Isometry3::from_rotation_translation(
+            Quat::from_rotation_z(core::f32::consts::FRAC_PI_2),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        assert_approx_eq!(
+            Vec3A::new(1.0, 3.0, 3.0),
+            iso.transform_point3a(Vec3A::X),
+            1e-6
+        );
+        assert_approx_eq!(Vec3A::Y, iso.transform_vector3a(Vec3A::X), 1e-6);
+    });
+
+    glam_test!(test_as, {
+        use glam::{DIsometry3, DVec3};
+        let iso = Isometry3::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            DIsometry3::from_translation(DVec3::new(1.0, 2.0, 3.0)),
+            iso.as_f64()
+        );
+        assert_eq!(iso, iso.as_f64().as_f32());
+    });
+
+    impl_isometry3_tests!(f32, Isometry3, Quat, Vec3, Mat4, Affine3A);
+}
+
+mod disometry3 {
+    use glam::{DAffine3, DIsometry3, DMat4, DQuat, DVec3};
+
+    impl_isometry3_tests!(f64, DIsometry3, DQuat, DVec3, DMat4, DAffine3);
+}
@@ -155,6 +155,66 @@ macro_rules! impl_mat2_tests {
             should_glam_assert!({ $mat2::ZERO.inverse() });
         });
 
+        glam_test!(test_mat2_symmetric_eigen, {
+            let check = |m: $mat2, expected: $vec2| {
+                let (values, vectors) = m.symmetric_eigen();
+                assert_approx_eq!(expected, values, 1e-5);
+                assert_approx_eq!($mat2::IDENTITY, vectors.transpose() * vectors, 1e-5);
+                assert_approx_eq!(1.0, vectors.determinant(), 1e-5);
+                assert_approx_eq!(m * vectors, vectors * $mat2::from_diagonal(values), 1e-5);
+            };
+
+            check($mat2::IDENTITY, $vec2::ONE);
+            check($mat2::ZERO, $vec2::ZERO);
+            check(
+                $mat2::from_diagonal($newvec2(-1.0, 3.0)),
+                $newvec2(3.0, -1.0),
+            );
+
+            let rot = $mat2::from_angle(deg(30.0));
+            let m = rot * $mat2::from_diagonal($newvec2(-2.0, 5.0)) * rot.transpose();
+            check(m, $newvec2(5.0, -2.0));
+
+            // only the symmetric part is used
+            let m = $mat2::from_cols_array(&[2.0, 1.0, 3.0, 2.0]);
+            check((m + m.transpose()) * 0.5, $newvec2(4.0, 0.0));
+            assert_eq!(
+                m.symmetric_eigen(),
+                ((m + m.transpose()) * 0.5).symmetric_eigen()
+            );
+        });
+
+        glam_test!(test_mat2_svd, {
+            let check = |m: $mat2| {
+                let (u, sigma, v) = m.svd();
+                assert!(sigma.x >= sigma.y && sigma.y >= 0.0);
+                assert_approx_eq!($mat2::IDENTITY, u.transpose() * u, 1e-5);
+                assert_approx_eq!($mat2::IDENTITY, v.transpose() * v, 1e-5);
+                assert_approx_eq!(1.0, v.determinant(), 1e-5);
+                assert_approx_eq!(m, u * $mat2::from_diagonal(sigma) * v.transpose(), 1e-5);
+                sigma
+            };
+
+            assert_eq!($vec2::ONE, check($mat2::IDENTITY));
+            assert_eq!($vec2::ZERO, check($mat2::ZERO));
+
+            let rot = $mat2::from_angle(deg(30.0));
+            let scale = $mat2::from_diagonal($newvec2(0.5, 3.0));
+            assert_approx_eq!($newvec2(3.0, 0.5), check(rot * scale), 1e-5);
+            assert_approx_eq!($newvec2(3.0, 0.5), check(scale * rot), 1e-5);
+
+            let m = rot * $mat2::from_diagonal($newvec2(0.5, -3.0));
+            assert_approx_eq!($newvec2(3.0, 0.5), check(m), 1e-5);
+            assert_approx_eq!(-1.0, m.svd().0.determinant(), 1e-5);
+
+            let m = $mat2::from_cols($vec2::ONE, $vec2::ONE);
+            assert_approx_eq!($newvec2(2.0, 0.0), check(m), 1e-5);
+
+            let m = $mat2::from_cols_array(&[1.0, -2.0, 4.0, 3.0]);
+            let sigma = check(m);
+            assert_approx_eq!(m.determinant().abs(), sigma.x * sigma.y, 1e-4);
+        });
+
         glam_test!(test_mat2_ops, {
             let m0 = $mat2::from_cols_array_2d(&MATRIX);
             let m0x2 = $mat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]);
@@ -200,6 +200,35 @@ macro_rules! impl_mat3_tests {
             assert_approx_eq!(yxz0, yxz1, 1e-6);
         });
 
+        glam_test!(test_mat3_to_euler, {
+            use glam::EulerRot;
+            let (a, b, c) = (deg(30.0), deg(60.0), deg(90.0));
+            for order in [
+                EulerRot::YXZ,
+                EulerRot::ZXZ,
+                EulerRot::XYZEx,
+                EulerRot::YZYEx,
+            ] {
+                let m = $mat3::from_euler(order, a, b, c);
+                assert_approx_eq!(m, $mat3::from_quat($quat::from_euler(order, a, b, c)), 1e-6);
+                let (a1, b1, c1) = m.to_euler(order);
+                assert_approx_eq!(a, a1, 1e-5);
+                assert_approx_eq!(b, b1, 1e-5);
+                assert_approx_eq!(c, c1, 1e-5);
+            }
+
+            // gimbal lock returns a third angle of zero
+            let m = $mat3::from_euler(EulerRot::YXZ, a, deg(90.0), c);
+            let (a1, b1, c1) = m.to_euler(EulerRot::YXZ);
+            assert_approx_eq!(deg(90.0), b1, 1e-3);
+            assert_eq!(0.0, c1);
+            assert_approx_eq!(m, $mat3::from_euler(EulerRot::YXZ, a1, b1, c1), 1e-5);
+
+            should_glam_assert!({
+                $mat3::from_diagonal($vec3::splat(2.0)).to_euler(EulerRot::XYZ)
+            });
+        });
+
         glam_test!(test_from_diagonal, {
             let m = $mat3::from_diagonal($vec3::new(2.0, 4.0, 8.0));
             assert_approx_eq!(m * $vec3::new(1.0, 1.0, 1.0), $vec3::new(2.0, 4.0, 8.0));
@@ -286,6 +315,161 @@ macro_rules! impl_mat3_tests {
             should_glam_assert!({ $mat3::ZERO.inverse() });
         });
 
+        glam_test!(test_mat3_symmetric_eigen, {
+            let check = |m: $mat3, expected: $vec3| {
+                let (values, vectors) = m.symmetric_eigen();
+                assert_approx_eq!(expected, values, 1e-5);
+                assert_approx_eq!($mat3::IDENTITY, vectors.transpose() * vectors, 1e-5);
+                assert_approx_eq!(1.0, vectors.determinant(), 1e-5);
+                assert_approx_eq!(m * vectors, vectors * $mat3::from_diagonal(values), 1e-5);
+            };
+
+            check($mat3::IDENTITY, $vec3::ONE);
+            check($mat3::ZERO, $vec3::ZERO);
+            check(
+                $mat3::from_diagonal($vec3::new(1.0, 3.0, -2.0)),
+                $vec3::new(3.0, 1.0, -2.0),
+            );
+
+            // repeated eigenvalues in a rotated basis
+            let rot = $mat3::from_quat($quat::from_euler(
+                glam::EulerRot::YXZ,
+                deg(30.0),
+                deg(-60.0),
+                deg(45.0),
+            ));
+            let m = rot * $mat3::from_diagonal($vec3::new(2.0, 5.0, 2.0)) * rot.transpose();
+            check(m, $vec3::new(5.0, 2.0, 2.0));
+            let m = rot * $mat3::from_diagonal($vec3::new(-1.0, 4.0, 0.5)) * rot.transpose();
+            check(m, $vec3::new(4.0, 0.5, -1.0));
+
+            // only the symmetric part is used
+            let m = $mat3::from_cols_array(&[2.0, 1.0, 0.0, 3.0, 2.0, 0.0, 0.0, 0.0, 1.0]);
+            assert_eq!(
+                m.symmetric_eigen(),
+                ((m + m.transpose()) * 0.5).symmetric_eigen()
+            );
+            check((m + m.transpose()) * 0.5, $vec3::new(4.0, 1.0, 0.0));
+        });
+
+        glam_test!(test_mat3_svd, {
+            let check = |m: $mat3| {
+                let (u, sigma, v) = m.svd();
+                assert!(sigma.x >= sigma.y && sigma.y >= sigma.z && sigma.z >= 0.0);
+                assert_approx_eq!($mat3::IDENTITY, u.transpose() * u, 1e-5);
+                assert_approx_eq!($mat3::IDENTITY, v.transpose() * v, 1e-5);
+                assert_approx_eq!(1.0, v.determinant(), 1e-5);
+                assert_approx_eq!(m, u * $mat3::from_diagonal(sigma) * v.transpose(), 1e-5);
+                sigma
+            };
+
+            assert_eq!($vec3::ONE, check($mat3::IDENTITY));
+            assert_eq!($vec3::ZERO, check($mat3::ZERO));
+            assert_eq!($mat3::IDENTITY, $mat3::ZERO.svd().0);
+
+            let rot = $mat3::from_quat($quat::from_euler(
+                glam::EulerRot::YXZ,
+                deg(30.0),
+                deg(-60.0),
+                deg(45.0),
+            ));
+            let scale = $mat3::from_diagonal($vec3::new(2.0, 0.5, 3.0));
+            assert_approx_eq!($vec3::new(3.0, 2.0, 0.5), check(rot * scale), 1e-5);
+            assert_approx_eq!($vec3::new(3.0, 2.0, 0.5), check(scale * rot), 1e-5);
+
+            // a reflection puts the negative determinant in `u`
+            let m = rot * $mat3::from_diagonal($vec3::new(2.0, -0.5, 3.0));
+            assert_approx_eq!($vec3::new(3.0, 2.0, 0.5), check(m), 1e-5);
+            assert_approx_eq!(-1.0, m.svd().0.determinant(), 1e-5);
+
+            // rank deficient matrices
+            let m = rot * $mat3::from_diagonal($vec3::new(2.0, 0.0, 3.0));
+            assert_approx_eq!($vec3::new(3.0, 2.0, 0.0), check(m), 1e-5);
+            let m = $mat3::from_cols($vec3::ONE.into(), $vec3::ONE.into(), $vec3::ONE.into());
+            assert_approx_eq!($vec3::new(3.0, 0.0, 0.0), check(m), 1e-5);
+
+            let m = $mat3::from_cols_array(&[1.0, -2.0, 4.0, 3.0, 0.5, -1.0, 2.0, 2.0, 7.0]);
+            let sigma = check(m);
+            assert_approx_eq!(m.determinant().abs(), sigma.x * sigma.y * sigma.z, 1e-4);
+        });
+
+        glam_test!(test_mat3_orthonormalize, {
+            let rot = $mat3::from_quat($quat::from_euler(
+                glam::EulerRot::YXZ,
+                deg(30.0),
+                deg(-60.0),
+                deg(45.0),
+            ));
+            for primary in 0..3 {
+                assert_approx_eq!(rot, rot.orthonormalize(primary), 1e-6);
+                assert_approx_eq!(rot, (rot * 2.0).orthonormalize(primary), 1e-6);
+            }
+
+            // drifted and scaled axes
+            let m = $mat3::from_cols(
+                rot.x_axis * 2.0,
+                rot.y_axis + rot.x_axis * 0.1,
+                rot.z_axis * 0.5 - rot.y_axis * 0.2,
+            );
+            for primary in 0..3 {
+                let r = m.orthonormalize(primary);
+                assert_approx_eq!($mat3::IDENTITY, r.transpose() * r, 1e-6);
+                assert_approx_eq!(1.0, r.determinant(), 1e-6);
+                assert_approx_eq!(m.col(primary).normalize(), r.col(primary), 1e-6);
+            }
+            assert_approx_eq!(rot.x_axis, m.orthonormalize(0).x_axis, 1e-6);
+            assert_approx_eq!(rot.y_axis, m.orthonormalize(0).y_axis, 1e-6);
+
+            // a reflection becomes a rotation
+            let m = $mat3::from_cols(rot.x_axis, rot.y_axis, -rot.z_axis);
+            assert_approx_eq!(rot, m.orthonormalize(0), 1e-6);
+
+            // parallel axes
+            let r = $mat3::from_cols(rot.x_axis, rot.x_axis, rot.x_axis).orthonormalize(0);
+            assert_approx_eq!($mat3::IDENTITY, r.transpose() * r, 1e-6);
+            assert_approx_eq!(rot.x_axis, r.x_axis, 1e-6);
+
+            should_panic!({ $mat3::IDENTITY.orthonormalize(3) });
+            should_glam_assert!({ $mat3::ZERO.orthonormalize(0) });
+        });
+
+        glam_test!(test_mat3_polar_decomposition, {
+            let rot = $mat3::from_quat($quat::from_euler(
+                glam::EulerRot::YXZ,
+                deg(30.0),
+                deg(-60.0),
+                deg(45.0),
+            ));
+            let check = |m: $mat3| {
+                let (r, s) = m.polar_decomposition();
+                assert_approx_eq!($mat3::IDENTITY, r.transpose() * r, 1e-5);
+                assert_approx_eq!(1.0, r.determinant(), 1e-5);
+                assert_approx_eq!(s, s.transpose(), 1e-5);
+                assert_approx_eq!(m, r * s, 1e-5);
+                (r, s)
+            };
+
+            let (r, s) = check(rot);
+            assert_approx_eq!(rot, r, 1e-5);
+            assert_approx_eq!($mat3::IDENTITY, s, 1e-5);
+
+            let stretch = $mat3::from_cols_array(&[2.0, 0.5, 0.0, 0.5, 1.0, 0.25, 0.0, 0.25, 3.0]);
+            let (r, s) = check(rot * stretch);
+            assert_approx_eq!(rot, r, 1e-5);
+            assert_approx_eq!(stretch, s, 1e-5);
+
+            let (r, s) = check(rot * -$mat3::IDENTITY);
+            assert_approx_eq!(-1.0, s.determinant(), 1e-5);
+            let (values, _) = s.symmetric_eigen();
+            assert_approx_eq!($vec3::new(1.0, 1.0, -1.0), values, 1e-5);
+            assert_approx_eq!(rot * -$mat3::IDENTITY, r * s, 1e-5);
+
+            let (r, s) = check($mat3::ZERO);
+            assert_eq!($mat3::IDENTITY, r);
+            assert_eq!($mat3::ZERO, s);
+            check(rot * $mat3::from_diagonal($vec3::new(0.0, 2.0, 1.0)));
+        });
+
         glam_test!(test_mat3_ops, {
             let m0 = $mat3::from_cols_array_2d(&MATRIX);
             let m0x2 = $mat3::from_cols_array_2d(&[
@@ -249,6 +249,36 @@ macro_rules! impl_mat4_tests {
             assert_approx_eq!(yxz0, yxz1, 1e-6);
         });
 
+        glam_test!(test_mat4_to_euler, {
+            use glam::EulerRot;
+            let (a, b, c) = (deg(30.0), deg(60.0), deg(90.0));
+            for order in [
+                EulerRot::YXZ,
+                EulerRot::ZXZ,
+                EulerRot::XYZEx,
+                EulerRot::YZYEx,
+            ] {
+                let m = $mat4::from_euler(order, a, b, c);
+                assert_approx_eq!(m, $mat4::from_quat($quat::from_euler(order, a, b, c)), 1e-6);
+                let (a1, b1, c1) = m.to_euler(order);
+                assert_approx_eq!(a, a1, 1e-5);
+                assert_approx_eq!(b, b1, 1e-5);
+                assert_approx_eq!(c, c1, 1e-5);
+            }
+
+            // the translation is ignored
+            let m = $mat4::from_rotation_translation(
+                $quat::from_euler(EulerRot::ZYX, a, b, c),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let (a1, b1, c1) = m.to_euler(EulerRot::ZYX);
+            assert_approx_eq!(a, a1, 1e-5);
+            assert_approx_eq!(b, b1, 1e-5);
+            assert_approx_eq!(c, c1, 1e-5);
+
+            should_glam_assert!({ $mat4::from_scale($vec3::splat(2.0)).to_euler(EulerRot::XYZ) });
+        });
+
         glam_test!(test_from_scale, {
             let m = $mat4::from_scale($vec3::new(2.0, 4.0, 8.0));
             assert_approx_eq!($vec4::X * 2.0, m.x_axis);
@@ -663,6 +693,47 @@ macro_rules! impl_mat4_tests {
             assert!(!($mat4::IDENTITY * NEG_INFINITY).is_finite());
             assert!(!($mat4::IDENTITY * NAN).is_finite());
         });
+
+        glam_test!(test_mat4_transform_slice, {
+            let m = $mat4::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, 2.0),
+                $quat::from_rotation_x(deg(30.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let p = $mat4::perspective_rh(deg(60.0), 1.5, 0.1, 100.0);
+            for len in 0..=11 {
+                let src: Vec<$vec3> = (0..len)
+                    .map(|i| {
+                        let i = i as $t;
+                        $vec3::new(i - 5.0, 2.0 - i * 0.5, i * 0.25 - 10.0)
+                    })
+                    .collect();
+
+                let mut points = src.clone();
+                m.transform_points3_slice(&mut points);
+                let mut vectors = src.clone();
+                m.transform_vectors3_slice(&mut vectors);
+                let mut projected = src.clone();
+                p.project_points3_slice(&mut projected);
+                for i in 0..len {
+                    assert_approx_eq!(m.transform_point3(src[i]), points[i], 1e-5);
+                    assert_approx_eq!(m.transform_vector3(src[i]), vectors[i], 1e-5);
+                    assert_approx_eq!(p.project_point3(src[i]), projected[i], 1e-5);
+                }
+
+                let mut dst = vec![$vec3::ZERO; len];
+                m.transform_points3_slice_to(&src, &mut dst);
+                assert_eq!(points, dst);
+                m.transform_vectors3_slice_to(&src, &mut dst);
+                assert_eq!(vectors, dst);
+                p.project_points3_slice_to(&src, &mut dst);
+                assert_eq!(projected, dst);
+            }
+
+            should_panic!({
+                m.transform_points3_slice_to(&[$vec3::ZERO; 2], &mut [$vec3::ZERO; 3])
+            });
+        });
     };
 }
 
@@ -0,0 +1,370 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_mat_cxr_tests {
+    ($t:ident, $mat:ident, $transpose:ident, $matc:ident, $matr:ident, $vecc:ident, $vecr:ident, $cols:literal, $rows:literal, $matrix:expr, $display:literal) => {
+        use core::$t::NAN;
+
+        const MATRIX: [[$t; $rows]; $cols] = $matrix;
+
+        fn matrix1d() -> [$t; $cols * $rows] {
+            let mut out = [0.0; $cols * $rows];
+            for (i, v) in out.iter_mut().enumerate() {
+                *v = (i + 1) as $t;
+            }
+            out
+        }
+
+        glam_test!(test_const, {
+            const M: $mat = $mat::from_cols_array_2d(&MATRIX);
+            assert_eq!(matrix1d(), M.to_cols_array());
+            assert_eq!(MATRIX, M.to_cols_array_2d());
+            assert_eq!(M, $mat::from_cols_array(&matrix1d()));
+            assert_eq!(M, $mat::from_cols_slice(&matrix1d()));
+        });
+
+        glam_test!(test_identity, {
+            let identity = $mat::IDENTITY;
+            assert_eq!(identity, $mat::default());
+            for i in 0..$cols {
+                for j in 0..$rows {
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    assert_eq!(expected, identity.col(i)[j]);
+                }
+            }
+            assert_eq!(identity, identity * $matc::IDENTITY);
+            assert_eq!(identity.transpose(), $transpose::IDENTITY);
+        });
+
+        glam_test!(test_zero_nan, {
+            assert_eq!([0.0; $cols * $rows], $mat::ZERO.to_cols_array());
+            assert!($mat::NAN.is_nan());
+            assert!(!$mat::NAN.is_finite());
+            assert!($mat::IDENTITY.is_finite());
+            let mut m = $mat::IDENTITY;
+            m.col_mut(1)[0] = NAN;
+            assert!(m.is_nan());
+        });
+
+        glam_test!(test_accessors, {
+            let m = $mat::from_cols_array_2d(&MATRIX);
+            for i in 0..$cols {
+                for j in 0..$rows {
+                    assert_eq!(MATRIX[i][j], m.col(i)[j]);
+                    assert_eq!(MATRIX[i][j], m.row(j)[i]);
+                }
+            }
+            let mut n = $mat::ZERO;
+            for i in 0..$cols {
+                *n.col_mut(i) = m.col(i);
+            }
+            assert_eq!(m, n);
+            assert_eq!(&matrix1d(), m.as_ref());
+            n.as_mut()[0] = 0.0;
+            assert_eq!(0.0, n.col(0)[0]);
+        });
+
+        glam_test!(test_transpose, {
+            let m = $mat::from_cols_array_2d(&MATRIX);
+            let t = m.transpose();
+            for i in 0..$rows {
+                assert_eq!(m.row(i), t.col(i));
+            }
+            assert_eq!(m, t.transpose());
+        });
+
+        glam_test!(test_mul_vec, {
+            let m = $mat::from_cols_array_2d(&MATRIX);
+            let v = $vecc::from_slice(&[2.0, -1.0, 0.5, 3.0][..$cols]);
+            let r: $vecr = m * v;
+            for i in 0..$rows {
+                assert_eq!(m.row(i).dot(v), r[i]);
+            }
+        });
+
+        glam_test!(test_mul_mat, {
+            let m = $mat::from_cols_array_2d(&MATRIX);
+            let s = $matc::from_diagonal($vecc::from_slice(&[2.0, -1.0, 0.5, 3.0][..$cols]));
+            let ms = m * s;
+            for i in 0..$cols {
+                assert_eq!(m * s.col(i), ms.col(i));
+            }
+            let mut n = m;
+            n *= s;
+            assert_eq!(ms, n);
+
+            let p: $matr = m * m.transpose();
+            for i in 0..$rows {
+                for j in 0..$rows {
+                    assert_eq!(m.row(i).dot(m.row(j)), p.col(j)[i]);
+                }
+            }
+
+            let q = $matr::from_diagonal($vecr::splat(2.0)) * m;
+            assert_eq!(m * 2.0, q);
+        });
+
+        glam_test!(test_ops, {
+            let m = $mat::from_cols_array_2d(&MATRIX);
+            assert_eq!(m * 2.0, m + m);
+            assert_eq!(m * 2.0, 2.0 * m);
+            assert_eq!(m * -1.0, -m);
+            assert_eq!($mat::ZERO, m - m);
+            assert_eq!(m * 2.0, [m, m].iter().sum());
+            assert_eq!(m * 3.0, [m, m, m].into_iter().sum());
+            let mut n = m;
+            n += m;
+            assert_eq!(m * 2.0, n);
+            n -= m;
+            assert_eq!(m, n);
+            n *= 3.0;
+            assert_eq!(m * 3.0, n);
+            assert!(n.abs_diff_eq(m * 3.0 + $mat::IDENTITY * 0.001, 0.01));
+            assert!(!n.abs_diff_eq(m * 3.0 + $mat::IDENTITY * 0.1, 0.01));
+        });
+
+        glam_test!(test_to_from_slice, {
+            let m = $mat::from_cols_slice(&matrix1d());
+            let mut out = [0.0; $cols * $rows];
+            m.write_cols_to_slice(&mut out);
+            assert_eq!(matrix1d(), out);
+        });
+
+        glam_test!(test_fmt, {
+            let m = $mat::from_cols_array_2d(&MATRIX);
+            assert_eq!(format!("{}", m), $display);
+            assert!(format!("{:?}", m).starts_with(stringify!($mat)));
+        });
+
+        #[cfg(feature = "std")]
+        glam_test!(test_should_panic_col, {
+            should_panic!({ $mat::ZERO.col($cols) });
+            should_panic!({ $mat::ZERO.row($rows) });
+        });
+    };
+}
+
+macro_rules! impl_mat4x3_affine_tests {
+    ($t:ident, $mat4x3:ident, $affine3:ident, $mat4:ident, $quat:ident, $vec3:ident) => {
+        glam_test!(test_from_affine3, {
+            let a = $affine3::from_scale_rotation_translation(
+                $vec3::new(2.0, 0.5, 3.0).into(),
+                $quat::from_rotation_y(0.5),
+                $vec3::new(1.0, -2.0, 3.0).into(),
+            );
+            let m = $mat4x3::from(a);
+            assert_eq!(a, $affine3::from(m));
+            let p = $vec3::new(0.5, 1.5, -2.0);
+            #[allow(clippy::useless_conversion)]
+            let expected: $vec3 = a.transform_point3(p.into()).into();
+            assert_approx_eq!(expected, m.transform_point3(p));
+            assert_eq!(m.transform_point3(p), m * p.extend(1.0));
+            #[allow(clippy::useless_conversion)]
+            let expected: $vec3 = a.transform_vector3(p.into()).into();
+            assert_approx_eq!(expected, m.transform_vector3(p));
+
+            let m4 = $mat4::from(a);
+            let t = m.transpose();
+            for i in 0..3 {
+                assert_eq!(m4.row(i), t.col(i));
+            }
+            assert_eq!($mat4x3::IDENTITY, $mat4x3::from($affine3::IDENTITY));
+            assert_eq!(m, m * $mat4::IDENTITY);
+        });
+    };
+}
+
+macro_rules! impl_mat3x2_affine_tests {
+    ($t:ident, $mat3x2:ident, $affine2:ident, $mat3:ident, $vec2:ident) => {
+        glam_test!(test_from_affine2, {
+            let a = $affine2::from_scale_angle_translation(
+                $vec2::new(2.0, 0.5),
+                0.5,
+                $vec2::new(1.0, -2.0),
+            );
+            let m = $mat3x2::from(a);
+            assert_eq!(a, $affine2::from(m));
+            let p = $vec2::new(0.5, 1.5);
+            assert_approx_eq!(a.transform_point2(p), m.transform_point2(p));
+            assert_eq!(m.transform_point2(p), m * p.extend(1.0));
+            assert_approx_eq!(a.transform_vector2(p), m.transform_vector2(p));
+
+            let m3 = $mat3::from(a);
+            let t = m.transpose();
+            for i in 0..2 {
+                assert_eq!(m3.row(i), t.col(i));
+            }
+            assert_eq!($mat3x2::IDENTITY, $mat3x2::from($affine2::IDENTITY));
+        });
+    };
+}
+
+mod mat2x3 {
+    use glam::{Mat2, Mat2x3, Mat3, Mat3x2, Vec2, Vec3};
+
+    impl_mat_cxr_tests!(
+        f32,
+        Mat2x3,
+        Mat3x2,
+        Mat2,
+        Mat3,
+        Vec2,
+        Vec3,
+        2,
+        3,
+        [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+        "[[1, 2, 3], [4, 5, 6]]"
+    );
+}
+
+mod mat3x2 {
+    use glam::{Affine2, Mat2, Mat2x3, Mat3, Mat3x2, Vec2, Vec3};
+
+    impl_mat_cxr_tests!(
+        f32,
+        Mat3x2,
+        Mat2x3,
+        Mat3,
+        Mat2,
+        Vec3,
+        Vec2,
+        3,
+        2,
+        [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+        "[[1, 2], [3, 4], [5, 6]]"
+    );
+
+    impl_mat3x2_affine_tests!(f32, Mat3x2, Affine2, Mat3, Vec2);
+}
+
+mod mat3x4 {
+    use glam::{Mat3, Mat3x4, Mat4, Mat4x3, Vec3, Vec4};
+
+    impl_mat_cxr_tests!(
+        f32,
+        Mat3x4,
+        Mat4x3,
+        Mat3,
+        Mat4,
+        Vec3,
+        Vec4,
+        3,
+        4,
+        [
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0]
+        ],
+        "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]"
+    );
+}
+
+mod mat4x3 {
+    use glam::{Affine3A, Mat3, Mat3x4, Mat4, Mat4x3, Quat, Vec3, Vec4};
+
+    impl_mat_cxr_tests!(
+        f32,
+        Mat4x3,
+        Mat3x4,
+        Mat4,
+        Mat3,
+        Vec4,
+        Vec3,
+        4,
+        3,
+        [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+            [10.0, 11.0, 12.0]
+        ],
+        "[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]"
+    );
+
+    impl_mat4x3_affine_tests!(f32, Mat4x3, Affine3A, Mat4, Quat, Vec3);
+}
+
+mod dmat2x3 {
+    use glam::{DMat2, DMat2x3, DMat3, DMat3x2, DVec2, DVec3};
+
+    impl_mat_cxr_tests!(
+        f64,
+        DMat2x3,
+        DMat3x2,
+        DMat2,
+        DMat3,
+        DVec2,
+        DVec3,
+        2,
+        3,
+        [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+        "[[1, 2, 3], [4, 5, 6]]"
+    );
+}
+
+mod dmat3x2 {
+    use glam::{DAffine2, DMat2, DMat2x3, DMat3, DMat3x2, DVec2, DVec3};
+
+    impl_mat_cxr_tests!(
+        f64,
+        DMat3x2,
+        DMat2x3,
+        DMat3,
+        DMat2,
+        DVec3,
+        DVec2,
+        3,
+        2,
+        [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+        "[[1, 2], [3, 4], [5, 6]]"
+    );
+
+    impl_mat3x2_affine_tests!(f64, DMat3x2, DAffine2, DMat3, DVec2);
+}
+
+mod dmat3x4 {
+    use glam::{DMat3, DMat3x4, DMat4, DMat4x3, DVec3, DVec4};
+
+    impl_mat_cxr_tests!(
+        f64,
+        DMat3x4,
+        DMat4x3,
+        DMat3,
+        DMat4,
+        DVec3,
+        DVec4,
+        3,
+        4,
+        [
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0]
+        ],
+        "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]"
+    );
+}
+
+mod dmat4x3 {
+    use glam::{DAffine3, DMat3, DMat3x4, DMat4, DMat4x3, DQuat, DVec3, DVec4};
+
+    impl_mat_cxr_tests!(
+        f64,
+        DMat4x3,
+        DMat3x4,
+        DMat4,
+        DMat3,
+        DVec4,
+        DVec3,
+        4,
+        3,
+        [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+            [10.0, 11.0, 12.0]
+        ],
+        "[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]"
+    );
+
+    impl_mat4x3_affine_tests!(f64, DMat4x3, DAffine3, DMat4, DQuat, DVec3);
+}
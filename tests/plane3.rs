@@ -0,0 +1,115 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_plane3_tests {
+    ($t:ident, $plane3:ident, $vec3:ident, $vec4:ident, $affine3:ident, $mat4:ident, $quat:ident) => {
+        glam_test!(test_new, {
+            let p = $plane3::new($vec3::Y, -2.0);
+            assert_eq!($vec3::Y, p.normal);
+            assert_eq!(-2.0, p.d);
+            assert_eq!(
+                p,
+                $plane3::from_normal_point($vec3::Y, $vec3::new(5.0, 2.0, -3.0))
+            );
+            assert_eq!($vec4::new(0.0, 1.0, 0.0, -2.0), p.to_vec4());
+            assert_eq!(p, $plane3::from_vec4(p.to_vec4()));
+            assert!(p.is_finite());
+            assert!(!$plane3::new($vec3::Y, $t::NAN).is_finite());
+        });
+
+        glam_test!(test_from_points, {
+            let p = $plane3::from_points(
+                $vec3::new(0.0, 0.0, 1.0),
+                $vec3::new(1.0, 0.0, 1.0),
+                $vec3::new(0.0, 1.0, 1.0),
+            );
+            assert!(p.is_normalized());
+            assert_approx_eq!($plane3::new($vec3::Z, -1.0), p);
+            // clockwise points give the opposite normal
+            let p = $plane3::from_points(
+                $vec3::new(0.0, 0.0, 1.0),
+                $vec3::new(0.0, 1.0, 1.0),
+                $vec3::new(1.0, 0.0, 1.0),
+            );
+            assert_approx_eq!($plane3::new($vec3::NEG_Z, 1.0), p);
+            should_glam_assert!({ $plane3::from_points($vec3::ZERO, $vec3::X, $vec3::X * 2.0) });
+        });
+
+        glam_test!(test_normalize, {
+            let p = $plane3::new($vec3::new(0.0, 3.0, 4.0), 10.0);
+            assert!(!p.is_normalized());
+            let n = p.normalize();
+            assert!(n.is_normalized());
+            assert_approx_eq!($plane3::new($vec3::new(0.0, 0.6, 0.8), 2.0), n);
+            should_glam_assert!({ $plane3::new($vec3::ZERO, 1.0).normalize() });
+        });
+
+        glam_test!(test_signed_distance, {
+            let p = $plane3::from_normal_point($vec3::Y, $vec3::new(0.0, 2.0, 0.0));
+            assert_eq!(3.0, p.signed_distance($vec3::new(1.0, 5.0, -1.0)));
+            assert_eq!(-2.0, p.signed_distance($vec3::ZERO));
+            assert_eq!(0.0, p.signed_distance($vec3::new(7.0, 2.0, 3.0)));
+            assert_eq!(2.0, p.flip().signed_distance($vec3::ZERO));
+        });
+
+        glam_test!(test_project, {
+            let p = $plane3::from_normal_point($vec3::Y, $vec3::new(0.0, 2.0, 0.0));
+            assert_eq!(
+                $vec3::new(1.0, 2.0, -1.0),
+                p.project_point($vec3::new(1.0, 5.0, -1.0))
+            );
+            assert_eq!(
+                $vec3::new(1.0, 0.0, -1.0),
+                p.project_vector($vec3::new(1.0, 5.0, -1.0))
+            );
+            should_glam_assert!({ $plane3::new($vec3::ONE, 0.0).project_point($vec3::ONE) });
+        });
+
+        glam_test!(test_transformed_by, {
+            let p = $plane3::from_points(
+                $vec3::new(1.0, 0.0, 0.5),
+                $vec3::new(0.0, 2.0, 1.0),
+                $vec3::new(-1.0, 0.5, 3.0),
+            );
+            let t = $affine3::from_scale_rotation_translation(
+                $vec3::new(2.0, 0.5, 1.5).into(),
+                $quat::from_rotation_x(0.7),
+                $vec3::new(-1.0, 0.5, 2.0).into(),
+            );
+            #[allow(clippy::useless_conversion)]
+            let transform = |v: $vec3| -> $vec3 { t.transform_point3(v.into()).into() };
+            let expected = $plane3::from_points(
+                transform($vec3::new(1.0, 0.0, 0.5)),
+                transform($vec3::new(0.0, 2.0, 1.0)),
+                transform($vec3::new(-1.0, 0.5, 3.0)),
+            );
+            assert_approx_eq!(expected, p.transformed_by(&t), 1e-5);
+            assert_approx_eq!(expected, p.transformed_by_mat4(&$mat4::from(t)), 1e-5);
+        });
+
+        glam_test!(test_fmt, {
+            let p = $plane3::new($vec3::Y, -2.0);
+            assert_eq!(
+                format!("{:?}", p),
+                format!(
+                    "{} {{ normal: {:?}, d: -2.0 }}",
+                    stringify!($plane3),
+                    p.normal
+                )
+            );
+            assert_eq!(format!("{}", p), "[[0, 1, 0], -2]");
+        });
+    };
+}
+
+mod plane3 {
+    use glam::{Affine3A, Mat4, Plane3, Quat, Vec3, Vec4};
+
+    impl_plane3_tests!(f32, Plane3, Vec3, Vec4, Affine3A, Mat4, Quat);
+}
+
+mod dplane3 {
+    use glam::{DAffine3, DMat4, DPlane3, DQuat, DVec3, DVec4};
+
+    impl_plane3_tests!(f64, DPlane3, DVec3, DVec4, DAffine3, DMat4, DQuat);
+}
@@ -142,6 +142,25 @@ macro_rules! impl_quat_tests {
             should_glam_assert!({ $quat::from_axis_angle($vec3::ZERO, 0.0) });
         });
 
+        glam_test!(test_from_mat3_nearest, {
+            let q = $quat::from_euler(glam::EulerRot::YXZ, deg(30.0), deg(-60.0), deg(45.0));
+            let rot = $mat3::from_quat(q);
+            assert_approx_eq!(rot, $mat3::from_quat($quat::from_mat3_nearest(&rot)), 1e-5);
+
+            let scaled = rot * $mat3::from_diagonal($vec3::new(2.0, 0.5, 3.0));
+            let nearest = $quat::from_mat3_nearest(&scaled);
+            assert!(nearest.is_normalized());
+            assert_approx_eq!(rot, $mat3::from_quat(nearest), 1e-5);
+
+            let drifted =
+                rot + $mat3::from_cols_array(&[0.01, -0.02, 0.0, 0.0, 0.01, 0.02, -0.01, 0.0, 0.0]);
+            let nearest = $quat::from_mat3_nearest(&drifted);
+            assert!(nearest.is_normalized());
+            assert_approx_eq!(rot, $mat3::from_quat(nearest), 0.05);
+
+            assert_eq!($quat::IDENTITY, $quat::from_mat3_nearest(&$mat3::ZERO));
+        });
+
         glam_test!(test_from_scaled_axis, {
             assert_eq!($quat::from_scaled_axis($vec3::ZERO), $quat::IDENTITY);
             assert_eq!(
@@ -234,6 +253,30 @@ macro_rules! impl_quat_tests {
             should_glam_assert!({ ($quat::IDENTITY * 0.5) * $quat::IDENTITY });
         });
 
+        glam_test!(test_mul_vec3_slice, {
+            let q = $quat::from_euler(EulerRot::YXZ, deg(30.0), deg(-45.0), deg(60.0));
+            for len in 0..=11 {
+                let src: Vec<$vec3> = (0..len)
+                    .map(|i| {
+                        let i = i as $t;
+                        $vec3::new(i - 5.0, 2.0 - i * 0.5, i * 0.25 - 10.0)
+                    })
+                    .collect();
+
+                let mut vectors = src.clone();
+                q.mul_vec3_slice(&mut vectors);
+                for i in 0..len {
+                    assert_approx_eq!(q.mul_vec3(src[i]), vectors[i], 1e-5);
+                }
+
+                let mut dst = vec![$vec3::ZERO; len];
+                q.mul_vec3_slice_to(&src, &mut dst);
+                assert_eq!(vectors, dst);
+            }
+
+            should_panic!({ q.mul_vec3_slice_to(&[$vec3::ZERO; 2], &mut [$vec3::ZERO; 3]) });
+        });
+
         glam_test!(test_angle_between, {
             const TAU: $t = 2.0 * core::$t::consts::PI;
             let eps = 10.0 * core::$t::EPSILON as f32;
@@ -329,6 +372,227 @@ macro_rules! impl_quat_tests {
             assert!(s.is_normalized());
         });
 
+        glam_test!(test_exp_ln, {
+            let axis = $vec3::new(1.0, -2.0, 0.5).normalize();
+            let q = $quat::from_axis_angle(axis, deg(60.0));
+            let ln = q.ln();
+            assert_approx_eq!(axis * deg(30.0), ln.xyz(), 1e-6);
+            assert_approx_eq!(0.0, ln.w, 1e-6);
+            assert_approx_eq!(q, ln.exp(), 1e-6);
+            assert_approx_eq!(q * 2.0, (q * 2.0).ln().exp(), 1e-5);
+            assert_approx_eq!((2.0 as $t).ln(), (q * 2.0).ln().w, 1e-6);
+
+            assert_eq!($quat::IDENTITY, $quat::from_xyzw(0.0, 0.0, 0.0, 0.0).exp());
+            assert_eq!($quat::from_xyzw(0.0, 0.0, 0.0, 0.0), $quat::IDENTITY.ln());
+            let ln = (-$quat::IDENTITY).ln();
+            assert_approx_eq!($quat::from_xyzw(core::$t::consts::PI, 0.0, 0.0, 0.0), ln);
+            assert_approx_eq!(-$quat::IDENTITY, ln.exp(), 1e-6);
+
+            // small angles
+            let q = $quat::from_rotation_z(1e-6);
+            assert_approx_eq!($quat::from_xyzw(0.0, 0.0, 5e-7, 0.0), q.ln(), 1e-9);
+            assert_approx_eq!(q, q.ln().exp(), 1e-9);
+        });
+
+        glam_test!(test_powf, {
+            let axis = $vec3::new(1.0, -2.0, 0.5).normalize();
+            let q = $quat::from_axis_angle(axis, deg(60.0));
+            assert_approx_eq!($quat::IDENTITY, q.powf(0.0), 1e-6);
+            assert_approx_eq!(q, q.powf(1.0), 1e-6);
+            assert_approx_eq!(q * q, q.powf(2.0), 1e-6);
+            assert_approx_eq!(q.inverse(), q.powf(-1.0), 1e-6);
+            assert_approx_eq!($quat::from_axis_angle(axis, deg(15.0)), q.powf(0.25), 1e-6);
+            assert!(q.powf(0.3).is_normalized());
+        });
+
+        glam_test!(test_nlerp, {
+            let q0 = $quat::from_rotation_y(deg(10.0));
+            let q1 = $quat::from_rotation_x(deg(100.0));
+            for s in [0.0, 0.25, 0.5, 1.0] {
+                assert_eq!(q0.lerp(q1, s), q0.nlerp(q1, s));
+            }
+        });
+
+        glam_test!(test_slerp_unclamped, {
+            let q0 = $quat::from_rotation_y(deg(10.0));
+            let q1 = $quat::from_rotation_y(deg(90.0));
+            assert_approx_eq!(q0, q0.slerp_unclamped(q1, 0.0), 1e-6);
+            assert_approx_eq!(q1, q0.slerp_unclamped(q1, 1.0), 1e-6);
+            for s in [0.25, 0.5, 0.75] {
+                assert_approx_eq!(q0.slerp(q1, s), q0.slerp_unclamped(q1, s), 1e-5);
+            }
+            assert_approx_eq!(
+                $quat::from_rotation_y(deg(170.0)),
+                q0.slerp_unclamped(q1, 2.0),
+                1e-5
+            );
+            assert_approx_eq!(
+                $quat::from_rotation_y(deg(-70.0)),
+                q0.slerp_unclamped(q1, -1.0),
+                1e-5
+            );
+            // takes the shorter path
+            assert_approx_eq!(
+                q0.slerp_unclamped(q1, 0.5),
+                q0.slerp_unclamped(-q1, 0.5),
+                1e-6
+            );
+
+            // extrapolating nearly equal rotations stays on the arc
+            let q0 = $quat::from_rotation_x(deg(1.0));
+            let q1 = $quat::from_rotation_x(deg(1.01));
+            let q = q0.slerp_unclamped(q1, 100.0);
+            assert!(q.is_normalized());
+            assert_approx_eq!($quat::from_rotation_x(deg(2.0)), q, 1e-4);
+
+            should_glam_assert!({ $quat::IDENTITY.slerp_unclamped($quat::IDENTITY * 2.0, 1.0) });
+        });
+
+        glam_test!(test_rotate_towards, {
+            let q0 = $quat::from_rotation_y(deg(10.0));
+            let q1 = $quat::from_rotation_y(deg(90.0));
+            assert_approx_eq!(q0, q0.rotate_towards(q1, 0.0), 1e-6);
+            assert_approx_eq!(
+                $quat::from_rotation_y(deg(30.0)),
+                q0.rotate_towards(q1, deg(20.0)),
+                1e-4
+            );
+            assert_approx_eq!(
+                $quat::from_rotation_y(deg(-10.0)),
+                q0.rotate_towards(q1, deg(-20.0)),
+                1e-4
+            );
+            assert_approx_eq!(q1, q0.rotate_towards(q1, deg(80.0)), 1e-6);
+            assert_approx_eq!(q1, q0.rotate_towards(q1, deg(180.0)), 1e-6);
+            assert_approx_eq!(q1, q1.rotate_towards(q1, deg(10.0)), 1e-6);
+            assert_eq!(-q0, q0.rotate_towards(-q0, deg(10.0)));
+
+            should_glam_assert!({ $quat::IDENTITY.rotate_towards($quat::IDENTITY * 2.0, 1.0) });
+        });
+
+        glam_test!(test_swing_twist, {
+            let axis = $vec3::new(1.0, 2.0, -0.5).normalize();
+            let twist = $quat::from_axis_angle(axis, deg(40.0));
+            let swing = $quat::from_axis_angle(axis.any_orthonormal_vector(), deg(-70.0));
+            let q = swing * twist;
+            let (s, t) = q.to_swing_twist(axis);
+            assert_approx_eq!(swing, s, 1e-6);
+            assert_approx_eq!(twist, t, 1e-6);
+            assert_approx_eq!(q, $quat::from_swing_twist(s, t), 1e-6);
+
+            for q in [
+                $quat::from_euler(glam::EulerRot::YXZ, 1.0, -0.5, 2.0),
+                -$quat::from_euler(glam::EulerRot::YXZ, -2.5, 0.3, 0.1),
+            ] {
+                let (s, t) = q.to_swing_twist(axis);
+                assert!(s.is_normalized() && t.is_normalized());
+                assert!(s.w >= 0.0 && t.w >= 0.0);
+                assert_approx_eq!(0.0, s.xyz().dot(axis), 1e-6);
+                assert_approx_eq!($vec3::ZERO, t.xyz().cross(axis), 1e-6);
+                assert!((s * t).abs_diff_eq(q, 1e-6) || (s * t).abs_diff_eq(-q, 1e-6));
+            }
+
+            // pure twist and pure swing
+            let (s, t) = twist.to_swing_twist(axis);
+            assert_approx_eq!($quat::IDENTITY, s, 1e-6);
+            assert_approx_eq!(twist, t, 1e-6);
+            let (s, t) = swing.to_swing_twist(axis);
+            assert_approx_eq!(swing, s, 1e-6);
+            assert_approx_eq!($quat::IDENTITY, t, 1e-6);
+
+            // a half turn swing has no defined twist
+            let half = $quat::from_axis_angle(axis.any_orthonormal_vector(), deg(180.0));
+            let (s, t) = half.to_swing_twist(axis);
+            assert_eq!($quat::IDENTITY, t);
+            assert!(s.abs_diff_eq(half, 1e-6) || s.abs_diff_eq(-half, 1e-6));
+
+            should_glam_assert!({ $quat::IDENTITY.to_swing_twist($vec3::ONE) });
+            should_glam_assert!({ ($quat::IDENTITY * 2.0).to_swing_twist($vec3::X) });
+        });
+
+        glam_test!(test_clamp_swing_twist, {
+            let axis = $vec3::Y;
+            let twist = $quat::from_rotation_y(deg(40.0));
+            let swing = $quat::from_rotation_x(deg(-70.0));
+            let q = swing * twist;
+
+            assert_eq!(q, q.clamp_swing(axis, deg(80.0)));
+            assert_approx_eq!(
+                $quat::from_rotation_x(deg(-30.0)) * twist,
+                q.clamp_swing(axis, deg(30.0)),
+                1e-6
+            );
+            assert_approx_eq!(twist, q.clamp_swing(axis, 0.0), 1e-6);
+
+            assert_eq!(q, q.clamp_twist(axis, deg(-50.0), deg(50.0)));
+            assert_approx_eq!(
+                swing * $quat::from_rotation_y(deg(10.0)),
+                q.clamp_twist(axis, deg(-10.0), deg(10.0)),
+                1e-6
+            );
+            assert_approx_eq!(
+                swing * $quat::from_rotation_y(deg(60.0)),
+                q.clamp_twist(axis, deg(60.0), deg(90.0)),
+                1e-6
+            );
+            let q = swing * $quat::from_rotation_y(deg(-120.0));
+            assert_approx_eq!(
+                swing * $quat::from_rotation_y(deg(-90.0)),
+                q.clamp_twist(axis, deg(-90.0), deg(90.0)),
+                1e-6
+            );
+
+            should_glam_assert!({ q.clamp_twist(axis, 1.0, -1.0) });
+        });
+
+        glam_test!(test_squad, {
+            // evenly spaced rotations around one axis give a constant angular velocity
+            let keys = [0.0, 20.0, 40.0, 60.0].map(|a| $quat::from_rotation_y(deg(a)));
+            let t1 = keys[1].squad_tangent(keys[0], keys[2]);
+            let t2 = keys[2].squad_tangent(keys[1], keys[3]);
+            assert_approx_eq!(keys[1], t1, 1e-6);
+            for s in [0.0, 0.3, 0.5, 1.0] {
+                let q = keys[1].squad(t1, t2, keys[2], s);
+                assert_approx_eq!($quat::from_rotation_y(deg(20.0 + 20.0 * s)), q, 1e-5);
+            }
+
+            let keys = [
+                $quat::IDENTITY,
+                $quat::from_rotation_y(1.0),
+                $quat::from_euler(glam::EulerRot::YXZ, 1.0, 0.8, 0.0),
+                $quat::from_euler(glam::EulerRot::YXZ, 0.5, 1.0, -0.5),
+            ];
+            let tangents = [
+                keys[0].squad_tangent(keys[0], keys[1]),
+                keys[1].squad_tangent(keys[0], keys[2]),
+                keys[2].squad_tangent(keys[1], keys[3]),
+                keys[3].squad_tangent(keys[2], keys[3]),
+            ];
+            let segment =
+                |i: usize, s: $t| keys[i].squad(tangents[i], tangents[i + 1], keys[i + 1], s);
+            for i in 0..3 {
+                assert_approx_eq!(keys[i], segment(i, 0.0), 1e-5);
+                assert_approx_eq!(keys[i + 1], segment(i, 1.0), 1e-5);
+                assert!(segment(i, 0.4).is_normalized());
+            }
+
+            // the angular velocity is continuous across keys
+            let h = 1e-3;
+            for i in 1..3 {
+                let before = (segment(i - 1, 1.0 - h).conjugate() * keys[i]).to_scaled_axis();
+                let after = (keys[i].conjugate() * segment(i, h)).to_scaled_axis();
+                assert_approx_eq!(before / h, after / h, 2e-2);
+            }
+
+            // the shorter path is taken when `end` is in the other hemisphere
+            let q = keys[1].squad(tangents[1], -tangents[2], -keys[2], 0.5);
+            assert_approx_eq!(segment(1, 0.5), q, 1e-5);
+
+            should_glam_assert!({
+                $quat::IDENTITY.squad($quat::IDENTITY, $quat::IDENTITY, $quat::IDENTITY * 2.0, 0.5)
+            });
+        });
+
         glam_test!(test_fmt, {
             let a = $quat::IDENTITY;
             assert_eq!(
@@ -637,6 +901,16 @@ mod quat {
         assert_approx_eq!(y0, y2);
     });
 
+    glam_test!(test_from_mat3a_nearest, {
+        use glam::Mat3A;
+        let q = Quat::from_euler(EulerRot::YXZ, deg(30.0), deg(-60.0), deg(45.0));
+        let scaled = Mat3A::from_quat(q) * Mat3A::from_diagonal(Vec3::new(2.0, 0.5, 3.0));
+        let nearest = Quat::from_mat3a_nearest(&scaled);
+        assert!(nearest.is_normalized());
+        assert_approx_eq!(Mat3A::from_quat(q), Mat3A::from_quat(nearest), 1e-5);
+        assert_approx_eq!(Quat::from_mat3_nearest(&scaled.into()), nearest, 1e-6);
+    });
+
     glam_test!(test_as, {
         use glam::DQuat;
         assert_approx_eq!(
@@ -660,7 +934,16 @@ mod dquat {
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(32, mem::size_of::<DQuat>());
+        #[cfg(not(all(
+            target_feature = "avx",
+            not(any(feature = "core-simd", feature = "scalar-math"))
+        )))]
         assert_eq!(mem::align_of::<f64>(), mem::align_of::<DQuat>());
+        #[cfg(all(
+            target_feature = "avx",
+            not(any(feature = "core-simd", feature = "scalar-math"))
+        ))]
+        assert_eq!(32, mem::align_of::<DQuat>());
     });
 
     impl_quat_tests!(f64, dquat, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
@@ -0,0 +1,196 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_ray3_tests {
+    ($t:ident, $ray3:ident, $vec3:ident, $plane3:ident, $sphere:ident, $aabb3:ident, $affine3:ident, $mat4:ident, $quat:ident) => {
+        glam_test!(test_new, {
+            let r = $ray3::new($vec3::ONE, $vec3::X);
+            assert_eq!($vec3::ONE, r.origin);
+            assert_eq!($vec3::X, r.direction);
+            assert_eq!($vec3::new(3.0, 1.0, 1.0), r.at(2.0));
+
+            let r = $ray3::from_points($vec3::ONE, $vec3::new(1.0, 5.0, 1.0));
+            assert_eq!($vec3::Y, r.direction);
+            assert!(r.is_finite());
+            assert!(!$ray3::new($vec3::NAN, $vec3::X).is_finite());
+        });
+
+        glam_test!(test_intersect_plane, {
+            let plane = $plane3::from_normal_point($vec3::Z, $vec3::new(0.0, 0.0, 5.0));
+            let r = $ray3::new($vec3::new(1.0, 2.0, 0.0), $vec3::Z);
+            assert_eq!(Some(5.0), r.intersect_plane(&plane));
+            // hits from behind the plane
+            assert_eq!(Some(5.0), r.intersect_plane(&plane.flip()));
+            // plane behind the origin
+            let r = $ray3::new($vec3::new(1.0, 2.0, 0.0), $vec3::NEG_Z);
+            assert_eq!(None, r.intersect_plane(&plane));
+            // parallel
+            let r = $ray3::new($vec3::ZERO, $vec3::X);
+            assert_eq!(None, r.intersect_plane(&plane));
+            // distances are in multiples of direction
+            let r = $ray3::new($vec3::ZERO, $vec3::new(0.0, 0.0, 2.0));
+            assert_eq!(Some(2.5), r.intersect_plane(&plane));
+            // short direction
+            let r = $ray3::new($vec3::new(0.0, 0.0, 10.0), $vec3::new(0.0, 0.0, -1e-8));
+            let t = r.intersect_plane(&plane).unwrap();
+            assert_approx_eq!($vec3::new(0.0, 0.0, 5.0), r.at(t), 1e-4);
+        });
+
+        glam_test!(test_intersect_sphere, {
+            let sphere = $sphere::new($vec3::new(0.0, 0.0, 5.0), 1.0);
+            let r = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_eq!(Some(4.0), r.intersect_sphere(&sphere));
+            // origin inside returns the exit distance
+            let r = $ray3::new($vec3::new(0.0, 0.0, 5.0), $vec3::Z);
+            assert_eq!(Some(1.0), r.intersect_sphere(&sphere));
+            // sphere behind the origin
+            let r = $ray3::new($vec3::ZERO, $vec3::NEG_Z);
+            assert_eq!(None, r.intersect_sphere(&sphere));
+            // miss
+            let r = $ray3::new($vec3::new(0.0, 1.5, 0.0), $vec3::Z);
+            assert_eq!(None, r.intersect_sphere(&sphere));
+            // tangent
+            let r = $ray3::new($vec3::new(0.0, 1.0, 0.0), $vec3::Z);
+            assert_eq!(Some(5.0), r.intersect_sphere(&sphere));
+            // non-normalized direction
+            let r = $ray3::new($vec3::ZERO, $vec3::new(0.0, 0.0, 2.0));
+            assert_eq!(Some(2.0), r.intersect_sphere(&sphere));
+        });
+
+        glam_test!(test_intersect_aabb, {
+            let aabb = $aabb3::new($vec3::new(-1.0, -1.0, 4.0), $vec3::new(1.0, 1.0, 6.0));
+            let r = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_eq!(Some(4.0), r.intersect_aabb(&aabb));
+            // origin inside
+            let r = $ray3::new($vec3::new(0.0, 0.0, 5.0), $vec3::Z);
+            assert_eq!(Some(0.0), r.intersect_aabb(&aabb));
+            // box behind the origin
+            let r = $ray3::new($vec3::ZERO, $vec3::NEG_Z);
+            assert_eq!(None, r.intersect_aabb(&aabb));
+            // miss, parallel to an axis
+            let r = $ray3::new($vec3::new(2.0, 0.0, 0.0), $vec3::Z);
+            assert_eq!(None, r.intersect_aabb(&aabb));
+            // diagonal
+            let r = $ray3::from_points($vec3::new(-3.0, -3.0, 5.0), $vec3::new(0.0, 0.0, 5.0));
+            assert_approx_eq!(
+                $vec3::new(-1.0, -1.0, 5.0),
+                r.at(r.intersect_aabb(&aabb).unwrap()),
+                1e-5
+            );
+            let r = $ray3::from_points($vec3::new(-3.0, -1.0, 5.0), $vec3::new(-1.0, 1.5, 5.0));
+            assert_eq!(None, r.intersect_aabb(&aabb));
+        });
+
+        glam_test!(test_intersect_aabb_grazing, {
+            let aabb = $aabb3::new($vec3::ZERO, $vec3::ONE);
+            // origin on the x = 0 face, parallel to it
+            let r = $ray3::new($vec3::new(0.0, 0.5, -5.0), $vec3::Z);
+            assert!(aabb.contains_point(r.at(5.5)));
+            assert_eq!(Some(5.0), r.intersect_aabb(&aabb));
+            // along an edge
+            let r = $ray3::new($vec3::new(1.0, 1.0, -5.0), $vec3::Z);
+            assert_eq!(Some(5.0), r.intersect_aabb(&aabb));
+            // just outside the face
+            let r = $ray3::new($vec3::new(-0.01, 0.5, -5.0), $vec3::Z);
+            assert_eq!(None, r.intersect_aabb(&aabb));
+        });
+
+        glam_test!(test_intersect_triangle, {
+            let a = $vec3::new(-1.0, -1.0, 5.0);
+            let b = $vec3::new(1.0, -1.0, 5.0);
+            let c = $vec3::new(0.0, 1.0, 5.0);
+            let r = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_approx_eq!(5.0, r.intersect_triangle(a, b, c).unwrap());
+            // either winding
+            assert_approx_eq!(5.0, r.intersect_triangle(a, c, b).unwrap());
+            // behind the origin
+            let r = $ray3::new($vec3::ZERO, $vec3::NEG_Z);
+            assert_eq!(None, r.intersect_triangle(a, b, c));
+            // outside the edges
+            let r = $ray3::new($vec3::new(0.9, 0.5, 0.0), $vec3::Z);
+            assert_eq!(None, r.intersect_triangle(a, b, c));
+            let r = $ray3::new($vec3::new(0.0, -1.5, 0.0), $vec3::Z);
+            assert_eq!(None, r.intersect_triangle(a, b, c));
+            // parallel
+            let r = $ray3::new($vec3::new(-5.0, 0.0, 5.0), $vec3::X);
+            assert_eq!(None, r.intersect_triangle(a, b, c));
+            // at an angle
+            let r = $ray3::from_points($vec3::new(0.0, 0.0, 10.0), $vec3::new(0.5, -0.5, 5.0));
+            let t = r.intersect_triangle(a, b, c).unwrap();
+            assert_approx_eq!($vec3::new(0.5, -0.5, 5.0), r.at(t), 1e-5);
+            // small triangle
+            let a = $vec3::new(-1e-4, -1e-4, 5.0);
+            let b = $vec3::new(1e-4, -1e-4, 5.0);
+            let c = $vec3::new(0.0, 1e-4, 5.0);
+            let r = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_approx_eq!(5.0, r.intersect_triangle(a, b, c).unwrap());
+            // short direction
+            let r = $ray3::new($vec3::ZERO, $vec3::new(0.0, 0.0, 1e-8));
+            assert_approx_eq!(5e8, r.intersect_triangle(a, b, c).unwrap(), 1e3);
+        });
+
+        glam_test!(test_transformed_by, {
+            let r = $ray3::new($vec3::new(1.0, 2.0, 3.0), $vec3::X);
+            let sphere = $sphere::new($vec3::new(5.0, 2.0, 3.0), 1.0);
+            let t = $affine3::from_scale_rotation_translation(
+                $vec3::splat(2.0).into(),
+                $quat::from_rotation_z(1.0),
+                $vec3::new(-1.0, 0.5, 2.0).into(),
+            );
+            let tr = r.transformed_by(&t);
+            #[allow(clippy::useless_conversion)]
+            let origin: $vec3 = t.transform_point3(r.origin.into()).into();
+            assert_approx_eq!(origin, tr.origin);
+            // hit distances are preserved
+            let ts = $sphere::new(
+                t.transform_point3(sphere.center.into()).into(),
+                sphere.radius * 2.0,
+            );
+            assert_approx_eq!(
+                r.intersect_sphere(&sphere).unwrap(),
+                tr.intersect_sphere(&ts).unwrap(),
+                1e-5
+            );
+            assert_approx_eq!(tr, r.transformed_by_mat4(&$mat4::from(t)), 1e-5);
+        });
+
+        glam_test!(test_fmt, {
+            let r = $ray3::new($vec3::ONE, $vec3::X);
+            assert_eq!(
+                format!("{:?}", r),
+                format!(
+                    "{} {{ origin: {:?}, direction: {:?} }}",
+                    stringify!($ray3),
+                    r.origin,
+                    r.direction
+                )
+            );
+            assert_eq!(format!("{}", r), "[[1, 1, 1], [1, 0, 0]]");
+        });
+    };
+}
+
+mod ray3 {
+    use glam::{Aabb3, Affine3A, Mat4, Plane3, Quat, Ray3, Sphere, Vec3};
+
+    glam_test!(test_intersect_aabb3a, {
+        use glam::{Aabb3A, Vec3A};
+        let aabb = Aabb3A::new(Vec3A::new(-1.0, -1.0, 4.0), Vec3A::new(1.0, 1.0, 6.0));
+        let r = Ray3::new(Vec3::ZERO, Vec3::Z);
+        assert_eq!(Some(4.0), r.intersect_aabb3a(&aabb));
+        let r = Ray3::new(Vec3::new(0.0, 0.0, 5.0), Vec3::Z);
+        assert_eq!(Some(0.0), r.intersect_aabb3a(&aabb));
+        let r = Ray3::new(Vec3::new(2.0, 0.0, 0.0), Vec3::Z);
+        assert_eq!(None, r.intersect_aabb3a(&aabb));
+        let r = Ray3::new(Vec3::new(-1.0, 0.5, 0.0), Vec3::Z);
+        assert_eq!(Some(4.0), r.intersect_aabb3a(&aabb));
+    });
+
+    impl_ray3_tests!(f32, Ray3, Vec3, Plane3, Sphere, Aabb3, Affine3A, Mat4, Quat);
+}
+
+mod dray3 {
+    use glam::{DAabb3, DAffine3, DMat4, DPlane3, DQuat, DRay3, DSphere, DVec3};
+
+    impl_ray3_tests!(f64, DRay3, DVec3, DPlane3, DSphere, DAabb3, DAffine3, DMat4, DQuat);
+}
@@ -0,0 +1,179 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_rot2_tests {
+    ($t:ident, $rot2:ident, $vec2:ident, $mat2:ident, $mat3:ident, $affine2:ident) => {
+        use core::$t::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        use core::$t::NAN;
+
+        glam_test!(test_identity, {
+            let r = $rot2::IDENTITY;
+            assert_eq!($rot2::default(), r);
+            assert_eq!(1.0, r.cos);
+            assert_eq!(0.0, r.sin);
+            assert_eq!(0.0, r.angle());
+            assert!(r.is_normalized());
+            let v = $vec2::new(1.0, 2.0);
+            assert_eq!(v, r * v);
+        });
+
+        glam_test!(test_nan, {
+            assert!($rot2::NAN.is_nan());
+            assert!(!$rot2::NAN.is_finite());
+            assert!($rot2::from_angle(NAN).is_nan());
+            assert!($rot2::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_from_angle, {
+            let r = $rot2::from_angle(FRAC_PI_2);
+            assert!(r.is_normalized());
+            assert_approx_eq!(FRAC_PI_2, r.angle());
+            assert_approx_eq!($vec2::Y, r * $vec2::X);
+            assert_approx_eq!(-$vec2::X, r * $vec2::Y);
+            assert_approx_eq!(-FRAC_PI_2, $rot2::from_angle(-FRAC_PI_2).angle());
+            assert_approx_eq!(PI, $rot2::from_angle(PI).angle().abs(), 1e-6);
+
+            // matches Vec2::rotate
+            let v = $vec2::new(2.0, -3.0);
+            assert_eq!($vec2::from_angle(1.5).rotate(v), $rot2::from_angle(1.5) * v);
+            assert_eq!($vec2::from_angle(1.5), $rot2::from_angle(1.5).to_vec2());
+            assert_eq!(
+                $rot2::from_angle(1.5),
+                $rot2::from_vec2($vec2::from_angle(1.5))
+            );
+        });
+
+        glam_test!(test_from_rotation_arc, {
+            let r = $rot2::from_rotation_arc($vec2::X, $vec2::Y);
+            assert_approx_eq!($rot2::from_angle(FRAC_PI_2), r);
+
+            let from = $vec2::new(1.0, 1.0).normalize();
+            let to = $vec2::new(-1.0, 0.0);
+            let r = $rot2::from_rotation_arc(from, to);
+            assert!(r.is_normalized());
+            assert_approx_eq!(to, r * from);
+            assert_approx_eq!(from, r.inverse() * to);
+            assert_approx_eq!($rot2::IDENTITY, $rot2::from_rotation_arc(to, to));
+
+            should_glam_assert!({ $rot2::from_rotation_arc($vec2::ZERO, $vec2::X) });
+            should_glam_assert!({ $rot2::from_rotation_arc($vec2::X, $vec2::ZERO) });
+        });
+
+        glam_test!(test_mul, {
+            let a = $rot2::from_angle(0.5);
+            let b = $rot2::from_angle(-1.25);
+            let v = $vec2::new(0.25, 3.0);
+            let ab = a * b;
+            assert!(ab.is_normalized());
+            assert_approx_eq!($rot2::from_angle(-0.75), ab);
+            assert_approx_eq!(a * (b * v), ab * v, 1e-6);
+            assert_approx_eq!($mat2::from(a) * $mat2::from(b), $mat2::from(ab));
+            let mut c = a;
+            c *= b;
+            assert_eq!(ab, c);
+            assert_eq!(ab, a.mul_rot2(b));
+            assert_eq!(ab * v, ab.mul_vec2(v));
+        });
+
+        glam_test!(test_inverse, {
+            let r = $rot2::from_angle(0.5);
+            let inv = r.inverse();
+            assert_approx_eq!(-0.5, inv.angle());
+            assert_approx_eq!($rot2::IDENTITY, r * inv);
+            assert_approx_eq!($mat2::from(r).inverse(), $mat2::from(inv));
+            should_glam_assert!({ $rot2::from_sin_cos(0.0, 2.0).inverse() });
+        });
+
+        glam_test!(test_normalize, {
+            let r = $rot2::from_sin_cos(3.0, 4.0);
+            assert!(!r.is_normalized());
+            assert_eq!(25.0, r.length_squared());
+            assert_eq!(5.0, r.length());
+            assert_approx_eq!($rot2::from_sin_cos(0.6, 0.8), r.normalize());
+            assert!(r.normalize().is_normalized());
+            should_glam_assert!({ $rot2::from_sin_cos(0.0, 0.0).normalize() });
+        });
+
+        glam_test!(test_angle_between, {
+            let a = $rot2::from_angle(0.25);
+            let b = $rot2::from_angle(1.0);
+            assert_approx_eq!(0.75, a.angle_between(b), 1e-6);
+            assert_approx_eq!(-0.75, b.angle_between(a), 1e-6);
+            // wraps to the shortest angle
+            let a = $rot2::from_angle(PI - 0.25);
+            let b = $rot2::from_angle(-PI + 0.25);
+            assert_approx_eq!(0.5, a.angle_between(b), 1e-6);
+        });
+
+        glam_test!(test_slerp, {
+            let a = $rot2::from_angle(0.25);
+            let b = $rot2::from_angle(1.25);
+            assert_approx_eq!(a, a.slerp(b, 0.0), 1e-6);
+            assert_approx_eq!(b, a.slerp(b, 1.0), 1e-6);
+            assert_approx_eq!($rot2::from_angle(0.75), a.slerp(b, 0.5), 1e-6);
+            assert_approx_eq!($rot2::from_angle(0.5), a.slerp(b, 0.25), 1e-6);
+
+            // takes the shortest path across the -PI to PI boundary
+            let a = $rot2::from_angle(PI - 0.25);
+            let b = $rot2::from_angle(-PI + 0.25);
+            assert_approx_eq!($rot2::from_angle(PI), a.slerp(b, 0.5), 1e-6);
+        });
+
+        glam_test!(test_nlerp, {
+            let a = $rot2::IDENTITY;
+            let b = $rot2::from_angle(FRAC_PI_2);
+            assert_approx_eq!(a, a.nlerp(b, 0.0), 1e-6);
+            assert_approx_eq!(b, a.nlerp(b, 1.0), 1e-6);
+            let mid = a.nlerp(b, 0.5);
+            assert!(mid.is_normalized());
+            assert_approx_eq!($rot2::from_angle(FRAC_PI_4), mid, 1e-6);
+        });
+
+        glam_test!(test_conversions, {
+            let r = $rot2::from_angle(0.75);
+            assert_approx_eq!($mat2::from_angle(0.75), $mat2::from(r));
+            assert_approx_eq!($mat3::from_angle(0.75), $mat3::from(r));
+
+            let t = $vec2::new(-1.0, 2.0);
+            assert_approx_eq!(
+                $mat3::from($affine2::from_angle_translation(0.75, t)),
+                $mat3::from($affine2::from_rotation_translation(r, t))
+            );
+            assert_approx_eq!(
+                $mat3::from($affine2::from_angle(0.75)),
+                $mat3::from($affine2::from(r))
+            );
+        });
+
+        glam_test!(test_fmt, {
+            let a = $rot2::from_sin_cos(0.5, 1.0);
+            assert_eq!(
+                format!("{:?}", a),
+                format!("{} {{ cos: 1.0, sin: 0.5 }}", stringify!($rot2))
+            );
+            assert_eq!(format!("{}", a), "[1, 0.5]");
+        });
+    };
+}
+
+mod rot2 {
+    use glam::{Affine2, Mat2, Mat3, Rot2, Vec2};
+
+    glam_test!(test_as, {
+        use glam::DRot2;
+        let r = Rot2::from_sin_cos(0.6, 0.8);
+        assert_eq!(
+            DRot2::from_sin_cos(0.6_f32 as f64, 0.8_f32 as f64),
+            r.as_f64()
+        );
+        assert_eq!(r, r.as_f64().as_f32());
+    });
+
+    impl_rot2_tests!(f32, Rot2, Vec2, Mat2, Mat3, Affine2);
+}
+
+mod drot2 {
+    use glam::{DAffine2, DMat2, DMat3, DRot2, DVec2};
+
+    impl_rot2_tests!(f64, DRot2, DVec2, DMat2, DMat3, DAffine2);
+}
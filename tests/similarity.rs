@@ -0,0 +1,187 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_similarity3_tests {
+    ($t:ident, $similarity3:ident, $isometry3:ident, $quat:ident, $vec3:ident, $mat4:ident, $affine3:ident) => {
+        use core::$t::consts::FRAC_PI_2;
+        use core::$t::NAN;
+
+        glam_test!(test_identity, {
+            let sim = $similarity3::IDENTITY;
+            assert_eq!($similarity3::default(), sim);
+            assert_eq!($quat::IDENTITY, sim.rotation);
+            assert_eq!($vec3::ZERO, sim.translation);
+            assert_eq!(1.0, sim.scale);
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_eq!(p, sim.transform_point3(p));
+            assert_eq!(p, sim.transform_vector3(p));
+        });
+
+        glam_test!(test_nan, {
+            assert!($similarity3::NAN.is_nan());
+            assert!(!$similarity3::NAN.is_finite());
+            assert!($similarity3::from_scale(NAN).is_nan());
+            assert!($similarity3::IDENTITY.is_finite());
+        });
+
+        glam_test!(test_from_scale_rotation_translation, {
+            let r = $quat::from_rotation_z(FRAC_PI_2);
+            let t = $vec3::new(1.0, 2.0, 3.0);
+            let sim = $similarity3::from_scale_rotation_translation(2.0, r, t);
+            assert_eq!(2.0, sim.scale);
+            assert_eq!(r, sim.rotation);
+            assert_eq!(t, sim.translation);
+
+            // scale is applied first, then rotation and then translation
+            assert_approx_eq!(
+                $vec3::new(1.0, 4.0, 3.0),
+                sim.transform_point3($vec3::X),
+                1e-6
+            );
+            assert_approx_eq!($vec3::Y * 2.0, sim.transform_vector3($vec3::X), 1e-6);
+
+            assert_eq!(
+                $similarity3::from_scale_rotation_translation(2.0, $quat::IDENTITY, $vec3::ZERO),
+                $similarity3::from_scale(2.0)
+            );
+            assert_eq!(
+                $similarity3::from_scale_rotation_translation(1.0, r, $vec3::ZERO),
+                $similarity3::from_quat(r)
+            );
+            assert_eq!(
+                $similarity3::from_scale_rotation_translation(1.0, $quat::IDENTITY, t),
+                $similarity3::from_translation(t)
+            );
+        });
+
+        glam_test!(test_isometry, {
+            let iso = $isometry3::from_rotation_translation(
+                $quat::from_rotation_x(0.5),
+                $vec3::new(1.0, -2.0, 0.5),
+            );
+            let sim = $similarity3::from(iso);
+            assert_eq!(1.0, sim.scale);
+            assert_eq!(iso, sim.to_isometry());
+            assert_eq!(sim, $similarity3::from_isometry(iso));
+        });
+
+        glam_test!(test_mul, {
+            let a = $similarity3::from_scale_rotation_translation(
+                2.0,
+                $quat::from_rotation_x(0.5),
+                $vec3::new(1.0, -2.0, 0.5),
+            );
+            let b = $similarity3::from_scale_rotation_translation(
+                0.25,
+                $quat::from_rotation_y(-1.25),
+                $vec3::new(0.0, 4.0, -3.0),
+            );
+            let p = $vec3::new(0.25, 3.0, -2.0);
+            let ab = a * b;
+            assert_eq!(0.5, ab.scale);
+            assert_approx_eq!(
+                a.transform_point3(b.transform_point3(p)),
+                ab.transform_point3(p),
+                1e-5
+            );
+            assert_approx_eq!($mat4::from(a) * $mat4::from(b), $mat4::from(ab), 1e-5);
+            let mut c = a;
+            c *= b;
+            assert_eq!(ab, c);
+            assert_eq!(ab, a.mul_similarity(&b));
+        });
+
+        glam_test!(test_inverse, {
+            let sim = $similarity3::from_scale_rotation_translation(
+                4.0,
+                $quat::from_rotation_x(0.5),
+                $vec3::new(1.0, -2.0, 0.5),
+            );
+            let inv = sim.inverse();
+            assert_eq!(0.25, inv.scale);
+            assert_approx_eq!($similarity3::IDENTITY, sim * inv, 1e-6);
+            assert_approx_eq!($similarity3::IDENTITY, inv * sim, 1e-6);
+            assert_approx_eq!($mat4::from(sim).inverse(), $mat4::from(inv), 1e-5);
+            should_glam_assert!({ $similarity3::from_scale(0.0).inverse() });
+        });
+
+        glam_test!(test_lerp, {
+            let a = $similarity3::from_translation($vec3::new(2.0, 0.0, 0.0));
+            let b = $similarity3::from_scale_rotation_translation(
+                3.0,
+                $quat::from_rotation_z(FRAC_PI_2),
+                $vec3::new(0.0, 2.0, 0.0),
+            );
+            assert_approx_eq!(a, a.lerp(&b, 0.0), 1e-6);
+            assert_approx_eq!(b, a.lerp(&b, 1.0), 1e-6);
+            assert_approx_eq!(
+                $similarity3::from_scale_rotation_translation(
+                    2.0,
+                    $quat::from_rotation_z(FRAC_PI_2 * 0.5),
+                    $vec3::new(1.0, 1.0, 0.0)
+                ),
+                a.lerp(&b, 0.5),
+                1e-6
+            );
+        });
+
+        glam_test!(test_conversions, {
+            let r = $quat::from_rotation_y(0.75);
+            let t = $vec3::new(-1.0, 2.0, 5.0);
+            let sim = $similarity3::from_scale_rotation_translation(0.5, r, t);
+            let m = $mat4::from_scale_rotation_translation($vec3::splat(0.5), r, t);
+            assert_approx_eq!(m, $mat4::from(sim), 1e-6);
+            assert_approx_eq!(m, $mat4::from($affine3::from(sim)), 1e-6);
+        });
+
+        glam_test!(test_fmt, {
+            let a = $similarity3::from_scale(2.0);
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    "{} {{ rotation: {:?}, translation: {:?}, scale: 2.0 }}",
+                    stringify!($similarity3),
+                    a.rotation,
+                    a.translation
+                )
+            );
+            assert_eq!(format!("{}", a), "[[0, 0, 0, 1], [0, 0, 0], 2]");
+        });
+    };
+}
+
+mod similarity3 {
+    use glam::{Affine3A, Isometry3, Mat4, Quat, Similarity3, Vec3, Vec3A};
+
+    glam_test!(test_vec3a, {
+        let sim = Similarity3::from_scale_rotation_translation(
+            2.0,
+            Quat::from_rotation_z(core::f32::consts::FRAC_PI_2),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        assert_approx_eq!(
+            Vec3A::new(1.0, 4.0, 3.0),
+            sim.transform_point3a(Vec3A::X),
+            1e-6
+        );
+        assert_approx_eq!(Vec3A::Y * 2.0, sim.transform_vector3a(Vec3A::X), 1e-6);
+    });
+
+    glam_test!(test_as, {
+        use glam::{DSimilarity3, DVec3};
+        let sim = Similarity3::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            DSimilarity3::from_translation(DVec3::new(1.0, 2.0, 3.0)),
+            sim.as_f64()
+        );
+        assert_eq!(sim, sim.as_f64().as_f32());
+    });
+
+    impl_similarity3_tests!(f32, Similarity3, Isometry3, Quat, Vec3, Mat4, Affine3A);
+}
+
+mod dsimilarity3 {
+    use glam::{DAffine3, DIsometry3, DMat4, DQuat, DSimilarity3, DVec3};
+
+    impl_similarity3_tests!(f64, DSimilarity3, DIsometry3, DQuat, DVec3, DMat4, DAffine3);
+}
@@ -0,0 +1,112 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_sphere_tests {
+    ($t:ident, $sphere:ident, $vec3:ident, $aabb3:ident, $affine3:ident, $mat4:ident, $quat:ident) => {
+        glam_test!(test_new, {
+            let s = $sphere::new($vec3::ONE, 2.0);
+            assert_eq!($vec3::ONE, s.center);
+            assert_eq!(2.0, s.radius);
+            assert!(s.is_finite());
+            assert!(!$sphere::new($vec3::ONE, $t::INFINITY).is_finite());
+        });
+
+        glam_test!(test_contains_point, {
+            let s = $sphere::new($vec3::ONE, 2.0);
+            assert!(s.contains_point($vec3::ONE));
+            assert!(s.contains_point($vec3::new(3.0, 1.0, 1.0)));
+            assert!(!s.contains_point($vec3::new(3.0, 1.1, 1.0)));
+            assert_eq!(1.0, s.signed_distance($vec3::new(4.0, 1.0, 1.0)));
+            assert_eq!(-2.0, s.signed_distance($vec3::ONE));
+        });
+
+        glam_test!(test_intersects, {
+            let s = $sphere::new($vec3::ZERO, 2.0);
+            assert!(s.intersects(&$sphere::new($vec3::new(3.0, 0.0, 0.0), 1.0)));
+            assert!(s.intersects(&$sphere::new($vec3::ZERO, 0.5)));
+            assert!(!s.intersects(&$sphere::new($vec3::new(3.0, 0.1, 0.0), 1.0)));
+
+            let aabb = $aabb3::new($vec3::new(2.0, -1.0, -1.0), $vec3::new(3.0, 1.0, 1.0));
+            assert!(s.intersects_aabb(&aabb));
+            let aabb = $aabb3::new($vec3::new(1.5, 1.5, 1.5), $vec3::new(3.0, 3.0, 3.0));
+            assert!(!s.intersects_aabb(&aabb));
+            assert!(s.intersects_aabb(&$aabb3::new($vec3::NEG_ONE, $vec3::ONE)));
+        });
+
+        glam_test!(test_project_point, {
+            let s = $sphere::new($vec3::ONE, 2.0);
+            assert_eq!(
+                $vec3::new(1.0, 3.0, 1.0),
+                s.project_point($vec3::new(1.0, 5.0, 1.0))
+            );
+            assert_eq!(
+                $vec3::new(1.0, 1.0, -1.0),
+                s.project_point($vec3::new(1.0, 1.0, 0.5))
+            );
+            assert_eq!($vec3::ONE, s.project_point($vec3::ONE));
+        });
+
+        glam_test!(test_aabb, {
+            let s = $sphere::new($vec3::ONE, 2.0);
+            assert_eq!($aabb3::new($vec3::splat(-1.0), $vec3::splat(3.0)), s.aabb());
+        });
+
+        glam_test!(test_transformed_by, {
+            let s = $sphere::new($vec3::new(1.0, 2.0, 3.0), 2.0);
+            let t = $affine3::from_scale_rotation_translation(
+                $vec3::new(2.0, 0.5, 3.0).into(),
+                $quat::from_rotation_x(0.7),
+                $vec3::new(-1.0, 0.5, 2.0).into(),
+            );
+            let ts = s.transformed_by(&t);
+            #[allow(clippy::useless_conversion)]
+            let center: $vec3 = t.transform_point3(s.center.into()).into();
+            assert_approx_eq!(center, ts.center, 1e-5);
+            assert_approx_eq!(6.0, ts.radius, 1e-5);
+            assert_approx_eq!(ts, s.transformed_by_mat4(&$mat4::from(t)), 1e-5);
+        });
+
+        glam_test!(test_transformed_by_shear, {
+            let s = $sphere::new($vec3::ZERO, 1.0);
+            let t = $affine3::from_cols(
+                $vec3::X.into(),
+                $vec3::new(1.0, 1.0, 0.0).into(),
+                $vec3::Z.into(),
+                $vec3::ZERO.into(),
+            );
+            let ts = s.transformed_by(&t);
+            // the largest stretch of this shear is the golden ratio, along (1, phi, 0)
+            let phi = (1.0 + (5.0 as $t).sqrt()) * 0.5;
+            assert_approx_eq!(phi, ts.radius, 1e-5);
+            let v = $vec3::new(1.0, phi, 0.0).normalize();
+            #[allow(clippy::useless_conversion)]
+            let p: $vec3 = t.transform_point3(v.into()).into();
+            assert_approx_eq!(ts.radius, p.length(), 1e-5);
+        });
+
+        glam_test!(test_fmt, {
+            let s = $sphere::new($vec3::ONE, 2.0);
+            assert_eq!(
+                format!("{:?}", s),
+                format!(
+                    "{} {{ center: {:?}, radius: 2.0 }}",
+                    stringify!($sphere),
+                    s.center
+                )
+            );
+            assert_eq!(format!("{}", s), "[[1, 1, 1], 2]");
+        });
+    };
+}
+
+mod sphere {
+    use glam::{Aabb3, Affine3A, Mat4, Quat, Sphere, Vec3};
+
+    impl_sphere_tests!(f32, Sphere, Vec3, Aabb3, Affine3A, Mat4, Quat);
+}
+
+mod dsphere {
+    use glam::{DAabb3, DAffine3, DMat4, DQuat, DSphere, DVec3};
+
+    impl_sphere_tests!(f64, DSphere, DVec3, DAabb3, DAffine3, DMat4, DQuat);
+}
@@ -6,8 +6,10 @@ mod macros;
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
 use glam::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3,
-    Vec3A, Vec4,
+    DDegrees, DDualQuat, DIsometry2, DIsometry3, DMat2, DMat3, DMat4, DPlane3, DQuat, DRadians,
+    DRay3, DRot2, DSimilarity3, DSphere, DVec2, DVec3, DVec4, Degrees, DualQuat, Isometry2,
+    Isometry3, Mat2, Mat3, Mat3A, Mat4, Plane3, Quat, Radians, Ray3, Rot2, Similarity3, Sphere,
+    Vec2, Vec3, Vec3A, Vec4,
 };
 
 pub trait Deg {
@@ -190,6 +192,138 @@ impl FloatCompare for Quat {
     }
 }
 
+impl FloatCompare for DualQuat {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_real_dual(
+            self.real.abs_diff(&other.real),
+            self.dual.abs_diff(&other.dual),
+        )
+    }
+}
+
+impl FloatCompare for Radians {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for Degrees {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for Rot2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_sin_cos((self.sin - other.sin).abs(), (self.cos - other.cos).abs())
+    }
+}
+
+impl FloatCompare for Isometry2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_angle_translation(
+            (self.angle - other.angle).abs(),
+            self.translation.abs_diff(&other.translation),
+        )
+    }
+}
+
+impl FloatCompare for Isometry3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            rotation: self.rotation.abs_diff(&other.rotation),
+            translation: self.translation.abs_diff(&other.translation),
+        }
+    }
+}
+
+impl FloatCompare for Similarity3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            rotation: self.rotation.abs_diff(&other.rotation),
+            translation: self.translation.abs_diff(&other.translation),
+            scale: (self.scale - other.scale).abs(),
+        }
+    }
+}
+
+impl FloatCompare for Ray3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.origin.abs_diff(&other.origin),
+            self.direction.abs_diff(&other.direction),
+        )
+    }
+}
+
+impl FloatCompare for Plane3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.normal.abs_diff(&other.normal),
+            (self.d - other.d).abs(),
+        )
+    }
+}
+
+impl FloatCompare for Sphere {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.center.abs_diff(&other.center),
+            (self.radius - other.radius).abs(),
+        )
+    }
+}
+
 impl FloatCompare for Vec2 {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
@@ -247,6 +381,138 @@ impl FloatCompare for DQuat {
     }
 }
 
+impl FloatCompare for DDualQuat {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_real_dual(
+            self.real.abs_diff(&other.real),
+            self.dual.abs_diff(&other.dual),
+        )
+    }
+}
+
+impl FloatCompare for DRadians {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for DDegrees {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for DRot2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_sin_cos((self.sin - other.sin).abs(), (self.cos - other.cos).abs())
+    }
+}
+
+impl FloatCompare for DIsometry2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_angle_translation(
+            (self.angle - other.angle).abs(),
+            self.translation.abs_diff(&other.translation),
+        )
+    }
+}
+
+impl FloatCompare for DIsometry3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            rotation: self.rotation.abs_diff(&other.rotation),
+            translation: self.translation.abs_diff(&other.translation),
+        }
+    }
+}
+
+impl FloatCompare for DSimilarity3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            rotation: self.rotation.abs_diff(&other.rotation),
+            translation: self.translation.abs_diff(&other.translation),
+            scale: (self.scale - other.scale).abs(),
+        }
+    }
+}
+
+impl FloatCompare for DRay3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.origin.abs_diff(&other.origin),
+            self.direction.abs_diff(&other.direction),
+        )
+    }
+}
+
+impl FloatCompare for DPlane3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.normal.abs_diff(&other.normal),
+            (self.d - other.d).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DSphere {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.center.abs_diff(&other.center),
+            (self.radius - other.radius).abs(),
+        )
+    }
+}
+
 impl FloatCompare for DVec2 {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
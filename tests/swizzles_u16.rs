@@ -0,0 +1,497 @@
+// Generated by swizzlegen. Do not edit.
+#[macro_use]
+mod support;
+use glam::*;
+
+glam_test!(test_u16vec4_swizzles, {
+    let v = u16vec4(1_u16, 2_u16, 3_u16, 4_u16);
+    assert_eq!(v, v.xyzw());
+    assert_eq!(v.xxxx(), u16vec4(1_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.xxxy(), u16vec4(1_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.xxxz(), u16vec4(1_u16, 1_u16, 1_u16, 3_u16));
+    assert_eq!(v.xxxw(), u16vec4(1_u16, 1_u16, 1_u16, 4_u16));
+    assert_eq!(v.xxyx(), u16vec4(1_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.xxyy(), u16vec4(1_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.xxyz(), u16vec4(1_u16, 1_u16, 2_u16, 3_u16));
+    assert_eq!(v.xxyw(), u16vec4(1_u16, 1_u16, 2_u16, 4_u16));
+    assert_eq!(v.xxzx(), u16vec4(1_u16, 1_u16, 3_u16, 1_u16));
+    assert_eq!(v.xxzy(), u16vec4(1_u16, 1_u16, 3_u16, 2_u16));
+    assert_eq!(v.xxzz(), u16vec4(1_u16, 1_u16, 3_u16, 3_u16));
+    assert_eq!(v.xxzw(), u16vec4(1_u16, 1_u16, 3_u16, 4_u16));
+    assert_eq!(v.xxwx(), u16vec4(1_u16, 1_u16, 4_u16, 1_u16));
+    assert_eq!(v.xxwy(), u16vec4(1_u16, 1_u16, 4_u16, 2_u16));
+    assert_eq!(v.xxwz(), u16vec4(1_u16, 1_u16, 4_u16, 3_u16));
+    assert_eq!(v.xxww(), u16vec4(1_u16, 1_u16, 4_u16, 4_u16));
+    assert_eq!(v.xyxx(), u16vec4(1_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.xyxy(), u16vec4(1_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.xyxz(), u16vec4(1_u16, 2_u16, 1_u16, 3_u16));
+    assert_eq!(v.xyxw(), u16vec4(1_u16, 2_u16, 1_u16, 4_u16));
+    assert_eq!(v.xyyx(), u16vec4(1_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.xyyy(), u16vec4(1_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.xyyz(), u16vec4(1_u16, 2_u16, 2_u16, 3_u16));
+    assert_eq!(v.xyyw(), u16vec4(1_u16, 2_u16, 2_u16, 4_u16));
+    assert_eq!(v.xyzx(), u16vec4(1_u16, 2_u16, 3_u16, 1_u16));
+    assert_eq!(v.xyzy(), u16vec4(1_u16, 2_u16, 3_u16, 2_u16));
+    assert_eq!(v.xyzz(), u16vec4(1_u16, 2_u16, 3_u16, 3_u16));
+    assert_eq!(v.xywx(), u16vec4(1_u16, 2_u16, 4_u16, 1_u16));
+    assert_eq!(v.xywy(), u16vec4(1_u16, 2_u16, 4_u16, 2_u16));
+    assert_eq!(v.xywz(), u16vec4(1_u16, 2_u16, 4_u16, 3_u16));
+    assert_eq!(v.xyww(), u16vec4(1_u16, 2_u16, 4_u16, 4_u16));
+    assert_eq!(v.xzxx(), u16vec4(1_u16, 3_u16, 1_u16, 1_u16));
+    assert_eq!(v.xzxy(), u16vec4(1_u16, 3_u16, 1_u16, 2_u16));
+    assert_eq!(v.xzxz(), u16vec4(1_u16, 3_u16, 1_u16, 3_u16));
+    assert_eq!(v.xzxw(), u16vec4(1_u16, 3_u16, 1_u16, 4_u16));
+    assert_eq!(v.xzyx(), u16vec4(1_u16, 3_u16, 2_u16, 1_u16));
+    assert_eq!(v.xzyy(), u16vec4(1_u16, 3_u16, 2_u16, 2_u16));
+    assert_eq!(v.xzyz(), u16vec4(1_u16, 3_u16, 2_u16, 3_u16));
+    assert_eq!(v.xzyw(), u16vec4(1_u16, 3_u16, 2_u16, 4_u16));
+    assert_eq!(v.xzzx(), u16vec4(1_u16, 3_u16, 3_u16, 1_u16));
+    assert_eq!(v.xzzy(), u16vec4(1_u16, 3_u16, 3_u16, 2_u16));
+    assert_eq!(v.xzzz(), u16vec4(1_u16, 3_u16, 3_u16, 3_u16));
+    assert_eq!(v.xzzw(), u16vec4(1_u16, 3_u16, 3_u16, 4_u16));
+    assert_eq!(v.xzwx(), u16vec4(1_u16, 3_u16, 4_u16, 1_u16));
+    assert_eq!(v.xzwy(), u16vec4(1_u16, 3_u16, 4_u16, 2_u16));
+    assert_eq!(v.xzwz(), u16vec4(1_u16, 3_u16, 4_u16, 3_u16));
+    assert_eq!(v.xzww(), u16vec4(1_u16, 3_u16, 4_u16, 4_u16));
+    assert_eq!(v.xwxx(), u16vec4(1_u16, 4_u16, 1_u16, 1_u16));
+    assert_eq!(v.xwxy(), u16vec4(1_u16, 4_u16, 1_u16, 2_u16));
+    assert_eq!(v.xwxz(), u16vec4(1_u16, 4_u16, 1_u16, 3_u16));
+    assert_eq!(v.xwxw(), u16vec4(1_u16, 4_u16, 1_u16, 4_u16));
+    assert_eq!(v.xwyx(), u16vec4(1_u16, 4_u16, 2_u16, 1_u16));
+    assert_eq!(v.xwyy(), u16vec4(1_u16, 4_u16, 2_u16, 2_u16));
+    assert_eq!(v.xwyz(), u16vec4(1_u16, 4_u16, 2_u16, 3_u16));
+    assert_eq!(v.xwyw(), u16vec4(1_u16, 4_u16, 2_u16, 4_u16));
+    assert_eq!(v.xwzx(), u16vec4(1_u16, 4_u16, 3_u16, 1_u16));
+    assert_eq!(v.xwzy(), u16vec4(1_u16, 4_u16, 3_u16, 2_u16));
+    assert_eq!(v.xwzz(), u16vec4(1_u16, 4_u16, 3_u16, 3_u16));
+    assert_eq!(v.xwzw(), u16vec4(1_u16, 4_u16, 3_u16, 4_u16));
+    assert_eq!(v.xwwx(), u16vec4(1_u16, 4_u16, 4_u16, 1_u16));
+    assert_eq!(v.xwwy(), u16vec4(1_u16, 4_u16, 4_u16, 2_u16));
+    assert_eq!(v.xwwz(), u16vec4(1_u16, 4_u16, 4_u16, 3_u16));
+    assert_eq!(v.xwww(), u16vec4(1_u16, 4_u16, 4_u16, 4_u16));
+    assert_eq!(v.yxxx(), u16vec4(2_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.yxxy(), u16vec4(2_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.yxxz(), u16vec4(2_u16, 1_u16, 1_u16, 3_u16));
+    assert_eq!(v.yxxw(), u16vec4(2_u16, 1_u16, 1_u16, 4_u16));
+    assert_eq!(v.yxyx(), u16vec4(2_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.yxyy(), u16vec4(2_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.yxyz(), u16vec4(2_u16, 1_u16, 2_u16, 3_u16));
+    assert_eq!(v.yxyw(), u16vec4(2_u16, 1_u16, 2_u16, 4_u16));
+    assert_eq!(v.yxzx(), u16vec4(2_u16, 1_u16, 3_u16, 1_u16));
+    assert_eq!(v.yxzy(), u16vec4(2_u16, 1_u16, 3_u16, 2_u16));
+    assert_eq!(v.yxzz(), u16vec4(2_u16, 1_u16, 3_u16, 3_u16));
+    assert_eq!(v.yxzw(), u16vec4(2_u16, 1_u16, 3_u16, 4_u16));
+    assert_eq!(v.yxwx(), u16vec4(2_u16, 1_u16, 4_u16, 1_u16));
+    assert_eq!(v.yxwy(), u16vec4(2_u16, 1_u16, 4_u16, 2_u16));
+    assert_eq!(v.yxwz(), u16vec4(2_u16, 1_u16, 4_u16, 3_u16));
+    assert_eq!(v.yxww(), u16vec4(2_u16, 1_u16, 4_u16, 4_u16));
+    assert_eq!(v.yyxx(), u16vec4(2_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.yyxy(), u16vec4(2_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.yyxz(), u16vec4(2_u16, 2_u16, 1_u16, 3_u16));
+    assert_eq!(v.yyxw(), u16vec4(2_u16, 2_u16, 1_u16, 4_u16));
+    assert_eq!(v.yyyx(), u16vec4(2_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.yyyy(), u16vec4(2_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.yyyz(), u16vec4(2_u16, 2_u16, 2_u16, 3_u16));
+    assert_eq!(v.yyyw(), u16vec4(2_u16, 2_u16, 2_u16, 4_u16));
+    assert_eq!(v.yyzx(), u16vec4(2_u16, 2_u16, 3_u16, 1_u16));
+    assert_eq!(v.yyzy(), u16vec4(2_u16, 2_u16, 3_u16, 2_u16));
+    assert_eq!(v.yyzz(), u16vec4(2_u16, 2_u16, 3_u16, 3_u16));
+    assert_eq!(v.yyzw(), u16vec4(2_u16, 2_u16, 3_u16, 4_u16));
+    assert_eq!(v.yywx(), u16vec4(2_u16, 2_u16, 4_u16, 1_u16));
+    assert_eq!(v.yywy(), u16vec4(2_u16, 2_u16, 4_u16, 2_u16));
+    assert_eq!(v.yywz(), u16vec4(2_u16, 2_u16, 4_u16, 3_u16));
+    assert_eq!(v.yyww(), u16vec4(2_u16, 2_u16, 4_u16, 4_u16));
+    assert_eq!(v.yzxx(), u16vec4(2_u16, 3_u16, 1_u16, 1_u16));
+    assert_eq!(v.yzxy(), u16vec4(2_u16, 3_u16, 1_u16, 2_u16));
+    assert_eq!(v.yzxz(), u16vec4(2_u16, 3_u16, 1_u16, 3_u16));
+    assert_eq!(v.yzxw(), u16vec4(2_u16, 3_u16, 1_u16, 4_u16));
+    assert_eq!(v.yzyx(), u16vec4(2_u16, 3_u16, 2_u16, 1_u16));
+    assert_eq!(v.yzyy(), u16vec4(2_u16, 3_u16, 2_u16, 2_u16));
+    assert_eq!(v.yzyz(), u16vec4(2_u16, 3_u16, 2_u16, 3_u16));
+    assert_eq!(v.yzyw(), u16vec4(2_u16, 3_u16, 2_u16, 4_u16));
+    assert_eq!(v.yzzx(), u16vec4(2_u16, 3_u16, 3_u16, 1_u16));
+    assert_eq!(v.yzzy(), u16vec4(2_u16, 3_u16, 3_u16, 2_u16));
+    assert_eq!(v.yzzz(), u16vec4(2_u16, 3_u16, 3_u16, 3_u16));
+    assert_eq!(v.yzzw(), u16vec4(2_u16, 3_u16, 3_u16, 4_u16));
+    assert_eq!(v.yzwx(), u16vec4(2_u16, 3_u16, 4_u16, 1_u16));
+    assert_eq!(v.yzwy(), u16vec4(2_u16, 3_u16, 4_u16, 2_u16));
+    assert_eq!(v.yzwz(), u16vec4(2_u16, 3_u16, 4_u16, 3_u16));
+    assert_eq!(v.yzww(), u16vec4(2_u16, 3_u16, 4_u16, 4_u16));
+    assert_eq!(v.ywxx(), u16vec4(2_u16, 4_u16, 1_u16, 1_u16));
+    assert_eq!(v.ywxy(), u16vec4(2_u16, 4_u16, 1_u16, 2_u16));
+    assert_eq!(v.ywxz(), u16vec4(2_u16, 4_u16, 1_u16, 3_u16));
+    assert_eq!(v.ywxw(), u16vec4(2_u16, 4_u16, 1_u16, 4_u16));
+    assert_eq!(v.ywyx(), u16vec4(2_u16, 4_u16, 2_u16, 1_u16));
+    assert_eq!(v.ywyy(), u16vec4(2_u16, 4_u16, 2_u16, 2_u16));
+    assert_eq!(v.ywyz(), u16vec4(2_u16, 4_u16, 2_u16, 3_u16));
+    assert_eq!(v.ywyw(), u16vec4(2_u16, 4_u16, 2_u16, 4_u16));
+    assert_eq!(v.ywzx(), u16vec4(2_u16, 4_u16, 3_u16, 1_u16));
+    assert_eq!(v.ywzy(), u16vec4(2_u16, 4_u16, 3_u16, 2_u16));
+    assert_eq!(v.ywzz(), u16vec4(2_u16, 4_u16, 3_u16, 3_u16));
+    assert_eq!(v.ywzw(), u16vec4(2_u16, 4_u16, 3_u16, 4_u16));
+    assert_eq!(v.ywwx(), u16vec4(2_u16, 4_u16, 4_u16, 1_u16));
+    assert_eq!(v.ywwy(), u16vec4(2_u16, 4_u16, 4_u16, 2_u16));
+    assert_eq!(v.ywwz(), u16vec4(2_u16, 4_u16, 4_u16, 3_u16));
+    assert_eq!(v.ywww(), u16vec4(2_u16, 4_u16, 4_u16, 4_u16));
+    assert_eq!(v.zxxx(), u16vec4(3_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.zxxy(), u16vec4(3_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.zxxz(), u16vec4(3_u16, 1_u16, 1_u16, 3_u16));
+    assert_eq!(v.zxxw(), u16vec4(3_u16, 1_u16, 1_u16, 4_u16));
+    assert_eq!(v.zxyx(), u16vec4(3_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.zxyy(), u16vec4(3_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.zxyz(), u16vec4(3_u16, 1_u16, 2_u16, 3_u16));
+    assert_eq!(v.zxyw(), u16vec4(3_u16, 1_u16, 2_u16, 4_u16));
+    assert_eq!(v.zxzx(), u16vec4(3_u16, 1_u16, 3_u16, 1_u16));
+    assert_eq!(v.zxzy(), u16vec4(3_u16, 1_u16, 3_u16, 2_u16));
+    assert_eq!(v.zxzz(), u16vec4(3_u16, 1_u16, 3_u16, 3_u16));
+    assert_eq!(v.zxzw(), u16vec4(3_u16, 1_u16, 3_u16, 4_u16));
+    assert_eq!(v.zxwx(), u16vec4(3_u16, 1_u16, 4_u16, 1_u16));
+    assert_eq!(v.zxwy(), u16vec4(3_u16, 1_u16, 4_u16, 2_u16));
+    assert_eq!(v.zxwz(), u16vec4(3_u16, 1_u16, 4_u16, 3_u16));
+    assert_eq!(v.zxww(), u16vec4(3_u16, 1_u16, 4_u16, 4_u16));
+    assert_eq!(v.zyxx(), u16vec4(3_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.zyxy(), u16vec4(3_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.zyxz(), u16vec4(3_u16, 2_u16, 1_u16, 3_u16));
+    assert_eq!(v.zyxw(), u16vec4(3_u16, 2_u16, 1_u16, 4_u16));
+    assert_eq!(v.zyyx(), u16vec4(3_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.zyyy(), u16vec4(3_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.zyyz(), u16vec4(3_u16, 2_u16, 2_u16, 3_u16));
+    assert_eq!(v.zyyw(), u16vec4(3_u16, 2_u16, 2_u16, 4_u16));
+    assert_eq!(v.zyzx(), u16vec4(3_u16, 2_u16, 3_u16, 1_u16));
+    assert_eq!(v.zyzy(), u16vec4(3_u16, 2_u16, 3_u16, 2_u16));
+    assert_eq!(v.zyzz(), u16vec4(3_u16, 2_u16, 3_u16, 3_u16));
+    assert_eq!(v.zyzw(), u16vec4(3_u16, 2_u16, 3_u16, 4_u16));
+    assert_eq!(v.zywx(), u16vec4(3_u16, 2_u16, 4_u16, 1_u16));
+    assert_eq!(v.zywy(), u16vec4(3_u16, 2_u16, 4_u16, 2_u16));
+    assert_eq!(v.zywz(), u16vec4(3_u16, 2_u16, 4_u16, 3_u16));
+    assert_eq!(v.zyww(), u16vec4(3_u16, 2_u16, 4_u16, 4_u16));
+    assert_eq!(v.zzxx(), u16vec4(3_u16, 3_u16, 1_u16, 1_u16));
+    assert_eq!(v.zzxy(), u16vec4(3_u16, 3_u16, 1_u16, 2_u16));
+    assert_eq!(v.zzxz(), u16vec4(3_u16, 3_u16, 1_u16, 3_u16));
+    assert_eq!(v.zzxw(), u16vec4(3_u16, 3_u16, 1_u16, 4_u16));
+    assert_eq!(v.zzyx(), u16vec4(3_u16, 3_u16, 2_u16, 1_u16));
+    assert_eq!(v.zzyy(), u16vec4(3_u16, 3_u16, 2_u16, 2_u16));
+    assert_eq!(v.zzyz(), u16vec4(3_u16, 3_u16, 2_u16, 3_u16));
+    assert_eq!(v.zzyw(), u16vec4(3_u16, 3_u16, 2_u16, 4_u16));
+    assert_eq!(v.zzzx(), u16vec4(3_u16, 3_u16, 3_u16, 1_u16));
+    assert_eq!(v.zzzy(), u16vec4(3_u16, 3_u16, 3_u16, 2_u16));
+    assert_eq!(v.zzzz(), u16vec4(3_u16, 3_u16, 3_u16, 3_u16));
+    assert_eq!(v.zzzw(), u16vec4(3_u16, 3_u16, 3_u16, 4_u16));
+    assert_eq!(v.zzwx(), u16vec4(3_u16, 3_u16, 4_u16, 1_u16));
+    assert_eq!(v.zzwy(), u16vec4(3_u16, 3_u16, 4_u16, 2_u16));
+    assert_eq!(v.zzwz(), u16vec4(3_u16, 3_u16, 4_u16, 3_u16));
+    assert_eq!(v.zzww(), u16vec4(3_u16, 3_u16, 4_u16, 4_u16));
+    assert_eq!(v.zwxx(), u16vec4(3_u16, 4_u16, 1_u16, 1_u16));
+    assert_eq!(v.zwxy(), u16vec4(3_u16, 4_u16, 1_u16, 2_u16));
+    assert_eq!(v.zwxz(), u16vec4(3_u16, 4_u16, 1_u16, 3_u16));
+    assert_eq!(v.zwxw(), u16vec4(3_u16, 4_u16, 1_u16, 4_u16));
+    assert_eq!(v.zwyx(), u16vec4(3_u16, 4_u16, 2_u16, 1_u16));
+    assert_eq!(v.zwyy(), u16vec4(3_u16, 4_u16, 2_u16, 2_u16));
+    assert_eq!(v.zwyz(), u16vec4(3_u16, 4_u16, 2_u16, 3_u16));
+    assert_eq!(v.zwyw(), u16vec4(3_u16, 4_u16, 2_u16, 4_u16));
+    assert_eq!(v.zwzx(), u16vec4(3_u16, 4_u16, 3_u16, 1_u16));
+    assert_eq!(v.zwzy(), u16vec4(3_u16, 4_u16, 3_u16, 2_u16));
+    assert_eq!(v.zwzz(), u16vec4(3_u16, 4_u16, 3_u16, 3_u16));
+    assert_eq!(v.zwzw(), u16vec4(3_u16, 4_u16, 3_u16, 4_u16));
+    assert_eq!(v.zwwx(), u16vec4(3_u16, 4_u16, 4_u16, 1_u16));
+    assert_eq!(v.zwwy(), u16vec4(3_u16, 4_u16, 4_u16, 2_u16));
+    assert_eq!(v.zwwz(), u16vec4(3_u16, 4_u16, 4_u16, 3_u16));
+    assert_eq!(v.zwww(), u16vec4(3_u16, 4_u16, 4_u16, 4_u16));
+    assert_eq!(v.wxxx(), u16vec4(4_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.wxxy(), u16vec4(4_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.wxxz(), u16vec4(4_u16, 1_u16, 1_u16, 3_u16));
+    assert_eq!(v.wxxw(), u16vec4(4_u16, 1_u16, 1_u16, 4_u16));
+    assert_eq!(v.wxyx(), u16vec4(4_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.wxyy(), u16vec4(4_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.wxyz(), u16vec4(4_u16, 1_u16, 2_u16, 3_u16));
+    assert_eq!(v.wxyw(), u16vec4(4_u16, 1_u16, 2_u16, 4_u16));
+    assert_eq!(v.wxzx(), u16vec4(4_u16, 1_u16, 3_u16, 1_u16));
+    assert_eq!(v.wxzy(), u16vec4(4_u16, 1_u16, 3_u16, 2_u16));
+    assert_eq!(v.wxzz(), u16vec4(4_u16, 1_u16, 3_u16, 3_u16));
+    assert_eq!(v.wxzw(), u16vec4(4_u16, 1_u16, 3_u16, 4_u16));
+    assert_eq!(v.wxwx(), u16vec4(4_u16, 1_u16, 4_u16, 1_u16));
+    assert_eq!(v.wxwy(), u16vec4(4_u16, 1_u16, 4_u16, 2_u16));
+    assert_eq!(v.wxwz(), u16vec4(4_u16, 1_u16, 4_u16, 3_u16));
+    assert_eq!(v.wxww(), u16vec4(4_u16, 1_u16, 4_u16, 4_u16));
+    assert_eq!(v.wyxx(), u16vec4(4_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.wyxy(), u16vec4(4_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.wyxz(), u16vec4(4_u16, 2_u16, 1_u16, 3_u16));
+    assert_eq!(v.wyxw(), u16vec4(4_u16, 2_u16, 1_u16, 4_u16));
+    assert_eq!(v.wyyx(), u16vec4(4_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.wyyy(), u16vec4(4_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.wyyz(), u16vec4(4_u16, 2_u16, 2_u16, 3_u16));
+    assert_eq!(v.wyyw(), u16vec4(4_u16, 2_u16, 2_u16, 4_u16));
+    assert_eq!(v.wyzx(), u16vec4(4_u16, 2_u16, 3_u16, 1_u16));
+    assert_eq!(v.wyzy(), u16vec4(4_u16, 2_u16, 3_u16, 2_u16));
+    assert_eq!(v.wyzz(), u16vec4(4_u16, 2_u16, 3_u16, 3_u16));
+    assert_eq!(v.wyzw(), u16vec4(4_u16, 2_u16, 3_u16, 4_u16));
+    assert_eq!(v.wywx(), u16vec4(4_u16, 2_u16, 4_u16, 1_u16));
+    assert_eq!(v.wywy(), u16vec4(4_u16, 2_u16, 4_u16, 2_u16));
+    assert_eq!(v.wywz(), u16vec4(4_u16, 2_u16, 4_u16, 3_u16));
+    assert_eq!(v.wyww(), u16vec4(4_u16, 2_u16, 4_u16, 4_u16));
+    assert_eq!(v.wzxx(), u16vec4(4_u16, 3_u16, 1_u16, 1_u16));
+    assert_eq!(v.wzxy(), u16vec4(4_u16, 3_u16, 1_u16, 2_u16));
+    assert_eq!(v.wzxz(), u16vec4(4_u16, 3_u16, 1_u16, 3_u16));
+    assert_eq!(v.wzxw(), u16vec4(4_u16, 3_u16, 1_u16, 4_u16));
+    assert_eq!(v.wzyx(), u16vec4(4_u16, 3_u16, 2_u16, 1_u16));
+    assert_eq!(v.wzyy(), u16vec4(4_u16, 3_u16, 2_u16, 2_u16));
+    assert_eq!(v.wzyz(), u16vec4(4_u16, 3_u16, 2_u16, 3_u16));
+    assert_eq!(v.wzyw(), u16vec4(4_u16, 3_u16, 2_u16, 4_u16));
+    assert_eq!(v.wzzx(), u16vec4(4_u16, 3_u16, 3_u16, 1_u16));
+    assert_eq!(v.wzzy(), u16vec4(4_u16, 3_u16, 3_u16, 2_u16));
+    assert_eq!(v.wzzz(), u16vec4(4_u16, 3_u16, 3_u16, 3_u16));
+    assert_eq!(v.wzzw(), u16vec4(4_u16, 3_u16, 3_u16, 4_u16));
+    assert_eq!(v.wzwx(), u16vec4(4_u16, 3_u16, 4_u16, 1_u16));
+    assert_eq!(v.wzwy(), u16vec4(4_u16, 3_u16, 4_u16, 2_u16));
+    assert_eq!(v.wzwz(), u16vec4(4_u16, 3_u16, 4_u16, 3_u16));
+    assert_eq!(v.wzww(), u16vec4(4_u16, 3_u16, 4_u16, 4_u16));
+    assert_eq!(v.wwxx(), u16vec4(4_u16, 4_u16, 1_u16, 1_u16));
+    assert_eq!(v.wwxy(), u16vec4(4_u16, 4_u16, 1_u16, 2_u16));
+    assert_eq!(v.wwxz(), u16vec4(4_u16, 4_u16, 1_u16, 3_u16));
+    assert_eq!(v.wwxw(), u16vec4(4_u16, 4_u16, 1_u16, 4_u16));
+    assert_eq!(v.wwyx(), u16vec4(4_u16, 4_u16, 2_u16, 1_u16));
+    assert_eq!(v.wwyy(), u16vec4(4_u16, 4_u16, 2_u16, 2_u16));
+    assert_eq!(v.wwyz(), u16vec4(4_u16, 4_u16, 2_u16, 3_u16));
+    assert_eq!(v.wwyw(), u16vec4(4_u16, 4_u16, 2_u16, 4_u16));
+    assert_eq!(v.wwzx(), u16vec4(4_u16, 4_u16, 3_u16, 1_u16));
+    assert_eq!(v.wwzy(), u16vec4(4_u16, 4_u16, 3_u16, 2_u16));
+    assert_eq!(v.wwzz(), u16vec4(4_u16, 4_u16, 3_u16, 3_u16));
+    assert_eq!(v.wwzw(), u16vec4(4_u16, 4_u16, 3_u16, 4_u16));
+    assert_eq!(v.wwwx(), u16vec4(4_u16, 4_u16, 4_u16, 1_u16));
+    assert_eq!(v.wwwy(), u16vec4(4_u16, 4_u16, 4_u16, 2_u16));
+    assert_eq!(v.wwwz(), u16vec4(4_u16, 4_u16, 4_u16, 3_u16));
+    assert_eq!(v.wwww(), u16vec4(4_u16, 4_u16, 4_u16, 4_u16));
+    assert_eq!(v.xxx(), u16vec3(1_u16, 1_u16, 1_u16));
+    assert_eq!(v.xxy(), u16vec3(1_u16, 1_u16, 2_u16));
+    assert_eq!(v.xxz(), u16vec3(1_u16, 1_u16, 3_u16));
+    assert_eq!(v.xxw(), u16vec3(1_u16, 1_u16, 4_u16));
+    assert_eq!(v.xyx(), u16vec3(1_u16, 2_u16, 1_u16));
+    assert_eq!(v.xyy(), u16vec3(1_u16, 2_u16, 2_u16));
+    assert_eq!(v.xyz(), u16vec3(1_u16, 2_u16, 3_u16));
+    assert_eq!(v.xyw(), u16vec3(1_u16, 2_u16, 4_u16));
+    assert_eq!(v.xzx(), u16vec3(1_u16, 3_u16, 1_u16));
+    assert_eq!(v.xzy(), u16vec3(1_u16, 3_u16, 2_u16));
+    assert_eq!(v.xzz(), u16vec3(1_u16, 3_u16, 3_u16));
+    assert_eq!(v.xzw(), u16vec3(1_u16, 3_u16, 4_u16));
+    assert_eq!(v.xwx(), u16vec3(1_u16, 4_u16, 1_u16));
+    assert_eq!(v.xwy(), u16vec3(1_u16, 4_u16, 2_u16));
+    assert_eq!(v.xwz(), u16vec3(1_u16, 4_u16, 3_u16));
+    assert_eq!(v.xww(), u16vec3(1_u16, 4_u16, 4_u16));
+    assert_eq!(v.yxx(), u16vec3(2_u16, 1_u16, 1_u16));
+    assert_eq!(v.yxy(), u16vec3(2_u16, 1_u16, 2_u16));
+    assert_eq!(v.yxz(), u16vec3(2_u16, 1_u16, 3_u16));
+    assert_eq!(v.yxw(), u16vec3(2_u16, 1_u16, 4_u16));
+    assert_eq!(v.yyx(), u16vec3(2_u16, 2_u16, 1_u16));
+    assert_eq!(v.yyy(), u16vec3(2_u16, 2_u16, 2_u16));
+    assert_eq!(v.yyz(), u16vec3(2_u16, 2_u16, 3_u16));
+    assert_eq!(v.yyw(), u16vec3(2_u16, 2_u16, 4_u16));
+    assert_eq!(v.yzx(), u16vec3(2_u16, 3_u16, 1_u16));
+    assert_eq!(v.yzy(), u16vec3(2_u16, 3_u16, 2_u16));
+    assert_eq!(v.yzz(), u16vec3(2_u16, 3_u16, 3_u16));
+    assert_eq!(v.yzw(), u16vec3(2_u16, 3_u16, 4_u16));
+    assert_eq!(v.ywx(), u16vec3(2_u16, 4_u16, 1_u16));
+    assert_eq!(v.ywy(), u16vec3(2_u16, 4_u16, 2_u16));
+    assert_eq!(v.ywz(), u16vec3(2_u16, 4_u16, 3_u16));
+    assert_eq!(v.yww(), u16vec3(2_u16, 4_u16, 4_u16));
+    assert_eq!(v.zxx(), u16vec3(3_u16, 1_u16, 1_u16));
+    assert_eq!(v.zxy(), u16vec3(3_u16, 1_u16, 2_u16));
+    assert_eq!(v.zxz(), u16vec3(3_u16, 1_u16, 3_u16));
+    assert_eq!(v.zxw(), u16vec3(3_u16, 1_u16, 4_u16));
+    assert_eq!(v.zyx(), u16vec3(3_u16, 2_u16, 1_u16));
+    assert_eq!(v.zyy(), u16vec3(3_u16, 2_u16, 2_u16));
+    assert_eq!(v.zyz(), u16vec3(3_u16, 2_u16, 3_u16));
+    assert_eq!(v.zyw(), u16vec3(3_u16, 2_u16, 4_u16));
+    assert_eq!(v.zzx(), u16vec3(3_u16, 3_u16, 1_u16));
+    assert_eq!(v.zzy(), u16vec3(3_u16, 3_u16, 2_u16));
+    assert_eq!(v.zzz(), u16vec3(3_u16, 3_u16, 3_u16));
+    assert_eq!(v.zzw(), u16vec3(3_u16, 3_u16, 4_u16));
+    assert_eq!(v.zwx(), u16vec3(3_u16, 4_u16, 1_u16));
+    assert_eq!(v.zwy(), u16vec3(3_u16, 4_u16, 2_u16));
+    assert_eq!(v.zwz(), u16vec3(3_u16, 4_u16, 3_u16));
+    assert_eq!(v.zww(), u16vec3(3_u16, 4_u16, 4_u16));
+    assert_eq!(v.wxx(), u16vec3(4_u16, 1_u16, 1_u16));
+    assert_eq!(v.wxy(), u16vec3(4_u16, 1_u16, 2_u16));
+    assert_eq!(v.wxz(), u16vec3(4_u16, 1_u16, 3_u16));
+    assert_eq!(v.wxw(), u16vec3(4_u16, 1_u16, 4_u16));
+    assert_eq!(v.wyx(), u16vec3(4_u16, 2_u16, 1_u16));
+    assert_eq!(v.wyy(), u16vec3(4_u16, 2_u16, 2_u16));
+    assert_eq!(v.wyz(), u16vec3(4_u16, 2_u16, 3_u16));
+    assert_eq!(v.wyw(), u16vec3(4_u16, 2_u16, 4_u16));
+    assert_eq!(v.wzx(), u16vec3(4_u16, 3_u16, 1_u16));
+    assert_eq!(v.wzy(), u16vec3(4_u16, 3_u16, 2_u16));
+    assert_eq!(v.wzz(), u16vec3(4_u16, 3_u16, 3_u16));
+    assert_eq!(v.wzw(), u16vec3(4_u16, 3_u16, 4_u16));
+    assert_eq!(v.wwx(), u16vec3(4_u16, 4_u16, 1_u16));
+    assert_eq!(v.wwy(), u16vec3(4_u16, 4_u16, 2_u16));
+    assert_eq!(v.wwz(), u16vec3(4_u16, 4_u16, 3_u16));
+    assert_eq!(v.www(), u16vec3(4_u16, 4_u16, 4_u16));
+    assert_eq!(v.xx(), u16vec2(1_u16, 1_u16));
+    assert_eq!(v.xy(), u16vec2(1_u16, 2_u16));
+    assert_eq!(v.xz(), u16vec2(1_u16, 3_u16));
+    assert_eq!(v.xw(), u16vec2(1_u16, 4_u16));
+    assert_eq!(v.yx(), u16vec2(2_u16, 1_u16));
+    assert_eq!(v.yy(), u16vec2(2_u16, 2_u16));
+    assert_eq!(v.yz(), u16vec2(2_u16, 3_u16));
+    assert_eq!(v.yw(), u16vec2(2_u16, 4_u16));
+    assert_eq!(v.zx(), u16vec2(3_u16, 1_u16));
+    assert_eq!(v.zy(), u16vec2(3_u16, 2_u16));
+    assert_eq!(v.zz(), u16vec2(3_u16, 3_u16));
+    assert_eq!(v.zw(), u16vec2(3_u16, 4_u16));
+    assert_eq!(v.wx(), u16vec2(4_u16, 1_u16));
+    assert_eq!(v.wy(), u16vec2(4_u16, 2_u16));
+    assert_eq!(v.wz(), u16vec2(4_u16, 3_u16));
+    assert_eq!(v.ww(), u16vec2(4_u16, 4_u16));
+});
+
+glam_test!(test_u16vec3_swizzles, {
+    let v = u16vec3(1_u16, 2_u16, 3_u16);
+    assert_eq!(v, v.xyz());
+    assert_eq!(v.xxxx(), u16vec4(1_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.xxxy(), u16vec4(1_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.xxxz(), u16vec4(1_u16, 1_u16, 1_u16, 3_u16));
+    assert_eq!(v.xxyx(), u16vec4(1_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.xxyy(), u16vec4(1_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.xxyz(), u16vec4(1_u16, 1_u16, 2_u16, 3_u16));
+    assert_eq!(v.xxzx(), u16vec4(1_u16, 1_u16, 3_u16, 1_u16));
+    assert_eq!(v.xxzy(), u16vec4(1_u16, 1_u16, 3_u16, 2_u16));
+    assert_eq!(v.xxzz(), u16vec4(1_u16, 1_u16, 3_u16, 3_u16));
+    assert_eq!(v.xyxx(), u16vec4(1_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.xyxy(), u16vec4(1_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.xyxz(), u16vec4(1_u16, 2_u16, 1_u16, 3_u16));
+    assert_eq!(v.xyyx(), u16vec4(1_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.xyyy(), u16vec4(1_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.xyyz(), u16vec4(1_u16, 2_u16, 2_u16, 3_u16));
+    assert_eq!(v.xyzx(), u16vec4(1_u16, 2_u16, 3_u16, 1_u16));
+    assert_eq!(v.xyzy(), u16vec4(1_u16, 2_u16, 3_u16, 2_u16));
+    assert_eq!(v.xyzz(), u16vec4(1_u16, 2_u16, 3_u16, 3_u16));
+    assert_eq!(v.xzxx(), u16vec4(1_u16, 3_u16, 1_u16, 1_u16));
+    assert_eq!(v.xzxy(), u16vec4(1_u16, 3_u16, 1_u16, 2_u16));
+    assert_eq!(v.xzxz(), u16vec4(1_u16, 3_u16, 1_u16, 3_u16));
+    assert_eq!(v.xzyx(), u16vec4(1_u16, 3_u16, 2_u16, 1_u16));
+    assert_eq!(v.xzyy(), u16vec4(1_u16, 3_u16, 2_u16, 2_u16));
+    assert_eq!(v.xzyz(), u16vec4(1_u16, 3_u16, 2_u16, 3_u16));
+    assert_eq!(v.xzzx(), u16vec4(1_u16, 3_u16, 3_u16, 1_u16));
+    assert_eq!(v.xzzy(), u16vec4(1_u16, 3_u16, 3_u16, 2_u16));
+    assert_eq!(v.xzzz(), u16vec4(1_u16, 3_u16, 3_u16, 3_u16));
+    assert_eq!(v.yxxx(), u16vec4(2_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.yxxy(), u16vec4(2_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.yxxz(), u16vec4(2_u16, 1_u16, 1_u16, 3_u16));
+    assert_eq!(v.yxyx(), u16vec4(2_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.yxyy(), u16vec4(2_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.yxyz(), u16vec4(2_u16, 1_u16, 2_u16, 3_u16));
+    assert_eq!(v.yxzx(), u16vec4(2_u16, 1_u16, 3_u16, 1_u16));
+    assert_eq!(v.yxzy(), u16vec4(2_u16, 1_u16, 3_u16, 2_u16));
+    assert_eq!(v.yxzz(), u16vec4(2_u16, 1_u16, 3_u16, 3_u16));
+    assert_eq!(v.yyxx(), u16vec4(2_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.yyxy(), u16vec4(2_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.yyxz(), u16vec4(2_u16, 2_u16, 1_u16, 3_u16));
+    assert_eq!(v.yyyx(), u16vec4(2_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.yyyy(), u16vec4(2_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.yyyz(), u16vec4(2_u16, 2_u16, 2_u16, 3_u16));
+    assert_eq!(v.yyzx(), u16vec4(2_u16, 2_u16, 3_u16, 1_u16));
+    assert_eq!(v.yyzy(), u16vec4(2_u16, 2_u16, 3_u16, 2_u16));
+    assert_eq!(v.yyzz(), u16vec4(2_u16, 2_u16, 3_u16, 3_u16));
+    assert_eq!(v.yzxx(), u16vec4(2_u16, 3_u16, 1_u16, 1_u16));
+    assert_eq!(v.yzxy(), u16vec4(2_u16, 3_u16, 1_u16, 2_u16));
+    assert_eq!(v.yzxz(), u16vec4(2_u16, 3_u16, 1_u16, 3_u16));
+    assert_eq!(v.yzyx(), u16vec4(2_u16, 3_u16, 2_u16, 1_u16));
+    assert_eq!(v.yzyy(), u16vec4(2_u16, 3_u16, 2_u16, 2_u16));
+    assert_eq!(v.yzyz(), u16vec4(2_u16, 3_u16, 2_u16, 3_u16));
+    assert_eq!(v.yzzx(), u16vec4(2_u16, 3_u16, 3_u16, 1_u16));
+    assert_eq!(v.yzzy(), u16vec4(2_u16, 3_u16, 3_u16, 2_u16));
+    assert_eq!(v.yzzz(), u16vec4(2_u16, 3_u16, 3_u16, 3_u16));
+    assert_eq!(v.zxxx(), u16vec4(3_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.zxxy(), u16vec4(3_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.zxxz(), u16vec4(3_u16, 1_u16, 1_u16, 3_u16));
+    assert_eq!(v.zxyx(), u16vec4(3_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.zxyy(), u16vec4(3_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.zxyz(), u16vec4(3_u16, 1_u16, 2_u16, 3_u16));
+    assert_eq!(v.zxzx(), u16vec4(3_u16, 1_u16, 3_u16, 1_u16));
+    assert_eq!(v.zxzy(), u16vec4(3_u16, 1_u16, 3_u16, 2_u16));
+    assert_eq!(v.zxzz(), u16vec4(3_u16, 1_u16, 3_u16, 3_u16));
+    assert_eq!(v.zyxx(), u16vec4(3_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.zyxy(), u16vec4(3_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.zyxz(), u16vec4(3_u16, 2_u16, 1_u16, 3_u16));
+    assert_eq!(v.zyyx(), u16vec4(3_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.zyyy(), u16vec4(3_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.zyyz(), u16vec4(3_u16, 2_u16, 2_u16, 3_u16));
+    assert_eq!(v.zyzx(), u16vec4(3_u16, 2_u16, 3_u16, 1_u16));
+    assert_eq!(v.zyzy(), u16vec4(3_u16, 2_u16, 3_u16, 2_u16));
+    assert_eq!(v.zyzz(), u16vec4(3_u16, 2_u16, 3_u16, 3_u16));
+    assert_eq!(v.zzxx(), u16vec4(3_u16, 3_u16, 1_u16, 1_u16));
+    assert_eq!(v.zzxy(), u16vec4(3_u16, 3_u16, 1_u16, 2_u16));
+    assert_eq!(v.zzxz(), u16vec4(3_u16, 3_u16, 1_u16, 3_u16));
+    assert_eq!(v.zzyx(), u16vec4(3_u16, 3_u16, 2_u16, 1_u16));
+    assert_eq!(v.zzyy(), u16vec4(3_u16, 3_u16, 2_u16, 2_u16));
+    assert_eq!(v.zzyz(), u16vec4(3_u16, 3_u16, 2_u16, 3_u16));
+    assert_eq!(v.zzzx(), u16vec4(3_u16, 3_u16, 3_u16, 1_u16));
+    assert_eq!(v.zzzy(), u16vec4(3_u16, 3_u16, 3_u16, 2_u16));
+    assert_eq!(v.zzzz(), u16vec4(3_u16, 3_u16, 3_u16, 3_u16));
+    assert_eq!(v.xxx(), u16vec3(1_u16, 1_u16, 1_u16));
+    assert_eq!(v.xxy(), u16vec3(1_u16, 1_u16, 2_u16));
+    assert_eq!(v.xxz(), u16vec3(1_u16, 1_u16, 3_u16));
+    assert_eq!(v.xyx(), u16vec3(1_u16, 2_u16, 1_u16));
+    assert_eq!(v.xyy(), u16vec3(1_u16, 2_u16, 2_u16));
+    assert_eq!(v.xzx(), u16vec3(1_u16, 3_u16, 1_u16));
+    assert_eq!(v.xzy(), u16vec3(1_u16, 3_u16, 2_u16));
+    assert_eq!(v.xzz(), u16vec3(1_u16, 3_u16, 3_u16));
+    assert_eq!(v.yxx(), u16vec3(2_u16, 1_u16, 1_u16));
+    assert_eq!(v.yxy(), u16vec3(2_u16, 1_u16, 2_u16));
+    assert_eq!(v.yxz(), u16vec3(2_u16, 1_u16, 3_u16));
+    assert_eq!(v.yyx(), u16vec3(2_u16, 2_u16, 1_u16));
+    assert_eq!(v.yyy(), u16vec3(2_u16, 2_u16, 2_u16));
+    assert_eq!(v.yyz(), u16vec3(2_u16, 2_u16, 3_u16));
+    assert_eq!(v.yzx(), u16vec3(2_u16, 3_u16, 1_u16));
+    assert_eq!(v.yzy(), u16vec3(2_u16, 3_u16, 2_u16));
+    assert_eq!(v.yzz(), u16vec3(2_u16, 3_u16, 3_u16));
+    assert_eq!(v.zxx(), u16vec3(3_u16, 1_u16, 1_u16));
+    assert_eq!(v.zxy(), u16vec3(3_u16, 1_u16, 2_u16));
+    assert_eq!(v.zxz(), u16vec3(3_u16, 1_u16, 3_u16));
+    assert_eq!(v.zyx(), u16vec3(3_u16, 2_u16, 1_u16));
+    assert_eq!(v.zyy(), u16vec3(3_u16, 2_u16, 2_u16));
+    assert_eq!(v.zyz(), u16vec3(3_u16, 2_u16, 3_u16));
+    assert_eq!(v.zzx(), u16vec3(3_u16, 3_u16, 1_u16));
+    assert_eq!(v.zzy(), u16vec3(3_u16, 3_u16, 2_u16));
+    assert_eq!(v.zzz(), u16vec3(3_u16, 3_u16, 3_u16));
+    assert_eq!(v.xx(), u16vec2(1_u16, 1_u16));
+    assert_eq!(v.xy(), u16vec2(1_u16, 2_u16));
+    assert_eq!(v.xz(), u16vec2(1_u16, 3_u16));
+    assert_eq!(v.yx(), u16vec2(2_u16, 1_u16));
+    assert_eq!(v.yy(), u16vec2(2_u16, 2_u16));
+    assert_eq!(v.yz(), u16vec2(2_u16, 3_u16));
+    assert_eq!(v.zx(), u16vec2(3_u16, 1_u16));
+    assert_eq!(v.zy(), u16vec2(3_u16, 2_u16));
+    assert_eq!(v.zz(), u16vec2(3_u16, 3_u16));
+});
+
+glam_test!(test_u16vec2_swizzles, {
+    let v = u16vec2(1_u16, 2_u16);
+    assert_eq!(v, v.xy());
+    assert_eq!(v.xxxx(), u16vec4(1_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.xxxy(), u16vec4(1_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.xxyx(), u16vec4(1_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.xxyy(), u16vec4(1_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.xyxx(), u16vec4(1_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.xyxy(), u16vec4(1_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.xyyx(), u16vec4(1_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.xyyy(), u16vec4(1_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.yxxx(), u16vec4(2_u16, 1_u16, 1_u16, 1_u16));
+    assert_eq!(v.yxxy(), u16vec4(2_u16, 1_u16, 1_u16, 2_u16));
+    assert_eq!(v.yxyx(), u16vec4(2_u16, 1_u16, 2_u16, 1_u16));
+    assert_eq!(v.yxyy(), u16vec4(2_u16, 1_u16, 2_u16, 2_u16));
+    assert_eq!(v.yyxx(), u16vec4(2_u16, 2_u16, 1_u16, 1_u16));
+    assert_eq!(v.yyxy(), u16vec4(2_u16, 2_u16, 1_u16, 2_u16));
+    assert_eq!(v.yyyx(), u16vec4(2_u16, 2_u16, 2_u16, 1_u16));
+    assert_eq!(v.yyyy(), u16vec4(2_u16, 2_u16, 2_u16, 2_u16));
+    assert_eq!(v.xxx(), u16vec3(1_u16, 1_u16, 1_u16));
+    assert_eq!(v.xxy(), u16vec3(1_u16, 1_u16, 2_u16));
+    assert_eq!(v.xyx(), u16vec3(1_u16, 2_u16, 1_u16));
+    assert_eq!(v.xyy(), u16vec3(1_u16, 2_u16, 2_u16));
+    assert_eq!(v.yxx(), u16vec3(2_u16, 1_u16, 1_u16));
+    assert_eq!(v.yxy(), u16vec3(2_u16, 1_u16, 2_u16));
+    assert_eq!(v.yyx(), u16vec3(2_u16, 2_u16, 1_u16));
+    assert_eq!(v.yyy(), u16vec3(2_u16, 2_u16, 2_u16));
+    assert_eq!(v.xx(), u16vec2(1_u16, 1_u16));
+    assert_eq!(v.yx(), u16vec2(2_u16, 1_u16));
+    assert_eq!(v.yy(), u16vec2(2_u16, 2_u16));
+});
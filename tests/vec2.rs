@@ -5,6 +5,15 @@ mod support;
 
 macro_rules! impl_vec2_tests {
     ($t:ty, $new:ident, $vec2:ident, $vec3:ident, $mask:ident) => {
+        glam_test!(test_div_rem_euclid, {
+            let a = $new(7 as $t, 8 as $t);
+            let b = $new(2 as $t, 3 as $t);
+            assert_eq!($new(3 as $t, 2 as $t), a.div_euclid(b));
+            assert_eq!($new(1 as $t, 2 as $t), a.rem_euclid(b));
+            assert_eq!($new(1 as $t, 2 as $t), a.div_euclid_scalar(4 as $t));
+            assert_eq!($new(3 as $t, 0 as $t), a.rem_euclid_scalar(4 as $t));
+        });
+
         glam_test!(test_const, {
             const V0: $vec2 = $vec2::splat(1 as $t);
             const V1: $vec2 = $vec2::new(1 as $t, 2 as $t);
@@ -473,6 +482,15 @@ macro_rules! impl_vec2_signed_tests {
     ($t:ident, $new:ident, $vec2:ident, $vec3:ident, $mask:ident) => {
         impl_vec2_tests!($t, $new, $vec2, $vec3, $mask);
 
+        glam_test!(test_div_rem_euclid_signed, {
+            let a = $new(-7 as $t, 7 as $t);
+            let b = $new(2 as $t, -2 as $t);
+            assert_eq!($new(-4 as $t, -3 as $t), a.div_euclid(b));
+            assert_eq!($new(1 as $t, 1 as $t), a.rem_euclid(b));
+            assert_eq!($new(3 as $t, -2 as $t), a.div_euclid_scalar(-3 as $t));
+            assert_eq!($new(2 as $t, 1 as $t), a.rem_euclid_scalar(-3 as $t));
+        });
+
         glam_test!(test_dot_signed, {
             let x = $new(1 as $t, 0 as $t);
             let y = $new(0 as $t, 1 as $t);
@@ -706,6 +724,11 @@ macro_rules! impl_vec2_float_tests {
             );
         });
 
+        glam_test!(test_fract_negative, {
+            let a = $new(1.35, -1.5);
+            assert_approx_eq!(a.fract(), $new(0.35, 0.5));
+        });
+
         glam_test!(test_ceil, {
             assert_eq!($vec2::new(1.35, -1.5).ceil(), $vec2::new(2.0, -1.0));
             assert_eq!(
@@ -960,6 +983,111 @@ macro_rules! impl_vec2_bit_op_tests {
     };
 }
 
+macro_rules! impl_vec2_floor_div_tests {
+    ($t:ident, $new:ident) => {
+        glam_test!(test_floor_div, {
+            let a = $new(-7 as $t, 7 as $t);
+            let b = $new(2 as $t, -2 as $t);
+            assert_eq!($new(-4 as $t, -4 as $t), a.floor_div(b));
+            assert_eq!($new(-3 as $t, 2 as $t), a.floor_div_scalar(3 as $t));
+            assert_eq!(a.div_euclid(b.abs()), a.floor_div(b.abs()));
+        });
+    };
+}
+
+macro_rules! impl_vec2_overflow_tests {
+    ($t:ty, $vec2:ident, $mask:ident) => {
+        glam_test!(test_wrapping_ops, {
+            let a = $vec2::new(<$t>::MAX, 1);
+            assert_eq!($vec2::new(<$t>::MIN, 2), a.wrapping_add($vec2::splat(1)));
+            assert_eq!(
+                $vec2::new(<$t>::MAX, 0),
+                $vec2::new(<$t>::MIN, 1).wrapping_sub($vec2::splat(1))
+            );
+            assert_eq!(
+                $vec2::new(<$t>::MAX.wrapping_mul(2), 2),
+                a.wrapping_mul($vec2::splat(2))
+            );
+            assert_eq!($vec2::new(<$t>::MAX, 1), a.wrapping_div($vec2::splat(1)));
+            should_panic!({ $vec2::splat(1).wrapping_div($vec2::ZERO) });
+        });
+
+        glam_test!(test_saturating_ops, {
+            let a = $vec2::new(<$t>::MAX, 1);
+            assert_eq!($vec2::new(<$t>::MAX, 2), a.saturating_add($vec2::splat(1)));
+            assert_eq!(
+                $vec2::new(<$t>::MIN, 0),
+                $vec2::new(<$t>::MIN, 1).saturating_sub($vec2::splat(1))
+            );
+            assert_eq!($vec2::new(<$t>::MAX, 2), a.saturating_mul($vec2::splat(2)));
+            assert_eq!($vec2::new(<$t>::MAX, 1), a.saturating_div($vec2::splat(1)));
+            should_panic!({ $vec2::splat(1).saturating_div($vec2::ZERO) });
+        });
+
+        glam_test!(test_checked_ops, {
+            let a = $vec2::new(<$t>::MAX, 1);
+            assert_eq!(None, a.checked_add($vec2::splat(1)));
+            assert_eq!(
+                Some($vec2::splat(3)),
+                $vec2::splat(1).checked_add($vec2::splat(2))
+            );
+            assert_eq!(None, $vec2::new(<$t>::MIN, 1).checked_sub($vec2::splat(1)));
+            assert_eq!(
+                Some($vec2::splat(1)),
+                $vec2::splat(3).checked_sub($vec2::splat(2))
+            );
+            assert_eq!(None, a.checked_mul($vec2::splat(2)));
+            assert_eq!(
+                Some($vec2::splat(6)),
+                $vec2::splat(3).checked_mul($vec2::splat(2))
+            );
+            assert_eq!(None, $vec2::splat(1).checked_div($vec2::new(1, 0)));
+            assert_eq!(
+                Some($vec2::splat(3)),
+                $vec2::splat(6).checked_div($vec2::splat(2))
+            );
+        });
+
+        glam_test!(test_overflowing_ops, {
+            let a = $vec2::new(<$t>::MAX, 1);
+            assert_eq!(
+                ($vec2::new(<$t>::MIN, 2), $mask::new(true, false)),
+                a.overflowing_add($vec2::splat(1))
+            );
+            assert_eq!(
+                ($vec2::new(<$t>::MAX, 0), $mask::new(true, false)),
+                $vec2::new(<$t>::MIN, 1).overflowing_sub($vec2::splat(1))
+            );
+            assert_eq!(
+                (
+                    $vec2::new(<$t>::MAX.wrapping_mul(2), 2),
+                    $mask::new(true, false)
+                ),
+                a.overflowing_mul($vec2::splat(2))
+            );
+            assert_eq!(
+                ($vec2::splat(3), $mask::FALSE),
+                $vec2::splat(6).overflowing_div($vec2::splat(2))
+            );
+        });
+    };
+}
+
+macro_rules! impl_vec2_add_signed_tests {
+    ($t:ty, $vec2:ident, $ivec2:ident) => {
+        glam_test!(test_add_signed, {
+            let a = $vec2::new(<$t>::MAX, 1);
+            let b = $ivec2::new(1, -2);
+            assert_eq!($vec2::new(0, <$t>::MAX), a.wrapping_add_signed(b));
+            assert_eq!($vec2::new(<$t>::MAX, 0), a.saturating_add_signed(b));
+            assert_eq!(
+                $vec2::new(<$t>::MAX, 3),
+                $vec2::new(<$t>::MAX - 1, 5).saturating_add_signed(b)
+            );
+        });
+    };
+}
+
 mod vec2 {
     use glam::{vec2, BVec2, Vec2, Vec3};
 
@@ -975,7 +1103,7 @@ mod vec2 {
     });
 
     glam_test!(test_as, {
-        use glam::{DVec2, I64Vec2, IVec2, U64Vec2, UVec2};
+        use glam::{DVec2, I16Vec2, I64Vec2, I8Vec2, IVec2, U16Vec2, U64Vec2, U8Vec2, UVec2};
         assert_eq!(DVec2::new(-1.0, -2.0), Vec2::new(-1.0, -2.0).as_dvec2());
         assert_eq!(IVec2::new(-1, -2), Vec2::new(-1.0, -2.0).as_ivec2());
         assert_eq!(UVec2::new(1, 2), Vec2::new(1.0, 2.0).as_uvec2());
@@ -1011,6 +1139,21 @@ mod vec2 {
         assert_eq!(Vec2::new(1.0, 2.0), U64Vec2::new(1, 2).as_vec2());
         assert_eq!(I64Vec2::new(1, 2), U64Vec2::new(1, 2).as_i64vec2());
         assert_eq!(UVec2::new(1, 2), U64Vec2::new(1, 2).as_uvec2());
+
+        assert_eq!(I8Vec2::new(-1, -2), Vec2::new(-1.0, -2.0).as_i8vec2());
+        assert_eq!(U8Vec2::new(1, 2), Vec2::new(1.0, 2.0).as_u8vec2());
+        assert_eq!(I16Vec2::new(-1, -2), DVec2::new(-1.0, -2.0).as_i16vec2());
+        assert_eq!(U16Vec2::new(1, 2), IVec2::new(1, 2).as_u16vec2());
+        assert_eq!(U8Vec2::new(1, 2), U64Vec2::new(1, 2).as_u8vec2());
+
+        assert_eq!(Vec2::new(-1.0, -2.0), I8Vec2::new(-1, -2).as_vec2());
+        assert_eq!(I16Vec2::new(-1, -2), I8Vec2::new(-1, -2).as_i16vec2());
+        assert_eq!(DVec2::new(1.0, 2.0), U8Vec2::new(1, 2).as_dvec2());
+        assert_eq!(U16Vec2::new(1, 2), U8Vec2::new(1, 2).as_u16vec2());
+        assert_eq!(IVec2::new(-1, -2), I16Vec2::new(-1, -2).as_ivec2());
+        assert_eq!(I8Vec2::new(-1, -2), I16Vec2::new(-1, -2).as_i8vec2());
+        assert_eq!(U64Vec2::new(1, 2), U16Vec2::new(1, 2).as_u64vec2());
+        assert_eq!(I64Vec2::new(1, 2), U16Vec2::new(1, 2).as_i64vec2());
     });
 
     impl_vec2_float_tests!(f32, vec2, Vec2, Vec3, BVec2);
@@ -1022,10 +1165,22 @@ mod dvec2 {
     glam_test!(test_align, {
         use core::mem;
         assert_eq!(16, mem::size_of::<DVec2>());
-        #[cfg(not(feature = "cuda"))]
+        #[cfg(not(any(
+            feature = "cuda",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        )))]
         assert_eq!(mem::align_of::<f64>(), mem::align_of::<DVec2>());
         #[cfg(feature = "cuda")]
         assert_eq!(16, mem::align_of::<DVec2>());
+        #[cfg(all(
+            not(feature = "cuda"),
+            target_feature = "avx",
+            not(any(feature = "core-simd", feature = "scalar-math"))
+        ))]
+        assert_eq!(16, mem::align_of::<DVec2>());
         assert_eq!(2, mem::size_of::<BVec2>());
         assert_eq!(1, mem::align_of::<BVec2>());
     });
@@ -1055,6 +1210,9 @@ mod ivec2 {
 
     impl_vec2_scalar_bit_op_tests!(IVec2, -2, 2);
     impl_vec2_bit_op_tests!(IVec2, -2, 2);
+
+    impl_vec2_overflow_tests!(i32, IVec2, BVec2);
+    impl_vec2_floor_div_tests!(i32, ivec2);
 }
 
 mod uvec2 {
@@ -1079,6 +1237,9 @@ mod uvec2 {
 
     impl_vec2_scalar_bit_op_tests!(UVec2, 0, 2);
     impl_vec2_bit_op_tests!(UVec2, 0, 2);
+
+    impl_vec2_overflow_tests!(u32, UVec2, BVec2);
+    impl_vec2_add_signed_tests!(u32, UVec2, IVec2);
 }
 
 mod i64vec2 {
@@ -1103,10 +1264,13 @@ mod i64vec2 {
 
     impl_vec2_scalar_bit_op_tests!(I64Vec2, -2, 2);
     impl_vec2_bit_op_tests!(I64Vec2, -2, 2);
+
+    impl_vec2_overflow_tests!(i64, I64Vec2, BVec2);
+    impl_vec2_floor_div_tests!(i64, i64vec2);
 }
 
 mod u64vec2 {
-    use glam::{u64vec2, BVec2, IVec2, U64Vec2, U64Vec3, UVec2};
+    use glam::{u64vec2, BVec2, I64Vec2, IVec2, U64Vec2, U64Vec3, UVec2};
 
     glam_test!(test_align, {
         use core::mem;
@@ -1127,4 +1291,115 @@ mod u64vec2 {
 
     impl_vec2_scalar_bit_op_tests!(U64Vec2, 0, 2);
     impl_vec2_bit_op_tests!(U64Vec2, 0, 2);
+
+    impl_vec2_overflow_tests!(u64, U64Vec2, BVec2);
+    impl_vec2_add_signed_tests!(u64, U64Vec2, I64Vec2);
+}
+
+mod i8vec2 {
+    use glam::{i8vec2, BVec2, I8Vec2, I8Vec3, IVec2, UVec2};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(2, mem::size_of::<I8Vec2>());
+        #[cfg(not(feature = "cuda"))]
+        assert_eq!(1, mem::align_of::<I8Vec2>());
+        #[cfg(feature = "cuda")]
+        assert_eq!(2, mem::align_of::<I8Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    impl_vec2_signed_tests!(i8, i8vec2, I8Vec2, I8Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(i8, i8vec2);
+
+    impl_vec2_scalar_shift_op_tests!(I8Vec2, -2, 2);
+    impl_vec2_shift_op_tests!(I8Vec2);
+
+    impl_vec2_scalar_bit_op_tests!(I8Vec2, -2, 2);
+    impl_vec2_bit_op_tests!(I8Vec2, -2, 2);
+
+    impl_vec2_overflow_tests!(i8, I8Vec2, BVec2);
+    impl_vec2_floor_div_tests!(i8, i8vec2);
+}
+
+mod u8vec2 {
+    use glam::{u8vec2, BVec2, I8Vec2, IVec2, U8Vec2, U8Vec3, UVec2};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(2, mem::size_of::<U8Vec2>());
+        #[cfg(not(feature = "cuda"))]
+        assert_eq!(1, mem::align_of::<U8Vec2>());
+        #[cfg(feature = "cuda")]
+        assert_eq!(2, mem::align_of::<U8Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    impl_vec2_tests!(u8, u8vec2, U8Vec2, U8Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(u8, u8vec2);
+
+    impl_vec2_scalar_shift_op_tests!(U8Vec2, 0, 2);
+    impl_vec2_shift_op_tests!(U8Vec2);
+
+    impl_vec2_scalar_bit_op_tests!(U8Vec2, 0, 2);
+    impl_vec2_bit_op_tests!(U8Vec2, 0, 2);
+
+    impl_vec2_overflow_tests!(u8, U8Vec2, BVec2);
+    impl_vec2_add_signed_tests!(u8, U8Vec2, I8Vec2);
+}
+
+mod i16vec2 {
+    use glam::{i16vec2, BVec2, I16Vec2, I16Vec3, IVec2, UVec2};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(4, mem::size_of::<I16Vec2>());
+        #[cfg(not(feature = "cuda"))]
+        assert_eq!(2, mem::align_of::<I16Vec2>());
+        #[cfg(feature = "cuda")]
+        assert_eq!(4, mem::align_of::<I16Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    impl_vec2_signed_tests!(i16, i16vec2, I16Vec2, I16Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(i16, i16vec2);
+
+    impl_vec2_scalar_shift_op_tests!(I16Vec2, -2, 2);
+    impl_vec2_shift_op_tests!(I16Vec2);
+
+    impl_vec2_scalar_bit_op_tests!(I16Vec2, -2, 2);
+    impl_vec2_bit_op_tests!(I16Vec2, -2, 2);
+
+    impl_vec2_overflow_tests!(i16, I16Vec2, BVec2);
+    impl_vec2_floor_div_tests!(i16, i16vec2);
+}
+
+mod u16vec2 {
+    use glam::{u16vec2, BVec2, I16Vec2, IVec2, U16Vec2, U16Vec3, UVec2};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(4, mem::size_of::<U16Vec2>());
+        #[cfg(not(feature = "cuda"))]
+        assert_eq!(2, mem::align_of::<U16Vec2>());
+        #[cfg(feature = "cuda")]
+        assert_eq!(4, mem::align_of::<U16Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    impl_vec2_tests!(u16, u16vec2, U16Vec2, U16Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(u16, u16vec2);
+
+    impl_vec2_scalar_shift_op_tests!(U16Vec2, 0, 2);
+    impl_vec2_shift_op_tests!(U16Vec2);
+
+    impl_vec2_scalar_bit_op_tests!(U16Vec2, 0, 2);
+    impl_vec2_bit_op_tests!(U16Vec2, 0, 2);
+
+    impl_vec2_overflow_tests!(u16, U16Vec2, BVec2);
+    impl_vec2_add_signed_tests!(u16, U16Vec2, I16Vec2);
 }
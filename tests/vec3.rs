@@ -5,6 +5,21 @@ mod support;
 
 macro_rules! impl_vec3_tests {
     ($t:ident, $new:ident, $vec3:ident, $mask:ident) => {
+        glam_test!(test_div_rem_euclid, {
+            let a = $new(7 as $t, 8 as $t, 9 as $t);
+            let b = $new(2 as $t, 3 as $t, 4 as $t);
+            assert_eq!($new(3 as $t, 2 as $t, 2 as $t), a.div_euclid(b));
+            assert_eq!($new(1 as $t, 2 as $t, 1 as $t), a.rem_euclid(b));
+            assert_eq!(
+                $new(1 as $t, 2 as $t, 2 as $t),
+                a.div_euclid_scalar(4 as $t)
+            );
+            assert_eq!(
+                $new(3 as $t, 0 as $t, 1 as $t),
+                a.rem_euclid_scalar(4 as $t)
+            );
+        });
+
         glam_test!(test_const, {
             const V0: $vec3 = $vec3::splat(1 as $t);
             const V1: $vec3 = $vec3::new(1 as $t, 2 as $t, 3 as $t);
@@ -524,6 +539,21 @@ macro_rules! impl_vec3_signed_tests {
     ($t:ident, $new:ident, $vec3:ident, $mask:ident) => {
         impl_vec3_tests!($t, $new, $vec3, $mask);
 
+        glam_test!(test_div_rem_euclid_signed, {
+            let a = $new(-7 as $t, 7 as $t, -8 as $t);
+            let b = $new(2 as $t, -2 as $t, 3 as $t);
+            assert_eq!($new(-4 as $t, -3 as $t, -3 as $t), a.div_euclid(b));
+            assert_eq!($new(1 as $t, 1 as $t, 1 as $t), a.rem_euclid(b));
+            assert_eq!(
+                $new(3 as $t, -2 as $t, 3 as $t),
+                a.div_euclid_scalar(-3 as $t)
+            );
+            assert_eq!(
+                $new(2 as $t, 1 as $t, 1 as $t),
+                a.rem_euclid_scalar(-3 as $t)
+            );
+        });
+
         glam_test!(test_neg, {
             let a = $new(1 as $t, 2 as $t, 3 as $t);
             assert_eq!((-1 as $t, -2 as $t, -3 as $t), (-a).into());
@@ -774,6 +804,11 @@ macro_rules! impl_vec3_float_tests {
             );
         });
 
+        glam_test!(test_fract_negative, {
+            let a = $new(1.35, -1.5, 2.25);
+            assert_approx_eq!(a.fract(), $new(0.35, 0.5, 0.25));
+        });
+
         glam_test!(test_ceil, {
             assert_eq!(
                 $vec3::new(1.35, 1.5, -1.5).ceil(),
@@ -1059,6 +1094,129 @@ macro_rules! impl_vec3_bit_op_tests {
     };
 }
 
+macro_rules! impl_vec3_floor_div_tests {
+    ($t:ident, $new:ident) => {
+        glam_test!(test_floor_div, {
+            let a = $new(-7 as $t, 7 as $t, -8 as $t);
+            let b = $new(2 as $t, -2 as $t, 3 as $t);
+            assert_eq!($new(-4 as $t, -4 as $t, -3 as $t), a.floor_div(b));
+            assert_eq!(
+                $new(-3 as $t, 2 as $t, -3 as $t),
+                a.floor_div_scalar(3 as $t)
+            );
+            assert_eq!(a.div_euclid(b.abs()), a.floor_div(b.abs()));
+        });
+    };
+}
+
+macro_rules! impl_vec3_overflow_tests {
+    ($t:ty, $vec3:ident, $mask:ident) => {
+        glam_test!(test_wrapping_ops, {
+            let a = $vec3::new(<$t>::MAX, 1, 1);
+            assert_eq!($vec3::new(<$t>::MIN, 2, 2), a.wrapping_add($vec3::splat(1)));
+            assert_eq!(
+                $vec3::new(<$t>::MAX, 0, 0),
+                $vec3::new(<$t>::MIN, 1, 1).wrapping_sub($vec3::splat(1))
+            );
+            assert_eq!(
+                $vec3::new(<$t>::MAX.wrapping_mul(2), 2, 2),
+                a.wrapping_mul($vec3::splat(2))
+            );
+            assert_eq!($vec3::new(<$t>::MAX, 1, 1), a.wrapping_div($vec3::splat(1)));
+            should_panic!({ $vec3::splat(1).wrapping_div($vec3::ZERO) });
+        });
+
+        glam_test!(test_saturating_ops, {
+            let a = $vec3::new(<$t>::MAX, 1, 1);
+            assert_eq!(
+                $vec3::new(<$t>::MAX, 2, 2),
+                a.saturating_add($vec3::splat(1))
+            );
+            assert_eq!(
+                $vec3::new(<$t>::MIN, 0, 0),
+                $vec3::new(<$t>::MIN, 1, 1).saturating_sub($vec3::splat(1))
+            );
+            assert_eq!(
+                $vec3::new(<$t>::MAX, 2, 2),
+                a.saturating_mul($vec3::splat(2))
+            );
+            assert_eq!(
+                $vec3::new(<$t>::MAX, 1, 1),
+                a.saturating_div($vec3::splat(1))
+            );
+            should_panic!({ $vec3::splat(1).saturating_div($vec3::ZERO) });
+        });
+
+        glam_test!(test_checked_ops, {
+            let a = $vec3::new(<$t>::MAX, 1, 1);
+            assert_eq!(None, a.checked_add($vec3::splat(1)));
+            assert_eq!(
+                Some($vec3::splat(3)),
+                $vec3::splat(1).checked_add($vec3::splat(2))
+            );
+            assert_eq!(
+                None,
+                $vec3::new(<$t>::MIN, 1, 1).checked_sub($vec3::splat(1))
+            );
+            assert_eq!(
+                Some($vec3::splat(1)),
+                $vec3::splat(3).checked_sub($vec3::splat(2))
+            );
+            assert_eq!(None, a.checked_mul($vec3::splat(2)));
+            assert_eq!(
+                Some($vec3::splat(6)),
+                $vec3::splat(3).checked_mul($vec3::splat(2))
+            );
+            assert_eq!(None, $vec3::splat(1).checked_div($vec3::new(1, 1, 0)));
+            assert_eq!(
+                Some($vec3::splat(3)),
+                $vec3::splat(6).checked_div($vec3::splat(2))
+            );
+        });
+
+        glam_test!(test_overflowing_ops, {
+            let a = $vec3::new(<$t>::MAX, 1, 1);
+            assert_eq!(
+                ($vec3::new(<$t>::MIN, 2, 2), $mask::new(true, false, false)),
+                a.overflowing_add($vec3::splat(1))
+            );
+            assert_eq!(
+                ($vec3::new(<$t>::MAX, 0, 0), $mask::new(true, false, false)),
+                $vec3::new(<$t>::MIN, 1, 1).overflowing_sub($vec3::splat(1))
+            );
+            assert_eq!(
+                (
+                    $vec3::new(<$t>::MAX.wrapping_mul(2), 2, 2),
+                    $mask::new(true, false, false)
+                ),
+                a.overflowing_mul($vec3::splat(2))
+            );
+            assert_eq!(
+                ($vec3::splat(3), $mask::FALSE),
+                $vec3::splat(6).overflowing_div($vec3::splat(2))
+            );
+        });
+    };
+}
+
+macro_rules! impl_vec3_add_signed_tests {
+    ($t:ty, $vec3:ident, $ivec3:ident) => {
+        glam_test!(test_add_signed, {
+            let a = $vec3::new(<$t>::MAX, 1, 1);
+            let b = $ivec3::new(1, -2, -2);
+            assert_eq!(
+                $vec3::new(0, <$t>::MAX, <$t>::MAX),
+                a.wrapping_add_signed(b)
+            );
+            assert_eq!($vec3::new(<$t>::MAX, 0, 0), a.saturating_add_signed(b));
+            assert_eq!(
+                $vec3::new(<$t>::MAX, 3, 3),
+                $vec3::new(<$t>::MAX - 1, 5, 5).saturating_add_signed(b)
+            );
+        });
+    };
+}
+
 mod vec3 {
     use glam::{vec3, BVec3, Vec3};
 
@@ -1071,7 +1229,9 @@ mod vec3 {
     });
 
     glam_test!(test_as, {
-        use glam::{DVec3, I64Vec3, IVec3, U64Vec3, UVec3, Vec3A};
+        use glam::{
+            DVec3, I16Vec3, I64Vec3, I8Vec3, IVec3, U16Vec3, U64Vec3, U8Vec3, UVec3, Vec3A,
+        };
         assert_eq!(
             DVec3::new(-1.0, -2.0, -3.0),
             Vec3::new(-1.0, -2.0, -3.0).as_dvec3()
@@ -1172,6 +1332,36 @@ mod vec3 {
         assert_eq!(UVec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_uvec3());
         assert_eq!(Vec3::new(1.0, 2.0, 3.0), U64Vec3::new(1, 2, 3).as_vec3());
         assert_eq!(Vec3A::new(1.0, 2.0, 3.0), U64Vec3::new(1, 2, 3).as_vec3a());
+
+        assert_eq!(
+            I8Vec3::new(-1, -2, -3),
+            Vec3::new(-1.0, -2.0, -3.0).as_i8vec3()
+        );
+        assert_eq!(U8Vec3::new(1, 2, 3), Vec3::new(1.0, 2.0, 3.0).as_u8vec3());
+        assert_eq!(
+            I16Vec3::new(-1, -2, -3),
+            DVec3::new(-1.0, -2.0, -3.0).as_i16vec3()
+        );
+        assert_eq!(U16Vec3::new(1, 2, 3), IVec3::new(1, 2, 3).as_u16vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_u8vec3());
+
+        assert_eq!(
+            Vec3::new(-1.0, -2.0, -3.0),
+            I8Vec3::new(-1, -2, -3).as_vec3()
+        );
+        assert_eq!(
+            I16Vec3::new(-1, -2, -3),
+            I8Vec3::new(-1, -2, -3).as_i16vec3()
+        );
+        assert_eq!(DVec3::new(1.0, 2.0, 3.0), U8Vec3::new(1, 2, 3).as_dvec3());
+        assert_eq!(U16Vec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_u16vec3());
+        assert_eq!(IVec3::new(-1, -2, -3), I16Vec3::new(-1, -2, -3).as_ivec3());
+        assert_eq!(
+            I8Vec3::new(-1, -2, -3),
+            I16Vec3::new(-1, -2, -3).as_i8vec3()
+        );
+        assert_eq!(U64Vec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_u64vec3());
+        assert_eq!(I64Vec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_i64vec3());
     });
 
     impl_vec3_float_tests!(f32, vec3, Vec3, BVec3);
@@ -1280,6 +1470,9 @@ mod ivec3 {
 
     impl_vec3_scalar_bit_op_tests!(IVec3, -2, 2);
     impl_vec3_bit_op_tests!(IVec3, -2, 2);
+
+    impl_vec3_overflow_tests!(i32, IVec3, BVec3);
+    impl_vec3_floor_div_tests!(i32, ivec3);
 }
 
 mod uvec3 {
@@ -1301,6 +1494,9 @@ mod uvec3 {
 
     impl_vec3_scalar_bit_op_tests!(UVec3, 0, 2);
     impl_vec3_bit_op_tests!(UVec3, 0, 2);
+
+    impl_vec3_overflow_tests!(u32, UVec3, BVec3);
+    impl_vec3_add_signed_tests!(u32, UVec3, IVec3);
 }
 
 mod i64vec3 {
@@ -1322,10 +1518,13 @@ mod i64vec3 {
 
     impl_vec3_scalar_bit_op_tests!(I64Vec3, -2, 2);
     impl_vec3_bit_op_tests!(I64Vec3, -2, 2);
+
+    impl_vec3_overflow_tests!(i64, I64Vec3, BVec3);
+    impl_vec3_floor_div_tests!(i64, i64vec3);
 }
 
 mod u64vec3 {
-    use glam::{u64vec3, BVec3, IVec3, U64Vec3, UVec3};
+    use glam::{u64vec3, BVec3, I64Vec3, IVec3, U64Vec3, UVec3};
 
     glam_test!(test_align, {
         use std::mem;
@@ -1343,4 +1542,103 @@ mod u64vec3 {
 
     impl_vec3_scalar_bit_op_tests!(U64Vec3, 0, 2);
     impl_vec3_bit_op_tests!(U64Vec3, 0, 2);
+
+    impl_vec3_overflow_tests!(u64, U64Vec3, BVec3);
+    impl_vec3_add_signed_tests!(u64, U64Vec3, I64Vec3);
+}
+
+mod i8vec3 {
+    use glam::{i8vec3, BVec3, I8Vec3, IVec3, UVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(3, mem::size_of::<I8Vec3>());
+        assert_eq!(1, mem::align_of::<I8Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    impl_vec3_signed_tests!(i8, i8vec3, I8Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(i8, i8vec3);
+
+    impl_vec3_scalar_shift_op_tests!(I8Vec3, -2, 2);
+    impl_vec3_shift_op_tests!(I8Vec3);
+
+    impl_vec3_scalar_bit_op_tests!(I8Vec3, -2, 2);
+    impl_vec3_bit_op_tests!(I8Vec3, -2, 2);
+
+    impl_vec3_overflow_tests!(i8, I8Vec3, BVec3);
+    impl_vec3_floor_div_tests!(i8, i8vec3);
+}
+
+mod u8vec3 {
+    use glam::{u8vec3, BVec3, I8Vec3, IVec3, U8Vec3, UVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(3, mem::size_of::<U8Vec3>());
+        assert_eq!(1, mem::align_of::<U8Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    impl_vec3_tests!(u8, u8vec3, U8Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(u8, u8vec3);
+
+    impl_vec3_scalar_shift_op_tests!(U8Vec3, 0, 2);
+    impl_vec3_shift_op_tests!(U8Vec3);
+
+    impl_vec3_scalar_bit_op_tests!(U8Vec3, 0, 2);
+    impl_vec3_bit_op_tests!(U8Vec3, 0, 2);
+
+    impl_vec3_overflow_tests!(u8, U8Vec3, BVec3);
+    impl_vec3_add_signed_tests!(u8, U8Vec3, I8Vec3);
+}
+
+mod i16vec3 {
+    use glam::{i16vec3, BVec3, I16Vec3, IVec3, UVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(6, mem::size_of::<I16Vec3>());
+        assert_eq!(2, mem::align_of::<I16Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    impl_vec3_signed_tests!(i16, i16vec3, I16Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(i16, i16vec3);
+
+    impl_vec3_scalar_shift_op_tests!(I16Vec3, -2, 2);
+    impl_vec3_shift_op_tests!(I16Vec3);
+
+    impl_vec3_scalar_bit_op_tests!(I16Vec3, -2, 2);
+    impl_vec3_bit_op_tests!(I16Vec3, -2, 2);
+
+    impl_vec3_overflow_tests!(i16, I16Vec3, BVec3);
+    impl_vec3_floor_div_tests!(i16, i16vec3);
+}
+
+mod u16vec3 {
+    use glam::{u16vec3, BVec3, I16Vec3, IVec3, U16Vec3, UVec3};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(6, mem::size_of::<U16Vec3>());
+        assert_eq!(2, mem::align_of::<U16Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    impl_vec3_tests!(u16, u16vec3, U16Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(u16, u16vec3);
+
+    impl_vec3_scalar_shift_op_tests!(U16Vec3, 0, 2);
+    impl_vec3_shift_op_tests!(U16Vec3);
+
+    impl_vec3_scalar_bit_op_tests!(U16Vec3, 0, 2);
+    impl_vec3_bit_op_tests!(U16Vec3, 0, 2);
+
+    impl_vec3_overflow_tests!(u16, U16Vec3, BVec3);
+    impl_vec3_add_signed_tests!(u16, U16Vec3, I16Vec3);
 }
@@ -5,6 +5,21 @@ mod support;
 
 macro_rules! impl_vec4_tests {
     ($t:ident, $new:ident, $vec4:ident, $vec3:ident, $vec2:ident, $mask:ident) => {
+        glam_test!(test_div_rem_euclid, {
+            let a = $new(7 as $t, 8 as $t, 9 as $t, 10 as $t);
+            let b = $new(2 as $t, 3 as $t, 4 as $t, 3 as $t);
+            assert_eq!($new(3 as $t, 2 as $t, 2 as $t, 3 as $t), a.div_euclid(b));
+            assert_eq!($new(1 as $t, 2 as $t, 1 as $t, 1 as $t), a.rem_euclid(b));
+            assert_eq!(
+                $new(1 as $t, 2 as $t, 2 as $t, 2 as $t),
+                a.div_euclid_scalar(4 as $t)
+            );
+            assert_eq!(
+                $new(3 as $t, 0 as $t, 1 as $t, 2 as $t),
+                a.rem_euclid_scalar(4 as $t)
+            );
+        });
+
         glam_test!(test_const, {
             const V0: $vec4 = $vec4::splat(1 as $t);
             const V1: $vec4 = $vec4::new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
@@ -167,7 +182,10 @@ macro_rules! impl_vec4_tests {
             assert_eq!($new(2 as $t, 4 as $t, 8 as $t, 16 as $t), 0 as $t + a);
             assert_eq!($new(0 as $t, 0 as $t, 0 as $t, 0 as $t), a - a);
             assert_eq!($new(14 as $t, 12 as $t, 8 as $t, 0 as $t), 16 as $t - a);
-            assert_eq!($new(4 as $t, 16 as $t, 64 as $t, 256 as $t), a * a);
+            assert_eq!(
+                $new(4 as $t, 16 as $t, 64 as $t, 64 as $t),
+                a * $new(2 as $t, 4 as $t, 8 as $t, 4 as $t)
+            );
             assert_eq!($new(4 as $t, 8 as $t, 16 as $t, 32 as $t), a * 2 as $t);
             assert_eq!($new(4 as $t, 8 as $t, 16 as $t, 32 as $t), 2 as $t * a);
             assert_eq!($new(1 as $t, 1 as $t, 1 as $t, 1 as $t), a / a);
@@ -605,6 +623,24 @@ macro_rules! impl_vec4_signed_tests {
     ($t:ident, $new:ident, $vec4:ident, $vec3:ident, $vec2:ident, $mask:ident) => {
         impl_vec4_tests!($t, $new, $vec4, $vec3, $vec2, $mask);
 
+        glam_test!(test_div_rem_euclid_signed, {
+            let a = $new(-7 as $t, 7 as $t, -8 as $t, 8 as $t);
+            let b = $new(2 as $t, -2 as $t, 3 as $t, -3 as $t);
+            assert_eq!(
+                $new(-4 as $t, -3 as $t, -3 as $t, -2 as $t),
+                a.div_euclid(b)
+            );
+            assert_eq!($new(1 as $t, 1 as $t, 1 as $t, 2 as $t), a.rem_euclid(b));
+            assert_eq!(
+                $new(3 as $t, -2 as $t, 3 as $t, -2 as $t),
+                a.div_euclid_scalar(-3 as $t)
+            );
+            assert_eq!(
+                $new(2 as $t, 1 as $t, 1 as $t, 2 as $t),
+                a.rem_euclid_scalar(-3 as $t)
+            );
+        });
+
         glam_test!(test_neg, {
             let a = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
             assert_eq!((-1 as $t, -2 as $t, -3 as $t, -4 as $t), (-a).into());
@@ -880,6 +916,11 @@ macro_rules! impl_vec4_float_tests {
             );
         });
 
+        glam_test!(test_fract_negative, {
+            let a = $new(1.35, -1.5, 2.25, -0.25);
+            assert_approx_eq!(a.fract(), $new(0.35, 0.5, 0.25, 0.75));
+        });
+
         glam_test!(test_ceil, {
             assert_eq!(
                 $vec4::new(1.35, 1.5, -1.5, 1234.1234).ceil(),
@@ -1158,6 +1199,141 @@ macro_rules! impl_vec4_bit_op_tests {
         });
     };
 }
+macro_rules! impl_vec4_floor_div_tests {
+    ($t:ident, $new:ident) => {
+        glam_test!(test_floor_div, {
+            let a = $new(-7 as $t, 7 as $t, -8 as $t, 8 as $t);
+            let b = $new(2 as $t, -2 as $t, 3 as $t, -3 as $t);
+            assert_eq!($new(-4 as $t, -4 as $t, -3 as $t, -3 as $t), a.floor_div(b));
+            assert_eq!(
+                $new(-3 as $t, 2 as $t, -3 as $t, 2 as $t),
+                a.floor_div_scalar(3 as $t)
+            );
+            assert_eq!(a.div_euclid(b.abs()), a.floor_div(b.abs()));
+        });
+    };
+}
+
+macro_rules! impl_vec4_overflow_tests {
+    ($t:ty, $vec4:ident, $mask:ident) => {
+        glam_test!(test_wrapping_ops, {
+            let a = $vec4::new(<$t>::MAX, 1, 1, 1);
+            assert_eq!(
+                $vec4::new(<$t>::MIN, 2, 2, 2),
+                a.wrapping_add($vec4::splat(1))
+            );
+            assert_eq!(
+                $vec4::new(<$t>::MAX, 0, 0, 0),
+                $vec4::new(<$t>::MIN, 1, 1, 1).wrapping_sub($vec4::splat(1))
+            );
+            assert_eq!(
+                $vec4::new(<$t>::MAX.wrapping_mul(2), 2, 2, 2),
+                a.wrapping_mul($vec4::splat(2))
+            );
+            assert_eq!(
+                $vec4::new(<$t>::MAX, 1, 1, 1),
+                a.wrapping_div($vec4::splat(1))
+            );
+            should_panic!({ $vec4::splat(1).wrapping_div($vec4::ZERO) });
+        });
+
+        glam_test!(test_saturating_ops, {
+            let a = $vec4::new(<$t>::MAX, 1, 1, 1);
+            assert_eq!(
+                $vec4::new(<$t>::MAX, 2, 2, 2),
+                a.saturating_add($vec4::splat(1))
+            );
+            assert_eq!(
+                $vec4::new(<$t>::MIN, 0, 0, 0),
+                $vec4::new(<$t>::MIN, 1, 1, 1).saturating_sub($vec4::splat(1))
+            );
+            assert_eq!(
+                $vec4::new(<$t>::MAX, 2, 2, 2),
+                a.saturating_mul($vec4::splat(2))
+            );
+            assert_eq!(
+                $vec4::new(<$t>::MAX, 1, 1, 1),
+                a.saturating_div($vec4::splat(1))
+            );
+            should_panic!({ $vec4::splat(1).saturating_div($vec4::ZERO) });
+        });
+
+        glam_test!(test_checked_ops, {
+            let a = $vec4::new(<$t>::MAX, 1, 1, 1);
+            assert_eq!(None, a.checked_add($vec4::splat(1)));
+            assert_eq!(
+                Some($vec4::splat(3)),
+                $vec4::splat(1).checked_add($vec4::splat(2))
+            );
+            assert_eq!(
+                None,
+                $vec4::new(<$t>::MIN, 1, 1, 1).checked_sub($vec4::splat(1))
+            );
+            assert_eq!(
+                Some($vec4::splat(1)),
+                $vec4::splat(3).checked_sub($vec4::splat(2))
+            );
+            assert_eq!(None, a.checked_mul($vec4::splat(2)));
+            assert_eq!(
+                Some($vec4::splat(6)),
+                $vec4::splat(3).checked_mul($vec4::splat(2))
+            );
+            assert_eq!(None, $vec4::splat(1).checked_div($vec4::new(1, 1, 1, 0)));
+            assert_eq!(
+                Some($vec4::splat(3)),
+                $vec4::splat(6).checked_div($vec4::splat(2))
+            );
+        });
+
+        glam_test!(test_overflowing_ops, {
+            let a = $vec4::new(<$t>::MAX, 1, 1, 1);
+            assert_eq!(
+                (
+                    $vec4::new(<$t>::MIN, 2, 2, 2),
+                    $mask::new(true, false, false, false)
+                ),
+                a.overflowing_add($vec4::splat(1))
+            );
+            assert_eq!(
+                (
+                    $vec4::new(<$t>::MAX, 0, 0, 0),
+                    $mask::new(true, false, false, false)
+                ),
+                $vec4::new(<$t>::MIN, 1, 1, 1).overflowing_sub($vec4::splat(1))
+            );
+            assert_eq!(
+                (
+                    $vec4::new(<$t>::MAX.wrapping_mul(2), 2, 2, 2),
+                    $mask::new(true, false, false, false)
+                ),
+                a.overflowing_mul($vec4::splat(2))
+            );
+            assert_eq!(
+                ($vec4::splat(3), $mask::FALSE),
+                $vec4::splat(6).overflowing_div($vec4::splat(2))
+            );
+        });
+    };
+}
+
+macro_rules! impl_vec4_add_signed_tests {
+    ($t:ty, $vec4:ident, $ivec4:ident) => {
+        glam_test!(test_add_signed, {
+            let a = $vec4::new(<$t>::MAX, 1, 1, 1);
+            let b = $ivec4::new(1, -2, -2, -2);
+            assert_eq!(
+                $vec4::new(0, <$t>::MAX, <$t>::MAX, <$t>::MAX),
+                a.wrapping_add_signed(b)
+            );
+            assert_eq!($vec4::new(<$t>::MAX, 0, 0, 0), a.saturating_add_signed(b));
+            assert_eq!(
+                $vec4::new(<$t>::MAX, 3, 3, 3),
+                $vec4::new(<$t>::MAX - 1, 5, 5, 5).saturating_add_signed(b)
+            );
+        });
+    };
+}
+
 mod vec4 {
     #[cfg(any(
         not(any(target_feature = "sse2", target_feature = "simd128")),
@@ -1225,7 +1401,7 @@ mod vec4 {
     }
 
     glam_test!(test_as, {
-        use glam::{DVec4, I64Vec4, IVec4, U64Vec4, UVec4};
+        use glam::{DVec4, I16Vec4, I64Vec4, I8Vec4, IVec4, U16Vec4, U64Vec4, U8Vec4, UVec4};
         assert_eq!(
             DVec4::new(-1.0, -2.0, -3.0, -4.0),
             Vec4::new(-1.0, -2.0, -3.0, -4.0).as_dvec4()
@@ -1333,6 +1509,60 @@ mod vec4 {
             U64Vec4::new(1, 2, 3, 4).as_i64vec4()
         );
         assert_eq!(UVec4::new(1, 2, 3, 4), U64Vec4::new(1, 2, 3, 4).as_uvec4());
+
+        assert_eq!(
+            I8Vec4::new(-1, -2, -3, -4),
+            Vec4::new(-1.0, -2.0, -3.0, -4.0).as_i8vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            Vec4::new(1.0, 2.0, 3.0, 4.0).as_u8vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(-1, -2, -3, -4),
+            DVec4::new(-1.0, -2.0, -3.0, -4.0).as_i16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            IVec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            U64Vec4::new(1, 2, 3, 4).as_u8vec4()
+        );
+
+        assert_eq!(
+            Vec4::new(-1.0, -2.0, -3.0, -4.0),
+            I8Vec4::new(-1, -2, -3, -4).as_vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(-1, -2, -3, -4),
+            I8Vec4::new(-1, -2, -3, -4).as_i16vec4()
+        );
+        assert_eq!(
+            DVec4::new(1.0, 2.0, 3.0, 4.0),
+            U8Vec4::new(1, 2, 3, 4).as_dvec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            U8Vec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+        assert_eq!(
+            IVec4::new(-1, -2, -3, -4),
+            I16Vec4::new(-1, -2, -3, -4).as_ivec4()
+        );
+        assert_eq!(
+            I8Vec4::new(-1, -2, -3, -4),
+            I16Vec4::new(-1, -2, -3, -4).as_i8vec4()
+        );
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            U16Vec4::new(1, 2, 3, 4).as_u64vec4()
+        );
+        assert_eq!(
+            I64Vec4::new(1, 2, 3, 4),
+            U16Vec4::new(1, 2, 3, 4).as_i64vec4()
+        );
     });
 
     glam_test!(test_vec3a, {
@@ -1366,10 +1596,27 @@ mod dvec4 {
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(32, mem::size_of::<DVec4>());
-        #[cfg(not(feature = "cuda"))]
+        #[cfg(not(any(
+            feature = "cuda",
+            all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            )
+        )))]
         assert_eq!(mem::align_of::<f64>(), mem::align_of::<DVec4>());
-        #[cfg(feature = "cuda")]
+        #[cfg(all(
+            feature = "cuda",
+            not(all(
+                target_feature = "avx",
+                not(any(feature = "core-simd", feature = "scalar-math"))
+            ))
+        ))]
         assert_eq!(16, mem::align_of::<DVec4>());
+        #[cfg(all(
+            target_feature = "avx",
+            not(any(feature = "core-simd", feature = "scalar-math"))
+        ))]
+        assert_eq!(32, mem::align_of::<DVec4>());
         assert_eq!(4, mem::size_of::<BVec4>());
         assert_eq!(1, mem::align_of::<BVec4>());
     });
@@ -1399,6 +1646,9 @@ mod ivec4 {
 
     impl_vec4_scalar_bit_op_tests!(IVec4, -2, 2);
     impl_vec4_bit_op_tests!(IVec4, -2, 2);
+
+    impl_vec4_overflow_tests!(i32, IVec4, BVec4);
+    impl_vec4_floor_div_tests!(i32, ivec4);
 }
 
 mod uvec4 {
@@ -1423,6 +1673,242 @@ mod uvec4 {
 
     impl_vec4_scalar_bit_op_tests!(UVec4, 0, 2);
     impl_vec4_bit_op_tests!(UVec4, 0, 2);
+
+    impl_vec4_overflow_tests!(u32, UVec4, BVec4);
+    impl_vec4_add_signed_tests!(u32, UVec4, IVec4);
+}
+
+mod ivec4a {
+    #[cfg(any(
+        not(any(target_feature = "sse2", feature = "core-simd")),
+        feature = "scalar-math"
+    ))]
+    use glam::BVec4;
+    #[cfg(all(
+        any(target_feature = "sse2", feature = "core-simd"),
+        not(feature = "scalar-math")
+    ))]
+    use glam::BVec4A;
+    use glam::{ivec4a, IVec2, IVec3, IVec4, IVec4A, UVec4A, Vec4};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<IVec4A>());
+        assert_eq!(16, mem::align_of::<IVec4A>());
+    });
+
+    #[cfg(all(
+        any(target_feature = "sse2", feature = "core-simd"),
+        not(feature = "scalar-math")
+    ))]
+    impl_vec4_signed_tests!(i32, ivec4a, IVec4A, IVec3, IVec2, BVec4A);
+
+    #[cfg(any(
+        not(any(target_feature = "sse2", feature = "core-simd")),
+        feature = "scalar-math"
+    ))]
+    impl_vec4_signed_tests!(i32, ivec4a, IVec4A, IVec3, IVec2, BVec4);
+    impl_vec4_eq_hash_tests!(i32, ivec4a);
+
+    impl_vec4_scalar_shift_op_tests!(IVec4A, -2, 2);
+    mod shift_ivec4a_by_ivec4a {
+        use super::*;
+        impl_vec4_shift_op_test!(IVec4A, IVec4A, 0, 2);
+    }
+    mod shift_ivec4a_by_uvec4a {
+        use super::*;
+        impl_vec4_shift_op_test!(IVec4A, UVec4A, 0, 2);
+    }
+
+    impl_vec4_scalar_bit_op_tests!(IVec4A, -2, 2);
+    impl_vec4_bit_op_tests!(IVec4A, -2, 2);
+
+    #[cfg(all(
+        any(target_feature = "sse2", feature = "core-simd"),
+        not(feature = "scalar-math")
+    ))]
+    impl_vec4_overflow_tests!(i32, IVec4A, BVec4A);
+
+    #[cfg(any(
+        not(any(target_feature = "sse2", feature = "core-simd")),
+        feature = "scalar-math"
+    ))]
+    impl_vec4_overflow_tests!(i32, IVec4A, BVec4);
+    impl_vec4_floor_div_tests!(i32, ivec4a);
+
+    glam_test!(test_wrapping, {
+        let a = IVec4A::new(i32::MAX, i32::MIN, 0x1234_5678, -0x7654_3210);
+        let b = IVec4A::new(1, -1, 0x1357_9bdf, 0x0246_8ace);
+        assert_eq!(
+            IVec4::from(a).wrapping_add(IVec4::from(b)),
+            IVec4::from(a.wrapping_add(b))
+        );
+        assert_eq!(
+            IVec4::from(a).wrapping_sub(IVec4::from(b)),
+            IVec4::from(a.wrapping_sub(b))
+        );
+        assert_eq!(
+            IVec4::from(a).wrapping_mul(IVec4::from(b)),
+            IVec4::from(a.wrapping_mul(b))
+        );
+    });
+
+    glam_test!(test_overflow_panics, {
+        // matches `IVec4`: overflow panics when debug assertions are enabled
+        if cfg!(debug_assertions) {
+            should_panic!({ IVec4A::splat(i32::MAX) + IVec4A::ONE });
+            should_panic!({ IVec4A::splat(i32::MIN) - IVec4A::ONE });
+            should_panic!({ IVec4A::splat(i32::MAX) * IVec4A::splat(2) });
+            should_panic!({ -IVec4A::splat(i32::MIN) });
+            should_panic!({ IVec4A::splat(i32::MIN).abs() });
+            should_panic!({ IVec4A::splat(i32::MAX).dot(IVec4A::ONE) });
+            should_panic!({ IVec4A::ONE << 32 });
+            should_panic!({ IVec4A::ONE >> IVec4A::splat(32) });
+        }
+    });
+
+    glam_test!(test_shift, {
+        let a = IVec4A::new(1, -2, 3, -4);
+        assert_eq!(IVec4A::new(2, -4, 6, -8), a << 1);
+        assert_eq!(IVec4A::new(0, -1, 1, -2), a >> 1);
+        assert_eq!(a >> 1, a >> 1u64);
+        assert_eq!(a << 1, a << IVec4A::splat(1));
+        assert_eq!(a >> 1, a >> UVec4A::splat(1));
+    });
+
+    glam_test!(test_abs_signum, {
+        let a = IVec4A::new(-3, 0, 7, i32::MIN + 1);
+        assert_eq!(IVec4A::new(3, 0, 7, i32::MAX), a.abs());
+        assert_eq!(IVec4A::new(-1, 0, 1, -1), a.signum());
+        assert_eq!(0b1001, a.is_negative_bitmask());
+    });
+
+    glam_test!(test_conversions, {
+        let a = IVec4::new(1, -2, 3, -4);
+        assert_eq!(a, IVec4::from(IVec4A::from(a)));
+        assert_eq!(Vec4::new(1.0, -2.0, 3.0, -4.0), IVec4A::from(a).as_vec4());
+        assert_eq!(
+            [1, u32::MAX - 1, 3, u32::MAX - 3],
+            IVec4A::from(a).as_uvec4a().to_array()
+        );
+    });
+}
+
+mod uvec4a {
+    #[cfg(any(
+        not(any(target_feature = "sse2", feature = "core-simd")),
+        feature = "scalar-math"
+    ))]
+    use glam::BVec4;
+    #[cfg(all(
+        any(target_feature = "sse2", feature = "core-simd"),
+        not(feature = "scalar-math")
+    ))]
+    use glam::BVec4A;
+    use glam::{uvec4a, IVec4A, UVec2, UVec3, UVec4, UVec4A, Vec4};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<UVec4A>());
+        assert_eq!(16, mem::align_of::<UVec4A>());
+    });
+
+    #[cfg(all(
+        any(target_feature = "sse2", feature = "core-simd"),
+        not(feature = "scalar-math")
+    ))]
+    impl_vec4_tests!(u32, uvec4a, UVec4A, UVec3, UVec2, BVec4A);
+
+    #[cfg(any(
+        not(any(target_feature = "sse2", feature = "core-simd")),
+        feature = "scalar-math"
+    ))]
+    impl_vec4_tests!(u32, uvec4a, UVec4A, UVec3, UVec2, BVec4);
+    impl_vec4_eq_hash_tests!(u32, uvec4a);
+
+    impl_vec4_scalar_shift_op_tests!(UVec4A, 0, 2);
+    mod shift_uvec4a_by_ivec4a {
+        use super::*;
+        impl_vec4_shift_op_test!(UVec4A, IVec4A, 0, 2);
+    }
+    mod shift_uvec4a_by_uvec4a {
+        use super::*;
+        impl_vec4_shift_op_test!(UVec4A, UVec4A, 0, 2);
+    }
+
+    impl_vec4_scalar_bit_op_tests!(UVec4A, 0, 2);
+    impl_vec4_bit_op_tests!(UVec4A, 0, 2);
+
+    glam_test!(test_unsigned_range, {
+        // values above `i32::MAX` must not be treated as negative
+        let a = UVec4A::new(u32::MAX, 0x8000_0000, 1, 0x7fff_ffff);
+        let b = UVec4A::new(0x8000_0000, 0x7fff_ffff, u32::MAX, 2);
+        assert_eq!(UVec4A::new(0x8000_0000, 0x7fff_ffff, 1, 2), a.min(b));
+        assert_eq!(
+            UVec4A::new(u32::MAX, 0x8000_0000, u32::MAX, 0x7fff_ffff),
+            a.max(b)
+        );
+        assert_eq!(0b1011, a.cmpgt(b).bitmask());
+        assert_eq!(0b0100, a.cmplt(b).bitmask());
+        assert_eq!(1, a.min_element());
+        assert_eq!(u32::MAX, a.max_element());
+        assert_eq!(
+            UVec4A::new(0x7fff_ffff, 0x4000_0000, 0, 0x3fff_ffff),
+            a >> 1
+        );
+    });
+
+    #[cfg(all(
+        any(target_feature = "sse2", feature = "core-simd"),
+        not(feature = "scalar-math")
+    ))]
+    impl_vec4_overflow_tests!(u32, UVec4A, BVec4A);
+
+    #[cfg(any(
+        not(any(target_feature = "sse2", feature = "core-simd")),
+        feature = "scalar-math"
+    ))]
+    impl_vec4_overflow_tests!(u32, UVec4A, BVec4);
+    impl_vec4_add_signed_tests!(u32, UVec4A, IVec4A);
+
+    glam_test!(test_wrapping, {
+        let a = UVec4A::new(u32::MAX, 0, 0x1234_5678, 0x8765_4321);
+        let b = UVec4A::new(1, 1, 0x1357_9bdf, 0x0246_8ace);
+        assert_eq!(
+            UVec4::from(a).wrapping_add(UVec4::from(b)),
+            UVec4::from(a.wrapping_add(b))
+        );
+        assert_eq!(
+            UVec4::from(a).wrapping_sub(UVec4::from(b)),
+            UVec4::from(a.wrapping_sub(b))
+        );
+        assert_eq!(
+            UVec4::from(a).wrapping_mul(UVec4::from(b)),
+            UVec4::from(a.wrapping_mul(b))
+        );
+    });
+
+    glam_test!(test_overflow_panics, {
+        // matches `UVec4`: overflow panics when debug assertions are enabled
+        if cfg!(debug_assertions) {
+            should_panic!({ UVec4A::splat(u32::MAX) + UVec4A::ONE });
+            should_panic!({ UVec4A::ZERO - UVec4A::ONE });
+            should_panic!({ UVec4A::splat(u32::MAX) * UVec4A::splat(2) });
+            should_panic!({ UVec4A::splat(u32::MAX).dot(UVec4A::ONE) });
+            should_panic!({ UVec4A::ONE << 32 });
+            should_panic!({ UVec4A::ONE >> UVec4A::splat(32) });
+        }
+    });
+
+    glam_test!(test_conversions, {
+        let a = UVec4::new(1, u32::MAX, 3, 0x8000_0000);
+        assert_eq!(a, UVec4::from(UVec4A::from(a)));
+        assert_eq!(
+            Vec4::new(1.0, u32::MAX as f32, 3.0, 0x8000_0000u32 as f32),
+            UVec4A::from(a).as_vec4()
+        );
+        assert_eq!([1, -1, 3, i32::MIN], UVec4A::from(a).as_ivec4a().to_array());
+    });
 }
 
 mod i64vec4 {
@@ -1447,10 +1933,13 @@ mod i64vec4 {
 
     impl_vec4_scalar_bit_op_tests!(I64Vec4, -2, 2);
     impl_vec4_bit_op_tests!(I64Vec4, -2, 2);
+
+    impl_vec4_overflow_tests!(i64, I64Vec4, BVec4);
+    impl_vec4_floor_div_tests!(i64, i64vec4);
 }
 
 mod u64vec4 {
-    use glam::{u64vec4, BVec4, IVec4, U64Vec2, U64Vec3, U64Vec4, UVec4};
+    use glam::{u64vec4, BVec4, I64Vec4, IVec4, U64Vec2, U64Vec3, U64Vec4, UVec4};
 
     glam_test!(test_align, {
         use std::mem;
@@ -1471,4 +1960,115 @@ mod u64vec4 {
 
     impl_vec4_scalar_bit_op_tests!(U64Vec4, 0, 2);
     impl_vec4_bit_op_tests!(U64Vec4, 0, 2);
+
+    impl_vec4_overflow_tests!(u64, U64Vec4, BVec4);
+    impl_vec4_add_signed_tests!(u64, U64Vec4, I64Vec4);
+}
+
+mod i8vec4 {
+    use glam::{i8vec4, BVec4, I8Vec2, I8Vec3, I8Vec4, IVec4, UVec4};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(4, mem::size_of::<I8Vec4>());
+        #[cfg(not(feature = "cuda"))]
+        assert_eq!(1, mem::align_of::<I8Vec4>());
+        #[cfg(feature = "cuda")]
+        assert_eq!(4, mem::align_of::<I8Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    impl_vec4_signed_tests!(i8, i8vec4, I8Vec4, I8Vec3, I8Vec2, BVec4);
+    impl_vec4_eq_hash_tests!(i8, i8vec4);
+
+    impl_vec4_scalar_shift_op_tests!(I8Vec4, -2, 2);
+    impl_vec4_shift_op_tests!(I8Vec4);
+
+    impl_vec4_scalar_bit_op_tests!(I8Vec4, -2, 2);
+    impl_vec4_bit_op_tests!(I8Vec4, -2, 2);
+
+    impl_vec4_overflow_tests!(i8, I8Vec4, BVec4);
+    impl_vec4_floor_div_tests!(i8, i8vec4);
+}
+
+mod u8vec4 {
+    use glam::{u8vec4, BVec4, I8Vec4, IVec4, U8Vec2, U8Vec3, U8Vec4, UVec4};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(4, mem::size_of::<U8Vec4>());
+        #[cfg(not(feature = "cuda"))]
+        assert_eq!(1, mem::align_of::<U8Vec4>());
+        #[cfg(feature = "cuda")]
+        assert_eq!(4, mem::align_of::<U8Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    impl_vec4_tests!(u8, u8vec4, U8Vec4, U8Vec3, U8Vec2, BVec4);
+    impl_vec4_eq_hash_tests!(u8, u8vec4);
+
+    impl_vec4_scalar_shift_op_tests!(U8Vec4, 0, 2);
+    impl_vec4_shift_op_tests!(U8Vec4);
+
+    impl_vec4_scalar_bit_op_tests!(U8Vec4, 0, 2);
+    impl_vec4_bit_op_tests!(U8Vec4, 0, 2);
+
+    impl_vec4_overflow_tests!(u8, U8Vec4, BVec4);
+    impl_vec4_add_signed_tests!(u8, U8Vec4, I8Vec4);
+}
+
+mod i16vec4 {
+    use glam::{i16vec4, BVec4, I16Vec2, I16Vec3, I16Vec4, IVec4, UVec4};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(8, mem::size_of::<I16Vec4>());
+        #[cfg(not(feature = "cuda"))]
+        assert_eq!(2, mem::align_of::<I16Vec4>());
+        #[cfg(feature = "cuda")]
+        assert_eq!(8, mem::align_of::<I16Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    impl_vec4_signed_tests!(i16, i16vec4, I16Vec4, I16Vec3, I16Vec2, BVec4);
+    impl_vec4_eq_hash_tests!(i16, i16vec4);
+
+    impl_vec4_scalar_shift_op_tests!(I16Vec4, -2, 2);
+    impl_vec4_shift_op_tests!(I16Vec4);
+
+    impl_vec4_scalar_bit_op_tests!(I16Vec4, -2, 2);
+    impl_vec4_bit_op_tests!(I16Vec4, -2, 2);
+
+    impl_vec4_overflow_tests!(i16, I16Vec4, BVec4);
+    impl_vec4_floor_div_tests!(i16, i16vec4);
+}
+
+mod u16vec4 {
+    use glam::{u16vec4, BVec4, I16Vec4, IVec4, U16Vec2, U16Vec3, U16Vec4, UVec4};
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(8, mem::size_of::<U16Vec4>());
+        #[cfg(not(feature = "cuda"))]
+        assert_eq!(2, mem::align_of::<U16Vec4>());
+        #[cfg(feature = "cuda")]
+        assert_eq!(8, mem::align_of::<U16Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    impl_vec4_tests!(u16, u16vec4, U16Vec4, U16Vec3, U16Vec2, BVec4);
+    impl_vec4_eq_hash_tests!(u16, u16vec4);
+
+    impl_vec4_scalar_shift_op_tests!(U16Vec4, 0, 2);
+    impl_vec4_shift_op_tests!(U16Vec4);
+
+    impl_vec4_scalar_bit_op_tests!(U16Vec4, 0, 2);
+    impl_vec4_bit_op_tests!(U16Vec4, 0, 2);
+
+    impl_vec4_overflow_tests!(u16, U16Vec4, BVec4);
+    impl_vec4_add_signed_tests!(u16, U16Vec4, I16Vec4);
 }
@@ -0,0 +1,228 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_wide_tests {
+    ($n:literal, $f:ident, $b:ident, $vec3:ident, $vec4:ident, $quat:ident) => {
+        use glam::wide::{$b, $f, $quat, $vec3, $vec4};
+        use glam::{EulerRot, Mat4, Quat, Vec3, Vec4};
+
+        fn vec3s() -> [Vec3; $n] {
+            let mut out = [Vec3::ZERO; $n];
+            for (i, v) in out.iter_mut().enumerate() {
+                let i = i as f32;
+                *v = Vec3::new(i + 1.0, 2.0 - i * 0.5, i * i - 3.0);
+            }
+            out
+        }
+
+        fn vec4s() -> [Vec4; $n] {
+            let mut out = [Vec4::ZERO; $n];
+            for (v, v3) in out.iter_mut().zip(vec3s().iter()) {
+                *v = v3.extend(v3.x - v3.z);
+            }
+            out
+        }
+
+        fn quats() -> [Quat; $n] {
+            let mut out = [Quat::IDENTITY; $n];
+            for (i, q) in out.iter_mut().enumerate() {
+                let i = i as f32;
+                *q = Quat::from_euler(EulerRot::YXZ, i * 0.7, 1.0 - i * 0.3, i * 0.2 - 0.5);
+            }
+            out
+        }
+
+        glam_test!(test_lanes, {
+            let mut a = [0.0; $n];
+            for (i, v) in a.iter_mut().enumerate() {
+                *v = i as f32 - 2.0;
+            }
+            let v = $f::from_array(a);
+            assert_eq!(a, v.to_array());
+            assert_eq!($n, $f::LANES);
+            assert_eq!([1.0; $n], $f::ONE.to_array());
+            assert_eq!($f::ZERO, $f::default());
+            assert_eq!($f::splat(3.0), $f::ONE + $f::splat(2.0));
+            assert_eq!($f::splat(-2.0), -$f::splat(2.0));
+            assert_eq!($f::splat(0.25), $f::splat(4.0).recip());
+            assert_eq!($f::splat(3.0), $f::splat(9.0).sqrt());
+            assert_eq!(v.abs().to_array(), a.map(f32::abs));
+
+            let lt = v.cmplt($f::ZERO);
+            assert_eq!(lt.to_array(), a.map(|x| x < 0.0));
+            assert_eq!(v.cmpge($f::ZERO), !lt);
+            assert_eq!(
+                $f::select(lt, $f::NEG_ONE, $f::ONE).to_array(),
+                a.map(|x| if x < 0.0 { -1.0 } else { 1.0 })
+            );
+            assert!(lt.any());
+            assert!(!lt.all());
+            assert!($b::TRUE.all());
+            assert!(!$b::FALSE.any());
+            assert_eq!(0, (lt & !lt).bitmask());
+            assert_eq!((1 << $n) - 1, (lt | !lt).bitmask());
+
+            let mut b = [1.0; $n];
+            b[0] = f32::NAN;
+            b[1] = f32::INFINITY;
+            let finite = $f::from_array(b).is_finite().to_array();
+            assert!(!finite[0]);
+            assert!(!finite[1]);
+            assert!(finite[2..].iter().all(|f| *f));
+        });
+
+        glam_test!(test_transpose, {
+            let a = vec3s();
+            let v = $vec3::from_array(a);
+            assert_eq!(a, v.to_array());
+            assert_eq!(v, $vec3::from(a));
+            assert_eq!(a, <[Vec3; $n]>::from(v));
+            assert_eq!([Vec3::Y; $n], $vec3::Y.to_array());
+
+            let a = vec4s();
+            let v = $vec4::from_array(a);
+            assert_eq!(a, v.to_array());
+            assert_eq!(v.truncate(), $vec3::from_array(vec3s()));
+            assert_eq!(v, v.truncate().extend(v.w));
+
+            let a = quats();
+            let q = $quat::from_array(a);
+            assert_eq!(a, q.to_array());
+            assert_eq!([Quat::IDENTITY; $n], $quat::IDENTITY.to_array());
+        });
+
+        glam_test!(test_vec3, {
+            let a = vec3s();
+            let mut b = vec3s();
+            b.reverse();
+            let (va, vb) = ($vec3::from_array(a), $vec3::from_array(b));
+            let dot = va.dot(vb).to_array();
+            let length = va.length().to_array();
+            let distance = va.distance(vb).to_array();
+            let cross = va.cross(vb).to_array();
+            let normalized = va.normalize().to_array();
+            let min = va.min(vb).to_array();
+            let max = va.max(vb).to_array();
+            let lerp = va.lerp(vb, $f::splat(0.25)).to_array();
+            let sum = (va + vb).to_array();
+            let scaled = (va * $f::splat(2.0)).to_array();
+            for i in 0..$n {
+                assert_eq!(a[i].dot(b[i]), dot[i]);
+                assert_eq!(a[i].length(), length[i]);
+                assert_eq!(a[i].distance(b[i]), distance[i]);
+                assert_eq!(a[i].cross(b[i]), cross[i]);
+                assert_eq!(a[i].normalize(), normalized[i]);
+                assert_eq!(a[i].min(b[i]), min[i]);
+                assert_eq!(a[i].max(b[i]), max[i]);
+                assert_eq!(a[i].lerp(b[i], 0.25), lerp[i]);
+                assert_eq!(a[i] + b[i], sum[i]);
+                assert_eq!(a[i] * 2.0, scaled[i]);
+            }
+
+            let mut c = a;
+            c[0] = Vec3::ZERO;
+            let normalized = $vec3::from_array(c).normalize_or_zero().to_array();
+            for i in 0..$n {
+                assert_eq!(c[i].normalize_or_zero(), normalized[i]);
+            }
+
+            let mask = va.x.cmpgt($f::splat(2.0));
+            let selected = $vec3::select(mask, va, vb).to_array();
+            for i in 0..$n {
+                assert_eq!(if a[i].x > 2.0 { a[i] } else { b[i] }, selected[i]);
+            }
+
+            let mut v = va;
+            v += vb;
+            v *= $f::splat(0.5);
+            assert_eq!((va + vb) * $f::splat(0.5), v);
+            assert_eq!($vec3::ZERO, va - va);
+            assert_eq!(-va, $vec3::ZERO - va);
+        });
+
+        glam_test!(test_vec4, {
+            let a = vec4s();
+            let mut b = vec4s();
+            b.reverse();
+            let (va, vb) = ($vec4::from_array(a), $vec4::from_array(b));
+            let dot = va.dot(vb).to_array();
+            let length = va.length().to_array();
+            let normalized = va.normalize().to_array();
+            let product = (va * vb).to_array();
+            for i in 0..$n {
+                assert_eq!(a[i].dot(b[i]), dot[i]);
+                assert_eq!(a[i].length(), length[i]);
+                assert_approx_eq!(a[i].normalize(), normalized[i]);
+                assert_eq!(a[i] * b[i], product[i]);
+            }
+        });
+
+        glam_test!(test_mat4_transform, {
+            let m = Mat4::from_scale_rotation_translation(
+                Vec3::new(1.0, 2.0, 0.5),
+                Quat::from_rotation_y(0.5),
+                Vec3::new(1.0, -2.0, 3.0),
+            );
+            let a = vec3s();
+            let v = $vec3::from_array(a);
+            let points = v.transform_point3(&m).to_array();
+            let vectors = v.transform_vector3(&m).to_array();
+            for i in 0..$n {
+                assert_approx_eq!(m.transform_point3(a[i]), points[i], 1e-5);
+                assert_approx_eq!(m.transform_vector3(a[i]), vectors[i], 1e-5);
+            }
+
+            let a = vec4s();
+            let v = (m * $vec4::from_array(a)).to_array();
+            for i in 0..$n {
+                assert_approx_eq!(m * a[i], v[i], 1e-5);
+            }
+        });
+
+        glam_test!(test_quat, {
+            let a = quats();
+            let mut b = quats();
+            b.reverse();
+            let v = vec3s();
+            let (qa, qb, vv) = (
+                $quat::from_array(a),
+                $quat::from_array(b),
+                $vec3::from_array(v),
+            );
+            let rotated = (qa * vv).to_array();
+            let product = (qa * qb).to_array();
+            let broadcast = (a[1] * vv).to_array();
+            let conjugate = qa.conjugate().to_array();
+            let length = qa.length().to_array();
+            for i in 0..$n {
+                assert_approx_eq!(a[i] * v[i], rotated[i], 1e-5);
+                assert_approx_eq!(a[i] * b[i], product[i], 1e-6);
+                assert_approx_eq!(a[1] * v[i], broadcast[i], 1e-5);
+                assert_eq!(a[i].conjugate(), conjugate[i]);
+                assert_approx_eq!(1.0, length[i], 1e-6);
+            }
+
+            let scaled = $quat::from_xyzw(qa.x, qa.y, qa.z, qa.w * $f::splat(2.0));
+            let normalized = scaled.normalize().to_array();
+            for i in 0..$n {
+                let q = Quat::from_xyzw(a[i].x, a[i].y, a[i].z, a[i].w * 2.0);
+                assert_approx_eq!(q.normalize(), normalized[i], 1e-6);
+            }
+
+            let mask = $vec3::from_array(v).x.cmplt($f::splat(2.5));
+            let selected = $quat::select(mask, qa, $quat::IDENTITY).to_array();
+            for i in 0..$n {
+                let expected = if v[i].x < 2.5 { a[i] } else { Quat::IDENTITY };
+                assert_eq!(expected, selected[i]);
+            }
+        });
+    };
+}
+
+mod x4 {
+    impl_wide_tests!(4, F32x4, B32x4, Vec3x4, Vec4x4, Quatx4);
+}
+
+mod x8 {
+    impl_wide_tests!(8, F32x8, B32x8, Vec3x8, Vec4x8, Quatx8);
+}